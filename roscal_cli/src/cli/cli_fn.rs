@@ -1,30 +1,232 @@
-use crate::models::workspace::{Validator, Workspace};
+use crate::models::{
+    model::ModelWarning,
+    workspace::{CliError, Output, Reporter, Validator, Workspace},
+};
+
+use super::cli_opts::{
+    AddResource, BuildPackage, Commands, Convert, Coverage, Dissect, Edit,
+    ExtractResources, ListResources, Merge, OscalCli, Package, PackageCommands,
+    Query, RemoveResource, Render, ResolveProfile, Resources,
+    ResourcesCommands, Validate, ValidateChain, VerifyPackage, VerifyResources,
+};
+
+fn print_warnings(warnings: &[ModelWarning]) {
+    for warning in warnings {
+        eprintln!("Warning: {warning}")
+    }
+}
+
+/// Print the warnings, then the content unless it went to the output file
+fn print_output(output: Output) {
+    print_warnings(&output.warnings);
+    if let Some(content) = output.content {
+        println!("{content}");
+    }
+}
+
+/// Run the command given on the command line
+pub async fn run(cli: &OscalCli) -> Result<(), CliError> {
+    match cli.command {
+        Commands::Dissect(ref opts) => run_dissect(opts).await,
+        Commands::Merge(ref opts) => run_merge(opts).await,
+        Commands::Edit(ref opts) => run_edit(opts).await,
+        Commands::Convert(ref opts) => run_convert(opts).await,
+        Commands::Validate(ref opts) => run_validate(opts).await,
+        Commands::ValidateChain(ref opts) => run_validate_chain(opts).await,
+        Commands::ResolveProfile(ref opts) => run_resolve_profile(opts).await,
+        Commands::Coverage(ref opts) => run_coverage(opts).await,
+        Commands::Render(ref opts) => run_render(opts).await,
+        Commands::Query(ref opts) => run_query(opts).await,
+        Commands::Resources(ref opts) => run_resources(opts).await,
+        Commands::Package(ref opts) => run_package(opts).await,
+        Commands::ShowDissect => show_dissect().await,
+    }
+}
+
+pub async fn run_dissect(opts: &Dissect) -> Result<(), CliError> {
+    let ctx = Workspace::dissect(opts).await?;
+    print_warnings(&ctx.warnings);
+    println!(
+        "Dissect operation successful!\nYou can locate your workspace at: {}",
+        &ctx.root
+    );
+
+    Ok(())
+}
+
+pub async fn run_merge(opts: &Merge) -> Result<(), CliError> {
+    let ctx = Workspace::merge(opts).await?;
+    print_warnings(&ctx.warnings);
+    print!(
+        "You can locate you merge manifest at: {}",
+        ctx.manifest.display()
+    );
+    if let Some(backup) = &ctx.original_backup {
+        println!("You can locate the original file at: {}", backup.display());
+    }
+    println!(
+        "Merge Operation Successful!\nYou can locate you merged {} file at: {}",
+        ctx.output_format
+            .as_ref()
+            .map(|opt| opt.extension().to_uppercase())
+            .unwrap_or_default(),
+        &ctx.output_file
+    );
+
+    Ok(())
+}
+
+pub async fn run_edit(opts: &Edit) -> Result<(), CliError> {
+    print_output(Workspace::edit(opts).await?);
 
-use super::cli_opts::{Dissect, Merge, Validate};
+    Ok(())
+}
+
+pub async fn run_convert(opts: &Convert) -> Result<(), CliError> {
+    print_output(Workspace::convert(opts).await?);
+
+    Ok(())
+}
+
+pub async fn run_validate(opts: &Validate) -> Result<(), CliError> {
+    let report = Validator::validate(opts).await?;
+    print_warnings(&report.warnings);
+    println!("{report}");
+
+    Ok(())
+}
+
+pub async fn run_validate_chain(opts: &ValidateChain) -> Result<(), CliError> {
+    let report = Validator::validate_chain(opts).await?;
+    println!("{report}");
+
+    match report.issues.len() {
+        0 => Ok(()),
+        n => Err(CliError::BrokenChain(n)),
+    }
+}
+
+pub async fn run_resolve_profile(
+    opts: &ResolveProfile,
+) -> Result<(), CliError> {
+    let resolution = Workspace::resolve_profile(opts).await?;
+    print_warnings(&resolution.warnings);
+    match &opts.output_file {
+        Some(file) => println!(
+            "{resolution}\nYou can locate the resolved catalog at: {}",
+            file.display()
+        ),
+        None => {
+            println!("{}", serde_json::to_string_pretty(&resolution.catalog)?)
+        }
+    }
+
+    Ok(())
+}
 
-pub async fn run_dissect(
-    opts: &Dissect,
-) -> Result<(), Box<dyn std::error::Error>> {
-    Workspace::dissect(opts).await?;
+pub async fn run_coverage(opts: &Coverage) -> Result<(), CliError> {
+    print_output(Reporter::coverage(opts).await?);
 
     Ok(())
 }
 
-pub async fn run_merge(opts: &Merge) -> Result<(), Box<dyn std::error::Error>> {
-    Workspace::merge(opts).await?;
+pub async fn run_render(opts: &Render) -> Result<(), CliError> {
+    print_output(Reporter::render(opts).await?);
 
     Ok(())
 }
 
-pub async fn run_validate(
-    opts: &Validate,
-) -> Result<(), Box<dyn std::error::Error>> {
-    Validator::validate(opts).await?;
+pub async fn run_query(opts: &Query) -> Result<(), CliError> {
+    print_output(Reporter::query(opts).await?);
 
     Ok(())
 }
 
-pub async fn show_dissect() -> Result<(), Box<dyn std::error::Error>> {
+pub async fn run_resources(opts: &Resources) -> Result<(), CliError> {
+    match opts.command {
+        ResourcesCommands::Add(ref opts) => run_add_resource(opts).await,
+        ResourcesCommands::List(ref opts) => run_list_resources(opts).await,
+        ResourcesCommands::Extract(ref opts) => {
+            run_extract_resources(opts).await
+        }
+        ResourcesCommands::Remove(ref opts) => run_remove_resource(opts).await,
+        ResourcesCommands::Verify(ref opts) => run_verify_resources(opts).await,
+    }
+}
+
+pub async fn run_add_resource(opts: &AddResource) -> Result<(), CliError> {
+    let uuid = Workspace::add_resource(opts).await?;
+    println!("Added resource {uuid}");
+
+    Ok(())
+}
+
+pub async fn run_list_resources(opts: &ListResources) -> Result<(), CliError> {
+    let list = Workspace::list_resources(opts).await?;
+    println!("{list}");
+
+    Ok(())
+}
+
+pub async fn run_extract_resources(
+    opts: &ExtractResources,
+) -> Result<(), CliError> {
+    for (uuid, path) in Workspace::extract_resources(opts).await? {
+        println!("Extracted resource {uuid} to {}", path.display());
+    }
+
+    Ok(())
+}
+
+pub async fn run_remove_resource(
+    opts: &RemoveResource,
+) -> Result<(), CliError> {
+    Workspace::remove_resource(opts).await?;
+    println!("Removed resource {}", opts.uuid);
+
+    Ok(())
+}
+
+pub async fn run_verify_resources(
+    opts: &VerifyResources,
+) -> Result<(), CliError> {
+    let report = Validator::verify_resources(opts).await?;
+    println!("{report}");
+
+    match report.failures() {
+        0 => Ok(()),
+        n => Err(CliError::HashMismatch(n)),
+    }
+}
+
+pub async fn run_package(opts: &Package) -> Result<(), CliError> {
+    match opts.command {
+        PackageCommands::Build(ref opts) => run_build_package(opts).await,
+        PackageCommands::Verify(ref opts) => run_verify_package(opts).await,
+    }
+}
+
+pub async fn run_build_package(opts: &BuildPackage) -> Result<(), CliError> {
+    let manifest = Workspace::build_package(opts).await?;
+    println!(
+        "{manifest}\nYou can locate the package at: {}",
+        opts.output_file.display()
+    );
+
+    Ok(())
+}
+
+pub async fn run_verify_package(opts: &VerifyPackage) -> Result<(), CliError> {
+    let report = Validator::verify_package(opts).await?;
+    println!("{report}");
+
+    match report.failures() {
+        0 => Ok(()),
+        n => Err(CliError::BrokenPackage(n)),
+    }
+}
+
+pub async fn show_dissect() -> Result<(), CliError> {
     let desc = r#"
 Available Model Options:
 
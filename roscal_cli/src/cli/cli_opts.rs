@@ -27,16 +27,91 @@ pub enum Commands {
     /// Full Example:
     /// roscal merge --dir /dir/existing_workspace
     /// --output-dir /dir/merged
+    /// --output-file /dir/merged/catalog.yaml
     /// --output-format yaml
     /// --update-uuid v4
+    /// --update-metadata
+    /// --bump-version minor
     #[clap(verbatim_doc_comment)]
     Merge(#[clap(flatten)] Merge),
+    /// Apply declarative edit operations to an OSCAL model file
+    /// The result is validated before it is written
+    /// Full Example:
+    /// roscal edit --file /dir/catalog.json
+    /// --model Catalog
+    /// --ops /dir/ops.yaml
+    /// --output-file /dir/catalog_edited.json
+    #[clap(verbatim_doc_comment)]
+    Edit(#[clap(flatten)] Edit),
+    /// Convert an OSCAL model file between encodings
+    /// The model is validated while converting
+    /// Full Example:
+    /// cat /dir/catalog.yaml | roscal convert --to json
+    /// --compact
+    /// --output-file /dir/catalog.json
+    #[clap(verbatim_doc_comment)]
+    Convert(#[clap(flatten)] Convert),
     /// Validate a specific type of OSCAL model file
     /// Full Example:
     /// roscal validate --file /dir/catalog.json
     /// --model Catalog
     #[clap(verbatim_doc_comment)]
     Validate(#[clap(flatten)] Validate),
+    /// Validate an OSCAL model file together with the documents it imports
+    /// Full Example:
+    /// roscal validate-chain --file /dir/ar.json
+    #[clap(verbatim_doc_comment)]
+    ValidateChain(#[clap(flatten)] ValidateChain),
+    /// Resolve a profile into a catalog of the controls it selects
+    /// Resolved catalogs are cached by the content of each profile and its imports
+    /// Full Example:
+    /// roscal resolve-profile --file /dir/profile.json
+    /// --cache-dir /dir/cache
+    /// --output-file /dir/profile_resolved_catalog.json
+    #[clap(verbatim_doc_comment)]
+    ResolveProfile(#[clap(flatten)] ResolveProfile),
+    /// Report control coverage of an SSP against a baseline
+    /// Full Example:
+    /// roscal coverage --baseline /dir/profile_resolved_catalog.json
+    /// --model Catalog
+    /// --ssp /dir/ssp.json
+    /// --output-format markdown
+    /// --output-file /dir/coverage.md
+    #[clap(verbatim_doc_comment)]
+    Coverage(#[clap(flatten)] Coverage),
+    /// Render a Catalog, SSP or POA&M model file into a human-readable document
+    /// Full Example:
+    /// roscal render --file /dir/ssp.json
+    /// --model Ssp
+    /// --output-format html
+    /// --output-file /dir/ssp.html
+    #[clap(verbatim_doc_comment)]
+    Render(#[clap(flatten)] Render),
+    /// Select nodes of an OSCAL model file with a path expression
+    /// Full Example:
+    /// roscal query --file /dir/catalog.json
+    /// --model Catalog
+    /// --expr "..controls[props[name=status][value=withdrawn]]"
+    /// --output-format table
+    #[clap(verbatim_doc_comment)]
+    Query(#[clap(flatten)] Query),
+    /// Manage the back-matter resources of an OSCAL model file
+    /// Full Example:
+    /// roscal resources add --file /dir/ssp.json
+    /// --model Ssp
+    /// --source /dir/policy.pdf
+    /// --title "Access Control Policy"
+    /// --embed
+    /// --hash-algorithms SHA-256,SHA3-512
+    #[clap(verbatim_doc_comment)]
+    Resources(#[clap(flatten)] Resources),
+    /// Bundle an SSP with everything it references into a single archive
+    /// Full Example:
+    /// roscal package build --ssp /dir/ssp.json
+    /// --include /dir/ap.json,/dir/ar.json,/dir/poam.json
+    /// --output-file /dir/package.tar
+    #[clap(verbatim_doc_comment)]
+    Package(#[clap(flatten)] Package),
     /// Show available models and blocks for dissect operation
     ShowDissect,
 }
@@ -48,8 +123,9 @@ pub struct Dissect {
     pub file: PathBuf,
     /// Specifiy which OSCAL model to be processed
     /// Run `roscal show-dissect` for available models
+    /// Optional. Will be detected from the file if unspecified
     #[arg(long, verbatim_doc_comment)]
-    pub model: String,
+    pub model: Option<String>,
     /// Specifiy which blocks to be dissected
     /// Duplicate blocks will be combined
     /// Run `roscal show-dissect` for available blocks
@@ -64,6 +140,10 @@ pub struct Dissect {
     /// see https://pages.nist.gov/metaschema/specification/datatypes/#markup-data-types
     #[arg(long, verbatim_doc_comment)]
     pub parse_markup: bool,
+    /// Keep fields unknown to the model instead of failing
+    /// Each one is reported as a warning and kept in its block
+    #[arg(long, verbatim_doc_comment)]
+    pub lenient: bool,
 }
 
 #[derive(Args, Debug, Default, Clone)]
@@ -76,13 +156,93 @@ pub struct Merge {
     /// Optional. Can be run directly in existing workspace
     #[arg(long, verbatim_doc_comment)]
     pub output_dir: Option<PathBuf>,
-    /// Options: json or yaml
-    #[arg(long)]
-    pub output_format: String,
+    /// Options: json, yaml or xml
+    /// XML is export only, it can't be dissected again
+    /// Optional. Will use the format of the written file if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub output_format: Option<String>,
+    /// Specify the path of the merged file
+    /// Optional. Will write the dissected file name to output dir if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub output_file: Option<PathBuf>,
+    /// Write the merged file back to the original dissected file
+    /// The original is kept alongside with a `.bak` suffix, which must not exist
+    #[arg(long, conflicts_with = "output_file", verbatim_doc_comment)]
+    pub in_place: bool,
     /// Options: v4 or v5 (as in uuid version)
     /// Optional. No-op if model unchanged or uuid manually updated
     #[arg(long, verbatim_doc_comment)]
     pub update_uuid: Option<String>,
+    /// Refresh last-modified and append a revision listing the changed blocks
+    /// Optional. No-op if model unchanged
+    #[arg(long, verbatim_doc_comment)]
+    pub update_metadata: bool,
+    /// Options: major, minor, patch or date
+    /// Optional. Increment the metadata version if model changed
+    #[arg(long, verbatim_doc_comment)]
+    pub bump_version: Option<String>,
+    /// Keep fields unknown to the model instead of failing
+    /// Optional. Always on for workspaces dissected with --lenient
+    #[arg(long, verbatim_doc_comment)]
+    pub lenient: bool,
+}
+
+#[derive(Args, Debug, Default, Clone)]
+pub struct Edit {
+    /// Location of OSCAL model file
+    #[arg(long)]
+    pub file: PathBuf,
+    /// Model type of OSCAL model file
+    /// Run `roscal show-dissect` for available models
+    /// Optional. Will be detected from the file if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub model: Option<String>,
+    /// Location of a json or yaml file listing the operations
+    /// Applied in order. Supported operations:
+    ///   - op: set, path: <query>, field: <name>, value: <any>
+    ///   - op: add-prop, control: <id>, prop: <property>
+    ///   - op: add-party, party: <party>
+    ///   - op: remove-control, control: <id>
+    ///   - op: bump-version, version: <version> (optional),
+    ///     scheme: major, minor, patch or date (optional, patch by default)
+    #[arg(long, verbatim_doc_comment)]
+    pub ops: PathBuf,
+    /// Specify where the edited file should be written
+    /// Optional. Will overwrite the model file if unspecified
+    /// Written as json for `.json` files and yaml otherwise
+    #[arg(long, verbatim_doc_comment)]
+    pub output_file: Option<PathBuf>,
+    /// Keep fields unknown to the model instead of failing
+    /// Each one is reported as a warning and written back unchanged
+    #[arg(long, verbatim_doc_comment)]
+    pub lenient: bool,
+}
+
+#[derive(Args, Debug, Default, Clone)]
+pub struct Convert {
+    /// Location of a json or yaml OSCAL model file
+    /// Optional. Will read from stdin if unspecified or `-`
+    #[arg(long, verbatim_doc_comment)]
+    pub file: Option<PathBuf>,
+    /// Model type of OSCAL model file
+    /// Optional. Will be detected from the file if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub model: Option<String>,
+    /// Options: json, yaml or xml
+    /// XML is export only, it can't be read back by roscal
+    #[arg(long, verbatim_doc_comment)]
+    pub to: String,
+    /// Write json or xml on a single line instead of pretty printing
+    #[arg(long)]
+    pub compact: bool,
+    /// Keep fields unknown to the model instead of failing
+    /// Each one is reported as a warning and written back unchanged
+    #[arg(long, verbatim_doc_comment)]
+    pub lenient: bool,
+    /// Specify where the converted file should be written
+    /// Optional. Will print to stdout if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub output_file: Option<PathBuf>,
 }
 
 #[derive(Args, Debug, Default, Clone)]
@@ -92,6 +252,308 @@ pub struct Validate {
     pub file: PathBuf,
     /// Model type of OSCAL model file
     /// Run `roscal show-dissect` for available models
+    /// Optional. Will be detected from the file if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub model: Option<String>,
+    /// Accept fields unknown to the model, reporting each as a warning
+    /// The official JSON schema check still lists them
+    #[arg(long, verbatim_doc_comment)]
+    pub lenient: bool,
+}
+
+#[derive(Args, Debug, Default, Clone)]
+pub struct ValidateChain {
+    /// Location of OSCAL model file
+    /// Imports are followed from here, the model types are detected
+    /// SSP -> profile -> catalog, AP -> SSP, AR -> AP and POA&M -> SSP
+    #[arg(long, verbatim_doc_comment)]
+    pub file: PathBuf,
+    /// Directory mirroring remote hrefs
+    /// `https://example.com/dir/file.json` is read from
+    /// `<mirror-dir>/example.com/dir/file.json`
+    /// Optional. Remote hrefs are not followed if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub mirror_dir: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, Default, Clone)]
+pub struct ResolveProfile {
+    /// Location of the profile file
+    /// Imports are followed from here, through catalogs and other profiles
+    #[arg(long, verbatim_doc_comment)]
+    pub file: PathBuf,
+    /// Location of the resolved catalog, json or yaml by its extension
+    /// Optional. Will print json to stdout if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub output_file: Option<PathBuf>,
+    /// Directory caching the resolved catalog of each profile
+    /// Entries are keyed by a hash of the profile and everything it imports
+    /// Optional. Nothing is cached if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub cache_dir: Option<PathBuf>,
+    /// Directory mirroring remote hrefs
+    /// `https://example.com/dir/file.json` is read from
+    /// `<mirror-dir>/example.com/dir/file.json`
+    /// Optional. Remote hrefs can't be resolved if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub mirror_dir: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, Default, Clone)]
+pub struct Coverage {
+    /// Location of the baseline OSCAL model file
+    /// Either a catalog (e.g. a resolved profile catalog) or a profile
     #[arg(long, verbatim_doc_comment)]
-    pub model: String,
+    pub baseline: PathBuf,
+    /// Model type of the baseline file
+    /// Options: Catalog or Profile
+    /// Profile baselines are resolved first, like with resolve-profile
+    /// Optional. Will be detected from the file if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub model: Option<String>,
+    /// Location of the System Security Plan model file
+    #[arg(long)]
+    pub ssp: PathBuf,
+    /// Options: json, csv or markdown
+    #[arg(long)]
+    pub output_format: String,
+    /// Specify where the report should be written
+    /// Optional. Will print to stdout if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub output_file: Option<PathBuf>,
+    /// Directory mirroring remote hrefs of a profile baseline
+    /// `https://example.com/dir/file.json` is read from
+    /// `<mirror-dir>/example.com/dir/file.json`
+    /// Optional. Remote hrefs can't be resolved if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub mirror_dir: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, Default, Clone)]
+pub struct Render {
+    /// Location of OSCAL model file
+    #[arg(long)]
+    pub file: PathBuf,
+    /// Model type of OSCAL model file
+    /// Options: Catalog, Ssp or Poam
+    /// Optional. Will be detected from the file if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub model: Option<String>,
+    /// Options: markdown or html
+    #[arg(long)]
+    pub output_format: String,
+    /// Specify where the document should be written
+    /// Optional. Will print to stdout if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub output_file: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, Default, Clone)]
+pub struct Query {
+    /// Location of OSCAL model file
+    #[arg(long)]
+    pub file: PathBuf,
+    /// Model type of OSCAL model file
+    /// Run `roscal show-dissect` for available models
+    /// Optional. Will be detected from the file if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub model: Option<String>,
+    /// Path expression over OSCAL field names
+    /// e.g. `system-security-plan..components[type=service].title`
+    /// `..b` searches at any depth, `a[b=v]` keeps nodes with a matching `b`
+    #[arg(long, verbatim_doc_comment)]
+    pub expr: String,
+    /// Options: json, yaml or table
+    #[arg(long)]
+    pub output_format: String,
+    /// Specify where the matches should be written
+    /// Optional. Will print to stdout if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub output_file: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct Resources {
+    #[clap(subcommand)]
+    pub command: ResourcesCommands,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Subcommand)]
+pub enum ResourcesCommands {
+    /// Add a resource linking to a local file, with its hashes
+    /// The file is embedded as base64 too with --embed
+    #[clap(verbatim_doc_comment)]
+    Add(#[clap(flatten)] AddResource),
+    /// List the resources with their links and embedded files
+    List(#[clap(flatten)] ListResources),
+    /// Write embedded base64 payloads to disk
+    Extract(#[clap(flatten)] ExtractResources),
+    /// Remove a resource no longer referenced by a `#uuid` link
+    Remove(#[clap(flatten)] RemoveResource),
+    /// Recompute the hashes declared by resource links
+    /// Local link targets and base64 payloads are checked, remote links are skipped
+    /// Fails if any hash does not match
+    #[clap(verbatim_doc_comment)]
+    Verify(#[clap(flatten)] VerifyResources),
+}
+
+#[derive(Args, Debug, Default, Clone)]
+pub struct VerifyResources {
+    /// Location of OSCAL model file
+    /// Relative links are resolved against its directory
+    #[arg(long, verbatim_doc_comment)]
+    pub file: PathBuf,
+    /// Model type of OSCAL model file
+    /// Run `roscal show-dissect` for available models
+    /// Optional. Will be detected from the file if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub model: Option<String>,
+    /// Directory mirroring remote links
+    /// `https://example.com/dir/file.pdf` is read from
+    /// `<mirror-dir>/example.com/dir/file.pdf`
+    /// Optional. Remote links are skipped if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub mirror_dir: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, Default, Clone)]
+pub struct AddResource {
+    /// Location of OSCAL model file
+    #[arg(long)]
+    pub file: PathBuf,
+    /// Model type of OSCAL model file
+    /// Run `roscal show-dissect` for available models
+    /// Optional. Will be detected from the file if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub model: Option<String>,
+    /// Location of the file to add as a resource
+    /// Linked relative to the model file
+    #[arg(long, verbatim_doc_comment)]
+    pub source: PathBuf,
+    /// Title of the resource
+    /// Optional. Will use the file name if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub title: Option<String>,
+    /// Whether to embed the file as base64 as well as linking to it
+    #[arg(long)]
+    pub embed: bool,
+    /// Algorithms of the hashes to compute for the link
+    /// Options: SHA-224, SHA-256, SHA-384, SHA-512, SHA3-224, SHA3-256, SHA3-384 or SHA3-512
+    #[arg(
+        long,
+        num_args = 1..,
+        value_delimiter = ',',
+        default_value = "SHA-256",
+        verbatim_doc_comment
+    )]
+    pub hash_algorithms: Vec<String>,
+    /// Specify where the updated file should be written
+    /// Optional. Will overwrite the model file if unspecified
+    /// Written as json for `.json` files and yaml otherwise
+    #[arg(long, verbatim_doc_comment)]
+    pub output_file: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, Default, Clone)]
+pub struct ListResources {
+    /// Location of OSCAL model file
+    #[arg(long)]
+    pub file: PathBuf,
+    /// Model type of OSCAL model file
+    /// Run `roscal show-dissect` for available models
+    /// Optional. Will be detected from the file if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub model: Option<String>,
+}
+
+#[derive(Args, Debug, Default, Clone)]
+pub struct ExtractResources {
+    /// Location of OSCAL model file
+    #[arg(long)]
+    pub file: PathBuf,
+    /// Model type of OSCAL model file
+    /// Run `roscal show-dissect` for available models
+    /// Optional. Will be detected from the file if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub model: Option<String>,
+    /// Uuid of the resource to extract
+    /// Optional. Will extract every embedded payload if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub uuid: Option<String>,
+    /// Specify where the payloads should be written
+    /// Named after their base64 filename, or the resource uuid
+    /// Optional. Will use current directory if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub output_dir: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, Default, Clone)]
+pub struct RemoveResource {
+    /// Location of OSCAL model file
+    #[arg(long)]
+    pub file: PathBuf,
+    /// Model type of OSCAL model file
+    /// Run `roscal show-dissect` for available models
+    /// Optional. Will be detected from the file if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub model: Option<String>,
+    /// Uuid of the resource to remove
+    #[arg(long)]
+    pub uuid: String,
+    /// Specify where the updated file should be written
+    /// Optional. Will overwrite the model file if unspecified
+    /// Written as json for `.json` files and yaml otherwise
+    #[arg(long, verbatim_doc_comment)]
+    pub output_file: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct Package {
+    #[clap(subcommand)]
+    pub command: PackageCommands,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Subcommand)]
+pub enum PackageCommands {
+    /// Write an SSP, the documents it imports and the files they link to
+    /// into a tar archive, with a manifest of every file and its SHA-256
+    /// Hrefs between packaged files are rewritten to package-relative paths
+    #[clap(verbatim_doc_comment)]
+    Build(#[clap(flatten)] BuildPackage),
+    /// Check the files of a package against its manifest
+    /// Fails if a file is missing, unlisted, altered or imports something
+    /// outside the package
+    #[clap(verbatim_doc_comment)]
+    Verify(#[clap(flatten)] VerifyPackage),
+}
+
+#[derive(Args, Debug, Default, Clone)]
+pub struct BuildPackage {
+    /// Location of the System Security Plan model file
+    /// Its profile and catalogs are followed through the imports
+    #[arg(long, verbatim_doc_comment)]
+    pub ssp: PathBuf,
+    /// Other documents to package, e.g. component definitions, AP, AR or POA&M
+    /// Their imports and linked files are packaged too
+    /// Optional. Only the SSP and what it references if unspecified
+    #[arg(long, num_args = 1.., value_delimiter = ',', verbatim_doc_comment)]
+    pub include: Vec<PathBuf>,
+    /// Location of the package, an uncompressed tar archive
+    #[arg(long, verbatim_doc_comment)]
+    pub output_file: PathBuf,
+    /// Directory mirroring remote hrefs
+    /// `https://example.com/dir/file.json` is read from
+    /// `<mirror-dir>/example.com/dir/file.json`
+    /// Optional. Remote hrefs are left as is if unspecified
+    #[arg(long, verbatim_doc_comment)]
+    pub mirror_dir: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, Default, Clone)]
+pub struct VerifyPackage {
+    /// Location of the package
+    #[arg(long)]
+    pub file: PathBuf,
 }
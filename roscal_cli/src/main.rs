@@ -1,22 +1,14 @@
-mod cli;
-mod models;
+use std::process::exit;
 
 use clap::Parser;
-use cli::{
-    cli_fn::{run_dissect, run_merge, run_validate, show_dissect},
-    cli_opts::{Commands, OscalCli},
-};
+use roscal_cli::cli::{cli_fn::run, cli_opts::OscalCli};
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+async fn main() {
     let cli = OscalCli::parse();
 
-    match cli.command {
-        Commands::Dissect(ref opts) => run_dissect(opts).await?,
-        Commands::Merge(ref opts) => run_merge(opts).await?,
-        Commands::Validate(ref opts) => run_validate(opts).await?,
-        Commands::ShowDissect => show_dissect().await?,
+    if let Err(e) = run(&cli).await {
+        eprintln!("{:#}", anyhow::Error::from(e));
+        exit(1)
     }
-
-    Ok(())
 }
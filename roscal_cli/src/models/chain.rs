@@ -0,0 +1,799 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use roscal_lib::{
+    assessment::{
+        assessment_plan::{self, AssessmentPlan},
+        assessment_results::{self, AssessmentResults},
+        poam::{self, PlanOfActionAndMilestones},
+    },
+    control::{
+        catalog::{Catalog, Control, ControlGroup},
+        profile::{
+            ImportResource, IncludeContainedControlsWithControl, Profile,
+            SelectControl,
+        },
+    },
+    document::{AnyOscalDocument, ModelType},
+    href::HrefSource,
+    implementation::ssp::SystemSecurityPlan,
+    parse::ParseOptions,
+};
+
+use crate::cli::cli_opts::ValidateChain;
+
+use super::{
+    imports::{ImportError, Imports},
+    workspace::Result,
+};
+
+/// Subject types whose uuids are defined in the system implementation
+const SYSTEM_SUBJECTS: [&str; 3] = ["component", "inventory-item", "user"];
+
+/// A document reached while following the imports
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainDocument {
+    pub path: PathBuf,
+    pub model_type: ModelType,
+    /// Number of imports between the document and the one checked
+    pub depth: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChainIssueKind {
+    /// An import that could not be followed
+    Import(ImportError),
+    /// A control not selected by the upstream catalog or profile
+    UnknownControl {
+        control_id: String,
+        upstream: PathBuf,
+    },
+    /// A subject neither defined by the upstream SSP nor locally
+    UnknownSubject {
+        subject_type: String,
+        uuid: String,
+        upstream: PathBuf,
+    },
+}
+
+/// A broken link of the chain, found in `document`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainIssue {
+    pub document: PathBuf,
+    pub kind: ChainIssueKind,
+}
+
+impl fmt::Display for ChainIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ERROR {}: ", self.document.display())?;
+
+        match &self.kind {
+            ChainIssueKind::Import(e) => write!(f, "{e}"),
+            ChainIssueKind::UnknownControl {
+                control_id,
+                upstream,
+            } => write!(
+                f,
+                "control `{control_id}` is not in {}",
+                upstream.display()
+            ),
+            ChainIssueKind::UnknownSubject {
+                subject_type,
+                uuid,
+                upstream,
+            } => write!(
+                f,
+                "{subject_type} `{uuid}` is not defined in {}",
+                upstream.display()
+            ),
+        }
+    }
+}
+
+/// Outcome of validating the import chain of a model file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChainReport {
+    pub documents: Vec<ChainDocument>,
+    pub issues: Vec<ChainIssue>,
+}
+
+impl ChainReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl fmt::Display for ChainReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for document in &self.documents {
+            writeln!(
+                f,
+                "{}{} {}",
+                "  ".repeat(document.depth),
+                document.model_type,
+                document.path.display()
+            )?;
+        }
+        for issue in &self.issues {
+            writeln!(f, "{issue}")?;
+        }
+
+        write!(
+            f,
+            "{} document(s) checked, {} issue(s)",
+            self.documents.len(),
+            self.issues.len()
+        )
+    }
+}
+
+/// Controls a catalog or profile makes available downstream
+#[derive(Debug, Clone, Default)]
+pub(super) struct Baseline {
+    /// File the controls come from
+    pub(super) source: PathBuf,
+    /// Id of each control with the ids of its child controls
+    pub(super) controls: BTreeMap<String, Vec<String>>,
+}
+
+impl Baseline {
+    /// The control and, if asked, all of its descendants
+    fn select(&self, id: &str, children: bool, col: &mut Vec<String>) {
+        col.push(id.to_owned());
+        if children {
+            for child in self.controls.get(id).into_iter().flatten() {
+                self.select(child, children, col)
+            }
+        }
+    }
+
+    pub(super) fn selection(&self, select: &SelectControl) -> Vec<String> {
+        let children = matches!(
+            select.with_child_controls,
+            Some(IncludeContainedControlsWithControl::Yes)
+        );
+        let mut col = vec![];
+
+        for id in select.with_ids.iter().flatten() {
+            self.select(id, children, &mut col);
+        }
+        for pattern in select.matching.iter().flatten() {
+            let pattern = pattern.pattern.as_deref().unwrap_or("*");
+            for id in self.controls.keys() {
+                if glob_match(pattern, id) {
+                    self.select(id, children, &mut col);
+                }
+            }
+        }
+
+        col
+    }
+
+    /// Ids of the controls an import includes and does not exclude. Ids
+    /// missing from the baseline are kept so callers can report them
+    pub(super) fn import(&self, import: &ImportResource) -> Vec<String> {
+        let mut selected: Vec<String> = match &import.include_controls {
+            Some(include) if import.include_all.is_none() => {
+                include.iter().flat_map(|s| self.selection(s)).collect()
+            }
+            _ => self.controls.keys().cloned().collect(),
+        };
+        let excluded: HashSet<String> = import
+            .exclude_controls
+            .iter()
+            .flatten()
+            .flat_map(|s| self.selection(s))
+            .collect();
+        selected.retain(|id| !excluded.contains(id));
+
+        selected
+    }
+
+    /// Controls of a catalog
+    pub(super) fn from_catalog(path: &Path, catalog: &Catalog) -> Self {
+        let mut baseline = Baseline {
+            source: path.to_path_buf(),
+            ..Default::default()
+        };
+
+        for elt in catalog.catalog.controls.iter().flatten() {
+            collect_control(elt, &mut baseline)
+        }
+
+        for elt in catalog.catalog.groups.iter().flatten() {
+            collect_group(elt, &mut baseline)
+        }
+
+        baseline
+    }
+}
+
+/// Match `*` and `?` wildcards of a profile `matching` pattern
+fn glob_match(pattern: &str, id: &str) -> bool {
+    match pattern.chars().next() {
+        None => id.is_empty(),
+        Some('*') => {
+            let mut chars = id.chars();
+            glob_match(&pattern[1..], id)
+                || (chars.next().is_some()
+                    && glob_match(pattern, chars.as_str()))
+        }
+        Some('?') => {
+            let mut chars = id.chars();
+            chars.next().is_some() && glob_match(&pattern[1..], chars.as_str())
+        }
+        Some(c) => {
+            id.starts_with(c)
+                && glob_match(&pattern[c.len_utf8()..], &id[c.len_utf8()..])
+        }
+    }
+}
+
+fn collect_control(control: &Control, baseline: &mut Baseline) {
+    let children = control.controls.iter().flatten();
+    baseline.controls.insert(
+        control.id.to_string(),
+        children.clone().map(|c| c.id.to_string()).collect(),
+    );
+
+    for elt in children {
+        collect_control(elt, baseline)
+    }
+}
+
+fn collect_group(group: &ControlGroup, baseline: &mut Baseline) {
+    for elt in group.controls.iter().flatten() {
+        collect_control(elt, baseline)
+    }
+
+    for elt in group.groups.iter().flatten() {
+        collect_group(elt, baseline)
+    }
+}
+
+/// What an SSP makes available to assessment documents
+#[derive(Debug, Clone, Default)]
+struct SystemContext {
+    ssp: PathBuf,
+    baseline: Option<Baseline>,
+    /// Uuids of the components, inventory items and users
+    defined: HashSet<String>,
+}
+
+/// Control ids and subjects an assessment document refers to, along with
+/// the components, inventory items and users it defines itself
+#[derive(Debug, Default)]
+struct References {
+    defined: HashSet<String>,
+    /// Type and uuid of each subject
+    subjects: Vec<(String, String)>,
+    controls: Vec<String>,
+}
+
+impl<'ast> assessment_plan::visit::Visit<'ast> for References {
+    fn visit_component(&mut self, node: &'ast assessment_plan::Component) {
+        self.defined.insert(node.uuid.to_string());
+        assessment_plan::visit::walk_component(self, node)
+    }
+
+    fn visit_inventory_item(
+        &mut self,
+        node: &'ast assessment_plan::InventoryItem,
+    ) {
+        self.defined.insert(node.uuid.to_string());
+        assessment_plan::visit::walk_inventory_item(self, node)
+    }
+
+    fn visit_system_user(&mut self, node: &'ast assessment_plan::SystemUser) {
+        self.defined.insert(node.uuid.to_string());
+        assessment_plan::visit::walk_system_user(self, node)
+    }
+
+    fn visit_select_assessment_subject(
+        &mut self,
+        node: &'ast assessment_plan::SelectAssessmentSubject,
+    ) {
+        self.subjects.push((
+            node.select_assessment_subject_type.to_string(),
+            node.subject_uuid.to_string(),
+        ));
+        assessment_plan::visit::walk_select_assessment_subject(self, node)
+    }
+
+    fn visit_uses_component(
+        &mut self,
+        node: &'ast assessment_plan::UsesComponent,
+    ) {
+        self.subjects
+            .push(("component".to_owned(), node.component_uuid.to_string()));
+        assessment_plan::visit::walk_uses_component(self, node)
+    }
+
+    fn visit_select_control(
+        &mut self,
+        node: &'ast assessment_plan::SelectControl,
+    ) {
+        self.controls.push(node.control_id.to_string());
+    }
+}
+
+impl<'ast> assessment_results::visit::Visit<'ast> for References {
+    fn visit_component(&mut self, node: &'ast assessment_results::Component) {
+        self.defined.insert(node.uuid.to_string());
+        assessment_results::visit::walk_component(self, node)
+    }
+
+    fn visit_inventory_item(
+        &mut self,
+        node: &'ast assessment_results::InventoryItem,
+    ) {
+        self.defined.insert(node.uuid.to_string());
+        assessment_results::visit::walk_inventory_item(self, node)
+    }
+
+    fn visit_system_user(
+        &mut self,
+        node: &'ast assessment_results::SystemUser,
+    ) {
+        self.defined.insert(node.uuid.to_string());
+        assessment_results::visit::walk_system_user(self, node)
+    }
+
+    fn visit_select_assessment_subject(
+        &mut self,
+        node: &'ast assessment_results::SelectAssessmentSubject,
+    ) {
+        self.subjects.push((
+            node.select_assessment_subject_type.to_string(),
+            node.subject_uuid.to_string(),
+        ));
+        assessment_results::visit::walk_select_assessment_subject(self, node)
+    }
+
+    fn visit_identifies_the_subject(
+        &mut self,
+        node: &'ast assessment_results::IdentifiesTheSubject,
+    ) {
+        self.subjects.push((
+            node.identifies_the_subject_type.to_string(),
+            node.subject_uuid.to_string(),
+        ));
+        assessment_results::visit::walk_identifies_the_subject(self, node)
+    }
+
+    fn visit_uses_component(
+        &mut self,
+        node: &'ast assessment_results::UsesComponent,
+    ) {
+        self.subjects
+            .push(("component".to_owned(), node.component_uuid.to_string()));
+        assessment_results::visit::walk_uses_component(self, node)
+    }
+
+    fn visit_select_control(
+        &mut self,
+        node: &'ast assessment_results::SelectControl,
+    ) {
+        self.controls.push(node.control_id.to_string());
+    }
+}
+
+impl<'ast> poam::visit::Visit<'ast> for References {
+    fn visit_component(&mut self, node: &'ast poam::Component) {
+        self.defined.insert(node.uuid.to_string());
+        poam::visit::walk_component(self, node)
+    }
+
+    fn visit_inventory_item(&mut self, node: &'ast poam::InventoryItem) {
+        self.defined.insert(node.uuid.to_string());
+        poam::visit::walk_inventory_item(self, node)
+    }
+
+    fn visit_select_assessment_subject(
+        &mut self,
+        node: &'ast poam::SelectAssessmentSubject,
+    ) {
+        self.subjects.push((
+            node.select_assessment_subject_type.to_string(),
+            node.subject_uuid.to_string(),
+        ));
+        poam::visit::walk_select_assessment_subject(self, node)
+    }
+
+    fn visit_identifies_the_subject(
+        &mut self,
+        node: &'ast poam::IdentifiesTheSubject,
+    ) {
+        self.subjects.push((
+            node.identifies_the_subject_type.to_string(),
+            node.subject_uuid.to_string(),
+        ));
+        poam::visit::walk_identifies_the_subject(self, node)
+    }
+
+    fn visit_uses_component(&mut self, node: &'ast poam::UsesComponent) {
+        self.subjects
+            .push(("component".to_owned(), node.component_uuid.to_string()));
+        poam::visit::walk_uses_component(self, node)
+    }
+}
+
+/// Follows the imports of a document, recording what it finds
+struct Resolver {
+    imports: Imports,
+    report: ChainReport,
+    /// Catalogs and profiles already resolved, by canonical path
+    baselines: HashMap<PathBuf, Baseline>,
+}
+
+impl Resolver {
+    fn issue(&mut self, document: &Path, kind: ChainIssueKind) {
+        self.report.issues.push(ChainIssue {
+            document: document.to_path_buf(),
+            kind,
+        })
+    }
+
+    /// Read and parse the document an href points to, if it has one of the
+    /// expected model types
+    fn load(
+        &mut self,
+        href: &str,
+        source: &HrefSource,
+        expected: &[ModelType],
+        depth: usize,
+    ) -> Option<(HrefSource, AnyOscalDocument)> {
+        let parsed = self
+            .imports
+            .load(href, source, expected)
+            .and_then(|imported| self.imports.parse(&imported));
+
+        match parsed {
+            Ok(parsed) => {
+                self.report.documents.push(ChainDocument {
+                    path: parsed.source.path.clone(),
+                    model_type: parsed.document.model_type(),
+                    depth,
+                });
+                Some((parsed.source, parsed.document))
+            }
+            Err(e) => {
+                self.issue(&source.path, ChainIssueKind::Import(e));
+                None
+            }
+        }
+    }
+
+    /// Controls of a catalog, or selected by a profile
+    fn baseline(
+        &mut self,
+        source: &HrefSource,
+        doc: &AnyOscalDocument,
+        depth: usize,
+    ) -> Option<Baseline> {
+        let path = &source.path;
+        let canonical =
+            path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        if let Some(baseline) = self.baselines.get(&canonical) {
+            return Some(baseline.clone());
+        }
+
+        let baseline = match doc {
+            AnyOscalDocument::Catalog(catalog) => {
+                Baseline::from_catalog(path, catalog)
+            }
+            AnyOscalDocument::Profile(profile) => {
+                self.imports.enter(path);
+                let baseline = self.profile_baseline(source, profile, depth);
+                self.imports.leave();
+                baseline
+            }
+            _ => return None,
+        };
+        self.baselines.insert(canonical, baseline.clone());
+
+        Some(baseline)
+    }
+
+    fn profile_baseline(
+        &mut self,
+        source: &HrefSource,
+        profile: &Profile,
+        depth: usize,
+    ) -> Baseline {
+        let path = &source.path;
+        let mut baseline = Baseline {
+            source: path.to_path_buf(),
+            ..Default::default()
+        };
+
+        for import in &profile.profile.imports {
+            let Some((upstream_source, upstream)) = self.load(
+                &import.href,
+                source,
+                &[ModelType::Catalog, ModelType::Profile],
+                depth + 1,
+            ) else {
+                continue;
+            };
+            let Some(upstream) =
+                self.baseline(&upstream_source, &upstream, depth + 1)
+            else {
+                continue;
+            };
+
+            for id in upstream.import(import) {
+                match upstream.controls.get(&id) {
+                    Some(children) => {
+                        baseline.controls.insert(id, children.clone());
+                    }
+                    None => self.issue(
+                        path,
+                        ChainIssueKind::UnknownControl {
+                            control_id: id,
+                            upstream: upstream.source.clone(),
+                        },
+                    ),
+                }
+            }
+        }
+
+        let alters = profile
+            .profile
+            .modify
+            .iter()
+            .flat_map(|m| m.alters.iter().flatten());
+        for alter in alters {
+            if !baseline.controls.contains_key(alter.control_id.as_str()) {
+                self.issue(
+                    path,
+                    ChainIssueKind::UnknownControl {
+                        control_id: alter.control_id.to_string(),
+                        upstream: path.to_path_buf(),
+                    },
+                )
+            }
+        }
+
+        baseline
+    }
+
+    fn check_controls<'a>(
+        &mut self,
+        document: &Path,
+        controls: impl IntoIterator<Item = &'a String>,
+        baseline: &Option<Baseline>,
+    ) {
+        let Some(baseline) = baseline else {
+            return;
+        };
+
+        for id in controls {
+            if !baseline.controls.contains_key(id) {
+                self.issue(
+                    document,
+                    ChainIssueKind::UnknownControl {
+                        control_id: id.clone(),
+                        upstream: baseline.source.clone(),
+                    },
+                )
+            }
+        }
+    }
+
+    fn check_subjects(
+        &mut self,
+        document: &Path,
+        refs: &References,
+        system: &SystemContext,
+    ) {
+        for (subject_type, uuid) in &refs.subjects {
+            if SYSTEM_SUBJECTS.contains(&subject_type.as_str())
+                && !system.defined.contains(uuid)
+                && !refs.defined.contains(uuid)
+            {
+                self.issue(
+                    document,
+                    ChainIssueKind::UnknownSubject {
+                        subject_type: subject_type.clone(),
+                        uuid: uuid.clone(),
+                        upstream: system.ssp.clone(),
+                    },
+                )
+            }
+        }
+    }
+
+    fn ssp(
+        &mut self,
+        source: &HrefSource,
+        ssp: &SystemSecurityPlan,
+        depth: usize,
+    ) -> SystemContext {
+        let path = &source.path;
+        let ssp = &ssp.system_security_plan;
+        let baseline = self
+            .load(
+                &ssp.import_profile.href,
+                source,
+                &[ModelType::Profile],
+                depth + 1,
+            )
+            .and_then(|(profile_source, profile)| {
+                self.baseline(&profile_source, &profile, depth + 1)
+            });
+
+        let controls = ssp
+            .control_implementation
+            .implemented_requirements
+            .iter()
+            .map(|r| &r.control_id);
+        self.check_controls(path, controls, &baseline);
+
+        let system = &ssp.system_implementation;
+        let defined = system
+            .components
+            .iter()
+            .map(|c| c.uuid.to_string())
+            .chain(system.users.iter().map(|u| u.uuid.to_string()))
+            .chain(
+                system
+                    .inventory_items
+                    .iter()
+                    .flatten()
+                    .map(|i| i.uuid.to_string()),
+            )
+            .collect();
+
+        SystemContext {
+            ssp: path.to_path_buf(),
+            baseline,
+            defined,
+        }
+    }
+
+    /// The SSP an assessment document imports
+    fn system(
+        &mut self,
+        href: &str,
+        source: &HrefSource,
+        depth: usize,
+    ) -> Option<SystemContext> {
+        match self.load(
+            href,
+            source,
+            &[ModelType::SystemSecurityPlan],
+            depth + 1,
+        )? {
+            (ssp_source, AnyOscalDocument::SystemSecurityPlan(ssp)) => {
+                Some(self.ssp(&ssp_source, &ssp, depth + 1))
+            }
+            _ => None,
+        }
+    }
+
+    /// The SSP imported by the plan, along with the components, inventory
+    /// items and users the plan defines
+    fn assessment_plan(
+        &mut self,
+        source: &HrefSource,
+        ap: &AssessmentPlan,
+        depth: usize,
+    ) -> Option<SystemContext> {
+        let path = &source.path;
+        let plan = &ap.assessment_plan;
+        let mut system = self.system(&plan.import_ssp.href, source, depth)?;
+
+        let mut refs = References::default();
+        assessment_plan::visit::Visit::visit_assessment_plan(&mut refs, ap);
+        self.check_controls(path, &refs.controls, &system.baseline);
+        self.check_subjects(path, &refs, &system);
+        system.defined.extend(refs.defined);
+
+        Some(system)
+    }
+
+    fn assessment_results(
+        &mut self,
+        source: &HrefSource,
+        ar: &AssessmentResults,
+        depth: usize,
+    ) {
+        let path = &source.path;
+        let results = &ar.assessment_results;
+        let plan = self.load(
+            &results.import_ap.href,
+            source,
+            &[ModelType::AssessmentPlan],
+            depth + 1,
+        );
+        let Some((ap_source, AnyOscalDocument::AssessmentPlan(ap))) = plan
+        else {
+            return;
+        };
+        let Some(system) = self.assessment_plan(&ap_source, &ap, depth + 1)
+        else {
+            return;
+        };
+
+        let mut refs = References::default();
+        assessment_results::visit::Visit::visit_assessment_results(
+            &mut refs, ar,
+        );
+        self.check_controls(path, &refs.controls, &system.baseline);
+        self.check_subjects(path, &refs, &system);
+    }
+
+    fn poam(
+        &mut self,
+        source: &HrefSource,
+        poam: &PlanOfActionAndMilestones,
+        depth: usize,
+    ) {
+        let path = &source.path;
+        let plan = &poam.plan_of_action_and_milestones;
+        let Some(import_ssp) = &plan.import_ssp else {
+            return;
+        };
+        let Some(system) = self.system(&import_ssp.href, source, depth) else {
+            return;
+        };
+
+        let mut refs = References::default();
+        poam::visit::Visit::visit_plan_of_action_and_milestones(
+            &mut refs, poam,
+        );
+        self.check_subjects(path, &refs, &system);
+    }
+}
+
+pub(super) async fn validate_chain(
+    opts: &ValidateChain,
+) -> Result<ChainReport> {
+    let mut resolver = Resolver {
+        imports: Imports::new(ParseOptions::default(), opts.mirror_dir.clone()),
+        report: ChainReport::default(),
+        baselines: HashMap::new(),
+    };
+
+    let parsed = resolver
+        .imports
+        .read(&opts.file, &ModelType::ALL)
+        .and_then(|imported| resolver.imports.parse(&imported));
+    let (source, doc) = match parsed {
+        Ok(parsed) => (parsed.source, parsed.document),
+        Err(e) => {
+            resolver.issue(&opts.file, ChainIssueKind::Import(e));
+            return Ok(resolver.report);
+        }
+    };
+    resolver.report.documents.push(ChainDocument {
+        path: opts.file.clone(),
+        model_type: doc.model_type(),
+        depth: 0,
+    });
+
+    match &doc {
+        AnyOscalDocument::Catalog(_) | AnyOscalDocument::Profile(_) => {
+            resolver.baseline(&source, &doc, 0);
+        }
+        AnyOscalDocument::SystemSecurityPlan(ssp) => {
+            resolver.ssp(&source, ssp, 0);
+        }
+        AnyOscalDocument::AssessmentPlan(ap) => {
+            resolver.assessment_plan(&source, ap, 0);
+        }
+        AnyOscalDocument::AssessmentResults(ar) => {
+            resolver.assessment_results(&source, ar, 0)
+        }
+        AnyOscalDocument::PlanOfActionAndMilestones(poam) => {
+            resolver.poam(&source, poam, 0)
+        }
+        AnyOscalDocument::ComponentDefinition(_) => {}
+    }
+
+    Ok(resolver.report)
+}
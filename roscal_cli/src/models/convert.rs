@@ -0,0 +1,75 @@
+use std::{
+    fs::read_to_string,
+    io::{stdin, Read},
+    str::FromStr,
+};
+
+use roscal_lib::xml;
+use strum::EnumString;
+
+use crate::cli::cli_opts::Convert;
+
+use super::{
+    model::OscalModels,
+    utils::{lenient_options, write_output},
+    workspace::{CliError, Context, Output, Result},
+};
+
+#[derive(Debug, Clone, PartialEq, EnumString)]
+pub enum ConvertOpt {
+    #[strum(serialize = "json")]
+    Json,
+    #[strum(serialize = "yaml")]
+    Yaml,
+    #[strum(serialize = "xml")]
+    Xml,
+}
+
+/// Content of the input file, or of stdin if unspecified or `-`
+fn read_input(opts: &Convert) -> Result<(String, String)> {
+    match &opts.file {
+        Some(file) if file.as_os_str() != "-" => {
+            let content = read_to_string(file).with_context(|| {
+                format!("Could not open model file: `{}`", file.display())
+            })?;
+
+            Ok((file.to_string_lossy().into_owned(), content))
+        }
+        _ => {
+            let mut content = String::new();
+            stdin()
+                .read_to_string(&mut content)
+                .with_context(|| "Could not read model from stdin")?;
+
+            Ok(("stdin".to_owned(), content))
+        }
+    }
+}
+
+pub(super) async fn convert_model(opts: &Convert) -> Result<Output> {
+    let convert_opt = ConvertOpt::from_str(&opts.to)
+        .map_err(|_| CliError::UnknownConvertOpt)?;
+
+    let (source, input) = read_input(opts)?;
+    let model = match &opts.model {
+        Some(model) => {
+            OscalModels::from_str(model).map_err(|_| CliError::UnknownModel)?
+        }
+        None => OscalModels::detect_str(&input, &source)?,
+    };
+
+    let options = lenient_options(opts.lenient);
+    let (root, warnings) = model.parse_value(&input, &source, &options)?;
+    let content = match convert_opt {
+        ConvertOpt::Json if opts.compact => serde_json::to_string(&root)?,
+        ConvertOpt::Json => serde_json::to_string_pretty(&root)?,
+        ConvertOpt::Yaml => serde_yaml::to_string(&root)?,
+        ConvertOpt::Xml if opts.compact => xml::to_string_compact(&root)?,
+        ConvertOpt::Xml => xml::to_string(&root)?,
+    };
+
+    Ok(Output {
+        content: write_output(opts.output_file.as_ref(), content)?,
+        warnings,
+    })
+}
@@ -0,0 +1,405 @@
+use std::{collections::BTreeMap, fmt::Write, str::FromStr};
+
+use roscal_lib::{
+    control::catalog::{Catalog, Control, ControlGroup, Part},
+    implementation::ssp::{ComponentControlImplementation, SystemSecurityPlan},
+    parse::{ParseOptions, ParseWith},
+};
+use serde::Serialize;
+use strum::EnumString;
+
+use crate::cli::cli_opts::Coverage;
+
+use super::{
+    model::OscalModels,
+    resolve::resolve_catalog,
+    utils::write_output,
+    workspace::{CliError, Context, Output, Result},
+};
+
+const UNSPECIFIED_STATUS: &str = "unspecified";
+
+#[derive(Debug, Clone, PartialEq, EnumString)]
+pub enum ReportOpt {
+    #[strum(serialize = "json")]
+    Json,
+    #[strum(serialize = "csv")]
+    Csv,
+    #[strum(serialize = "markdown")]
+    Markdown,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CoverageReport {
+    pub summary: CoverageSummary,
+    pub controls: Vec<ControlCoverage>,
+    /// Controls implemented in the SSP but not selected by the baseline
+    pub unknown_controls: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CoverageSummary {
+    pub total_controls: usize,
+    pub implemented_controls: usize,
+    pub missing_controls: usize,
+    pub controls_without_by_components: usize,
+    pub total_statements: usize,
+    pub implemented_statements: usize,
+    pub missing_statements: usize,
+    /// Count of by-component entries per implementation status state
+    pub implementation_status: BTreeMap<String, usize>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ControlCoverage {
+    pub control_id: String,
+    pub implemented: bool,
+    pub has_by_components: bool,
+    pub implementation_status: Vec<String>,
+    pub statements: Vec<StatementCoverage>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct StatementCoverage {
+    pub statement_id: String,
+    pub implemented: bool,
+    pub has_by_components: bool,
+}
+
+/// A control selected by the baseline along with its statement ids
+struct BaselineControl {
+    id: String,
+    statements: Vec<String>,
+}
+
+fn collect_statement_ids(part: &Part, ids: &mut Vec<String>) {
+    if let Some(id) = &part.id {
+        ids.push(id.to_string())
+    }
+
+    for elt in part.parts.iter().flatten() {
+        if elt.name == "item" {
+            collect_statement_ids(elt, ids)
+        }
+    }
+}
+
+fn collect_control(control: &Control, col: &mut Vec<BaselineControl>) {
+    let mut statements = vec![];
+
+    for part in control.parts.iter().flatten() {
+        if part.name == "statement" {
+            collect_statement_ids(part, &mut statements)
+        }
+    }
+
+    col.push(BaselineControl {
+        id: control.id.to_string(),
+        statements,
+    });
+
+    for elt in control.controls.iter().flatten() {
+        collect_control(elt, col)
+    }
+}
+
+fn collect_group(group: &ControlGroup, col: &mut Vec<BaselineControl>) {
+    for elt in group.controls.iter().flatten() {
+        collect_control(elt, col)
+    }
+
+    for elt in group.groups.iter().flatten() {
+        collect_group(elt, col)
+    }
+}
+
+fn catalog_controls(catalog: &Catalog) -> Vec<BaselineControl> {
+    let mut col = vec![];
+
+    for elt in catalog.catalog.controls.iter().flatten() {
+        collect_control(elt, &mut col)
+    }
+
+    for elt in catalog.catalog.groups.iter().flatten() {
+        collect_group(elt, &mut col)
+    }
+
+    col
+}
+
+fn status_of(by_component: &ComponentControlImplementation) -> String {
+    by_component
+        .implementation_status
+        .as_ref()
+        .map(|s| s.state.clone())
+        .unwrap_or(UNSPECIFIED_STATUS.to_owned())
+}
+
+fn gen_report(
+    baseline: Vec<BaselineControl>,
+    ssp: &SystemSecurityPlan,
+) -> CoverageReport {
+    let reqs = &ssp
+        .system_security_plan
+        .control_implementation
+        .implemented_requirements;
+    let mut report = CoverageReport::default();
+
+    for control in baseline {
+        let req = reqs.iter().find(|r| r.control_id == control.id);
+        let mut coverage = ControlCoverage {
+            control_id: control.id.clone(),
+            implemented: req.is_some(),
+            ..Default::default()
+        };
+
+        if let Some(req) = req {
+            let mut by_components: Vec<&ComponentControlImplementation> =
+                req.by_components.iter().flatten().collect();
+            by_components.extend(
+                req.statements
+                    .iter()
+                    .flatten()
+                    .flat_map(|s| s.by_components.iter().flatten()),
+            );
+
+            coverage.has_by_components = !by_components.is_empty();
+
+            for elt in by_components {
+                let status = status_of(elt);
+                *report
+                    .summary
+                    .implementation_status
+                    .entry(status.clone())
+                    .or_default() += 1;
+
+                if !coverage.implementation_status.contains(&status) {
+                    coverage.implementation_status.push(status)
+                }
+            }
+        }
+
+        for statement_id in control.statements {
+            let statement =
+                req.and_then(|r| r.statements.as_ref()).and_then(|s| {
+                    s.iter().find(|s| s.statement_id == statement_id)
+                });
+
+            coverage.statements.push(StatementCoverage {
+                statement_id,
+                implemented: statement.is_some(),
+                has_by_components: statement
+                    .is_some_and(|s| s.by_components.is_some()),
+            })
+        }
+
+        report.summary.total_controls += 1;
+        if coverage.implemented {
+            report.summary.implemented_controls += 1;
+            if !coverage.has_by_components {
+                report.summary.controls_without_by_components += 1;
+            }
+        } else {
+            report.summary.missing_controls += 1;
+        }
+
+        report.summary.total_statements += coverage.statements.len();
+        report.summary.implemented_statements +=
+            coverage.statements.iter().filter(|s| s.implemented).count();
+
+        report.controls.push(coverage)
+    }
+
+    report.summary.missing_statements =
+        report.summary.total_statements - report.summary.implemented_statements;
+
+    for req in reqs {
+        if !report
+            .controls
+            .iter()
+            .any(|c| c.control_id == req.control_id)
+        {
+            report.unknown_controls.push(req.control_id.clone())
+        }
+    }
+
+    report
+}
+
+/// Field of a CSV record, quoted as RFC 4180 requires when it holds a comma,
+/// a double quote or a line break
+fn csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\r', '\n']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_owned(),
+    }
+}
+
+fn csv_record(output: &mut String, fields: &[&str]) {
+    let fields: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+    let _ = writeln!(output, "{}", fields.join(","));
+}
+
+impl CoverageReport {
+    pub fn to_csv(&self) -> String {
+        let mut output = String::new();
+        csv_record(
+            &mut output,
+            &[
+                "control_id",
+                "statement_id",
+                "implemented",
+                "has_by_components",
+                "implementation_status",
+            ],
+        );
+
+        for control in &self.controls {
+            csv_record(
+                &mut output,
+                &[
+                    &control.control_id,
+                    "",
+                    &control.implemented.to_string(),
+                    &control.has_by_components.to_string(),
+                    &control.implementation_status.join(";"),
+                ],
+            );
+
+            for statement in &control.statements {
+                csv_record(
+                    &mut output,
+                    &[
+                        &control.control_id,
+                        &statement.statement_id,
+                        &statement.implemented.to_string(),
+                        &statement.has_by_components.to_string(),
+                        "",
+                    ],
+                );
+            }
+        }
+
+        output
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let summary = &self.summary;
+        let mut output = String::from("# Control Coverage Report\n\n");
+
+        output.push_str("## Summary\n\n| Metric | Count |\n| --- | --- |\n");
+        for (metric, count) in [
+            ("Controls", summary.total_controls),
+            ("Implemented controls", summary.implemented_controls),
+            ("Missing controls", summary.missing_controls),
+            (
+                "Controls without by-components",
+                summary.controls_without_by_components,
+            ),
+            ("Statements", summary.total_statements),
+            ("Implemented statements", summary.implemented_statements),
+            ("Missing statements", summary.missing_statements),
+        ] {
+            let _ = writeln!(output, "| {metric} | {count} |");
+        }
+
+        output.push_str(
+            "\n## Implementation Status\n\n| State | Count |\n| --- | --- |\n",
+        );
+        for (state, count) in &summary.implementation_status {
+            let _ = writeln!(output, "| {state} | {count} |");
+        }
+
+        output.push_str(
+            "\n## Controls\n\n| Control | Implemented | By-Components | Status | Missing Statements |\n| --- | --- | --- | --- | --- |\n",
+        );
+        for control in &self.controls {
+            let missing: Vec<&str> = control
+                .statements
+                .iter()
+                .filter(|s| !s.implemented)
+                .map(|s| s.statement_id.as_str())
+                .collect();
+
+            let _ = writeln!(
+                output,
+                "| {} | {} | {} | {} | {} |",
+                control.control_id,
+                if control.implemented { "yes" } else { "no" },
+                if control.has_by_components {
+                    "yes"
+                } else {
+                    "no"
+                },
+                control.implementation_status.join(", "),
+                missing.join(", ")
+            );
+        }
+
+        if !self.unknown_controls.is_empty() {
+            output.push_str("\n## Controls Not In Baseline\n\n");
+            for control_id in &self.unknown_controls {
+                let _ = writeln!(output, "- {control_id}");
+            }
+        }
+
+        output
+    }
+}
+
+pub(super) async fn coverage_report(opts: &Coverage) -> Result<Output> {
+    let output_format = ReportOpt::from_str(&opts.output_format)
+        .map_err(|_| CliError::UnknownReportOpt)?;
+
+    let model = match &opts.model {
+        Some(model) => OscalModels::from_str(model)
+            .map_err(|_| CliError::UnknownBaselineModel)?,
+        None => OscalModels::detect(&opts.baseline)?,
+    };
+
+    let baseline = match model {
+        OscalModels::Catalog => catalog_controls(
+            &OscalModels::Catalog
+                .read_catalog(&opts.baseline, &ParseOptions::default())
+                .await?
+                .0,
+        ),
+        OscalModels::Profile => {
+            let resolution =
+                resolve_catalog(&opts.baseline, opts.mirror_dir.clone(), None)
+                    .with_context(|| {
+                        format!(
+                            "Could not resolve the baseline profile {}",
+                            opts.baseline.display()
+                        )
+                    })?;
+            catalog_controls(&Catalog::deserialize_with(
+                &resolution.catalog,
+                &ParseOptions::default(),
+            )?)
+        }
+        _ => return Err(CliError::UnknownBaselineModel),
+    };
+
+    let ssp = OscalModels::Ssp
+        .read_ssp(&opts.ssp, &ParseOptions::default())
+        .await?
+        .0;
+    let report = gen_report(baseline, &ssp);
+
+    let content = match output_format {
+        ReportOpt::Json => serde_json::to_string_pretty(&report)
+            .with_context(|| "Could not parse coverage report to json")?,
+        ReportOpt::Csv => report.to_csv(),
+        ReportOpt::Markdown => report.to_markdown(),
+    };
+
+    Ok(Output {
+        content: write_output(opts.output_file.as_ref(), content)?,
+        ..Default::default()
+    })
+}
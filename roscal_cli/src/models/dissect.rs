@@ -3,7 +3,6 @@ use std::{
     str::FromStr,
 };
 
-use anyhow::{Context, Result};
 use tokio::{
     fs::{create_dir_all, remove_dir_all, File},
     io::{AsyncReadExt, AsyncWriteExt},
@@ -14,8 +13,8 @@ use crate::cli::cli_opts::Dissect;
 use super::{
     model::{
         AssessmentPlanBlock, AssessmentResultsBlock, CatalogBlock,
-        ComponentDefinitionBlock, OscalModels, PoamBlock, ProfileBlock,
-        SspBlock,
+        ComponentDefinitionBlock, ModelWarning, OscalModels, PoamBlock,
+        ProfileBlock, SspBlock,
     },
     utils::{
         gen_created_at, gen_hash_from_path, gen_rand, is_valid_dir,
@@ -27,7 +26,7 @@ use super::{
         is_valid_model, is_valid_poam_block, is_valid_profile_block,
         is_valid_ssp_block,
     },
-    workspace::DissectCtx,
+    workspace::{Context, DissectCtx, Result},
 };
 
 async fn gen_dissect_root(
@@ -36,30 +35,27 @@ async fn gen_dissect_root(
     path: &impl AsRef<Path>,
     output_dir: &Option<PathBuf>,
 ) -> Result<String> {
-    let dirname = if is_valid_file(path) {
-        format!(
-            "{}_{created_at}_{rand}",
-            path.as_ref().file_name().unwrap().to_string_lossy()
-        )
-    } else {
-        std::process::exit(1)
-    };
+    is_valid_file(path)?;
+    let dirname = format!(
+        "{}_{created_at}_{rand}",
+        path.as_ref().file_name().unwrap().to_string_lossy()
+    );
 
     if let Some(dir) = output_dir {
-        if is_valid_dir(dir) {
-            return Ok(format!(
-                "{}/{}",
-                dir.canonicalize()
-                    .with_context(|| {
-                        format!(
-                            "Could not determine the output path: `{}`",
-                            dir.display()
-                        )
-                    })?
-                    .to_string_lossy(),
-                dirname
-            ));
-        }
+        is_valid_dir(dir)?;
+
+        return Ok(format!(
+            "{}/{}",
+            dir.canonicalize()
+                .with_context(|| {
+                    format!(
+                        "Could not determine the output path: `{}`",
+                        dir.display()
+                    )
+                })?
+                .to_string_lossy(),
+            dirname
+        ));
     }
 
     let cur_dir = format!(
@@ -82,251 +78,158 @@ async fn gen_dissect_modifiable(root: &String) -> String {
     format!("{}/modifiable", root)
 }
 
-async fn gen_blocks(opts: &Dissect) -> Result<Vec<String>> {
+async fn gen_blocks(opts: &Dissect, model: &str) -> Result<Vec<String>> {
     let mut blocks_in = opts.blocks.clone();
     blocks_in.dedup();
-    let mut blocks_out: Vec<String> = vec![];
-
-    if is_valid_model(&opts.model) {
-        let models = OscalModels::from_str(&opts.model).with_context(|| {
-            "Could not determine the provided OSCAL model".to_string()
-        })?;
-
-        match models {
-            OscalModels::AssessmentPlan => {
-                if is_valid_assessment_plan_block(&blocks_in) {
-                    if blocks_in[0] == AssessmentPlanBlock::All.to_string() {
-                        let all = vec![
-                            AssessmentPlanBlock::Uuid.to_string(),
-                            AssessmentPlanBlock::Metadata.to_string(),
-                            AssessmentPlanBlock::ImportSsp.to_string(),
-                            AssessmentPlanBlock::LocalDefinitions.to_string(),
-                            AssessmentPlanBlock::TermsAndConditions.to_string(),
-                            AssessmentPlanBlock::ReviewedControls.to_string(),
-                            AssessmentPlanBlock::AssessmentSubjects.to_string(),
-                            AssessmentPlanBlock::AssessmentAssets.to_string(),
-                            AssessmentPlanBlock::Tasks.to_string(),
-                            AssessmentPlanBlock::BackMatter.to_string(),
-                        ];
-                        blocks_out.extend(all);
-
-                        Ok(blocks_out)
-                    } else {
-                        Ok(blocks_in)
-                    }
-                } else {
-                    std::process::exit(1)
-                }
-            }
-            OscalModels::AssessmentResults => {
-                if is_valid_assessment_results_block(&blocks_in) {
-                    if blocks_in[0] == AssessmentResultsBlock::All.to_string() {
-                        let all = vec![
-                            AssessmentResultsBlock::Uuid.to_string(),
-                            AssessmentResultsBlock::Metadata.to_string(),
-                            AssessmentResultsBlock::ImportAp.to_string(),
-                            AssessmentResultsBlock::LocalDefinitions
-                                .to_string(),
-                            AssessmentResultsBlock::Results.to_string(),
-                            AssessmentResultsBlock::BackMatter.to_string(),
-                        ];
-                        blocks_out.extend(all);
-
-                        Ok(blocks_out)
-                    } else {
-                        Ok(blocks_in)
-                    }
-                } else {
-                    std::process::exit(1)
-                }
-            }
-            OscalModels::Poam => {
-                if is_valid_poam_block(&blocks_in) {
-                    if blocks_in[0] == PoamBlock::All.to_string() {
-                        let all = vec![
-                            PoamBlock::Uuid.to_string(),
-                            PoamBlock::Metadata.to_string(),
-                            PoamBlock::ImportSsp.to_string(),
-                            PoamBlock::SystemId.to_string(),
-                            PoamBlock::LocalDefinitions.to_string(),
-                            PoamBlock::Observations.to_string(),
-                            PoamBlock::Risks.to_string(),
-                            PoamBlock::Findings.to_string(),
-                            PoamBlock::PoamItems.to_string(),
-                            PoamBlock::BackMatter.to_string(),
-                        ];
-                        blocks_out.extend(all);
-
-                        Ok(blocks_out)
-                    } else {
-                        Ok(blocks_in)
-                    }
-                } else {
-                    std::process::exit(1)
-                }
-            }
-            OscalModels::Catalog => {
-                if is_valid_catalog_block(&blocks_in) {
-                    if blocks_in[0] == CatalogBlock::All.to_string() {
-                        let all = vec![
-                            CatalogBlock::Uuid.to_string(),
-                            CatalogBlock::Metadata.to_string(),
-                            CatalogBlock::Params.to_string(),
-                            CatalogBlock::Controls.to_string(),
-                            CatalogBlock::Groups.to_string(),
-                            CatalogBlock::BackMatter.to_string(),
-                        ];
-                        blocks_out.extend(all);
-
-                        Ok(blocks_out)
-                    } else {
-                        Ok(blocks_in)
-                    }
-                } else {
-                    std::process::exit(1)
-                }
-            }
-            OscalModels::Profile => {
-                if is_valid_profile_block(&blocks_in) {
-                    if blocks_in[0] == ProfileBlock::All.to_string() {
-                        let all = vec![
-                            ProfileBlock::Uuid.to_string(),
-                            ProfileBlock::Metadata.to_string(),
-                            ProfileBlock::Imports.to_string(),
-                            ProfileBlock::Merge.to_string(),
-                            ProfileBlock::Modify.to_string(),
-                            ProfileBlock::BackMatter.to_string(),
-                        ];
-                        blocks_out.extend(all);
-
-                        Ok(blocks_out)
-                    } else {
-                        Ok(blocks_in)
-                    }
-                } else {
-                    std::process::exit(1)
-                }
-            }
-            OscalModels::ComponentDefinition => {
-                if is_valid_component_definition_block(&blocks_in) {
-                    if blocks_in[0] == ComponentDefinitionBlock::All.to_string()
-                    {
-                        let all = vec![
-                            ComponentDefinitionBlock::Uuid.to_string(),
-                            ComponentDefinitionBlock::Metadata.to_string(),
-                            ComponentDefinitionBlock::ImportComponentDefinitions.to_string(),
-                            ComponentDefinitionBlock::Components.to_string(),
-                            ComponentDefinitionBlock::Capabilities.to_string(),
-                            ComponentDefinitionBlock::BackMatter.to_string(),
-                        ];
-                        blocks_out.extend(all);
-
-                        Ok(blocks_out)
-                    } else {
-                        Ok(blocks_in)
-                    }
-                } else {
-                    std::process::exit(1)
-                }
-            }
-            OscalModels::Ssp => {
-                if is_valid_ssp_block(&blocks_in) {
-                    if blocks_in[0] == SspBlock::All.to_string() {
-                        let all = vec![
-                            SspBlock::Uuid.to_string(),
-                            SspBlock::Metadata.to_string(),
-                            SspBlock::ImportProfile.to_string(),
-                            SspBlock::SystemCharacteristics.to_string(),
-                            SspBlock::SystemImplementation.to_string(),
-                            SspBlock::ControlImplementation.to_string(),
-                            SspBlock::BackMatter.to_string(),
-                        ];
-                        blocks_out.extend(all);
-
-                        Ok(blocks_out)
-                    } else {
-                        Ok(blocks_in)
-                    }
-                } else {
-                    std::process::exit(1)
-                }
-            }
-        }
-    } else {
-        std::process::exit(1)
-    }
-}
 
-async fn gen_dissect_files(ctx: &DissectCtx) -> Result<()> {
-    let models = OscalModels::from_str(&ctx.model).with_context(|| {
+    is_valid_model(model)?;
+    let models = OscalModels::from_str(model).with_context(|| {
         "Could not determine the provided OSCAL model".to_string()
     })?;
 
-    match models {
+    let all = match models {
         OscalModels::AssessmentPlan => {
-            if is_valid_assessment_plan_block(&ctx.blocks) {
-                OscalModels::gen_models(&models, ctx).await?;
-
-                Ok(())
-            } else {
-                std::process::exit(1)
+            is_valid_assessment_plan_block(&blocks_in)?;
+            if blocks_in[0] != AssessmentPlanBlock::All.to_string() {
+                return Ok(blocks_in);
             }
+
+            vec![
+                AssessmentPlanBlock::Uuid.to_string(),
+                AssessmentPlanBlock::Metadata.to_string(),
+                AssessmentPlanBlock::ImportSsp.to_string(),
+                AssessmentPlanBlock::LocalDefinitions.to_string(),
+                AssessmentPlanBlock::TermsAndConditions.to_string(),
+                AssessmentPlanBlock::ReviewedControls.to_string(),
+                AssessmentPlanBlock::AssessmentSubjects.to_string(),
+                AssessmentPlanBlock::AssessmentAssets.to_string(),
+                AssessmentPlanBlock::Tasks.to_string(),
+                AssessmentPlanBlock::BackMatter.to_string(),
+            ]
         }
         OscalModels::AssessmentResults => {
-            if is_valid_assessment_results_block(&ctx.blocks) {
-                OscalModels::gen_models(&models, ctx).await?;
-
-                Ok(())
-            } else {
-                std::process::exit(1)
+            is_valid_assessment_results_block(&blocks_in)?;
+            if blocks_in[0] != AssessmentResultsBlock::All.to_string() {
+                return Ok(blocks_in);
             }
+
+            vec![
+                AssessmentResultsBlock::Uuid.to_string(),
+                AssessmentResultsBlock::Metadata.to_string(),
+                AssessmentResultsBlock::ImportAp.to_string(),
+                AssessmentResultsBlock::LocalDefinitions.to_string(),
+                AssessmentResultsBlock::Results.to_string(),
+                AssessmentResultsBlock::BackMatter.to_string(),
+            ]
         }
         OscalModels::Poam => {
-            if is_valid_poam_block(&ctx.blocks) {
-                OscalModels::gen_models(&models, ctx).await?;
-
-                Ok(())
-            } else {
-                std::process::exit(1)
+            is_valid_poam_block(&blocks_in)?;
+            if blocks_in[0] != PoamBlock::All.to_string() {
+                return Ok(blocks_in);
             }
+
+            vec![
+                PoamBlock::Uuid.to_string(),
+                PoamBlock::Metadata.to_string(),
+                PoamBlock::ImportSsp.to_string(),
+                PoamBlock::SystemId.to_string(),
+                PoamBlock::LocalDefinitions.to_string(),
+                PoamBlock::Observations.to_string(),
+                PoamBlock::Risks.to_string(),
+                PoamBlock::Findings.to_string(),
+                PoamBlock::PoamItems.to_string(),
+                PoamBlock::BackMatter.to_string(),
+            ]
         }
         OscalModels::Catalog => {
-            if is_valid_catalog_block(&ctx.blocks) {
-                OscalModels::gen_models(&models, ctx).await?;
-
-                Ok(())
-            } else {
-                std::process::exit(1)
+            is_valid_catalog_block(&blocks_in)?;
+            if blocks_in[0] != CatalogBlock::All.to_string() {
+                return Ok(blocks_in);
             }
+
+            vec![
+                CatalogBlock::Uuid.to_string(),
+                CatalogBlock::Metadata.to_string(),
+                CatalogBlock::Params.to_string(),
+                CatalogBlock::Controls.to_string(),
+                CatalogBlock::Groups.to_string(),
+                CatalogBlock::BackMatter.to_string(),
+            ]
         }
         OscalModels::Profile => {
-            if is_valid_profile_block(&ctx.blocks) {
-                OscalModels::gen_models(&models, ctx).await?;
-
-                Ok(())
-            } else {
-                std::process::exit(1)
+            is_valid_profile_block(&blocks_in)?;
+            if blocks_in[0] != ProfileBlock::All.to_string() {
+                return Ok(blocks_in);
             }
+
+            vec![
+                ProfileBlock::Uuid.to_string(),
+                ProfileBlock::Metadata.to_string(),
+                ProfileBlock::Imports.to_string(),
+                ProfileBlock::Merge.to_string(),
+                ProfileBlock::Modify.to_string(),
+                ProfileBlock::BackMatter.to_string(),
+            ]
         }
         OscalModels::ComponentDefinition => {
-            if is_valid_component_definition_block(&ctx.blocks) {
-                OscalModels::gen_models(&models, ctx).await?;
-
-                Ok(())
-            } else {
-                std::process::exit(1)
+            is_valid_component_definition_block(&blocks_in)?;
+            if blocks_in[0] != ComponentDefinitionBlock::All.to_string() {
+                return Ok(blocks_in);
             }
+
+            vec![
+                ComponentDefinitionBlock::Uuid.to_string(),
+                ComponentDefinitionBlock::Metadata.to_string(),
+                ComponentDefinitionBlock::ImportComponentDefinitions
+                    .to_string(),
+                ComponentDefinitionBlock::Components.to_string(),
+                ComponentDefinitionBlock::Capabilities.to_string(),
+                ComponentDefinitionBlock::BackMatter.to_string(),
+            ]
         }
         OscalModels::Ssp => {
-            if is_valid_ssp_block(&ctx.blocks) {
-                OscalModels::gen_models(&models, ctx).await?;
-
-                Ok(())
-            } else {
-                std::process::exit(1)
+            is_valid_ssp_block(&blocks_in)?;
+            if blocks_in[0] != SspBlock::All.to_string() {
+                return Ok(blocks_in);
             }
+
+            vec![
+                SspBlock::Uuid.to_string(),
+                SspBlock::Metadata.to_string(),
+                SspBlock::ImportProfile.to_string(),
+                SspBlock::SystemCharacteristics.to_string(),
+                SspBlock::SystemImplementation.to_string(),
+                SspBlock::ControlImplementation.to_string(),
+                SspBlock::BackMatter.to_string(),
+            ]
         }
+    };
+
+    Ok(all)
+}
+
+async fn gen_dissect_files(ctx: &DissectCtx) -> Result<Vec<ModelWarning>> {
+    let models = OscalModels::from_str(&ctx.model).with_context(|| {
+        "Could not determine the provided OSCAL model".to_string()
+    })?;
+
+    match models {
+        OscalModels::AssessmentPlan => {
+            is_valid_assessment_plan_block(&ctx.blocks)?
+        }
+        OscalModels::AssessmentResults => {
+            is_valid_assessment_results_block(&ctx.blocks)?
+        }
+        OscalModels::Poam => is_valid_poam_block(&ctx.blocks)?,
+        OscalModels::Catalog => is_valid_catalog_block(&ctx.blocks)?,
+        OscalModels::Profile => is_valid_profile_block(&ctx.blocks)?,
+        OscalModels::ComponentDefinition => {
+            is_valid_component_definition_block(&ctx.blocks)?
+        }
+        OscalModels::Ssp => is_valid_ssp_block(&ctx.blocks)?,
     }
+
+    OscalModels::gen_models(&models, ctx).await
 }
 
 async fn dissect_cleanup(ctx: &DissectCtx) -> Result<()> {
@@ -418,13 +321,7 @@ pub(super) async fn gen_dissect_dir(ctx: &DissectCtx) -> Result<()> {
     Ok(())
 }
 
-pub(super) async fn dissect_workspace(opts: &Dissect) -> Result<()> {
-    if opts.parse_markup {
-        std::env::set_var("OSCAL_MARKUP_RENDER_MODE", "ENABLED")
-    } else {
-        std::env::set_var("OSCAL_MARKUP_RENDER_MODE", "DISABLED")
-    }
-
+pub(super) async fn dissect_workspace(opts: &Dissect) -> Result<DissectCtx> {
     let created_at = gen_created_at();
     let model_loc =
         PathBuf::from(&opts.file).canonicalize().with_context(|| {
@@ -440,26 +337,33 @@ pub(super) async fn dissect_workspace(opts: &Dissect) -> Result<()> {
     let backup = gen_dissect_backup(&root).await;
     let modifiable = gen_dissect_modifiable(&root).await;
     let hash = gen_hash_from_path(&opts.file)?;
-    let blocks = gen_blocks(opts).await?;
-    let ctx = DissectCtx {
+    let model = match &opts.model {
+        Some(model) => model.clone(),
+        None => OscalModels::detect(&opts.file)?.to_string(),
+    };
+    let blocks = gen_blocks(opts, &model).await?;
+    let mut ctx = DissectCtx {
         created_at,
         model_loc,
-        model: opts.model.clone(),
+        model,
         blocks,
         rand,
         root,
         backup,
         modifiable,
         hash,
+        parse_markup: opts.parse_markup,
+        lenient: opts.lenient,
+        warnings: vec![],
     };
 
-    if let Err(e) = gen_dissect_files(&ctx).await {
-        eprintln!("{e}");
-        dissect_cleanup(&ctx).await?;
-        std::process::exit(1)
-    } else {
-        println!("Dissect operation successful!\nYou can locate your workspace at: {}", &ctx.root)
+    match gen_dissect_files(&ctx).await {
+        Ok(warnings) => ctx.warnings = warnings,
+        Err(e) => {
+            dissect_cleanup(&ctx).await?;
+            return Err(e);
+        }
     }
 
-    Ok(())
+    Ok(ctx)
 }
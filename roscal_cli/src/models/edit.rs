@@ -0,0 +1,222 @@
+use std::{fs::File, io::BufReader, path::Path};
+
+use chrono::{SecondsFormat, Utc};
+use roscal_lib::query::Query;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::cli::cli_opts::Edit;
+
+use super::{
+    merge::VersionScheme,
+    model::OscalModels,
+    utils::{lenient_options, write_model},
+    workspace::{CliError, Context, Output, Result},
+};
+
+/// A single declarative change to a model
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "op", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum EditOp {
+    /// Set `field` on every object selected by the `path` query
+    Set {
+        path: String,
+        field: String,
+        value: Value,
+    },
+    /// Append a property to the control with the given id
+    AddProp { control: String, prop: Value },
+    /// Append a party to the metadata
+    AddParty { party: Value },
+    /// Remove the control with the given id
+    RemoveControl { control: String },
+    /// Set the metadata version, or bump it following a scheme, patch by
+    /// default, and refresh last-modified
+    BumpVersion {
+        #[serde(default)]
+        version: Option<String>,
+        #[serde(default)]
+        scheme: Option<VersionScheme>,
+    },
+}
+
+impl EditOp {
+    pub fn apply(&self, root: &mut Value) -> Result<()> {
+        match self {
+            EditOp::Set { path, field, value } => {
+                for pointer in select(root, path)? {
+                    as_object(root, &pointer)?
+                        .insert(field.to_owned(), value.clone());
+                }
+            }
+            EditOp::AddProp { control, prop } => {
+                for pointer in controls(root, control)? {
+                    push(as_object(root, &pointer)?, "props", prop.clone());
+                }
+            }
+            EditOp::AddParty { party } => {
+                for pointer in select(root, "*.metadata")? {
+                    push(as_object(root, &pointer)?, "parties", party.clone());
+                }
+            }
+            EditOp::RemoveControl { control } => {
+                for pointer in controls(root, control)?.iter().rev() {
+                    remove(root, pointer)?;
+                }
+            }
+            EditOp::BumpVersion { version, scheme } => {
+                for pointer in select(root, "*.metadata")? {
+                    let metadata = as_object(root, &pointer)?;
+                    let current = metadata
+                        .get("version")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_owned();
+                    let version = match version {
+                        Some(version) => version.to_owned(),
+                        None => scheme
+                            .as_ref()
+                            .unwrap_or(&VersionScheme::Patch)
+                            .bump(&current)?,
+                    };
+
+                    metadata.insert("version".to_owned(), version.into());
+                    metadata.insert(
+                        "last-modified".to_owned(),
+                        Utc::now()
+                            .to_rfc3339_opts(SecondsFormat::Secs, true)
+                            .into(),
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Pointers of the controls with the given id, at least one
+fn controls(root: &Value, id: &str) -> Result<Vec<String>> {
+    let mut pointers = vec![];
+    find_controls(root, "", id, &mut pointers);
+
+    if pointers.is_empty() {
+        return Err(CliError::EditNoMatch(format!("control `{id}`")));
+    }
+
+    Ok(pointers)
+}
+
+/// Pointers of the controls with the given id at any depth, each before
+/// those of its child controls
+fn find_controls(node: &Value, pointer: &str, id: &str, col: &mut Vec<String>) {
+    match node {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let pointer = format!(
+                    "{pointer}/{}",
+                    key.replace('~', "~0").replace('/', "~1")
+                );
+                if let ("controls", Value::Array(items)) = (key.as_str(), value)
+                {
+                    for (index, item) in items.iter().enumerate() {
+                        let pointer = format!("{pointer}/{index}");
+                        if item["id"] == id {
+                            col.push(pointer.clone());
+                        }
+                        find_controls(item, &pointer, id, col);
+                    }
+                } else {
+                    find_controls(value, &pointer, id, col);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                find_controls(item, &format!("{pointer}/{index}"), id, col);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Pointers of the nodes selected by a query, at least one
+fn select(root: &Value, expr: &str) -> Result<Vec<String>> {
+    let pointers = Query::parse(expr)
+        .map_err(|e| CliError::InvalidQuery(e.to_string()))?
+        .pointers(root);
+
+    if pointers.is_empty() {
+        return Err(CliError::EditNoMatch(expr.to_owned()));
+    }
+
+    Ok(pointers)
+}
+
+fn as_object<'a>(
+    root: &'a mut Value,
+    pointer: &str,
+) -> Result<&'a mut Map<String, Value>> {
+    root.pointer_mut(pointer)
+        .and_then(Value::as_object_mut)
+        .with_context(|| format!("Not an object: `{pointer}`"))
+}
+
+fn push(object: &mut Map<String, Value>, field: &str, value: Value) {
+    let entry = object.entry(field).or_insert_with(|| Value::Array(vec![]));
+
+    if let Value::Array(items) = entry {
+        items.push(value);
+    }
+}
+
+/// Remove an array element, dropping the array once it is empty
+fn remove(root: &mut Value, pointer: &str) -> Result<()> {
+    let (array, index) = pointer.rsplit_once('/').unwrap_or_default();
+    let (parent, field) = array.rsplit_once('/').unwrap_or_default();
+    let index: usize = index
+        .parse()
+        .with_context(|| format!("Not an array element: `{pointer}`"))?;
+
+    let parent = as_object(root, parent)?;
+    if let Some(Value::Array(items)) = parent.get_mut(field) {
+        items.remove(index);
+        if items.is_empty() {
+            parent.remove(field);
+        }
+    }
+
+    Ok(())
+}
+
+fn read_ops(path: &Path) -> Result<Vec<EditOp>> {
+    let file = File::open(path).with_context(|| {
+        format!("Could not open edit operations file: `{}`", path.display())
+    })?;
+
+    serde_yaml::from_reader(BufReader::new(file)).map_err(|e| {
+        CliError::ParseEditOps(
+            path.to_string_lossy().into_owned(),
+            e.to_string(),
+        )
+    })
+}
+
+pub(super) async fn edit_model(opts: &Edit) -> Result<Output> {
+    let model = OscalModels::from_opt(opts.model.as_deref(), &opts.file)?;
+    let ops = read_ops(&opts.ops)?;
+
+    let options = lenient_options(opts.lenient);
+    let (mut root, warnings) = model.read_value(&opts.file, &options).await?;
+    for op in &ops {
+        op.apply(&mut root)?;
+    }
+    model.check_value(root.clone(), &options)?;
+
+    write_model(opts.output_file.as_ref().unwrap_or(&opts.file), &root)?;
+
+    Ok(Output {
+        content: None,
+        warnings,
+    })
+}
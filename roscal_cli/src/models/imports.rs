@@ -0,0 +1,199 @@
+use std::{
+    fmt,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use roscal_lib::{
+    document::{AnyOscalDocument, ModelType},
+    href::{standard_resolver, HrefResolver, HrefSource},
+    parse::{ParseOptions, ParseWarning},
+};
+
+/// Why an import could not be followed
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportError {
+    /// The href could not be resolved to a local file, for the given reason
+    Unresolved { href: String, reason: String },
+    /// The file could not be read or parsed, for the given reason
+    Unreadable { path: PathBuf, reason: String },
+    /// The file has none of the expected model types
+    WrongModel {
+        path: PathBuf,
+        expected: Vec<ModelType>,
+        found: ModelType,
+    },
+    /// The file imports itself through the given chain of files
+    Cycle(Vec<PathBuf>),
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::Unresolved { href, reason } => {
+                write!(f, "could not resolve `{href}`: {reason}")
+            }
+            ImportError::Unreadable { path, reason } => {
+                write!(f, "could not read {}: {reason}", path.display())
+            }
+            ImportError::WrongModel {
+                path,
+                expected,
+                found,
+            } => {
+                let expected: Vec<String> =
+                    expected.iter().map(ModelType::to_string).collect();
+                write!(
+                    f,
+                    "{} is a {found}, expected {}",
+                    path.display(),
+                    expected.join(" or ")
+                )
+            }
+            ImportError::Cycle(chain) => {
+                let chain: Vec<String> =
+                    chain.iter().map(|p| p.display().to_string()).collect();
+                write!(f, "import cycle {}", chain.join(" -> "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+/// A file reached through an import, read but not parsed yet
+#[derive(Debug, Clone)]
+pub(super) struct Imported {
+    pub(super) path: PathBuf,
+    pub(super) canonical: PathBuf,
+    pub(super) model_type: ModelType,
+    pub(super) content: String,
+}
+
+/// A parsed document, with the base its own hrefs resolve against
+pub(super) struct Parsed {
+    pub(super) document: AnyOscalDocument,
+    pub(super) source: HrefSource,
+    pub(super) warnings: Vec<ParseWarning>,
+}
+
+/// Follows the imports between documents: resolves their hrefs, reads the
+/// files they point to and detects cycles
+pub(super) struct Imports {
+    options: ParseOptions,
+    hrefs: Box<dyn HrefResolver>,
+    /// Canonical and displayed paths of the documents being followed
+    following: Vec<(PathBuf, PathBuf)>,
+}
+
+impl Imports {
+    pub(super) fn new(
+        options: ParseOptions,
+        mirror_dir: Option<PathBuf>,
+    ) -> Self {
+        Imports {
+            options,
+            hrefs: Box::new(standard_resolver(mirror_dir)),
+            following: vec![],
+        }
+    }
+
+    pub(super) fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    /// Read the file an href of `source` points to, if it has one of the
+    /// expected model types and isn't one of the documents being followed
+    pub(super) fn load(
+        &self,
+        href: &str,
+        source: &HrefSource,
+        expected: &[ModelType],
+    ) -> Result<Imported, ImportError> {
+        let path = self.hrefs.resolve(href, source).map_err(|e| {
+            ImportError::Unresolved {
+                href: href.to_owned(),
+                reason: e.to_string(),
+            }
+        })?;
+
+        let canonical = canonical(&path);
+        if let Some(start) =
+            self.following.iter().position(|(c, _)| c == &canonical)
+        {
+            let mut chain: Vec<PathBuf> = self.following[start..]
+                .iter()
+                .map(|(_, p)| p.clone())
+                .collect();
+            chain.push(path);
+            return Err(ImportError::Cycle(chain));
+        }
+
+        self.read(&path, expected)
+    }
+
+    /// Read a file, if it has one of the expected model types
+    pub(super) fn read(
+        &self,
+        path: &Path,
+        expected: &[ModelType],
+    ) -> Result<Imported, ImportError> {
+        let unreadable = |reason: String| ImportError::Unreadable {
+            path: path.to_path_buf(),
+            reason,
+        };
+        let content =
+            read_to_string(path).map_err(|e| unreadable(e.to_string()))?;
+        let model_type = ModelType::detect(&content)
+            .map_err(|e| unreadable(e.to_string()))?;
+        if !expected.contains(&model_type) {
+            return Err(ImportError::WrongModel {
+                path: path.to_path_buf(),
+                expected: expected.to_vec(),
+                found: model_type,
+            });
+        }
+
+        Ok(Imported {
+            path: path.to_path_buf(),
+            canonical: canonical(path),
+            model_type,
+            content,
+        })
+    }
+
+    /// Parse a file read, keeping the base its hrefs resolve against
+    pub(super) fn parse(
+        &self,
+        imported: &Imported,
+    ) -> Result<Parsed, ImportError> {
+        let (document, warnings) = AnyOscalDocument::from_str_with_warnings(
+            &imported.content,
+            &self.options,
+        )
+        .map_err(|e| ImportError::Unreadable {
+            path: imported.path.clone(),
+            reason: e.to_string(),
+        })?;
+
+        Ok(Parsed {
+            source: HrefSource::from_document(&imported.path, &document),
+            document,
+            warnings,
+        })
+    }
+
+    /// Follow the imports of a document, until `leave` is called
+    pub(super) fn enter(&mut self, path: &Path) {
+        self.following.push((canonical(path), path.to_path_buf()));
+    }
+
+    pub(super) fn leave(&mut self) {
+        self.following.pop();
+    }
+}
+
+/// Canonical form of a path, to recognize a file reached twice
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
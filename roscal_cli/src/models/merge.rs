@@ -1,11 +1,11 @@
 use std::{
-    fs::File,
+    fs::{self, File},
     io::{BufReader, Read, Write},
     path::{Path, PathBuf},
     str::FromStr,
 };
 
-use anyhow::{Context, Result};
+use chrono::{SecondsFormat, Utc};
 use roscal_lib::{
     assessment::{
         assessment_plan::AssessmentPlanBuilder,
@@ -17,8 +17,11 @@ use roscal_lib::{
         component_definition::ComponentDefinitionBuilder,
         ssp::SystemSecurityPlanBuilder,
     },
-    UpdateUuid,
+    parse::{ParseOptions, ParseWith},
+    xml, UpdateUuid,
 };
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
 use strum::EnumString;
 use tokio::fs::create_dir_all;
 
@@ -30,8 +33,8 @@ use super::{
         ComponentDefinitionBlock, OscalModels, PoamBlock, ProfileBlock,
         SspBlock,
     },
-    utils::{gen_created_at, gen_rand},
-    workspace::{CliError, DissectCtx, MergeCtx},
+    utils::{gen_created_at, gen_rand, lenient_options},
+    workspace::{CliError, Context, DissectCtx, MergeCtx, Result},
 };
 use crate::models::utils::is_valid_dir;
 
@@ -41,6 +44,52 @@ pub enum MergeOpt {
     Json,
     #[strum(serialize = "yaml")]
     Yaml,
+    #[strum(serialize = "xml")]
+    Xml,
+}
+
+impl MergeOpt {
+    /// Format of a model file judging by its extension
+    fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(MergeOpt::Json),
+            "yaml" | "yml" => Some(MergeOpt::Yaml),
+            "xml" => Some(MergeOpt::Xml),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            MergeOpt::Json => "json",
+            MergeOpt::Yaml => "yaml",
+            MergeOpt::Xml => "xml",
+        }
+    }
+
+    /// Name of the original file, with the extension of this format if it
+    /// was written in another one, e.g. `ssp.json` merged as yaml is
+    /// `ssp.yaml`
+    fn file_name(&self, model_loc: &Path) -> PathBuf {
+        let name = PathBuf::from(model_loc.file_name().unwrap_or_default());
+
+        if MergeOpt::from_path(model_loc).as_ref() == Some(self) {
+            name
+        } else {
+            name.with_extension(self.extension())
+        }
+    }
+
+    fn serialize<T: Serialize>(&self, model: &T) -> Result<String> {
+        match self {
+            MergeOpt::Json => serde_json::to_string(model)
+                .with_context(|| "Could not parse model to json file"),
+            MergeOpt::Yaml => serde_yaml::to_string(model)
+                .with_context(|| "Could not parse model to yaml file"),
+            MergeOpt::Xml => xml::to_string(model)
+                .with_context(|| "Could not parse model to xml file"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, EnumString)]
@@ -51,22 +100,59 @@ pub enum UpdateUuidOpt {
     V5,
 }
 
+#[derive(Debug, Clone, PartialEq, EnumString, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum VersionScheme {
+    #[strum(serialize = "major")]
+    Major,
+    #[strum(serialize = "minor")]
+    Minor,
+    #[strum(serialize = "patch")]
+    Patch,
+    #[strum(serialize = "date")]
+    Date,
+}
+
+impl VersionScheme {
+    /// Next version after `current`, missing semver parts count as zero
+    pub(super) fn bump(&self, current: &str) -> Result<String> {
+        if *self == VersionScheme::Date {
+            return Ok(Utc::now().format("%Y-%m-%d").to_string());
+        }
+
+        let mut parts = current
+            .split('.')
+            .map(str::parse::<u64>)
+            .collect::<Result<Vec<_>, _>>()
+            .ok()
+            .filter(|parts| parts.len() <= 3)
+            .ok_or_else(|| CliError::BumpSemver(current.to_owned()))?;
+        parts.resize(3, 0);
+
+        match self {
+            VersionScheme::Major => parts = vec![parts[0] + 1, 0, 0],
+            VersionScheme::Minor => parts = vec![parts[0], parts[1] + 1, 0],
+            _ => parts[2] += 1,
+        }
+
+        Ok(format!("{}.{}.{}", parts[0], parts[1], parts[2]))
+    }
+}
+
 async fn gen_dir(
     path: &Option<PathBuf>,
     default: impl AsRef<Path>,
 ) -> Result<String> {
     if let Some(path) = path {
-        if is_valid_dir(path) {
-            Ok(format!(
-                "{}",
-                Path::new(&path).canonicalize().with_context(|| {
-                    format!("Could not determine the full path of the workspace: `{}`", 
-                    path.display())
-                })?.to_string_lossy()
-            ))
-        } else {
-            std::process::exit(1)
-        }
+        is_valid_dir(path)?;
+
+        Ok(format!(
+            "{}",
+            Path::new(&path).canonicalize().with_context(|| {
+                format!("Could not determine the full path of the workspace: `{}`", 
+                path.display())
+            })?.to_string_lossy()
+        ))
     } else {
         Ok(format!(
             "{}",
@@ -141,47 +227,57 @@ async fn gen_merge_blocks(ctx: &MergeCtx) -> Result<Vec<PathBuf>> {
 async fn gen_merge_dir(
     ctx: &mut MergeCtx,
     opts: &Merge,
+    merge_opt: &MergeOpt,
     model: String,
 ) -> Result<()> {
     create_dir_all(&ctx.output_dir).await.with_context(|| {
         format!("Could not create merge directory: `{}`", &ctx.output_dir)
     })?;
 
-    match MergeOpt::from_str(&opts.output_format)
-        .map_err(|_| CliError::UnknownMergeOpt)?
-    {
-        MergeOpt::Json => {
-            let merged_path = format!("{}/merged.json", &ctx.output_dir);
-            let mut merged = File::create(&merged_path).with_context(|| {
-                format!(
-                    "Could not create merged.json file at this location: {}",
-                    &ctx.output_dir
-                )
-            })?;
-
-            merged
-                .write_all(model.as_bytes())
-                .with_context(|| "Could not write to merge file".to_owned())?;
-            ctx.update_hash(&model)?;
-            println!("Merge Operation Successful!\nYou can locate you merged JSON file at: {}", merged_path);
+    let model_loc = &ctx.dissect_workspace_ref.model_loc;
+    let merged_path = if opts.in_place {
+        let backup_path = backup_path(model_loc);
+        if backup_path.exists() {
+            return Err(CliError::BackupExists(backup_path));
         }
-        MergeOpt::Yaml => {
-            let merged_path = format!("{}/merged.yaml", &ctx.output_dir);
-            let mut merged = File::create(&merged_path).with_context(|| {
-                format!(
-                    "Could not create merged.yaml file at this location: {}",
-                    &ctx.output_dir
-                )
-            })?;
-            merged
-                .write_all(model.as_bytes())
-                .with_context(|| "Could not write to merge file".to_owned())?;
-            ctx.update_hash(&model)?;
-            println!("Merge Operation Successful!\nYou can locate you merged YAML file at: {}", merged_path);
+        fs::copy(model_loc, &backup_path).with_context(|| {
+            format!(
+                "Could not back up original file to this location: {}",
+                backup_path.display()
+            )
+        })?;
+        ctx.original_backup = Some(backup_path);
+
+        model_loc.clone()
+    } else if let Some(output_file) = &opts.output_file {
+        output_file.clone()
+    } else {
+        let merged_path =
+            Path::new(&ctx.output_dir).join(merge_opt.file_name(model_loc));
+
+        // Only --in-place may replace the original, as it keeps a backup
+        if fs::canonicalize(model_loc).ok() == Some(merged_path.clone()) {
+            return Err(CliError::OverwriteOriginal(merged_path));
         }
-    }
+
+        merged_path
+    };
+
+    let mut merged = File::create(&merged_path).with_context(|| {
+        format!(
+            "Could not create merged file at this location: {}",
+            merged_path.display()
+        )
+    })?;
+    merged
+        .write_all(model.as_bytes())
+        .with_context(|| "Could not write to merge file".to_owned())?;
+    ctx.update_hash(&model)?;
+    ctx.output_file = merged_path.to_string_lossy().into_owned();
+    ctx.output_format = Some(merge_opt.clone());
 
     let manifest_path = format!("{}/merge_manifest.yaml", &ctx.output_dir);
+    ctx.manifest = PathBuf::from(&manifest_path);
 
     let mut manifest = File::create(&manifest_path).with_context(|| {
         format!(
@@ -196,11 +292,35 @@ async fn gen_merge_dir(
     manifest
         .write_all(ctx_content.as_bytes())
         .with_context(|| "Could not write to merge manifest".to_owned())?;
-    print!("You can locate you merge manifest at: {}", manifest_path);
 
     Ok(())
 }
 
+/// Output format given explicitly, or else that of the file being written
+fn gen_merge_opt(ctx: &MergeCtx, opts: &Merge) -> Result<MergeOpt> {
+    if let Some(output_format) = &opts.output_format {
+        return MergeOpt::from_str(output_format)
+            .map_err(|_| CliError::UnknownMergeOpt);
+    }
+
+    let path = match &opts.output_file {
+        Some(output_file) if !opts.in_place => output_file,
+        _ => &ctx.dissect_workspace_ref.model_loc,
+    };
+
+    MergeOpt::from_path(path).ok_or_else(|| {
+        CliError::UnknownFileFormat(path.to_string_lossy().into_owned())
+    })
+}
+
+/// Original file with `.bak` appended, e.g. `ssp.json.bak`
+fn backup_path(path: &Path) -> PathBuf {
+    let mut backup = path.as_os_str().to_owned();
+    backup.push(".bak");
+
+    PathBuf::from(backup)
+}
+
 async fn process_merge_blocks(
     blocks: &Vec<PathBuf>,
     ctx: &mut MergeCtx,
@@ -210,13 +330,16 @@ async fn process_merge_blocks(
         .with_context(|| {
             "Could not determine the provided OSCAL model".to_string()
         })?;
+    let merge_opt = gen_merge_opt(ctx, opts)?;
+    let options =
+        lenient_options(opts.lenient || ctx.dissect_workspace_ref.lenient);
     let model_loc =
         PathBuf::from(&format!("{}/backup", ctx.dissect_workspace_ref.backup));
 
     match models {
         OscalModels::AssessmentPlan => {
-            let assessment_plan_orig =
-                models.read_assessment_plan(&model_loc).await?;
+            let (assessment_plan_orig, _) =
+                models.read_assessment_plan(&model_loc, &options).await?;
             let mut assessment_plan_sap_orig =
                 assessment_plan_orig.assessment_plan.clone();
             let mut assessment_plan_builder = AssessmentPlanBuilder::default();
@@ -231,44 +354,66 @@ async fn process_merge_blocks(
 
                 match block {
                     AssessmentPlanBlock::Uuid => {
-                        let res = block.read_uuid(elt).await?;
+                        let (res, warnings) =
+                            block.read_uuid(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         assessment_plan_sap_orig.uuid = res
                     }
                     AssessmentPlanBlock::Metadata => {
-                        let res = block.read_metadata(elt).await?;
+                        let (res, warnings) =
+                            block.read_metadata(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         assessment_plan_sap_orig.metadata = res
                     }
                     AssessmentPlanBlock::ImportSsp => {
-                        let res = block.read_import_ssp(elt).await?;
+                        let (res, warnings) =
+                            block.read_import_ssp(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         assessment_plan_sap_orig.import_ssp = res
                     }
                     AssessmentPlanBlock::LocalDefinitions => {
-                        let res = block.read_local_definitions(elt).await?;
+                        let (res, warnings) =
+                            block.read_local_definitions(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         assessment_plan_sap_orig.local_definitions = Some(res)
                     }
                     AssessmentPlanBlock::TermsAndConditions => {
-                        let res = block.read_terms_and_conditions(elt).await?;
+                        let (res, warnings) = block
+                            .read_terms_and_conditions(elt, &options)
+                            .await?;
+                        ctx.warnings.extend(warnings);
                         assessment_plan_sap_orig.terms_and_conditions =
                             Some(res)
                     }
                     AssessmentPlanBlock::ReviewedControls => {
-                        let res = block.read_reviewed_controls(elt).await?;
+                        let (res, warnings) =
+                            block.read_reviewed_controls(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         assessment_plan_sap_orig.reviewed_controls = res
                     }
                     AssessmentPlanBlock::AssessmentSubjects => {
-                        let res = block.read_assessment_subjects(elt).await?;
+                        let (res, warnings) = block
+                            .read_assessment_subjects(elt, &options)
+                            .await?;
+                        ctx.warnings.extend(warnings);
                         assessment_plan_sap_orig.assessment_subjects = Some(res)
                     }
                     AssessmentPlanBlock::AssessmentAssets => {
-                        let res = block.read_assessment_assets(elt).await?;
+                        let (res, warnings) =
+                            block.read_assessment_assets(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         assessment_plan_sap_orig.assessment_assets = Some(res)
                     }
                     AssessmentPlanBlock::Tasks => {
-                        let res = block.read_tasks(elt).await?;
+                        let (res, warnings) =
+                            block.read_tasks(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         assessment_plan_sap_orig.tasks = Some(res)
                     }
                     AssessmentPlanBlock::BackMatter => {
-                        let res = block.read_back_matter(elt).await?;
+                        let (res, warnings) =
+                            block.read_back_matter(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         assessment_plan_sap_orig.back_matter = Some(res)
                     }
                     _ => {}
@@ -294,24 +439,22 @@ async fn process_merge_blocks(
 
             let res = assessment_plan_builder
                 .assessment_plan(assessment_plan_sap_orig)
-                .build()?;
+                .build()
+                .map_err(|e| {
+                    CliError::InvalidModel(format!("{models:?}"), e.to_string())
+                })?;
+            let res =
+                update_metadata(&assessment_plan_orig, res, opts, &options)?;
 
-            let model = match MergeOpt::from_str(&opts.output_format)
-                .map_err(|_| CliError::UnknownMergeOpt)?
-            {
-                MergeOpt::Json => serde_json::to_string(&res)
-                    .with_context(|| "Could not parse model to json file")?,
-                MergeOpt::Yaml => serde_yaml::to_string(&res)
-                    .with_context(|| "Could not parse model to yaml file")?,
-            };
+            let model = merge_opt.serialize(&res)?;
 
-            gen_merge_dir(ctx, opts, model).await?;
+            gen_merge_dir(ctx, opts, &merge_opt, model).await?;
 
             Ok(())
         }
         OscalModels::AssessmentResults => {
-            let assessment_results_orig =
-                models.read_assessment_results(&model_loc).await?;
+            let (assessment_results_orig, _) =
+                models.read_assessment_results(&model_loc, &options).await?;
             let mut assessment_results_sar_orig =
                 assessment_results_orig.assessment_results.clone();
             let mut assessment_results_builder =
@@ -325,28 +468,40 @@ async fn process_merge_blocks(
 
                 match block {
                     AssessmentResultsBlock::Uuid => {
-                        let res = block.read_uuid(elt).await?;
+                        let (res, warnings) =
+                            block.read_uuid(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         assessment_results_sar_orig.uuid = res
                     }
                     AssessmentResultsBlock::Metadata => {
-                        let res = block.read_metadata(elt).await?;
+                        let (res, warnings) =
+                            block.read_metadata(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         assessment_results_sar_orig.metadata = res
                     }
                     AssessmentResultsBlock::ImportAp => {
-                        let res = block.read_import_ap(elt).await?;
+                        let (res, warnings) =
+                            block.read_import_ap(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         assessment_results_sar_orig.import_ap = res
                     }
                     AssessmentResultsBlock::LocalDefinitions => {
-                        let res = block.read_local_definitions(elt).await?;
+                        let (res, warnings) =
+                            block.read_local_definitions(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         assessment_results_sar_orig.local_definitions =
                             Some(res)
                     }
                     AssessmentResultsBlock::Results => {
-                        let res = block.read_results(elt).await?;
+                        let (res, warnings) =
+                            block.read_results(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         assessment_results_sar_orig.results = res
                     }
                     AssessmentResultsBlock::BackMatter => {
-                        let res = block.read_back_matter(elt).await?;
+                        let (res, warnings) =
+                            block.read_back_matter(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         assessment_results_sar_orig.back_matter = Some(res)
                     }
                     _ => {}
@@ -372,23 +527,21 @@ async fn process_merge_blocks(
 
             let res = assessment_results_builder
                 .assessment_results(assessment_results_sar_orig)
-                .build()?;
+                .build()
+                .map_err(|e| {
+                    CliError::InvalidModel(format!("{models:?}"), e.to_string())
+                })?;
+            let res =
+                update_metadata(&assessment_results_orig, res, opts, &options)?;
 
-            let model = match MergeOpt::from_str(&opts.output_format)
-                .map_err(|_| CliError::UnknownMergeOpt)?
-            {
-                MergeOpt::Json => serde_json::to_string(&res)
-                    .with_context(|| "Could not parse model to json file")?,
-                MergeOpt::Yaml => serde_yaml::to_string(&res)
-                    .with_context(|| "Could not parse model to yaml file")?,
-            };
+            let model = merge_opt.serialize(&res)?;
 
-            gen_merge_dir(ctx, opts, model).await?;
+            gen_merge_dir(ctx, opts, &merge_opt, model).await?;
 
             Ok(())
         }
         OscalModels::Poam => {
-            let poam_orig = models.read_poam(&model_loc).await?;
+            let (poam_orig, _) = models.read_poam(&model_loc, &options).await?;
             let mut poam_poam_orig =
                 poam_orig.plan_of_action_and_milestones.clone();
             let mut poam_builder = PlanOfActionAndMilestonesBuilder::default();
@@ -401,44 +554,64 @@ async fn process_merge_blocks(
 
                 match block {
                     PoamBlock::Uuid => {
-                        let res = block.read_uuid(elt).await?;
+                        let (res, warnings) =
+                            block.read_uuid(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         poam_poam_orig.uuid = res
                     }
                     PoamBlock::Metadata => {
-                        let res = block.read_metadata(elt).await?;
+                        let (res, warnings) =
+                            block.read_metadata(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         poam_poam_orig.metadata = res
                     }
                     PoamBlock::ImportSsp => {
-                        let res = block.read_import_ssp(elt).await?;
+                        let (res, warnings) =
+                            block.read_import_ssp(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         poam_poam_orig.import_ssp = Some(res)
                     }
                     PoamBlock::SystemId => {
-                        let res = block.read_system_id(elt).await?;
+                        let (res, warnings) =
+                            block.read_system_id(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         poam_poam_orig.system_id = Some(res)
                     }
                     PoamBlock::LocalDefinitions => {
-                        let res =
-                            block.read_system_local_definitions(elt).await?;
+                        let (res, warnings) = block
+                            .read_system_local_definitions(elt, &options)
+                            .await?;
+                        ctx.warnings.extend(warnings);
                         poam_poam_orig.local_definitions = Some(res)
                     }
                     PoamBlock::Observations => {
-                        let res = block.read_observations(elt).await?;
+                        let (res, warnings) =
+                            block.read_observations(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         poam_poam_orig.observations = Some(res)
                     }
                     PoamBlock::Risks => {
-                        let res = block.read_risks(elt).await?;
+                        let (res, warnings) =
+                            block.read_risks(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         poam_poam_orig.risks = Some(res)
                     }
                     PoamBlock::Findings => {
-                        let res = block.read_findings(elt).await?;
+                        let (res, warnings) =
+                            block.read_findings(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         poam_poam_orig.findings = Some(res)
                     }
                     PoamBlock::PoamItems => {
-                        let res = block.read_poam_items(elt).await?;
+                        let (res, warnings) =
+                            block.read_poam_items(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         poam_poam_orig.poam_items = res
                     }
                     PoamBlock::BackMatter => {
-                        let res = block.read_back_matter(elt).await?;
+                        let (res, warnings) =
+                            block.read_back_matter(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         poam_poam_orig.back_matter = Some(res)
                     }
                     _ => {}
@@ -464,23 +637,21 @@ async fn process_merge_blocks(
 
             let res = poam_builder
                 .plan_of_action_and_milestones(poam_poam_orig)
-                .build()?;
+                .build()
+                .map_err(|e| {
+                    CliError::InvalidModel(format!("{models:?}"), e.to_string())
+                })?;
+            let res = update_metadata(&poam_orig, res, opts, &options)?;
 
-            let model = match MergeOpt::from_str(&opts.output_format)
-                .map_err(|_| CliError::UnknownMergeOpt)?
-            {
-                MergeOpt::Json => serde_json::to_string(&res)
-                    .with_context(|| "Could not parse model as json file")?,
-                MergeOpt::Yaml => serde_yaml::to_string(&res)
-                    .with_context(|| "Could not parse model as yaml file")?,
-            };
+            let model = merge_opt.serialize(&res)?;
 
-            gen_merge_dir(ctx, opts, model).await?;
+            gen_merge_dir(ctx, opts, &merge_opt, model).await?;
 
             Ok(())
         }
         OscalModels::Catalog => {
-            let catalog_orig = models.read_catalog(&model_loc).await?;
+            let (catalog_orig, _) =
+                models.read_catalog(&model_loc, &options).await?;
             let mut catalog_class_orig = catalog_orig.catalog.clone();
             let mut catalog_builder = CatalogBuilder::default();
 
@@ -494,27 +665,39 @@ async fn process_merge_blocks(
 
                 match block {
                     CatalogBlock::Uuid => {
-                        let res = block.read_uuid(elt).await?;
+                        let (res, warnings) =
+                            block.read_uuid(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         catalog_class_orig.uuid = res
                     }
                     CatalogBlock::Metadata => {
-                        let res = block.read_metadata(elt).await?;
+                        let (res, warnings) =
+                            block.read_metadata(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         catalog_class_orig.metadata = res
                     }
                     CatalogBlock::Params => {
-                        let res = block.read_params(elt).await?;
+                        let (res, warnings) =
+                            block.read_params(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         catalog_class_orig.params = Some(res)
                     }
                     CatalogBlock::Controls => {
-                        let res = block.read_controls(elt).await?;
+                        let (res, warnings) =
+                            block.read_controls(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         catalog_class_orig.controls = Some(res)
                     }
                     CatalogBlock::Groups => {
-                        let res = block.read_groups(elt).await?;
+                        let (res, warnings) =
+                            block.read_groups(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         catalog_class_orig.groups = Some(res)
                     }
                     CatalogBlock::BackMatter => {
-                        let res = block.read_back_matter(elt).await?;
+                        let (res, warnings) =
+                            block.read_back_matter(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         catalog_class_orig.back_matter = Some(res)
                     }
                     _ => {}
@@ -536,23 +719,23 @@ async fn process_merge_blocks(
                 }
             }
 
-            let res = catalog_builder.catalog(catalog_class_orig).build()?;
+            let res = catalog_builder
+                .catalog(catalog_class_orig)
+                .build()
+                .map_err(|e| {
+                    CliError::InvalidModel(format!("{models:?}"), e.to_string())
+                })?;
+            let res = update_metadata(&catalog_orig, res, opts, &options)?;
 
-            let model = match MergeOpt::from_str(&opts.output_format)
-                .map_err(|_| CliError::UnknownMergeOpt)?
-            {
-                MergeOpt::Json => serde_json::to_string(&res)
-                    .with_context(|| "Could not parse model to json file")?,
-                MergeOpt::Yaml => serde_yaml::to_string(&res)
-                    .with_context(|| "Could not parse model to yaml file")?,
-            };
+            let model = merge_opt.serialize(&res)?;
 
-            gen_merge_dir(ctx, opts, model).await?;
+            gen_merge_dir(ctx, opts, &merge_opt, model).await?;
 
             Ok(())
         }
         OscalModels::Profile => {
-            let profile_orig = models.read_profile(&model_loc).await?;
+            let (profile_orig, _) =
+                models.read_profile(&model_loc, &options).await?;
             let mut profile_class_orig = profile_orig.profile.clone();
             let mut profile_builder = ProfileBuilder::default();
 
@@ -566,27 +749,39 @@ async fn process_merge_blocks(
 
                 match block {
                     ProfileBlock::Uuid => {
-                        let res = block.read_uuid(elt).await?;
+                        let (res, warnings) =
+                            block.read_uuid(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         profile_class_orig.uuid = res
                     }
                     ProfileBlock::Metadata => {
-                        let res = block.read_metadata(elt).await?;
+                        let (res, warnings) =
+                            block.read_metadata(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         profile_class_orig.metadata = res
                     }
                     ProfileBlock::Imports => {
-                        let res = block.read_imports(elt).await?;
+                        let (res, warnings) =
+                            block.read_imports(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         profile_class_orig.imports = res
                     }
                     ProfileBlock::Merge => {
-                        let res = block.read_merge(elt).await?;
+                        let (res, warnings) =
+                            block.read_merge(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         profile_class_orig.merge = Some(res)
                     }
                     ProfileBlock::Modify => {
-                        let res = block.read_modify(elt).await?;
+                        let (res, warnings) =
+                            block.read_modify(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         profile_class_orig.modify = Some(res)
                     }
                     ProfileBlock::BackMatter => {
-                        let res = block.read_back_matter(elt).await?;
+                        let (res, warnings) =
+                            block.read_back_matter(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         profile_class_orig.back_matter = Some(res)
                     }
                     _ => {}
@@ -608,24 +803,24 @@ async fn process_merge_blocks(
                 }
             }
 
-            let res = profile_builder.profile(profile_class_orig).build()?;
+            let res = profile_builder
+                .profile(profile_class_orig)
+                .build()
+                .map_err(|e| {
+                    CliError::InvalidModel(format!("{models:?}"), e.to_string())
+                })?;
+            let res = update_metadata(&profile_orig, res, opts, &options)?;
 
-            let model = match MergeOpt::from_str(&opts.output_format)
-                .map_err(|_| CliError::UnknownMergeOpt)?
-            {
-                MergeOpt::Json => serde_json::to_string(&res)
-                    .with_context(|| "Could not parse model to json file")?,
-                MergeOpt::Yaml => serde_yaml::to_string(&res)
-                    .with_context(|| "Could not parse model to yaml file")?,
-            };
+            let model = merge_opt.serialize(&res)?;
 
-            gen_merge_dir(ctx, opts, model).await?;
+            gen_merge_dir(ctx, opts, &merge_opt, model).await?;
 
             Ok(())
         }
         OscalModels::ComponentDefinition => {
-            let component_definition_orig =
-                models.read_component_definition(&model_loc).await?;
+            let (component_definition_orig, _) = models
+                .read_component_definition(&model_loc, &options)
+                .await?;
             let mut component_definition_class_orig =
                 component_definition_orig.component_definition.clone();
             let mut component_definition_builder =
@@ -639,30 +834,41 @@ async fn process_merge_blocks(
 
                 match block {
                     ComponentDefinitionBlock::Uuid => {
-                        let res = block.read_uuid(elt).await?;
+                        let (res, warnings) =
+                            block.read_uuid(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         component_definition_class_orig.uuid = res
                     }
                     ComponentDefinitionBlock::Metadata => {
-                        let res = block.read_metadata(elt).await?;
+                        let (res, warnings) =
+                            block.read_metadata(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         component_definition_class_orig.metadata = res
                     }
                     ComponentDefinitionBlock::ImportComponentDefinitions => {
-                        let res = block
-                            .read_import_component_definitions(elt)
+                        let (res, warnings) = block
+                            .read_import_component_definitions(elt, &options)
                             .await?;
+                        ctx.warnings.extend(warnings);
                         component_definition_class_orig
                             .import_component_definitions = Some(res)
                     }
                     ComponentDefinitionBlock::Components => {
-                        let res = block.read_components(elt).await?;
+                        let (res, warnings) =
+                            block.read_components(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         component_definition_class_orig.components = Some(res)
                     }
                     ComponentDefinitionBlock::Capabilities => {
-                        let res = block.read_capabilities(elt).await?;
+                        let (res, warnings) =
+                            block.read_capabilities(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         component_definition_class_orig.capabilities = Some(res)
                     }
                     ComponentDefinitionBlock::BackMatter => {
-                        let res = block.read_back_matter(elt).await?;
+                        let (res, warnings) =
+                            block.read_back_matter(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         component_definition_class_orig.back_matter = Some(res)
                     }
                     _ => {}
@@ -688,23 +894,25 @@ async fn process_merge_blocks(
 
             let res = component_definition_builder
                 .component_definition(component_definition_class_orig)
-                .build()?;
+                .build()
+                .map_err(|e| {
+                    CliError::InvalidModel(format!("{models:?}"), e.to_string())
+                })?;
+            let res = update_metadata(
+                &component_definition_orig,
+                res,
+                opts,
+                &options,
+            )?;
 
-            let model = match MergeOpt::from_str(&opts.output_format)
-                .map_err(|_| CliError::UnknownMergeOpt)?
-            {
-                MergeOpt::Json => serde_json::to_string(&res)
-                    .with_context(|| "Could not parse model to json file")?,
-                MergeOpt::Yaml => serde_yaml::to_string(&res)
-                    .with_context(|| "Could not parse model to yaml file")?,
-            };
+            let model = merge_opt.serialize(&res)?;
 
-            gen_merge_dir(ctx, opts, model).await?;
+            gen_merge_dir(ctx, opts, &merge_opt, model).await?;
 
             Ok(())
         }
         OscalModels::Ssp => {
-            let ssp_orig = models.read_ssp(&model_loc).await?;
+            let (ssp_orig, _) = models.read_ssp(&model_loc, &options).await?;
             let mut ssp_ssp_orig = ssp_orig.system_security_plan.clone();
             let mut ssp_builder = SystemSecurityPlanBuilder::default();
 
@@ -716,33 +924,48 @@ async fn process_merge_blocks(
 
                 match block {
                     SspBlock::Uuid => {
-                        let res = block.read_uuid(elt).await?;
+                        let (res, warnings) =
+                            block.read_uuid(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         ssp_ssp_orig.uuid = res
                     }
                     SspBlock::Metadata => {
-                        let res = block.read_metadata(elt).await?;
+                        let (res, warnings) =
+                            block.read_metadata(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         ssp_ssp_orig.metadata = res
                     }
                     SspBlock::ImportProfile => {
-                        let res = block.read_import_profile(elt).await?;
+                        let (res, warnings) =
+                            block.read_import_profile(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         ssp_ssp_orig.import_profile = res
                     }
                     SspBlock::SystemCharacteristics => {
-                        let res =
-                            block.read_system_characteristics(elt).await?;
+                        let (res, warnings) = block
+                            .read_system_characteristics(elt, &options)
+                            .await?;
+                        ctx.warnings.extend(warnings);
                         ssp_ssp_orig.system_characteristics = res
                     }
                     SspBlock::SystemImplementation => {
-                        let res = block.read_system_implementation(elt).await?;
+                        let (res, warnings) = block
+                            .read_system_implementation(elt, &options)
+                            .await?;
+                        ctx.warnings.extend(warnings);
                         ssp_ssp_orig.system_implementation = res
                     }
                     SspBlock::ControlImplementation => {
-                        let res =
-                            block.read_control_implementation(elt).await?;
+                        let (res, warnings) = block
+                            .read_control_implementation(elt, &options)
+                            .await?;
+                        ctx.warnings.extend(warnings);
                         ssp_ssp_orig.control_implementation = res
                     }
                     SspBlock::BackMatter => {
-                        let res = block.read_back_matter(elt).await?;
+                        let (res, warnings) =
+                            block.read_back_matter(elt, &options).await?;
+                        ctx.warnings.extend(warnings);
                         ssp_ssp_orig.back_matter = Some(res)
                     }
                     _ => {}
@@ -764,35 +987,115 @@ async fn process_merge_blocks(
                 }
             }
 
-            let res = ssp_builder.system_security_plan(ssp_ssp_orig).build()?;
+            let res = ssp_builder
+                .system_security_plan(ssp_ssp_orig)
+                .build()
+                .map_err(|e| {
+                    CliError::InvalidModel(format!("{models:?}"), e.to_string())
+                })?;
+            let res = update_metadata(&ssp_orig, res, opts, &options)?;
 
-            let model = match MergeOpt::from_str(&opts.output_format)
-                .map_err(|_| CliError::UnknownMergeOpt)?
-            {
-                MergeOpt::Json => serde_json::to_string(&res)
-                    .with_context(|| "Could not parse model to json file")?,
-                MergeOpt::Yaml => serde_yaml::to_string(&res)
-                    .with_context(|| "Could not parse model to yaml file")?,
-            };
+            let model = merge_opt.serialize(&res)?;
 
-            gen_merge_dir(ctx, opts, model).await?;
+            gen_merge_dir(ctx, opts, &merge_opt, model).await?;
 
             Ok(())
         }
     }
 }
 
-pub(super) async fn merge_workspace(opts: &Merge) -> Result<()> {
+/// Top level blocks of the model that differ from the original
+fn changed_blocks(orig: &Value, res: &Value) -> Vec<String> {
+    let inner = |root: &Value| -> Map<String, Value> {
+        root.as_object()
+            .and_then(|root| root.values().next())
+            .and_then(Value::as_object)
+            .cloned()
+            .unwrap_or_default()
+    };
+    let (orig, res) = (inner(orig), inner(res));
+
+    let mut blocks: Vec<String> = res
+        .keys()
+        .chain(orig.keys().filter(|key| !res.contains_key(*key)))
+        .filter(|key| orig.get(*key) != res.get(*key))
+        .cloned()
+        .collect();
+    blocks.sort();
+
+    blocks
+}
+
+/// Refresh metadata of the merged model according to the merge options
+fn update_metadata<T: ParseWith>(
+    orig: &T,
+    res: T,
+    opts: &Merge,
+    options: &ParseOptions,
+) -> Result<T> {
+    let scheme = opts
+        .bump_version
+        .as_deref()
+        .map(VersionScheme::from_str)
+        .transpose()
+        .map_err(|_| CliError::UnknownVersionScheme)?;
+    if !opts.update_metadata && scheme.is_none() {
+        return Ok(res);
+    }
+
+    let orig = serde_json::to_value(orig)?;
+    let mut root = serde_json::to_value(res)?;
+    let blocks = changed_blocks(&orig, &root);
+    if blocks.is_empty() {
+        return Ok(T::deserialize_with(root, options)?);
+    }
+
+    let metadata = root
+        .as_object_mut()
+        .and_then(|root| root.values_mut().next())
+        .and_then(|model| model.get_mut("metadata"))
+        .and_then(Value::as_object_mut)
+        .with_context(|| "Could not locate metadata of the merged model")?;
+    let now = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    if let Some(scheme) = scheme {
+        let current = metadata
+            .get("version")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let version = scheme.bump(current)?;
+        metadata.insert("version".to_owned(), version.into());
+    }
+    metadata.insert("last-modified".to_owned(), now.clone().into());
+
+    if opts.update_metadata {
+        let revision = json!({
+            "title": "Merged workspace changes",
+            "last-modified": now,
+            "version": metadata.get("version").cloned().unwrap_or_default(),
+            "remarks": format!("Changed blocks: {}", blocks.join(", ")),
+        });
+        let revisions = metadata
+            .entry("revisions")
+            .or_insert_with(|| Value::Array(vec![]));
+        if let Value::Array(revisions) = revisions {
+            revisions.push(revision);
+        }
+    }
+
+    Ok(T::deserialize_with(root, options)?)
+}
+
+pub(super) async fn merge_workspace(opts: &Merge) -> Result<MergeCtx> {
     let manifest = read_dissect_ctx(&opts.dir).await?;
 
-    if manifest.is_valid_hash()? {
-        let mut ctx = gen_merge_ctx(opts, manifest).await?;
-        let blocks = gen_merge_blocks(&ctx).await?;
-        process_merge_blocks(&blocks, &mut ctx, opts).await?;
-    } else {
-        eprintln!("File integrity check faild as the hash does not match the original, program aborted");
-        std::process::exit(1)
+    if !manifest.is_valid_hash()? {
+        return Err(CliError::IntegrityCheck);
     }
 
-    Ok(())
+    let mut ctx = gen_merge_ctx(opts, manifest).await?;
+    let blocks = gen_merge_blocks(&ctx).await?;
+    process_merge_blocks(&blocks, &mut ctx, opts).await?;
+
+    Ok(ctx)
 }
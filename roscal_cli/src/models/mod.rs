@@ -1,6 +1,16 @@
+pub mod chain;
+pub mod convert;
+pub mod coverage;
 pub mod dissect;
+pub mod edit;
+pub mod imports;
 pub mod merge;
 pub mod model;
+pub mod package;
+pub mod query;
+pub mod render;
+pub mod resolve;
+pub mod resources;
 pub mod utils;
 pub mod validate;
 pub mod validation;
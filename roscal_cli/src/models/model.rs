@@ -1,30 +1,34 @@
 use std::{
-    fs::File,
+    fmt,
+    fs::{read_to_string, File},
     io::{BufReader, Write},
-    path::PathBuf,
+    path::{Path, PathBuf},
     str::FromStr,
 };
 
-use anyhow::{Context, Result};
 use roscal_lib::{
     assessment::{
         assessment_plan::AssessmentPlan, assessment_results::AssessmentResults,
         poam::PlanOfActionAndMilestones,
     },
     control::{catalog::Catalog, profile::Profile},
+    document::ModelType,
     implementation::{
         component_definition::ComponentDefinition, ssp::SystemSecurityPlan,
     },
+    parse::{self, ParseOptions, ParseWarning, ParseWith},
+    scalar::OscalUuid,
 };
+use serde::Serialize;
 use strum::{Display, EnumString};
 
 use super::{
     dissect::gen_dissect_dir,
-    workspace::{CliError, DissectCtx},
+    workspace::{CliError, Context, DissectCtx, Result},
 };
 
 #[non_exhaustive]
-#[derive(Debug, Clone, PartialEq, EnumString)]
+#[derive(Debug, Clone, PartialEq, Display, EnumString)]
 pub(super) enum OscalModels {
     #[strum(serialize = "AssessmentPlan")]
     AssessmentPlan,
@@ -42,183 +46,374 @@ pub(super) enum OscalModels {
     Ssp,
 }
 
+impl From<ModelType> for OscalModels {
+    fn from(model_type: ModelType) -> Self {
+        match model_type {
+            ModelType::AssessmentPlan => OscalModels::AssessmentPlan,
+            ModelType::AssessmentResults => OscalModels::AssessmentResults,
+            ModelType::PlanOfActionAndMilestones => OscalModels::Poam,
+            ModelType::Catalog => OscalModels::Catalog,
+            ModelType::Profile => OscalModels::Profile,
+            ModelType::ComponentDefinition => OscalModels::ComponentDefinition,
+            ModelType::SystemSecurityPlan => OscalModels::Ssp,
+        }
+    }
+}
+
+/// Field unknown to the model kept by a lenient read, and the file it is in
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelWarning {
+    pub source: String,
+    pub warning: ParseWarning,
+}
+
+impl fmt::Display for ModelWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} in `{}`", self.warning, self.source)
+    }
+}
+
+pub(super) fn model_warnings(
+    warnings: Vec<ParseWarning>,
+    source: &str,
+) -> Vec<ModelWarning> {
+    warnings
+        .into_iter()
+        .map(|warning| ModelWarning {
+            source: source.to_owned(),
+            warning,
+        })
+        .collect()
+}
+
+/// A model read from a file, with the unknown fields a lenient read kept
+pub type Parsed<T> = (T, Vec<ModelWarning>);
+
+fn to_value<T: Serialize>(
+    (model, warnings): Parsed<T>,
+) -> Result<Parsed<serde_json::Value>> {
+    Ok((serde_json::to_value(model)?, warnings))
+}
+
+impl From<&OscalModels> for ModelType {
+    fn from(model: &OscalModels) -> Self {
+        match model {
+            OscalModels::AssessmentPlan => ModelType::AssessmentPlan,
+            OscalModels::AssessmentResults => ModelType::AssessmentResults,
+            OscalModels::Poam => ModelType::PlanOfActionAndMilestones,
+            OscalModels::Catalog => ModelType::Catalog,
+            OscalModels::Profile => ModelType::Profile,
+            OscalModels::ComponentDefinition => ModelType::ComponentDefinition,
+            OscalModels::Ssp => ModelType::SystemSecurityPlan,
+        }
+    }
+}
+
 impl OscalModels {
-    async fn read_file(path: &PathBuf) -> Result<BufReader<File>> {
+    async fn read_model<T: ParseWith>(
+        path: &PathBuf,
+        name: &str,
+        options: &ParseOptions,
+    ) -> Result<Parsed<T>> {
         let file = File::open(path).with_context(|| {
             format!("Could not open model file: `{}`", path.display())
         })?;
 
-        Ok(BufReader::new(file))
+        let (model, warnings) =
+            T::from_reader_with_warnings(BufReader::new(file), options)
+                .map_err(|e| {
+                    CliError::ParseModel(
+                        name.to_owned(),
+                        path.to_string_lossy().into_owned(),
+                        e.to_string(),
+                    )
+                })?;
+
+        Ok((model, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     pub async fn read_assessment_plan(
         &self,
         path: &PathBuf,
-    ) -> Result<AssessmentPlan> {
-        let reader = Self::read_file(path).await?;
-        let assessment_plan: AssessmentPlan = serde_yaml::from_reader(reader)
-            .map_err(|e| {
-            CliError::ParseModel(
-                "assessment plan".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
-
-        Ok(assessment_plan)
+        options: &ParseOptions,
+    ) -> Result<Parsed<AssessmentPlan>> {
+        Self::read_model(path, "assessment plan", options).await
     }
 
     pub async fn read_assessment_results(
         &self,
         path: &PathBuf,
-    ) -> Result<AssessmentResults> {
-        let reader = Self::read_file(path).await?;
-        let assessment_results: AssessmentResults =
-            serde_yaml::from_reader(reader).map_err(|e| {
-                CliError::ParseModel(
-                    "assessment results".to_owned(),
-                    path.to_string_lossy().into_owned(),
-                    e.to_string(),
-                )
-            })?;
-
-        Ok(assessment_results)
+        options: &ParseOptions,
+    ) -> Result<Parsed<AssessmentResults>> {
+        Self::read_model(path, "assessment results", options).await
     }
 
     pub async fn read_poam(
         &self,
         path: &PathBuf,
-    ) -> Result<PlanOfActionAndMilestones> {
-        let reader = Self::read_file(path).await?;
-        let poam: PlanOfActionAndMilestones = serde_yaml::from_reader(reader)
-            .map_err(|e| {
-            CliError::ParseModel(
-                "poam".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
+        options: &ParseOptions,
+    ) -> Result<Parsed<PlanOfActionAndMilestones>> {
+        Self::read_model(path, "poam", options).await
+    }
+
+    pub async fn read_catalog(
+        &self,
+        path: &PathBuf,
+        options: &ParseOptions,
+    ) -> Result<Parsed<Catalog>> {
+        Self::read_model(path, "catalog", options).await
+    }
+
+    pub async fn read_profile(
+        &self,
+        path: &PathBuf,
+        options: &ParseOptions,
+    ) -> Result<Parsed<Profile>> {
+        Self::read_model(path, "profile", options).await
+    }
+
+    pub async fn read_component_definition(
+        &self,
+        path: &PathBuf,
+        options: &ParseOptions,
+    ) -> Result<Parsed<ComponentDefinition>> {
+        Self::read_model(path, "component_definition", options).await
+    }
+
+    pub async fn read_ssp(
+        &self,
+        path: &PathBuf,
+        options: &ParseOptions,
+    ) -> Result<Parsed<SystemSecurityPlan>> {
+        Self::read_model(path, "ssp", options).await
+    }
+
+    /// Model named on the command line, or else detected from the file
+    pub fn from_opt(model: Option<&str>, path: &Path) -> Result<Self> {
+        match model {
+            Some(model) => {
+                Ok(Self::from_str(model).map_err(|_| CliError::UnknownModel)?)
+            }
+            None => Self::detect(path),
+        }
+    }
+
+    /// Model named by the root key or element of the file
+    pub fn detect(path: &Path) -> Result<Self> {
+        let input = read_to_string(path).with_context(|| {
+            format!("Could not open model file: `{}`", path.display())
         })?;
 
-        Ok(poam)
+        Self::detect_str(&input, &path.to_string_lossy())
     }
 
-    pub async fn read_catalog(&self, path: &PathBuf) -> Result<Catalog> {
-        let reader = Self::read_file(path).await?;
-        let catalog: Catalog =
-            serde_yaml::from_reader(reader).map_err(|e| {
-                CliError::ParseModel(
-                    "catalog".to_owned(),
-                    path.to_string_lossy().into_owned(),
-                    e.to_string(),
-                )
-            })?;
+    pub fn detect_str(input: &str, source: &str) -> Result<Self> {
+        let model_type = ModelType::detect(input)
+            .map_err(|_| CliError::UndetectedModel(source.to_owned()))?;
 
-        Ok(catalog)
+        Ok(model_type.into())
     }
 
-    pub async fn read_profile(&self, path: &PathBuf) -> Result<Profile> {
-        let reader = Self::read_file(path).await?;
-        let profile: Profile =
-            serde_yaml::from_reader(reader).map_err(|e| {
+    fn parse_model<T: ParseWith + Serialize>(
+        input: &str,
+        source: &str,
+        name: &str,
+        options: &ParseOptions,
+    ) -> Result<Parsed<serde_json::Value>> {
+        let (model, warnings) = T::from_str_with_warnings(input, options)
+            .map_err(|e| {
                 CliError::ParseModel(
-                    "profile".to_owned(),
-                    path.to_string_lossy().into_owned(),
+                    name.to_owned(),
+                    source.to_owned(),
                     e.to_string(),
                 )
             })?;
 
-        Ok(profile)
+        Ok((
+            serde_json::to_value(model)?,
+            model_warnings(warnings, source),
+        ))
     }
 
-    pub async fn read_component_definition(
+    /// Parse a JSON or YAML document of this type into a JSON value
+    pub fn parse_value(
         &self,
-        path: &PathBuf,
-    ) -> Result<ComponentDefinition> {
-        let reader = Self::read_file(path).await?;
-        let component_definition: ComponentDefinition =
-            serde_yaml::from_reader(reader).map_err(|e| {
-                CliError::ParseModel(
-                    "component_definition".to_owned(),
-                    path.to_string_lossy().into_owned(),
-                    e.to_string(),
+        input: &str,
+        source: &str,
+        options: &ParseOptions,
+    ) -> Result<Parsed<serde_json::Value>> {
+        match self {
+            OscalModels::AssessmentPlan => Self::parse_model::<AssessmentPlan>(
+                input,
+                source,
+                "assessment plan",
+                options,
+            ),
+            OscalModels::AssessmentResults => {
+                Self::parse_model::<AssessmentResults>(
+                    input,
+                    source,
+                    "assessment results",
+                    options,
                 )
-            })?;
-
-        Ok(component_definition)
+            }
+            OscalModels::Poam => {
+                Self::parse_model::<PlanOfActionAndMilestones>(
+                    input, source, "poam", options,
+                )
+            }
+            OscalModels::Catalog => {
+                Self::parse_model::<Catalog>(input, source, "catalog", options)
+            }
+            OscalModels::Profile => {
+                Self::parse_model::<Profile>(input, source, "profile", options)
+            }
+            OscalModels::ComponentDefinition => {
+                Self::parse_model::<ComponentDefinition>(
+                    input,
+                    source,
+                    "component_definition",
+                    options,
+                )
+            }
+            OscalModels::Ssp => Self::parse_model::<SystemSecurityPlan>(
+                input, source, "ssp", options,
+            ),
+        }
     }
 
-    pub async fn read_ssp(&self, path: &PathBuf) -> Result<SystemSecurityPlan> {
-        let reader = Self::read_file(path).await?;
-        let ssp: SystemSecurityPlan =
-            serde_yaml::from_reader(reader).map_err(|e| {
-                CliError::ParseModel(
-                    "ssp".to_owned(),
-                    path.to_string_lossy().into_owned(),
-                    e.to_string(),
-                )
-            })?;
+    /// Check that a JSON value is a valid model of this type
+    pub fn check_value(
+        &self,
+        value: serde_json::Value,
+        options: &ParseOptions,
+    ) -> Result<()> {
+        let res = match self {
+            OscalModels::AssessmentPlan => {
+                AssessmentPlan::deserialize_with(value, options).map(|_| ())
+            }
+            OscalModels::AssessmentResults => {
+                AssessmentResults::deserialize_with(value, options).map(|_| ())
+            }
+            OscalModels::Poam => {
+                PlanOfActionAndMilestones::deserialize_with(value, options)
+                    .map(|_| ())
+            }
+            OscalModels::Catalog => {
+                Catalog::deserialize_with(value, options).map(|_| ())
+            }
+            OscalModels::Profile => {
+                Profile::deserialize_with(value, options).map(|_| ())
+            }
+            OscalModels::ComponentDefinition => {
+                ComponentDefinition::deserialize_with(value, options)
+                    .map(|_| ())
+            }
+            OscalModels::Ssp => {
+                SystemSecurityPlan::deserialize_with(value, options).map(|_| ())
+            }
+        };
+
+        res.map_err(|e| {
+            CliError::InvalidModel(format!("{self:?}"), e.to_string())
+        })
+    }
 
-        Ok(ssp)
+    /// Read and check a model file, returning it in its JSON form
+    pub async fn read_value(
+        &self,
+        path: &PathBuf,
+        options: &ParseOptions,
+    ) -> Result<Parsed<serde_json::Value>> {
+        match self {
+            OscalModels::AssessmentPlan => {
+                to_value(self.read_assessment_plan(path, options).await?)
+            }
+            OscalModels::AssessmentResults => {
+                to_value(self.read_assessment_results(path, options).await?)
+            }
+            OscalModels::Poam => to_value(self.read_poam(path, options).await?),
+            OscalModels::Catalog => {
+                to_value(self.read_catalog(path, options).await?)
+            }
+            OscalModels::Profile => {
+                to_value(self.read_profile(path, options).await?)
+            }
+            OscalModels::ComponentDefinition => {
+                to_value(self.read_component_definition(path, options).await?)
+            }
+            OscalModels::Ssp => to_value(self.read_ssp(path, options).await?),
+        }
     }
 
-    pub async fn gen_models(&self, ctx: &DissectCtx) -> Result<()> {
+    /// Write the block files of a dissect workspace, returning the warnings
+    /// of the lenient read
+    pub async fn gen_models(
+        &self,
+        ctx: &DissectCtx,
+    ) -> Result<Vec<ModelWarning>> {
         let model_loc = &ctx.model_loc;
+        let options = ctx.parse_options();
         gen_dissect_dir(ctx).await?;
 
         match self {
             Self::AssessmentPlan => {
-                let assessment_plan =
-                    self.read_assessment_plan(model_loc).await?;
+                let (assessment_plan, warnings) =
+                    self.read_assessment_plan(model_loc, &options).await?;
 
                 AssessmentPlanBlock::gen_files(assessment_plan, ctx).await?;
 
-                Ok(())
+                Ok(warnings)
             }
             Self::AssessmentResults => {
-                let assessment_results =
-                    self.read_assessment_results(model_loc).await?;
+                let (assessment_results, warnings) =
+                    self.read_assessment_results(model_loc, &options).await?;
 
                 AssessmentResultsBlock::gen_files(assessment_results, ctx)
                     .await?;
 
-                Ok(())
+                Ok(warnings)
             }
             Self::Poam => {
-                let poam = self.read_poam(model_loc).await?;
+                let (poam, warnings) =
+                    self.read_poam(model_loc, &options).await?;
 
                 PoamBlock::gen_files(poam, ctx).await?;
 
-                Ok(())
+                Ok(warnings)
             }
             Self::Catalog => {
-                let catalog = self.read_catalog(model_loc).await?;
+                let (catalog, warnings) =
+                    self.read_catalog(model_loc, &options).await?;
 
                 CatalogBlock::gen_files(catalog, ctx).await?;
 
-                Ok(())
+                Ok(warnings)
             }
             Self::Profile => {
-                let profile = self.read_profile(model_loc).await?;
+                let (profile, warnings) =
+                    self.read_profile(model_loc, &options).await?;
 
                 ProfileBlock::gen_files(profile, ctx).await?;
 
-                Ok(())
+                Ok(warnings)
             }
             Self::ComponentDefinition => {
-                let component_definition =
-                    self.read_component_definition(model_loc).await?;
+                let (component_definition, warnings) =
+                    self.read_component_definition(model_loc, &options).await?;
 
                 ComponentDefinitionBlock::gen_files(component_definition, ctx)
                     .await?;
 
-                Ok(())
+                Ok(warnings)
             }
             Self::Ssp => {
-                let ssp = self.read_ssp(model_loc).await?;
+                let (ssp, warnings) =
+                    self.read_ssp(model_loc, &options).await?;
 
                 SspBlock::gen_files(ssp, ctx).await?;
 
-                Ok(())
+                Ok(warnings)
             }
         }
     }
@@ -274,19 +469,24 @@ impl AssessmentPlanBlock {
         Ok(())
     }
 
-    pub(super) async fn read_uuid(&self, path: &PathBuf) -> Result<String> {
+    pub(super) async fn read_uuid(
+        &self,
+        path: &PathBuf,
+        options: &ParseOptions,
+    ) -> Result<Parsed<OscalUuid>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let uuid = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "uuid".to_owned(),
-                "assessment plan".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (uuid, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "uuid".to_owned(),
+                    "assessment plan".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(uuid)
+        Ok((uuid, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_metadata(
@@ -314,19 +514,22 @@ impl AssessmentPlanBlock {
     pub(super) async fn read_metadata(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::assessment::assessment_plan::DocumentMetadata> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::assessment::assessment_plan::DocumentMetadata>>
+    {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let metadata = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "metadata".to_owned(),
-                "assessment plan".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (metadata, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "metadata".to_owned(),
+                    "assessment plan".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(metadata)
+        Ok((metadata, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_import_ssp(
@@ -356,20 +559,28 @@ impl AssessmentPlanBlock {
     pub(super) async fn read_import_ssp(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::assessment::assessment_plan::ImportSystemSecurityPlan>
-    {
+        options: &ParseOptions,
+    ) -> Result<
+        Parsed<
+            roscal_lib::assessment::assessment_plan::ImportSystemSecurityPlan,
+        >,
+    > {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let import_ssp = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "import_ssp".to_owned(),
-                "assessment plan".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (import_ssp, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "import_ssp".to_owned(),
+                    "assessment plan".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(import_ssp)
+        Ok((
+            import_ssp,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_local_definitions(
@@ -401,11 +612,13 @@ impl AssessmentPlanBlock {
     pub(super) async fn read_local_definitions(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::assessment::assessment_plan::LocalDefinitions> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::assessment::assessment_plan::LocalDefinitions>>
+    {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let local_definitions =
-            serde_yaml::from_reader(reader).map_err(|e| {
+        let (local_definitions, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
                 CliError::ParseBlock(
                     "local_definition".to_owned(),
                     "assessment plan".to_owned(),
@@ -414,7 +627,10 @@ impl AssessmentPlanBlock {
                 )
             })?;
 
-        Ok(local_definitions)
+        Ok((
+            local_definitions,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_terms_and_conditions(
@@ -449,14 +665,14 @@ impl AssessmentPlanBlock {
 
     pub(super) async fn read_terms_and_conditions(
         &self,
-        path: &PathBuf,
-    ) -> Result<
+        path: &PathBuf, options: &ParseOptions,
+    ) -> Result<Parsed<
     roscal_lib::assessment::assessment_plan::AssessmentPlanTermsAndConditions,
-    >{
+    >>{
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let terms_and_conditions =
-            serde_yaml::from_reader(reader).map_err(|e| {
+        let (terms_and_conditions, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
                 CliError::ParseBlock(
                     "terms_and_conditions".to_owned(),
                     "assessment plan".to_owned(),
@@ -465,7 +681,10 @@ impl AssessmentPlanBlock {
                 )
             })?;
 
-        Ok(terms_and_conditions)
+        Ok((
+            terms_and_conditions,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_reviewed_controls(
@@ -492,11 +711,19 @@ impl AssessmentPlanBlock {
         Ok(())
     }
 
-    pub(super) async fn read_reviewed_controls(&self, path: &PathBuf) -> Result<roscal_lib::assessment::assessment_plan::ReviewedControlsAndControlObjectives>{
+    pub(super) async fn read_reviewed_controls(
+        &self,
+        path: &PathBuf,
+        options: &ParseOptions,
+    ) -> Result<
+        Parsed<
+            roscal_lib::assessment::assessment_plan::ReviewedControlsAndControlObjectives,
+        >,
+    >{
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let reviewed_controls =
-            serde_yaml::from_reader(reader).map_err(|e| {
+        let (reviewed_controls, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
                 CliError::ParseBlock(
                     "reviewed_controls".to_owned(),
                     "assessment plan".to_owned(),
@@ -505,7 +732,10 @@ impl AssessmentPlanBlock {
                 )
             })?;
 
-        Ok(reviewed_controls)
+        Ok((
+            reviewed_controls,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_assessment_subjects(
@@ -541,12 +771,16 @@ impl AssessmentPlanBlock {
     pub(super) async fn read_assessment_subjects(
         &self,
         path: &PathBuf,
-    ) -> Result<Vec<roscal_lib::assessment::assessment_plan::SubjectOfAssessment>>
-    {
+        options: &ParseOptions,
+    ) -> Result<
+        Parsed<
+            Vec<roscal_lib::assessment::assessment_plan::SubjectOfAssessment>,
+        >,
+    > {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let assessment_subjects =
-            serde_yaml::from_reader(reader).map_err(|e| {
+        let (assessment_subjects, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
                 CliError::ParseBlock(
                     "assessment_subjects".to_owned(),
                     "assessment plan".to_owned(),
@@ -555,7 +789,10 @@ impl AssessmentPlanBlock {
                 )
             })?;
 
-        Ok(assessment_subjects)
+        Ok((
+            assessment_subjects,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_assessment_assets(
@@ -587,11 +824,13 @@ impl AssessmentPlanBlock {
     pub(super) async fn read_assessment_assets(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::assessment::assessment_plan::AssessmentAssets> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::assessment::assessment_plan::AssessmentAssets>>
+    {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let assessment_assets =
-            serde_yaml::from_reader(reader).map_err(|e| {
+        let (assessment_assets, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
                 CliError::ParseBlock(
                     "assessment_assets".to_owned(),
                     "assessment plan".to_owned(),
@@ -600,7 +839,10 @@ impl AssessmentPlanBlock {
                 )
             })?;
 
-        Ok(assessment_assets)
+        Ok((
+            assessment_assets,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_tasks(
@@ -630,19 +872,22 @@ impl AssessmentPlanBlock {
     pub(super) async fn read_tasks(
         &self,
         path: &PathBuf,
-    ) -> Result<Vec<roscal_lib::assessment::assessment_plan::Task>> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<Vec<roscal_lib::assessment::assessment_plan::Task>>>
+    {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let tasks = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "tasks".to_owned(),
-                "assessment plan".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (tasks, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "tasks".to_owned(),
+                    "assessment plan".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(tasks)
+        Ok((tasks, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_back_matter(
@@ -675,19 +920,25 @@ impl AssessmentPlanBlock {
     pub(super) async fn read_back_matter(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::assessment::assessment_plan::BackMatter> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::assessment::assessment_plan::BackMatter>>
+    {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let back_matter = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "back_matter".to_owned(),
-                "assessment plan".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (back_matter, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "back_matter".to_owned(),
+                    "assessment plan".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(back_matter)
+        Ok((
+            back_matter,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     pub async fn gen_files(
@@ -807,19 +1058,24 @@ impl AssessmentResultsBlock {
         Ok(())
     }
 
-    pub(super) async fn read_uuid(&self, path: &PathBuf) -> Result<String> {
+    pub(super) async fn read_uuid(
+        &self,
+        path: &PathBuf,
+        options: &ParseOptions,
+    ) -> Result<Parsed<OscalUuid>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let uuid = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "uuid".to_owned(),
-                "assessment results".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (uuid, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "uuid".to_owned(),
+                    "assessment results".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(uuid)
+        Ok((uuid, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_metadata(
@@ -848,20 +1104,23 @@ impl AssessmentResultsBlock {
     pub(super) async fn read_metadata(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::assessment::assessment_results::DocumentMetadata>
-    {
+        options: &ParseOptions,
+    ) -> Result<
+        Parsed<roscal_lib::assessment::assessment_results::DocumentMetadata>,
+    > {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let metadata = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "metadata".to_owned(),
-                "assessment results".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (metadata, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "metadata".to_owned(),
+                    "assessment results".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(metadata)
+        Ok((metadata, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_import_ap(
@@ -892,20 +1151,25 @@ impl AssessmentResultsBlock {
     pub(super) async fn read_import_ap(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::assessment::assessment_results::ImportAssessmentPlan>
-    {
+        options: &ParseOptions,
+    ) -> Result<
+        Parsed<
+            roscal_lib::assessment::assessment_results::ImportAssessmentPlan,
+        >,
+    > {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let import_ap = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "import_ap".to_owned(),
-                "assessment results".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (import_ap, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "import_ap".to_owned(),
+                    "assessment results".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(import_ap)
+        Ok((import_ap, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_local_definitions(
@@ -938,11 +1202,19 @@ impl AssessmentResultsBlock {
         Ok(())
     }
 
-    pub(super) async fn read_local_definitions(&self, path: &PathBuf) -> Result<roscal_lib::assessment::assessment_results::AssessmentResultsLocalDefinitions>{
+    pub(super) async fn read_local_definitions(
+        &self,
+        path: &PathBuf,
+        options: &ParseOptions,
+    ) -> Result<
+        Parsed<
+            roscal_lib::assessment::assessment_results::AssessmentResultsLocalDefinitions,
+        >,
+    >{
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let local_definitions =
-            serde_yaml::from_reader(reader).map_err(|e| {
+        let (local_definitions, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
                 CliError::ParseBlock(
                     "local_definition".to_owned(),
                     "assessment results".to_owned(),
@@ -951,7 +1223,10 @@ impl AssessmentResultsBlock {
                 )
             })?;
 
-        Ok(local_definitions)
+        Ok((
+            local_definitions,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_results(
@@ -977,20 +1252,25 @@ impl AssessmentResultsBlock {
     pub(super) async fn read_results(
         &self,
         path: &PathBuf,
-    ) -> Result<Vec<roscal_lib::assessment::assessment_results::AssessmentResult>>
-    {
+        options: &ParseOptions,
+    ) -> Result<
+        Parsed<
+            Vec<roscal_lib::assessment::assessment_results::AssessmentResult>,
+        >,
+    > {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let results = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "results".to_owned(),
-                "assessment results".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (results, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "results".to_owned(),
+                    "assessment results".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(results)
+        Ok((results, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_back_matter(
@@ -1023,19 +1303,25 @@ impl AssessmentResultsBlock {
     pub(super) async fn read_back_matter(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::assessment::assessment_results::BackMatter> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::assessment::assessment_results::BackMatter>>
+    {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let back_matter = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "back_matter".to_owned(),
-                "assessment results".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (back_matter, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "back_matter".to_owned(),
+                    "assessment results".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(back_matter)
+        Ok((
+            back_matter,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     pub async fn gen_files(
@@ -1142,19 +1428,24 @@ impl PoamBlock {
         Ok(())
     }
 
-    pub(super) async fn read_uuid(&self, path: &PathBuf) -> Result<String> {
+    pub(super) async fn read_uuid(
+        &self,
+        path: &PathBuf,
+        options: &ParseOptions,
+    ) -> Result<Parsed<OscalUuid>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let uuid = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "uuid".to_owned(),
-                "poam".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (uuid, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "uuid".to_owned(),
+                    "poam".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(uuid)
+        Ok((uuid, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_metadata(
@@ -1183,19 +1474,21 @@ impl PoamBlock {
     pub(super) async fn read_metadata(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::assessment::poam::DocumentMetadata> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::assessment::poam::DocumentMetadata>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let metadata = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "metadata".to_owned(),
-                "poam".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (metadata, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "metadata".to_owned(),
+                    "poam".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(metadata)
+        Ok((metadata, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_import_ssp(
@@ -1228,19 +1521,25 @@ impl PoamBlock {
     pub(super) async fn read_import_ssp(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::assessment::poam::ImportSystemSecurityPlan> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::assessment::poam::ImportSystemSecurityPlan>>
+    {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let import_ssp = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "import_ssp".to_owned(),
-                "poam".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (import_ssp, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "import_ssp".to_owned(),
+                    "poam".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(import_ssp)
+        Ok((
+            import_ssp,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_system_id(
@@ -1273,19 +1572,22 @@ impl PoamBlock {
     pub(super) async fn read_system_id(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::assessment::poam::SystemIdentification> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::assessment::poam::SystemIdentification>>
+    {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let system_id = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "system_id".to_owned(),
-                "poam".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (system_id, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "system_id".to_owned(),
+                    "poam".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(system_id)
+        Ok((system_id, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_system_local_definitions(
@@ -1321,11 +1623,12 @@ impl PoamBlock {
     pub(super) async fn read_system_local_definitions(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::assessment::poam::LocalDefinitions> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::assessment::poam::LocalDefinitions>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let system_local_definitions = serde_yaml::from_reader(reader)
-            .map_err(|e| {
+        let (system_local_definitions, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
                 CliError::ParseBlock(
                     "system_local_definition".to_owned(),
                     "poam".to_owned(),
@@ -1334,7 +1637,10 @@ impl PoamBlock {
                 )
             })?;
 
-        Ok(system_local_definitions)
+        Ok((
+            system_local_definitions,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_observations(
@@ -1367,19 +1673,24 @@ impl PoamBlock {
     pub(super) async fn read_observations(
         &self,
         path: &PathBuf,
-    ) -> Result<Vec<roscal_lib::assessment::poam::Observation>> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<Vec<roscal_lib::assessment::poam::Observation>>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let observations = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "observation".to_owned(),
-                "poam".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (observations, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "observation".to_owned(),
+                    "poam".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(observations)
+        Ok((
+            observations,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_risks(
@@ -1410,19 +1721,21 @@ impl PoamBlock {
     pub(super) async fn read_risks(
         &self,
         path: &PathBuf,
-    ) -> Result<Vec<roscal_lib::assessment::poam::IdentifiedRisk>> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<Vec<roscal_lib::assessment::poam::IdentifiedRisk>>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let risks = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "risks".to_owned(),
-                "poam".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (risks, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "risks".to_owned(),
+                    "poam".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(risks)
+        Ok((risks, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_findings(
@@ -1453,19 +1766,21 @@ impl PoamBlock {
     pub(super) async fn read_findings(
         &self,
         path: &PathBuf,
-    ) -> Result<Vec<roscal_lib::assessment::poam::Finding>> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<Vec<roscal_lib::assessment::poam::Finding>>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let findings = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "findings".to_owned(),
-                "poam".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (findings, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "findings".to_owned(),
+                    "poam".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(findings)
+        Ok((findings, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_poam_items(
@@ -1496,19 +1811,24 @@ impl PoamBlock {
     pub(super) async fn read_poam_items(
         &self,
         path: &PathBuf,
-    ) -> Result<Vec<roscal_lib::assessment::poam::PoaMItem>> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<Vec<roscal_lib::assessment::poam::PoaMItem>>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let poam_items = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "poam_item".to_owned(),
-                "poam".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (poam_items, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "poam_item".to_owned(),
+                    "poam".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(poam_items)
+        Ok((
+            poam_items,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_back_matter(
@@ -1541,19 +1861,24 @@ impl PoamBlock {
     pub(super) async fn read_back_matter(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::assessment::poam::BackMatter> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::assessment::poam::BackMatter>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let back_matter = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "back_matter".to_owned(),
-                "poam".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (back_matter, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "back_matter".to_owned(),
+                    "poam".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(back_matter)
+        Ok((
+            back_matter,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     pub async fn gen_files(
@@ -1649,19 +1974,24 @@ impl CatalogBlock {
         Ok(())
     }
 
-    pub(super) async fn read_uuid(&self, path: &PathBuf) -> Result<String> {
+    pub(super) async fn read_uuid(
+        &self,
+        path: &PathBuf,
+        options: &ParseOptions,
+    ) -> Result<Parsed<OscalUuid>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let uuid = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "uuid".to_owned(),
-                "catalog".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (uuid, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "uuid".to_owned(),
+                    "catalog".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(uuid)
+        Ok((uuid, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_metadata(catalog: &Catalog, path: &String) -> Result<()> {
@@ -1685,19 +2015,21 @@ impl CatalogBlock {
     pub(super) async fn read_metadata(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::control::catalog::DocumentMetadata> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::control::catalog::DocumentMetadata>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let metadata = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "metadata".to_owned(),
-                "catalog".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (metadata, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "metadata".to_owned(),
+                    "catalog".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(metadata)
+        Ok((metadata, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_params(catalog: &Catalog, path: &String) -> Result<()> {
@@ -1723,19 +2055,21 @@ impl CatalogBlock {
     pub(super) async fn read_params(
         &self,
         path: &PathBuf,
-    ) -> Result<Vec<roscal_lib::control::catalog::Parameter>> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<Vec<roscal_lib::control::catalog::Parameter>>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let params = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "params".to_owned(),
-                "catalog".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (params, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "params".to_owned(),
+                    "catalog".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(params)
+        Ok((params, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_controls(catalog: &Catalog, path: &String) -> Result<()> {
@@ -1761,20 +2095,21 @@ impl CatalogBlock {
     pub(super) async fn read_controls(
         &self,
         path: &PathBuf,
-    ) -> Result<Vec<roscal_lib::control::catalog::Control>> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<Vec<roscal_lib::control::catalog::Control>>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let controls = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "controls".to_owned(),
-                "catalog".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (controls, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "controls".to_owned(),
+                    "catalog".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        println!("{:?}", controls);
-        Ok(controls)
+        Ok((controls, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_groups(catalog: &Catalog, path: &String) -> Result<()> {
@@ -1800,19 +2135,21 @@ impl CatalogBlock {
     pub(super) async fn read_groups(
         &self,
         path: &PathBuf,
-    ) -> Result<Vec<roscal_lib::control::catalog::ControlGroup>> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<Vec<roscal_lib::control::catalog::ControlGroup>>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let groups = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "groups".to_owned(),
-                "catalog".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (groups, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "groups".to_owned(),
+                    "catalog".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(groups)
+        Ok((groups, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_back_matter(catalog: &Catalog, path: &String) -> Result<()> {
@@ -1844,19 +2181,24 @@ impl CatalogBlock {
     pub(super) async fn read_back_matter(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::control::catalog::BackMatter> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::control::catalog::BackMatter>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let back_matter = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "back_matter".to_owned(),
-                "catalog".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (back_matter, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "back_matter".to_owned(),
+                    "catalog".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(back_matter)
+        Ok((
+            back_matter,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     pub async fn gen_files(catalog: Catalog, ctx: &DissectCtx) -> Result<()> {
@@ -1933,19 +2275,24 @@ impl ProfileBlock {
         Ok(())
     }
 
-    pub(super) async fn read_uuid(&self, path: &PathBuf) -> Result<String> {
+    pub(super) async fn read_uuid(
+        &self,
+        path: &PathBuf,
+        options: &ParseOptions,
+    ) -> Result<Parsed<OscalUuid>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let uuid = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "uuid".to_owned(),
-                "profile".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (uuid, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "uuid".to_owned(),
+                    "profile".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(uuid)
+        Ok((uuid, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_metadata(profile: &Profile, path: &String) -> Result<()> {
@@ -1969,19 +2316,21 @@ impl ProfileBlock {
     pub(super) async fn read_metadata(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::control::profile::DocumentMetadata> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::control::profile::DocumentMetadata>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let metadata = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "uuid".to_owned(),
-                "metadata".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (metadata, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "uuid".to_owned(),
+                    "metadata".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(metadata)
+        Ok((metadata, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_imports(profile: &Profile, path: &String) -> Result<()> {
@@ -2002,19 +2351,21 @@ impl ProfileBlock {
     pub(super) async fn read_imports(
         &self,
         path: &PathBuf,
-    ) -> Result<Vec<roscal_lib::control::profile::ImportResource>> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<Vec<roscal_lib::control::profile::ImportResource>>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let imports = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "imports".to_owned(),
-                "profile".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (imports, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "imports".to_owned(),
+                    "profile".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(imports)
+        Ok((imports, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_merge(profile: &Profile, path: &String) -> Result<()> {
@@ -2040,19 +2391,21 @@ impl ProfileBlock {
     pub(super) async fn read_merge(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::control::profile::MergeControls> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::control::profile::MergeControls>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let merge = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "merge".to_owned(),
-                "profile".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (merge, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "merge".to_owned(),
+                    "profile".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(merge)
+        Ok((merge, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_modify(profile: &Profile, path: &String) -> Result<()> {
@@ -2078,19 +2431,21 @@ impl ProfileBlock {
     pub(super) async fn read_modify(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::control::profile::ModifyControls> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::control::profile::ModifyControls>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let modify = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "modify".to_owned(),
-                "profile".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (modify, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "modify".to_owned(),
+                    "profile".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(modify)
+        Ok((modify, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_back_matter(profile: &Profile, path: &String) -> Result<()> {
@@ -2122,19 +2477,24 @@ impl ProfileBlock {
     pub(super) async fn read_back_matter(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::control::profile::BackMatter> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::control::profile::BackMatter>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let back_matter = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "back_matter".to_owned(),
-                "profile".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (back_matter, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "back_matter".to_owned(),
+                    "profile".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(back_matter)
+        Ok((
+            back_matter,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     pub async fn gen_files(profile: Profile, ctx: &DissectCtx) -> Result<()> {
@@ -2216,19 +2576,24 @@ impl ComponentDefinitionBlock {
         Ok(())
     }
 
-    pub(super) async fn read_uuid(&self, path: &PathBuf) -> Result<String> {
+    pub(super) async fn read_uuid(
+        &self,
+        path: &PathBuf,
+        options: &ParseOptions,
+    ) -> Result<Parsed<OscalUuid>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let uuid = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "uuid".to_owned(),
-                "component definition".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (uuid, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "uuid".to_owned(),
+                    "component definition".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(uuid)
+        Ok((uuid, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_metadata(
@@ -2257,21 +2622,25 @@ impl ComponentDefinitionBlock {
     pub(super) async fn read_metadata(
         &self,
         path: &PathBuf,
+        options: &ParseOptions,
     ) -> Result<
-        roscal_lib::implementation::component_definition::DocumentMetadata,
+        Parsed<
+            roscal_lib::implementation::component_definition::DocumentMetadata,
+        >,
     > {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let metadata = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "metadata".to_owned(),
-                "component definition".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (metadata, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "metadata".to_owned(),
+                    "component definition".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(metadata)
+        Ok((metadata, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_import_component_definitions(
@@ -2306,11 +2675,19 @@ impl ComponentDefinitionBlock {
         Ok(())
     }
 
-    pub(super) async fn read_import_component_definitions(&self, path: &PathBuf) -> Result<Vec<roscal_lib::implementation::component_definition::ImportComponentDefinition>>{
+    pub(super) async fn read_import_component_definitions(
+        &self,
+        path: &PathBuf,
+        options: &ParseOptions,
+    ) -> Result<
+        Parsed<
+            Vec<roscal_lib::implementation::component_definition::ImportComponentDefinition>,
+        >,
+    >{
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let import_component_definitions = serde_yaml::from_reader(reader)
-            .map_err(|e| {
+        let (import_component_definitions, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
                 CliError::ParseBlock(
                     "import_component_definitions".to_owned(),
                     "component definition".to_owned(),
@@ -2319,7 +2696,10 @@ impl ComponentDefinitionBlock {
                 )
             })?;
 
-        Ok(import_component_definitions)
+        Ok((
+            import_component_definitions,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_components(
@@ -2356,20 +2736,28 @@ impl ComponentDefinitionBlock {
     pub(super) async fn read_components(
         &self,
         path: &PathBuf,
-    ) -> Result<Vec<roscal_lib::implementation::component_definition::Component>>
-    {
+        options: &ParseOptions,
+    ) -> Result<
+        Parsed<
+            Vec<roscal_lib::implementation::component_definition::Component>,
+        >,
+    > {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let components = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "component".to_owned(),
-                "component definition".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (components, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "component".to_owned(),
+                    "component definition".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(components)
+        Ok((
+            components,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_capabilities(
@@ -2406,20 +2794,28 @@ impl ComponentDefinitionBlock {
     pub(super) async fn read_capabilities(
         &self,
         path: &PathBuf,
-    ) -> Result<Vec<roscal_lib::implementation::component_definition::Capability>>
-    {
+        options: &ParseOptions,
+    ) -> Result<
+        Parsed<
+            Vec<roscal_lib::implementation::component_definition::Capability>,
+        >,
+    > {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let capabilities = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "capabilities".to_owned(),
-                "component definition".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (capabilities, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "capabilities".to_owned(),
+                    "component definition".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(capabilities)
+        Ok((
+            capabilities,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_back_matter(
@@ -2456,20 +2852,26 @@ impl ComponentDefinitionBlock {
     pub(super) async fn read_back_matter(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::implementation::component_definition::BackMatter>
-    {
+        options: &ParseOptions,
+    ) -> Result<
+        Parsed<roscal_lib::implementation::component_definition::BackMatter>,
+    > {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let back_matter = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "back_matter".to_owned(),
-                "component definition".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (back_matter, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "back_matter".to_owned(),
+                    "component definition".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(back_matter)
+        Ok((
+            back_matter,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     pub async fn gen_files(
@@ -2575,19 +2977,24 @@ impl SspBlock {
         Ok(())
     }
 
-    pub(super) async fn read_uuid(&self, path: &PathBuf) -> Result<String> {
+    pub(super) async fn read_uuid(
+        &self,
+        path: &PathBuf,
+        options: &ParseOptions,
+    ) -> Result<Parsed<OscalUuid>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let uuid = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "uuid".to_owned(),
-                "ssp".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (uuid, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "uuid".to_owned(),
+                    "ssp".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(uuid)
+        Ok((uuid, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_metadata(
@@ -2615,19 +3022,21 @@ impl SspBlock {
     pub(super) async fn read_metadata(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::implementation::ssp::DocumentMetadata> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::implementation::ssp::DocumentMetadata>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let metadata = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "metadata".to_owned(),
-                "ssp".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (metadata, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "metadata".to_owned(),
+                    "ssp".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(metadata)
+        Ok((metadata, model_warnings(warnings, &path.to_string_lossy())))
     }
 
     async fn gen_import_profile(
@@ -2656,19 +3065,24 @@ impl SspBlock {
     pub(super) async fn read_import_profile(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::implementation::ssp::ImportProfile> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::implementation::ssp::ImportProfile>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let import_profile = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "import_profile".to_owned(),
-                "ssp".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (import_profile, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "import_profile".to_owned(),
+                    "ssp".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(import_profile)
+        Ok((
+            import_profile,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_system_characteristics(
@@ -2698,11 +3112,13 @@ impl SspBlock {
     pub(super) async fn read_system_characteristics(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::implementation::ssp::SystemCharacteristics> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::implementation::ssp::SystemCharacteristics>>
+    {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let system_characteristics =
-            serde_yaml::from_reader(reader).map_err(|e| {
+        let (system_characteristics, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
                 CliError::ParseBlock(
                     "system_characteristics".to_owned(),
                     "ssp".to_owned(),
@@ -2711,7 +3127,10 @@ impl SspBlock {
                 )
             })?;
 
-        Ok(system_characteristics)
+        Ok((
+            system_characteristics,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_system_implementation(
@@ -2741,11 +3160,13 @@ impl SspBlock {
     pub(super) async fn read_system_implementation(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::implementation::ssp::SystemImplementation> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::implementation::ssp::SystemImplementation>>
+    {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let system_implementation =
-            serde_yaml::from_reader(reader).map_err(|e| {
+        let (system_implementation, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
                 CliError::ParseBlock(
                     "system_implementation".to_owned(),
                     "ssp".to_owned(),
@@ -2754,7 +3175,10 @@ impl SspBlock {
                 )
             })?;
 
-        Ok(system_implementation)
+        Ok((
+            system_implementation,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_control_implementation(
@@ -2784,11 +3208,13 @@ impl SspBlock {
     pub(super) async fn read_control_implementation(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::implementation::ssp::ControlImplementation> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::implementation::ssp::ControlImplementation>>
+    {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let control_implementation =
-            serde_yaml::from_reader(reader).map_err(|e| {
+        let (control_implementation, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
                 CliError::ParseBlock(
                     "control_implementation".to_owned(),
                     "ssp".to_owned(),
@@ -2797,7 +3223,10 @@ impl SspBlock {
                 )
             })?;
 
-        Ok(control_implementation)
+        Ok((
+            control_implementation,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     async fn gen_back_matter(
@@ -2832,19 +3261,24 @@ impl SspBlock {
     pub(super) async fn read_back_matter(
         &self,
         path: &PathBuf,
-    ) -> Result<roscal_lib::implementation::ssp::BackMatter> {
+        options: &ParseOptions,
+    ) -> Result<Parsed<roscal_lib::implementation::ssp::BackMatter>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let back_matter = serde_yaml::from_reader(reader).map_err(|e| {
-            CliError::ParseBlock(
-                "back_matter".to_owned(),
-                "ssp".to_owned(),
-                path.to_string_lossy().into_owned(),
-                e.to_string(),
-            )
-        })?;
+        let (back_matter, warnings) =
+            parse::from_reader_with_warnings(reader, options).map_err(|e| {
+                CliError::ParseBlock(
+                    "back_matter".to_owned(),
+                    "ssp".to_owned(),
+                    path.to_string_lossy().into_owned(),
+                    e.to_string(),
+                )
+            })?;
 
-        Ok(back_matter)
+        Ok((
+            back_matter,
+            model_warnings(warnings, &path.to_string_lossy()),
+        ))
     }
 
     pub async fn gen_files(
@@ -0,0 +1,661 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+    fs::{read, write},
+    io::{self, Read},
+    path::{Component, Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use roscal_lib::{
+    document::{AnyOscalDocument, DocumentError, ModelType},
+    hash::HashAlgorithm,
+    href::{
+        standard_resolver, BackMatterResolver, HrefError, HrefResolver,
+        HrefSource,
+    },
+    parse::ParseOptions,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tar::{Archive, Builder, EntryType, Header};
+
+use crate::cli::cli_opts::{BuildPackage, VerifyPackage};
+
+use super::{
+    utils::model_content,
+    workspace::{CliError, Context, Result},
+};
+
+const MANIFEST: &str = "manifest.json";
+
+/// Package directory of the files that are not OSCAL documents
+const ATTACHMENTS: &str = "resources";
+
+/// A file of a package, as listed in its manifest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PackageFile {
+    /// Location in the package
+    pub path: String,
+    /// Model type of an OSCAL document, none for attachments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oscal_version: Option<String>,
+    pub sha256: String,
+}
+
+/// Index of a package, stored in it as `manifest.json`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Location of the SSP in the package
+    pub ssp: String,
+    pub files: Vec<PackageFile>,
+}
+
+impl fmt::Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for file in &self.files {
+            writeln!(
+                f,
+                "{}  {} ({})",
+                file.sha256,
+                file.path,
+                file.model_type.as_deref().unwrap_or("attachment")
+            )?;
+        }
+
+        write!(f, "{} file(s) packaged", self.files.len())
+    }
+}
+
+/// Result of checking one file of a package
+#[derive(Debug, Clone, PartialEq)]
+pub enum PackageOutcome {
+    Match,
+    /// Listed in the manifest but not in the package
+    Missing,
+    /// In the package but not listed in the manifest
+    Unlisted,
+    /// The content has a different digest, which is given
+    Mismatch(String),
+    /// The document is not what the manifest lists, for the given reason
+    Invalid(String),
+    /// An import of the document does not resolve inside the package
+    BrokenImport {
+        href: String,
+        reason: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackageCheck {
+    /// Location in the package
+    pub path: String,
+    pub outcome: PackageOutcome,
+}
+
+impl fmt::Display for PackageCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = &self.path;
+
+        match &self.outcome {
+            PackageOutcome::Match => write!(f, "OK {path}"),
+            PackageOutcome::Missing => write!(f, "MISSING {path}"),
+            PackageOutcome::Unlisted => {
+                write!(f, "UNLISTED {path}: not in the manifest")
+            }
+            PackageOutcome::Mismatch(found) => {
+                write!(f, "MISMATCH {path}: found SHA-256 {found}")
+            }
+            PackageOutcome::Invalid(reason) => {
+                write!(f, "INVALID {path}: {reason}")
+            }
+            PackageOutcome::BrokenImport { href, reason } => {
+                write!(f, "BROKEN {path}: import `{href}`: {reason}")
+            }
+        }
+    }
+}
+
+/// Outcome of checking a package against its manifest
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PackageReport {
+    pub checks: Vec<PackageCheck>,
+}
+
+impl PackageReport {
+    pub fn failures(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|c| c.outcome != PackageOutcome::Match)
+            .count()
+    }
+}
+
+impl fmt::Display for PackageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            writeln!(f, "{check}")?;
+        }
+
+        write!(
+            f,
+            "{} file(s) verified, {} issue(s)",
+            self.checks.len() - self.failures(),
+            self.failures()
+        )
+    }
+}
+
+/// Hrefs of the imports of a document, with the model types they may point to
+fn imports(doc: &AnyOscalDocument) -> Vec<(String, &'static [ModelType])> {
+    match doc {
+        AnyOscalDocument::SystemSecurityPlan(ssp) => vec![(
+            ssp.system_security_plan.import_profile.href.to_string(),
+            &[ModelType::Profile],
+        )],
+        AnyOscalDocument::Profile(profile) => profile
+            .profile
+            .imports
+            .iter()
+            .map(|import| {
+                (
+                    import.href.to_string(),
+                    &[ModelType::Catalog, ModelType::Profile][..],
+                )
+            })
+            .collect(),
+        AnyOscalDocument::AssessmentPlan(ap) => vec![(
+            ap.assessment_plan.import_ssp.href.to_string(),
+            &[ModelType::SystemSecurityPlan],
+        )],
+        AnyOscalDocument::AssessmentResults(ar) => vec![(
+            ar.assessment_results.import_ap.href.to_string(),
+            &[ModelType::AssessmentPlan],
+        )],
+        AnyOscalDocument::PlanOfActionAndMilestones(poam) => poam
+            .plan_of_action_and_milestones
+            .import_ssp
+            .iter()
+            .map(|import| {
+                (
+                    import.href.to_string(),
+                    &[ModelType::SystemSecurityPlan][..],
+                )
+            })
+            .collect(),
+        AnyOscalDocument::ComponentDefinition(def) => def
+            .component_definition
+            .import_component_definitions
+            .iter()
+            .flatten()
+            .map(|import| {
+                (
+                    import.href.to_string(),
+                    &[ModelType::ComponentDefinition][..],
+                )
+            })
+            .collect(),
+        AnyOscalDocument::Catalog(_) => vec![],
+    }
+}
+
+/// Every `href` of a document other than `#uuid` fragments
+fn hrefs(value: &Value, col: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                match (key.as_str(), value) {
+                    ("href", Value::String(href)) if !href.starts_with('#') => {
+                        col.push(href.clone())
+                    }
+                    _ => hrefs(value, col),
+                }
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|v| hrefs(v, col)),
+        _ => {}
+    }
+}
+
+fn rewrite_hrefs(value: &mut Value, f: &impl Fn(&str) -> Option<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match (key.as_str(), value) {
+                    ("href", Value::String(href)) => {
+                        if let Some(rewritten) = f(href) {
+                            *href = rewritten;
+                        }
+                    }
+                    (_, value) => rewrite_hrefs(value, f),
+                }
+            }
+        }
+        Value::Array(items) => {
+            items.iter_mut().for_each(|v| rewrite_hrefs(v, f))
+        }
+        _ => {}
+    }
+}
+
+/// Href from one file of a package to another. Files are all one directory
+/// deep, grouped by model type
+fn relative_href(from: &str, to: &str) -> String {
+    match (from.split_once('/'), to.split_once('/')) {
+        (Some((a, _)), Some((b, name))) if a == b => name.to_owned(),
+        _ => format!("../{to}"),
+    }
+}
+
+/// A file going into the package
+struct Entry {
+    /// Location in the package
+    path: String,
+    source: PathBuf,
+    model_type: Option<ModelType>,
+    /// Document as json, none for attachments
+    document: Option<Value>,
+}
+
+fn wrong_type(entry: &Entry, expected: &[ModelType]) -> CliError {
+    let expected: Vec<String> =
+        expected.iter().map(ModelType::to_string).collect();
+
+    match entry.model_type {
+        Some(found) => CliError::WrongModelType(
+            entry.source.clone(),
+            found,
+            expected.join(" or "),
+        ),
+        None => CliError::NotOscal(entry.source.clone(), expected.join(" or ")),
+    }
+}
+
+struct Packer {
+    options: ParseOptions,
+    hrefs: Box<dyn HrefResolver>,
+    entries: Vec<Entry>,
+    /// Index of each entry, by canonical source path
+    index: HashMap<PathBuf, usize>,
+    names: HashSet<String>,
+}
+
+impl Packer {
+    /// Location in the package of a new file, in the directory of its model
+    /// type. Clashing names get a numbered prefix
+    fn package_path(&mut self, source: &Path, dir: &str) -> String {
+        let name = source
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let mut path = format!("{dir}/{name}");
+        let mut n = 1;
+        while self.names.contains(&path) {
+            n += 1;
+            path = format!("{dir}/{n}-{name}");
+        }
+        self.names.insert(path.clone());
+
+        path
+    }
+
+    /// Add a file and, for an OSCAL document, everything it imports or links
+    /// to locally, returning the index of its entry
+    fn add(&mut self, path: &Path) -> Result<usize> {
+        let canonical = path
+            .canonicalize()
+            .with_context(|| format!("Could not locate {}", path.display()))?;
+        if let Some(index) = self.index.get(&canonical) {
+            return Ok(*index);
+        }
+
+        let content = read(path)
+            .with_context(|| format!("Could not read {}", path.display()))?;
+        let doc = match std::str::from_utf8(&content) {
+            Ok(input) if ModelType::detect(input).is_ok() => {
+                match AnyOscalDocument::from_str_with(input, &self.options) {
+                    Ok(doc) => Some(doc),
+                    Err(DocumentError::UnsupportedXml(_)) => {
+                        return Err(CliError::PackageXml(path.to_path_buf()))
+                    }
+                    Err(e) => {
+                        return Err(CliError::from(e)).with_context(|| {
+                            format!("Could not parse {}", path.display())
+                        })
+                    }
+                }
+            }
+            _ => None,
+        };
+        let model_type = doc.as_ref().map(AnyOscalDocument::model_type);
+        let package_path = self.package_path(
+            path,
+            model_type.as_ref().map_or(ATTACHMENTS, ModelType::root_key),
+        );
+
+        // Registered before following the hrefs, in case they lead back here
+        let index = self.entries.len();
+        self.index.insert(canonical, index);
+        self.entries.push(Entry {
+            path: package_path,
+            source: path.to_path_buf(),
+            model_type,
+            document: None,
+        });
+
+        let Some(doc) = doc else {
+            return Ok(index);
+        };
+        let source = HrefSource::from_document(path, &doc);
+        for (href, expected) in imports(&doc) {
+            let target = self.hrefs.resolve(&href, &source).map_err(|e| {
+                CliError::UnresolvedImport(
+                    path.to_path_buf(),
+                    href.clone(),
+                    e.to_string(),
+                )
+            })?;
+            let target = self.add(&target)?;
+            let target = &self.entries[target];
+            if !target.model_type.is_some_and(|m| expected.contains(&m)) {
+                return Err(wrong_type(target, expected));
+            }
+        }
+
+        let value = serde_json::to_value(&doc)?;
+        let mut col = vec![];
+        hrefs(&value, &mut col);
+        for href in col {
+            // Remote links without a mirror, or to missing files, stay as is
+            if let Ok(target) = self.hrefs.resolve(&href, &source) {
+                self.add(&target)?;
+            }
+        }
+        self.entries[index].document = Some(value);
+
+        Ok(index)
+    }
+
+    /// Content of an entry, with the hrefs to other entries pointing inside
+    /// the package
+    fn content(&self, entry: &Entry) -> Result<Vec<u8>> {
+        let Some(document) = &entry.document else {
+            return read(&entry.source).with_context(|| {
+                format!("Could not read {}", entry.source.display())
+            });
+        };
+
+        let source = HrefSource::new(&entry.source);
+        let mut document = document.clone();
+        rewrite_hrefs(&mut document, &|href| {
+            if href.starts_with('#') {
+                return None;
+            }
+            let target = self.hrefs.resolve(href, &source).ok()?;
+            let target =
+                &self.entries[*self.index.get(&target.canonicalize().ok()?)?];
+
+            Some(relative_href(&entry.path, &target.path))
+        });
+
+        Ok(model_content(Path::new(&entry.path), &document)?.into_bytes())
+    }
+}
+
+fn oscal_version(model_type: &ModelType, document: &Value) -> Option<String> {
+    document[model_type.root_key()]["metadata"]["oscal-version"]
+        .as_str()
+        .map(str::to_owned)
+}
+
+/// Append a regular file to a package archive. Long paths are stored with
+/// the GNU extension
+fn append(
+    archive: &mut Builder<Vec<u8>>,
+    path: &str,
+    content: &[u8],
+    mtime: u64,
+) -> Result<()> {
+    let mut header = Header::new_gnu();
+    header.set_entry_type(EntryType::Regular);
+    header.set_size(content.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(mtime);
+
+    archive
+        .append_data(&mut header, path, content)
+        .with_context(|| format!("Could not add `{path}` to the package"))
+}
+
+/// Path and content of the regular files of a package archive
+fn entries(archive: &[u8]) -> io::Result<BTreeMap<PathBuf, Vec<u8>>> {
+    let mut col = BTreeMap::new();
+
+    for entry in Archive::new(archive).entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let path = entry.path()?.into_owned();
+        let mut content = vec![];
+        entry.read_to_end(&mut content)?;
+        col.insert(path, content);
+    }
+
+    Ok(col)
+}
+
+/// Bundle an SSP with the documents and attachments it references, along
+/// with the documents given to include, into a tar archive
+pub(super) async fn build_package(opts: &BuildPackage) -> Result<Manifest> {
+    let mut packer = Packer {
+        options: ParseOptions::default(),
+        hrefs: Box::new(standard_resolver(opts.mirror_dir.clone())),
+        entries: vec![],
+        index: HashMap::new(),
+        names: HashSet::from([MANIFEST.to_owned()]),
+    };
+
+    let ssp = packer.add(&opts.ssp)?;
+    let entry = &packer.entries[ssp];
+    if entry.model_type != Some(ModelType::SystemSecurityPlan) {
+        return Err(wrong_type(entry, &[ModelType::SystemSecurityPlan]));
+    }
+    for file in &opts.include {
+        packer.add(file)?;
+    }
+
+    let mut manifest = Manifest {
+        ssp: packer.entries[ssp].path.clone(),
+        files: vec![],
+    };
+    let mut contents = vec![];
+    for entry in &packer.entries {
+        let content = packer.content(entry)?;
+        manifest.files.push(PackageFile {
+            path: entry.path.clone(),
+            model_type: entry.model_type.map(|m| m.to_string()),
+            oscal_version: entry
+                .model_type
+                .zip(entry.document.as_ref())
+                .and_then(|(m, doc)| oscal_version(&m, doc)),
+            sha256: HashAlgorithm::Sha256.digest(&content),
+        });
+        contents.push(content);
+    }
+
+    let mtime = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let mut archive = Builder::new(vec![]);
+    append(
+        &mut archive,
+        MANIFEST,
+        serde_json::to_string_pretty(&manifest)?.as_bytes(),
+        mtime,
+    )?;
+    for (file, content) in manifest.files.iter().zip(&contents) {
+        append(&mut archive, &file.path, content, mtime)?;
+    }
+    let archive = archive.into_inner()?;
+
+    write(&opts.output_file, archive).with_context(|| {
+        format!(
+            "Could not create output file: `{}`",
+            opts.output_file.display()
+        )
+    })?;
+
+    Ok(manifest)
+}
+
+/// Resolves relative hrefs between the files of a package
+struct PackageResolver<'a> {
+    files: &'a BTreeMap<PathBuf, Vec<u8>>,
+}
+
+impl HrefResolver for PackageResolver<'_> {
+    fn resolve(
+        &self,
+        href: &str,
+        source: &HrefSource,
+    ) -> Result<PathBuf, HrefError> {
+        if href.starts_with('#') || href.contains(':') {
+            return Err(HrefError::Unsupported(href.to_owned()));
+        }
+
+        let mut path = PathBuf::new();
+        for component in source.base_dir().join(href).components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    path.pop();
+                }
+                c => path.push(c),
+            }
+        }
+
+        match self.files.contains_key(&path) {
+            true => Ok(path),
+            false => Err(HrefError::NotFound(path)),
+        }
+    }
+}
+
+/// Check a document of a package against its manifest entry, and that its
+/// imports resolve inside the package
+fn check_document(
+    file: &PackageFile,
+    content: &[u8],
+    resolver: &impl HrefResolver,
+    options: &ParseOptions,
+) -> Vec<PackageOutcome> {
+    let Some(expected) = &file.model_type else {
+        return vec![];
+    };
+    let doc = std::str::from_utf8(content)
+        .map_err(|e| e.to_string())
+        .and_then(|input| {
+            AnyOscalDocument::from_str_with(input, options)
+                .map_err(|e| e.to_string())
+        });
+    let doc = match doc {
+        Ok(doc) => doc,
+        Err(reason) => return vec![PackageOutcome::Invalid(reason)],
+    };
+
+    if &doc.model_type().to_string() != expected {
+        return vec![PackageOutcome::Invalid(format!(
+            "{} listed as a {expected}",
+            doc.model_type()
+        ))];
+    }
+    let version = serde_json::to_value(&doc)
+        .ok()
+        .and_then(|value| oscal_version(&doc.model_type(), &value));
+    if version != file.oscal_version {
+        return vec![PackageOutcome::Invalid(format!(
+            "OSCAL version {} listed as {}",
+            version.as_deref().unwrap_or("none"),
+            file.oscal_version.as_deref().unwrap_or("none")
+        ))];
+    }
+
+    let source = HrefSource::from_document(&file.path, &doc);
+    imports(&doc)
+        .into_iter()
+        .filter_map(|(href, _)| {
+            let reason = resolver.resolve(&href, &source).err()?;
+            Some(PackageOutcome::BrokenImport {
+                href,
+                reason: reason.to_string(),
+            })
+        })
+        .collect()
+}
+
+pub(super) async fn verify_package(
+    opts: &VerifyPackage,
+) -> Result<PackageReport> {
+    let archive = read(&opts.file).with_context(|| {
+        format!("Could not read package: `{}`", opts.file.display())
+    })?;
+    let files = entries(&archive)
+        .map_err(|e| CliError::InvalidPackage(e.to_string()))?;
+
+    let manifest = files
+        .get(Path::new(MANIFEST))
+        .ok_or_else(|| CliError::InvalidPackage(format!("no {MANIFEST}")))?;
+    let manifest: Manifest = serde_json::from_slice(manifest)
+        .map_err(|e| CliError::InvalidPackage(format!("{MANIFEST}: {e}")))?;
+
+    let options = ParseOptions::default();
+    let resolver = BackMatterResolver::new(PackageResolver { files: &files });
+    let mut report = PackageReport::default();
+    for file in &manifest.files {
+        let Some(content) = files.get(Path::new(&file.path)) else {
+            report.checks.push(PackageCheck {
+                path: file.path.clone(),
+                outcome: PackageOutcome::Missing,
+            });
+            continue;
+        };
+
+        let digest = HashAlgorithm::Sha256.digest(content);
+        let outcomes =
+            match HashAlgorithm::Sha256.matches(content, &file.sha256) {
+                true => check_document(file, content, &resolver, &options),
+                false => vec![PackageOutcome::Mismatch(digest)],
+            };
+        if outcomes.is_empty() {
+            report.checks.push(PackageCheck {
+                path: file.path.clone(),
+                outcome: PackageOutcome::Match,
+            });
+        }
+        report.checks.extend(outcomes.into_iter().map(|outcome| {
+            PackageCheck {
+                path: file.path.clone(),
+                outcome,
+            }
+        }));
+    }
+
+    let listed: HashSet<&str> =
+        manifest.files.iter().map(|f| f.path.as_str()).collect();
+    for path in files.keys() {
+        let path = path.to_string_lossy();
+        if path != MANIFEST && !listed.contains(path.as_ref()) {
+            report.checks.push(PackageCheck {
+                path: path.into_owned(),
+                outcome: PackageOutcome::Unlisted,
+            });
+        }
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,95 @@
+use std::{fmt::Write, str::FromStr};
+
+use roscal_lib::{parse::ParseOptions, query::Query as PathQuery};
+use serde_json::Value;
+use strum::EnumString;
+
+use crate::cli::cli_opts::Query;
+
+use super::{
+    model::OscalModels,
+    utils::write_output,
+    workspace::{CliError, Output, Result},
+};
+
+#[derive(Debug, Clone, PartialEq, EnumString)]
+pub enum QueryOpt {
+    #[strum(serialize = "json")]
+    Json,
+    #[strum(serialize = "yaml")]
+    Yaml,
+    #[strum(serialize = "table")]
+    Table,
+}
+
+pub(super) async fn query_model(opts: &Query) -> Result<Output> {
+    let output_format = QueryOpt::from_str(&opts.output_format)
+        .map_err(|_| CliError::UnknownQueryOpt)?;
+    let query = PathQuery::parse(&opts.expr)
+        .map_err(|e| CliError::InvalidQuery(e.to_string()))?;
+
+    let root = OscalModels::from_opt(opts.model.as_deref(), &opts.file)?
+        .read_value(&opts.file, &ParseOptions::default())
+        .await?
+        .0;
+    let matches = query.select(&root);
+
+    let content = match output_format {
+        QueryOpt::Json => serde_json::to_string_pretty(&matches)?,
+        QueryOpt::Yaml => serde_yaml::to_string(&matches)?,
+        QueryOpt::Table => to_table(&matches),
+    };
+
+    Ok(Output {
+        content: write_output(opts.output_file.as_ref(), content)?,
+        ..Default::default()
+    })
+}
+
+/// Markdown table of the scalar fields of each match
+fn to_table(matches: &[&Value]) -> String {
+    let mut columns: Vec<&str> = vec![];
+    for node in matches {
+        match node {
+            Value::Object(object) => object
+                .iter()
+                .filter(|(_, v)| !v.is_object() && !v.is_array())
+                .for_each(|(k, _)| {
+                    if !columns.contains(&k.as_str()) {
+                        columns.push(k);
+                    }
+                }),
+            _ => {
+                if !columns.contains(&"value") {
+                    columns.push("value");
+                }
+            }
+        }
+    }
+
+    let mut output = format!("| {} |\n", columns.join(" | "));
+    let _ = writeln!(output, "|{}", " --- |".repeat(columns.len()));
+
+    for node in matches {
+        let cells: Vec<String> = columns
+            .iter()
+            .map(|column| {
+                let cell = match node {
+                    Value::Object(object) => object.get(*column),
+                    _ if *column == "value" => Some(*node),
+                    _ => None,
+                };
+                match cell {
+                    Some(Value::String(s)) => {
+                        s.replace('|', "\\|").replace('\n', " ")
+                    }
+                    Some(v) if !v.is_object() && !v.is_array() => v.to_string(),
+                    _ => String::new(),
+                }
+            })
+            .collect();
+        let _ = writeln!(output, "| {} |", cells.join(" | "));
+    }
+
+    output
+}
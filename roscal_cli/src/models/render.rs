@@ -0,0 +1,70 @@
+use std::str::FromStr;
+
+use roscal_lib::{parse::ParseOptions, render::Render as _};
+use strum::EnumString;
+
+use crate::cli::cli_opts::Render;
+
+use super::{
+    model::OscalModels,
+    utils::write_output,
+    workspace::{CliError, Output, Result},
+};
+
+#[derive(Debug, Clone, PartialEq, EnumString)]
+pub enum RenderOpt {
+    #[strum(serialize = "markdown")]
+    Markdown,
+    #[strum(serialize = "html")]
+    Html,
+}
+
+pub(super) async fn render_model(opts: &Render) -> Result<Output> {
+    let output_format = RenderOpt::from_str(&opts.output_format)
+        .map_err(|_| CliError::UnknownRenderOpt)?;
+
+    let model = match &opts.model {
+        Some(model) => OscalModels::from_str(model)
+            .map_err(|_| CliError::UnknownRenderModel)?,
+        None => OscalModels::detect(&opts.file)?,
+    };
+
+    let content = match model {
+        OscalModels::Catalog => {
+            let catalog = OscalModels::Catalog
+                .read_catalog(&opts.file, &ParseOptions::default())
+                .await?
+                .0;
+            match output_format {
+                RenderOpt::Markdown => catalog.to_markdown(),
+                RenderOpt::Html => catalog.to_html(),
+            }
+        }
+        OscalModels::Ssp => {
+            let ssp = OscalModels::Ssp
+                .read_ssp(&opts.file, &ParseOptions::default())
+                .await?
+                .0;
+            match output_format {
+                RenderOpt::Markdown => ssp.to_markdown(),
+                RenderOpt::Html => ssp.to_html(),
+            }
+        }
+        OscalModels::Poam => {
+            let poam = OscalModels::Poam
+                .read_poam(&opts.file, &ParseOptions::default())
+                .await?
+                .0;
+            match output_format {
+                RenderOpt::Markdown => poam.to_markdown(),
+                RenderOpt::Html => poam.to_html(),
+            }
+        }
+        _ => return Err(CliError::UnknownRenderModel),
+    };
+
+    Ok(Output {
+        content: write_output(opts.output_file.as_ref(), content)?,
+        ..Default::default()
+    })
+}
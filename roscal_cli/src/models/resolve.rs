@@ -0,0 +1,685 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    fs::{create_dir_all, read_to_string},
+    path::{Path, PathBuf},
+};
+
+use chrono::{SecondsFormat, Utc};
+use roscal_lib::{
+    control::{
+        catalog::Catalog,
+        profile::{CombinationMethod, MergeControls, Profile},
+    },
+    document::{AnyOscalDocument, ModelType},
+    parse::{ParseOptions, ParseWith},
+};
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::cli::cli_opts::ResolveProfile;
+
+use super::{
+    chain::Baseline,
+    imports::{ImportError, Imported, Imports, Parsed},
+    model::{model_warnings, ModelWarning, OscalModels},
+    utils::{write_file, write_model},
+    workspace::{CliError, Context, Result},
+};
+
+/// Part of every cache key, so entries written by another version of the
+/// resolver are not reused
+const CACHE_VERSION: &str = concat!("roscal ", env!("CARGO_PKG_VERSION"));
+
+/// Model types a profile can import
+const UPSTREAM: [ModelType; 2] = [ModelType::Catalog, ModelType::Profile];
+
+/// Arrays of a control or part that alterations add to and remove from, with
+/// the item name a removal refers to them by
+const ITEMS: [(&str, &str); 4] = [
+    ("params", "param"),
+    ("props", "prop"),
+    ("links", "link"),
+    ("parts", "part"),
+];
+
+/// Outcome of resolving a profile
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Resolution {
+    pub catalog: Value,
+    /// Profiles resolved during this run, upstream ones first
+    pub resolved: Vec<PathBuf>,
+    /// Profiles whose resolved catalog was read from the cache
+    pub cached: Vec<PathBuf>,
+    /// Unknown fields kept while reading the catalogs
+    pub warnings: Vec<ModelWarning>,
+}
+
+impl fmt::Display for Resolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for path in &self.resolved {
+            writeln!(f, "RESOLVED {}", path.display())?;
+        }
+        for path in &self.cached {
+            writeln!(f, "CACHED {}", path.display())?;
+        }
+
+        write!(
+            f,
+            "{} profile(s) resolved, {} from cache",
+            self.resolved.len(),
+            self.cached.len()
+        )
+    }
+}
+
+/// A catalog or profile reached through the imports
+#[derive(Debug, Clone)]
+struct Node {
+    imported: Imported,
+    /// Hash of the document along with the keys of everything it imports
+    key: String,
+    /// For a profile, the canonical paths of its imports in order
+    profile: Option<(Profile, Vec<PathBuf>)>,
+}
+
+struct Resolver {
+    imports: Imports,
+    cache_dir: Option<PathBuf>,
+    /// Documents reached, by canonical path
+    nodes: HashMap<PathBuf, Node>,
+    /// Catalogs read or resolved during this run, by key
+    catalogs: HashMap<String, Value>,
+    resolution: Resolution,
+}
+
+impl Resolver {
+    /// Hash a document read and, for a profile, follow everything it
+    /// imports. Catalogs are only hashed here, they are parsed once a profile
+    /// needs resolving
+    fn follow(&mut self, imported: Imported) -> Result<PathBuf> {
+        let canonical = imported.canonical.clone();
+        if self.nodes.contains_key(&canonical) {
+            return Ok(canonical);
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(CACHE_VERSION);
+        hasher.update(&imported.content);
+        let profile = match imported.model_type {
+            ModelType::Profile => Some(self.profile(&imported, &mut hasher)?),
+            _ => None,
+        };
+
+        let node = Node {
+            imported,
+            key: format!("{:x}", hasher.finalize()),
+            profile,
+        };
+        self.nodes.insert(canonical.clone(), node);
+
+        Ok(canonical)
+    }
+
+    /// Parse a profile and follow its imports, adding their keys to the hash
+    fn profile(
+        &mut self,
+        imported: &Imported,
+        hasher: &mut Sha256,
+    ) -> Result<(Profile, Vec<PathBuf>)> {
+        let path = &imported.path;
+        let Parsed {
+            document,
+            source,
+            warnings,
+        } = self.imports.parse(imported)?;
+        self.resolution
+            .warnings
+            .extend(model_warnings(warnings, &path.to_string_lossy()));
+        let profile = match document {
+            AnyOscalDocument::Profile(profile) => *profile,
+            other => {
+                return Err(ImportError::WrongModel {
+                    path: path.clone(),
+                    expected: vec![ModelType::Profile],
+                    found: other.model_type(),
+                }
+                .into())
+            }
+        };
+
+        self.imports.enter(path);
+        let mut imports = vec![];
+        for import in &profile.profile.imports {
+            let upstream = self
+                .imports
+                .load(&import.href, &source, &UPSTREAM)
+                .with_context(|| {
+                    format!(
+                        "Could not follow import `{}` of {}",
+                        import.href,
+                        path.display()
+                    )
+                })?;
+            let upstream = self.follow(upstream)?;
+            hasher.update(&self.nodes[&upstream].key);
+            imports.push(upstream);
+        }
+        self.imports.leave();
+
+        Ok((profile, imports))
+    }
+
+    /// Catalog of a document reached, resolved first if it is a profile
+    fn catalog(&mut self, canonical: &Path) -> Result<Value> {
+        let node = self.nodes[canonical].clone();
+        if let Some(catalog) = self.catalogs.get(&node.key) {
+            return Ok(catalog.clone());
+        }
+
+        let path = &node.imported.path;
+        let catalog = match &node.profile {
+            None => {
+                let Parsed {
+                    document, warnings, ..
+                } = self.imports.parse(&node.imported)?;
+                self.resolution
+                    .warnings
+                    .extend(model_warnings(warnings, &path.to_string_lossy()));
+                serde_json::to_value(document)?
+            }
+            Some((profile, imports)) => match self.cached(&node.key) {
+                Some(catalog) => {
+                    self.resolution.cached.push(path.clone());
+                    catalog
+                }
+                None => {
+                    let catalog = self.resolve(&node, profile, imports)?;
+                    self.store(&node.key, &catalog)?;
+                    self.resolution.resolved.push(path.clone());
+                    catalog
+                }
+            },
+        };
+        self.catalogs.insert(node.key, catalog.clone());
+
+        Ok(catalog)
+    }
+
+    fn cache_file(&self, key: &str) -> Option<PathBuf> {
+        self.cache_dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{key}.json")))
+    }
+
+    /// Resolved catalog from the cache. Unreadable entries are resolved again
+    fn cached(&self, key: &str) -> Option<Value> {
+        let content = read_to_string(self.cache_file(key)?).ok()?;
+
+        serde_json::from_str(&content).ok()
+    }
+
+    fn store(&self, key: &str, catalog: &Value) -> Result<()> {
+        let Some(file) = self.cache_file(key) else {
+            return Ok(());
+        };
+        if let Some(dir) = file.parent() {
+            create_dir_all(dir).with_context(|| {
+                format!("Could not create cache directory: `{}`", dir.display())
+            })?;
+        }
+
+        write_file(&file, &serde_json::to_string(catalog)?)
+    }
+
+    /// Select the controls of each import, keeping the structure of the
+    /// catalog they come from, then apply the modifications of the profile
+    fn resolve(
+        &mut self,
+        node: &Node,
+        profile: &Profile,
+        imports: &[PathBuf],
+    ) -> Result<Value> {
+        let path = &node.imported.path;
+        let flat = flattens(path, profile.profile.merge.as_ref())?;
+        let value = serde_json::to_value(profile)?;
+        let value = &value["profile"];
+        let mut kept = HashSet::new();
+        let (mut params, mut controls, mut groups) = (vec![], vec![], vec![]);
+        let mut resources = items(&value["back-matter"]["resources"]);
+
+        for (import, upstream) in profile.profile.imports.iter().zip(imports) {
+            let catalog = self.catalog(upstream)?;
+            let baseline = Baseline::from_catalog(
+                &self.nodes[upstream].imported.path,
+                &Catalog::deserialize_with(&catalog, self.imports.options())?,
+            );
+            let catalog = &catalog["catalog"];
+
+            let mut selected = baseline.import(import);
+            if let Some(id) = selected
+                .iter()
+                .find(|id| !baseline.controls.contains_key(*id))
+            {
+                return Err(CliError::UnknownTarget(
+                    path.clone(),
+                    format!("control `{id}`"),
+                    baseline.source.display().to_string(),
+                ));
+            }
+            // Controls already imported are kept from their first import
+            selected.retain(|id| !kept.contains(id));
+            let selected = selected.into_iter().collect();
+
+            controls.extend(prune_controls(
+                &catalog["controls"],
+                &selected,
+                &mut kept,
+            ));
+            groups.extend(prune_groups(
+                &catalog["groups"],
+                &selected,
+                &mut kept,
+            ));
+            for param in items(&catalog["params"]) {
+                if !params.iter().any(|p: &Value| p["id"] == param["id"]) {
+                    params.push(param);
+                }
+            }
+            for resource in items(&catalog["back-matter"]["resources"]) {
+                if !resources.iter().any(|r| r["uuid"] == resource["uuid"]) {
+                    resources.push(resource);
+                }
+            }
+        }
+
+        let mut metadata = value["metadata"].clone();
+        metadata["last-modified"] =
+            Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true).into();
+        let mut catalog = json!({
+            "uuid": Uuid::new_v5(&Uuid::NAMESPACE_OID, node.key.as_bytes())
+                .to_string(),
+            "metadata": metadata,
+        });
+        if flat {
+            ungroup(std::mem::take(&mut groups), &mut controls);
+        }
+        set_items(&mut catalog, "params", params);
+        set_items(&mut catalog, "controls", controls);
+        set_items(&mut catalog, "groups", groups);
+        if !resources.is_empty() {
+            catalog["back-matter"] = json!({ "resources": resources });
+        }
+
+        let unknown = |what: String, target: &str| {
+            CliError::UnknownTarget(path.clone(), what, target.to_owned())
+        };
+        let modify = &value["modify"];
+        for setting in items(&modify["set-parameters"]) {
+            let id = setting["param-id"].as_str().unwrap_or_default();
+            let param =
+                find_by_id(&mut catalog, &["params"], id).ok_or_else(|| {
+                    unknown(format!("parameter `{id}`"), "the resolved catalog")
+                })?;
+            set_parameter(param, &setting);
+        }
+        for alter in items(&modify["alters"]) {
+            let id = alter["control-id"].as_str().unwrap_or_default();
+            let control = find_by_id(&mut catalog, &["controls"], id)
+                .ok_or_else(|| {
+                    unknown(format!("control `{id}`"), "the resolved catalog")
+                })?;
+            for removal in items(&alter["removes"]) {
+                remove(control, &removal);
+            }
+            for addition in items(&alter["adds"]) {
+                add(control, &addition).map_err(|what| {
+                    unknown(what, &format!("control `{id}`"))
+                })?;
+            }
+        }
+
+        let catalog = json!({ "catalog": catalog });
+        OscalModels::Catalog
+            .check_value(catalog.clone(), self.imports.options())?;
+
+        Ok(catalog)
+    }
+}
+
+fn items(value: &Value) -> Vec<Value> {
+    value.as_array().cloned().unwrap_or_default()
+}
+
+/// Set an array of an object, removing it when empty
+fn set_items(node: &mut Value, key: &str, items: Vec<Value>) {
+    let Some(map) = node.as_object_mut() else {
+        return;
+    };
+
+    match items.is_empty() {
+        true => map.remove(key),
+        false => map.insert(key.to_owned(), Value::Array(items)),
+    };
+}
+
+/// Whether the `merge` directive of a profile drops the groups. Custom
+/// groupings and combination methods other than `use-first` are not supported
+fn flattens(
+    path: &Path,
+    merge: Option<&MergeControls>,
+) -> Result<bool, CliError> {
+    let Some(merge) = merge else {
+        return Ok(false);
+    };
+    let unsupported = |what: &str| {
+        CliError::UnsupportedMerge(path.to_path_buf(), what.into())
+    };
+
+    match merge.combine.as_ref().and_then(|c| c.method.as_ref()) {
+        None | Some(CombinationMethod::UseFirst) => {}
+        Some(CombinationMethod::Merge) => {
+            return Err(unsupported("`combine` method `merge`"))
+        }
+        Some(_) => return Err(unsupported("`combine` method `keep`")),
+    }
+    if merge.custom.is_some() {
+        return Err(unsupported("`custom` grouping"));
+    }
+
+    Ok(merge.flat.is_some())
+}
+
+/// Controls of the groups, in order, without the groups
+fn ungroup(groups: Vec<Value>, controls: &mut Vec<Value>) {
+    for group in groups {
+        controls.extend(items(&group["controls"]));
+        ungroup(items(&group["groups"]), controls);
+    }
+}
+
+/// Selected controls, with the selected descendants of the others taking
+/// their place
+fn prune_controls(
+    controls: &Value,
+    selected: &HashSet<String>,
+    kept: &mut HashSet<String>,
+) -> Vec<Value> {
+    let mut col = vec![];
+
+    for control in controls.as_array().into_iter().flatten() {
+        let children = prune_controls(&control["controls"], selected, kept);
+        let id = control["id"].as_str().unwrap_or_default();
+        if selected.contains(id) {
+            let mut control = control.clone();
+            set_items(&mut control, "controls", children);
+            kept.insert(id.to_owned());
+            col.push(control);
+        } else {
+            col.extend(children);
+        }
+    }
+
+    col
+}
+
+/// Groups holding selected controls
+fn prune_groups(
+    groups: &Value,
+    selected: &HashSet<String>,
+    kept: &mut HashSet<String>,
+) -> Vec<Value> {
+    let mut col = vec![];
+
+    for group in groups.as_array().into_iter().flatten() {
+        let controls = prune_controls(&group["controls"], selected, kept);
+        let subgroups = prune_groups(&group["groups"], selected, kept);
+        if controls.is_empty() && subgroups.is_empty() {
+            continue;
+        }
+
+        let mut group = group.clone();
+        set_items(&mut group, "controls", controls);
+        set_items(&mut group, "groups", subgroups);
+        col.push(group);
+    }
+
+    col
+}
+
+/// Object with the given id in an array under one of the keys, at any depth
+fn find_by_id<'a>(
+    node: &'a mut Value,
+    keys: &[&str],
+    id: &str,
+) -> Option<&'a mut Value> {
+    match node {
+        Value::Object(map) => map.iter_mut().find_map(|(key, value)| {
+            if keys.contains(&key.as_str()) {
+                let index = value
+                    .as_array()
+                    .and_then(|a| a.iter().position(|item| item["id"] == id));
+                if let Some(index) = index {
+                    return value.get_mut(index);
+                }
+            }
+            find_by_id(value, keys, id)
+        }),
+        Value::Array(items) => {
+            items.iter_mut().find_map(|item| find_by_id(item, keys, id))
+        }
+        _ => None,
+    }
+}
+
+/// Object holding the array in which the object with the given id is, along
+/// with the key of the array and the index of the object
+fn find_parent<'a>(
+    node: &'a mut Value,
+    keys: &[&str],
+    id: &str,
+) -> Option<(&'a mut Map<String, Value>, String, usize)> {
+    match node {
+        Value::Object(map) => {
+            let found = keys.iter().find_map(|key| {
+                let items = map.get(*key)?.as_array()?;
+                let index = items.iter().position(|item| item["id"] == id)?;
+                Some((key.to_string(), index))
+            });
+            if let Some((key, index)) = found {
+                return Some((map, key, index));
+            }
+            map.values_mut()
+                .find_map(|value| find_parent(value, keys, id))
+        }
+        Value::Array(items) => items
+            .iter_mut()
+            .find_map(|item| find_parent(item, keys, id)),
+        _ => None,
+    }
+}
+
+/// Apply a `set-parameters` entry. Lists are added to, other fields replaced
+fn set_parameter(param: &mut Value, setting: &Value) {
+    for (key, value) in setting.as_object().into_iter().flatten() {
+        match key.as_str() {
+            "param-id" => {}
+            "props" | "links" | "constraints" | "guidelines" => {
+                let mut col = items(&param[key.as_str()]);
+                col.extend(items(value));
+                param[key.as_str()] = Value::Array(col);
+            }
+            _ => param[key.as_str()] = value.clone(),
+        }
+    }
+}
+
+/// Whether a `removes` entry matches an item. Every criterion given must match
+fn removes(removal: &Value, item_name: &str, item: &Value) -> bool {
+    let criteria = [
+        ("by-name", "name"),
+        ("by-class", "class"),
+        ("by-id", "id"),
+        ("by-ns", "ns"),
+    ];
+    let mut any = false;
+
+    for (by, field) in criteria {
+        if let Some(expected) = removal.get(by) {
+            any = true;
+            if item.get(field) != Some(expected) {
+                return false;
+            }
+        }
+    }
+    if let Some(name) = removal.get("by-item-name") {
+        any = true;
+        if name.as_str() != Some(item_name) {
+            return false;
+        }
+    }
+
+    any
+}
+
+/// Remove the items matching a `removes` entry, at any depth of the parts
+fn remove(node: &mut Value, removal: &Value) {
+    let Some(map) = node.as_object_mut() else {
+        return;
+    };
+
+    for (key, item_name) in ITEMS {
+        let Some(Value::Array(col)) = map.get_mut(key) else {
+            continue;
+        };
+        col.retain(|item| !removes(removal, item_name, item));
+        if key == "parts" {
+            for part in col.iter_mut() {
+                remove(part, removal);
+            }
+        }
+        if col.is_empty() {
+            map.remove(key);
+        }
+    }
+}
+
+/// Insert the items of an `adds` entry at the start or the end of the arrays
+/// of an object
+fn insert_items(node: &mut Value, addition: &Value, starting: bool) {
+    for (key, _) in ITEMS {
+        let mut added = items(&addition[key]);
+        if added.is_empty() {
+            continue;
+        }
+
+        let mut col = items(&node[key]);
+        if starting {
+            added.extend(col);
+            col = added;
+        } else {
+            col.extend(added);
+        }
+        node[key] = Value::Array(col);
+    }
+}
+
+/// Apply an `adds` entry to a control, returning the target it names if the
+/// control has no such part or parameter
+fn add(control: &mut Value, addition: &Value) -> Result<(), String> {
+    let position = addition["position"].as_str().unwrap_or("ending");
+    let by_id = addition["by-id"]
+        .as_str()
+        .filter(|id| control["id"].as_str() != Some(id));
+    let missing = || format!("part or parameter `{}`", by_id.unwrap_or(""));
+
+    match (by_id, position) {
+        (None, _) => {
+            if let Some(title) = addition.get("title") {
+                control["title"] = title.clone();
+            }
+            insert_items(
+                control,
+                addition,
+                matches!(position, "starting" | "before"),
+            );
+        }
+        (Some(id), "starting" | "ending") => {
+            let target = find_by_id(control, &["parts", "params"], id)
+                .ok_or_else(missing)?;
+            insert_items(target, addition, position == "starting");
+        }
+        (Some(id), _) => {
+            let (parent, target_key, index) =
+                find_parent(control, &["parts", "params"], id)
+                    .ok_or_else(missing)?;
+            let index = match position {
+                "after" => index + 1,
+                _ => index,
+            };
+
+            // Items of the same kind as the target go beside it, the others
+            // at the end of the object holding it
+            for (key, _) in ITEMS {
+                let added = items(&addition[key]);
+                if added.is_empty() {
+                    continue;
+                }
+                let entry =
+                    parent.entry(key).or_insert_with(|| Value::Array(vec![]));
+                if let Value::Array(col) = entry {
+                    match key == target_key {
+                        true => {
+                            col.splice(index..index, added);
+                        }
+                        false => col.extend(added),
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolve a profile into a catalog, caching the resolved catalogs of the
+/// profiles met under `cache_dir` if given
+pub(super) fn resolve_catalog(
+    file: &Path,
+    mirror_dir: Option<PathBuf>,
+    cache_dir: Option<PathBuf>,
+) -> Result<Resolution> {
+    let mut resolver = Resolver {
+        imports: Imports::new(ParseOptions::default(), mirror_dir),
+        cache_dir,
+        nodes: HashMap::new(),
+        catalogs: HashMap::new(),
+        resolution: Resolution::default(),
+    };
+
+    let root = resolver.imports.read(file, &[ModelType::Profile])?;
+    let root = resolver.follow(root)?;
+    let catalog = resolver.catalog(&root)?;
+
+    Ok(Resolution {
+        catalog,
+        ..resolver.resolution
+    })
+}
+
+pub(super) async fn resolve_profile(
+    opts: &ResolveProfile,
+) -> Result<Resolution> {
+    let resolution = resolve_catalog(
+        &opts.file,
+        opts.mirror_dir.clone(),
+        opts.cache_dir.clone(),
+    )?;
+
+    if let Some(file) = &opts.output_file {
+        write_model(file, &resolution.catalog)?;
+    }
+
+    Ok(resolution)
+}
@@ -0,0 +1,544 @@
+use std::{
+    fmt,
+    fs::{create_dir_all, read, write},
+    path::{Component, Path, PathBuf},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use roscal_lib::{
+    hash::HashAlgorithm,
+    href::{standard_resolver, HrefError, HrefResolver, HrefSource},
+    parse::ParseOptions,
+};
+use serde_json::{json, Map, Value};
+
+use crate::cli::cli_opts::{
+    AddResource, ExtractResources, ListResources, RemoveResource,
+    VerifyResources,
+};
+
+use super::{
+    model::OscalModels,
+    utils::{is_valid_file, write_model},
+    workspace::{CliError, Context, Result},
+};
+
+/// Result of checking one declared hash
+#[derive(Debug, Clone, PartialEq)]
+pub enum HashOutcome {
+    Match,
+    /// The content has a different digest, which is given
+    Mismatch(String),
+    /// The content could not be read, for the given reason
+    Unreadable(String),
+    /// The algorithm is not one OSCAL lists, so it can't be recomputed
+    UnknownAlgorithm,
+    /// The link points outside the local file system
+    Remote,
+}
+
+/// A hash declared by a resource link, checked against some content
+#[derive(Debug, Clone, PartialEq)]
+pub struct HashCheck {
+    /// Uuid of the back-matter resource
+    pub resource: String,
+    /// `base64` for the embedded payload, else the href of the link
+    pub target: String,
+    pub algorithm: String,
+    pub expected: String,
+    pub outcome: HashOutcome,
+}
+
+impl fmt::Display for HashCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            resource,
+            target,
+            algorithm,
+            expected,
+            outcome,
+        } = self;
+
+        match outcome {
+            HashOutcome::Match => {
+                write!(f, "OK {resource} `{target}` {algorithm}")
+            }
+            HashOutcome::Mismatch(found) => write!(
+                f,
+                "MISMATCH {resource} `{target}` {algorithm}: expected {expected}, found {found}"
+            ),
+            HashOutcome::Unreadable(reason) => {
+                write!(f, "UNREADABLE {resource} `{target}`: {reason}")
+            }
+            HashOutcome::UnknownAlgorithm => write!(
+                f,
+                "SKIPPED {resource} `{target}` {algorithm}: unknown algorithm"
+            ),
+            HashOutcome::Remote => {
+                write!(f, "SKIPPED {resource} `{target}`: remote link")
+            }
+        }
+    }
+}
+
+/// Outcome of verifying the back-matter resources of a model file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VerifyReport {
+    pub checks: Vec<HashCheck>,
+}
+
+impl VerifyReport {
+    /// Hashes that don't match or whose content could not be read
+    pub fn failures(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|c| {
+                matches!(
+                    c.outcome,
+                    HashOutcome::Mismatch(_) | HashOutcome::Unreadable(_)
+                )
+            })
+            .count()
+    }
+}
+
+impl fmt::Display for VerifyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            writeln!(f, "{check}")?;
+        }
+        let verified = self
+            .checks
+            .iter()
+            .filter(|c| c.outcome == HashOutcome::Match)
+            .count();
+
+        write!(
+            f,
+            "{verified} hash(es) verified, {} failed, {} skipped",
+            self.failures(),
+            self.checks.len() - verified - self.failures()
+        )
+    }
+}
+
+fn check_hash(
+    resource: &str,
+    target: &str,
+    hash: &Value,
+    content: &Result<Vec<u8>, String>,
+) -> HashCheck {
+    let algorithm = hash["algorithm"].as_str().unwrap_or_default();
+    let expected = hash["value"].as_str().unwrap_or_default();
+
+    let outcome = match (HashAlgorithm::from_name(algorithm), content) {
+        (None, _) => HashOutcome::UnknownAlgorithm,
+        (_, Err(e)) => HashOutcome::Unreadable(e.clone()),
+        (Some(a), Ok(data)) if a.matches(data, expected) => HashOutcome::Match,
+        (Some(a), Ok(data)) => HashOutcome::Mismatch(a.digest(data)),
+    };
+
+    HashCheck {
+        resource: resource.to_owned(),
+        target: target.to_owned(),
+        algorithm: algorithm.to_owned(),
+        expected: expected.to_owned(),
+        outcome,
+    }
+}
+
+/// Check the hashes of each link against its local target, and against the
+/// embedded payload when the media types agree
+fn verify_resource(
+    resource: &Value,
+    source: &HrefSource,
+    resolver: &impl HrefResolver,
+    out: &mut Vec<HashCheck>,
+) {
+    let uuid = resource["uuid"].as_str().unwrap_or_default();
+    let payload = resource.get("base64").map(|base64| {
+        let value = base64["value"].as_str().unwrap_or_default();
+        let content = STANDARD
+            .decode(value)
+            .map_err(|e| format!("invalid base64 payload: {e}"));
+
+        (base64.get("media-type"), content)
+    });
+
+    for rlink in resource["rlinks"].as_array().into_iter().flatten() {
+        let href = rlink["href"].as_str().unwrap_or_default();
+        let Some(hashes) = rlink["hashes"].as_array() else {
+            continue;
+        };
+
+        match resolver.resolve(href, source) {
+            Err(HrefError::Unsupported(_)) => {
+                out.extend(hashes.iter().map(|hash| {
+                    HashCheck {
+                        resource: uuid.to_owned(),
+                        target: href.to_owned(),
+                        algorithm: hash["algorithm"]
+                            .as_str()
+                            .unwrap_or_default()
+                            .to_owned(),
+                        expected: hash["value"]
+                            .as_str()
+                            .unwrap_or_default()
+                            .to_owned(),
+                        outcome: HashOutcome::Remote,
+                    }
+                }))
+            }
+            res => {
+                let content = res
+                    .map_err(|e| e.to_string())
+                    .and_then(|path| read(path).map_err(|e| e.to_string()));
+                for hash in hashes {
+                    out.push(check_hash(uuid, href, hash, &content));
+                }
+            }
+        }
+
+        if let Some((media_type, content)) = &payload {
+            let media_types_agree = match (media_type, rlink.get("media-type"))
+            {
+                (Some(a), Some(b)) => *a == b,
+                _ => true,
+            };
+            if media_types_agree {
+                for hash in hashes {
+                    out.push(check_hash(uuid, "base64", hash, content));
+                }
+            }
+        }
+    }
+}
+
+/// Directory the links of a model file are relative to
+fn base_dir(file: &Path) -> PathBuf {
+    file.parent().map(Path::to_path_buf).unwrap_or_default()
+}
+
+/// Back-matter resources of a model, if any
+fn resources(root: &Value) -> &[Value] {
+    root.as_object()
+        .and_then(|root| root.values().next())
+        .and_then(|model| model["back-matter"]["resources"].as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn find_resource<'a>(root: &'a Value, uuid: &str) -> Result<&'a Value> {
+    resources(root)
+        .iter()
+        .find(|r| r["uuid"] == uuid)
+        .ok_or_else(|| CliError::UnknownResource(uuid.to_owned()))
+}
+
+/// Top level object of a model, below its root key
+fn model_mut(root: &mut Value) -> Result<&mut Map<String, Value>> {
+    root.as_object_mut()
+        .and_then(|root| root.values_mut().next())
+        .and_then(Value::as_object_mut)
+        .context("Model file has no top level object")
+}
+
+/// Href of a file relative to a directory, with `/` separators
+fn relative_href(file: &Path, base: &Path) -> Result<String> {
+    let file = file
+        .canonicalize()
+        .with_context(|| format!("Could not locate {}", file.display()))?;
+    let base = match base.as_os_str().is_empty() {
+        true => Path::new("."),
+        false => base,
+    }
+    .canonicalize()
+    .with_context(|| format!("Could not locate {}", base.display()))?;
+
+    let file: Vec<Component> = file.components().collect();
+    let base: Vec<Component> = base.components().collect();
+    let common = file.iter().zip(&base).take_while(|(a, b)| a == b).count();
+
+    let parts: Vec<String> =
+        std::iter::repeat_n("..".to_owned(), base.len() - common)
+            .chain(
+                file[common..]
+                    .iter()
+                    .map(|c| c.as_os_str().to_string_lossy().into_owned()),
+            )
+            .collect();
+
+    Ok(parts.join("/"))
+}
+
+/// Pointers to the strings of a document that link to `#uuid`
+fn references(value: &Value, fragment: &str, pointer: String) -> Vec<String> {
+    match value {
+        Value::String(s) if s.contains(fragment) => vec![pointer],
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .flat_map(|(i, v)| {
+                references(v, fragment, format!("{pointer}/{i}"))
+            })
+            .collect(),
+        Value::Object(object) => object
+            .iter()
+            .flat_map(|(k, v)| {
+                let key = k.replace('~', "~0").replace('/', "~1");
+                references(v, fragment, format!("{pointer}/{key}"))
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+pub(super) async fn verify_resources(
+    opts: &VerifyResources,
+) -> Result<VerifyReport> {
+    let root = OscalModels::from_opt(opts.model.as_deref(), &opts.file)?
+        .read_value(&opts.file, &ParseOptions::default())
+        .await?
+        .0;
+    let source = HrefSource::new(&opts.file);
+    let resolver = standard_resolver(opts.mirror_dir.clone());
+
+    let mut checks = vec![];
+    for resource in resources(&root) {
+        verify_resource(resource, &source, &resolver, &mut checks);
+    }
+
+    Ok(VerifyReport { checks })
+}
+
+/// Add a resource linking to a local file, returning its uuid
+pub(super) async fn add_resource(opts: &AddResource) -> Result<String> {
+    is_valid_file(&opts.source)?;
+    let algorithms = opts
+        .hash_algorithms
+        .iter()
+        .map(|name| {
+            HashAlgorithm::from_name(name)
+                .ok_or_else(|| CliError::UnknownHashAlgorithm(name.to_owned()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let model = OscalModels::from_opt(opts.model.as_deref(), &opts.file)?;
+    let mut root = model
+        .read_value(&opts.file, &ParseOptions::default())
+        .await?
+        .0;
+
+    let content = read(&opts.source)
+        .with_context(|| format!("Could not read {}", opts.source.display()))?;
+    let file_name = opts
+        .source
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let media_type = mime_guess::from_path(&opts.source)
+        .first_or_octet_stream()
+        .essence_str()
+        .to_owned();
+    let hashes: Vec<Value> = algorithms
+        .iter()
+        .map(|a| json!({ "algorithm": a.name(), "value": a.digest(&content) }))
+        .collect();
+
+    let uuid = uuid::Uuid::new_v4().to_string();
+    let mut resource = json!({
+        "uuid": uuid,
+        "title": opts.title.as_deref().unwrap_or(&file_name),
+        "rlinks": [{
+            "href": relative_href(&opts.source, &base_dir(&opts.file))?,
+            "media-type": media_type,
+            "hashes": hashes,
+        }],
+    });
+    if opts.embed {
+        resource["base64"] = json!({
+            "filename": file_name,
+            "media-type": media_type,
+            "value": STANDARD.encode(&content),
+        });
+    }
+
+    let back_matter = model_mut(&mut root)?
+        .entry("back-matter")
+        .or_insert_with(|| json!({}));
+    match back_matter["resources"].as_array_mut() {
+        Some(resources) => resources.push(resource),
+        None => back_matter["resources"] = json!([resource]),
+    }
+    model.check_value(root.clone(), &ParseOptions::default())?;
+
+    write_model(opts.output_file.as_ref().unwrap_or(&opts.file), &root)?;
+
+    Ok(uuid)
+}
+
+/// Summary of a back-matter resource
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceSummary {
+    pub uuid: String,
+    pub title: Option<String>,
+    /// Media type of the embedded payload, else of the first link
+    pub media_type: Option<String>,
+    /// Hrefs of the links
+    pub links: Vec<String>,
+    /// File name of the embedded payload, if any
+    pub embedded: Option<String>,
+}
+
+/// Back-matter resources of a model file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResourceList {
+    pub resources: Vec<ResourceSummary>,
+}
+
+impl fmt::Display for ResourceList {
+    /// Markdown table of the resources
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cell = |s: &str| s.replace('|', "\\|").replace('\n', " ");
+
+        writeln!(f, "| uuid | title | media-type | links | embedded |")?;
+        write!(f, "|{}", " --- |".repeat(5))?;
+        for resource in &self.resources {
+            let links: Vec<String> =
+                resource.links.iter().map(|href| cell(href)).collect();
+            write!(
+                f,
+                "\n| {} | {} | {} | {} | {} |",
+                resource.uuid,
+                cell(resource.title.as_deref().unwrap_or_default()),
+                resource.media_type.as_deref().unwrap_or_default(),
+                links.join(", "),
+                cell(resource.embedded.as_deref().unwrap_or_default()),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+pub(super) async fn list_resources(
+    opts: &ListResources,
+) -> Result<ResourceList> {
+    let root = OscalModels::from_opt(opts.model.as_deref(), &opts.file)?
+        .read_value(&opts.file, &ParseOptions::default())
+        .await?
+        .0;
+    let as_string = |v: &Value| v.as_str().map(str::to_owned);
+
+    let resources = resources(&root)
+        .iter()
+        .map(|resource| {
+            let rlinks = resource["rlinks"].as_array();
+            ResourceSummary {
+                uuid: as_string(&resource["uuid"]).unwrap_or_default(),
+                title: as_string(&resource["title"]),
+                media_type: as_string(&resource["base64"]["media-type"])
+                    .or_else(|| {
+                        rlinks
+                            .and_then(|rlinks| rlinks.first())
+                            .and_then(|rlink| as_string(&rlink["media-type"]))
+                    }),
+                links: rlinks
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|rlink| as_string(&rlink["href"]))
+                    .collect(),
+                embedded: resource.get("base64").map(|base64| {
+                    as_string(&base64["filename"]).unwrap_or_default()
+                }),
+            }
+        })
+        .collect();
+
+    Ok(ResourceList { resources })
+}
+
+/// Write the embedded payloads to disk, returning the uuid and path of each
+pub(super) async fn extract_resources(
+    opts: &ExtractResources,
+) -> Result<Vec<(String, PathBuf)>> {
+    let root = OscalModels::from_opt(opts.model.as_deref(), &opts.file)?
+        .read_value(&opts.file, &ParseOptions::default())
+        .await?
+        .0;
+
+    let selected: Vec<&Value> = match &opts.uuid {
+        Some(uuid) => {
+            let resource = find_resource(&root, uuid)?;
+            if resource.get("base64").is_none() {
+                return Err(CliError::NoPayload(uuid.to_owned()));
+            }
+            vec![resource]
+        }
+        None => resources(&root)
+            .iter()
+            .filter(|r| r.get("base64").is_some())
+            .collect(),
+    };
+
+    let output_dir = opts.output_dir.clone().unwrap_or_default();
+    if !selected.is_empty() && !output_dir.as_os_str().is_empty() {
+        create_dir_all(&output_dir).with_context(|| {
+            format!("Could not create {}", output_dir.display())
+        })?;
+    }
+
+    let mut extracted = vec![];
+    for resource in selected {
+        let uuid = resource["uuid"].as_str().unwrap_or_default();
+        let base64 = &resource["base64"];
+        let content = STANDARD
+            .decode(base64["value"].as_str().unwrap_or_default())
+            .with_context(|| format!("Invalid base64 payload in {uuid}"))?;
+        // Only the final component, so a filename can't escape the directory
+        let file_name = base64["filename"]
+            .as_str()
+            .and_then(|name| Path::new(name).file_name())
+            .map(|name| name.to_os_string())
+            .unwrap_or_else(|| uuid.into());
+
+        let path = output_dir.join(file_name);
+        write(&path, content)
+            .with_context(|| format!("Could not write {}", path.display()))?;
+        extracted.push((uuid.to_owned(), path));
+    }
+
+    Ok(extracted)
+}
+
+/// Remove a resource, unless a `#uuid` link still points at it
+pub(super) async fn remove_resource(opts: &RemoveResource) -> Result<()> {
+    let model = OscalModels::from_opt(opts.model.as_deref(), &opts.file)?;
+    let mut root = model
+        .read_value(&opts.file, &ParseOptions::default())
+        .await?
+        .0;
+    find_resource(&root, &opts.uuid)?;
+
+    let pointers = references(&root, &format!("#{}", opts.uuid), String::new());
+    if !pointers.is_empty() {
+        return Err(CliError::ResourceInUse(opts.uuid.clone(), pointers));
+    }
+
+    let object = model_mut(&mut root)?;
+    if let Some(Value::Object(back_matter)) = object.get_mut("back-matter") {
+        if let Some(Value::Array(resources)) = back_matter.get_mut("resources")
+        {
+            resources.retain(|r| r["uuid"] != opts.uuid.as_str());
+            if resources.is_empty() {
+                back_matter.remove("resources");
+            }
+        }
+        if back_matter.is_empty() {
+            object.remove("back-matter");
+        }
+    }
+    model.check_value(root.clone(), &ParseOptions::default())?;
+
+    write_model(opts.output_file.as_ref().unwrap_or(&opts.file), &root)
+}
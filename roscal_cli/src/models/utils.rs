@@ -1,39 +1,42 @@
 #![allow(clippy::single_char_pattern)]
-use std::{fs::File, io::Read, path::Path};
+use std::{
+    fs::File,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
 
-use anyhow::{Context, Result};
 use chrono::Utc;
 use rand::{distr::Alphanumeric, rng, Rng};
+use roscal_lib::parse::{ParseOptions, UnknownFields};
 use sha2::{Digest, Sha256};
 
-pub(super) fn check_filename_len(path: impl AsRef<Path>) -> bool {
+use super::workspace::{CliError, Context, Result};
+
+pub(super) fn check_filename_len(
+    path: impl AsRef<Path>,
+) -> Result<(), CliError> {
     if path.as_ref().to_string_lossy().len() > 202 {
-        eprintln!("File name is too long");
-        return false;
+        return Err(CliError::FileNameTooLong);
     }
 
-    true
+    Ok(())
 }
 
-pub(super) fn is_valid_file(path: impl AsRef<Path>) -> bool {
-    if check_filename_len(&path) && !path.as_ref().is_file() {
-        eprintln!("Invalid file provided: {}", path.as_ref().display());
-        return false;
+pub(super) fn is_valid_file(path: impl AsRef<Path>) -> Result<(), CliError> {
+    check_filename_len(&path)?;
+    if !path.as_ref().is_file() {
+        return Err(CliError::InvalidFile(path.as_ref().to_path_buf()));
     }
 
-    true
+    Ok(())
 }
 
-pub(super) fn is_valid_dir(path: impl AsRef<Path>) -> bool {
+pub(super) fn is_valid_dir(path: impl AsRef<Path>) -> Result<(), CliError> {
     if !path.as_ref().is_dir() {
-        eprintln!(
-            "Invalid directory provided: {}",
-            path.as_ref().to_string_lossy()
-        );
-        return false;
+        return Err(CliError::InvalidDir(path.as_ref().to_path_buf()));
     }
 
-    true
+    Ok(())
 }
 
 pub(super) fn gen_created_at() -> String {
@@ -79,3 +82,58 @@ pub(super) fn gen_hash_from_str(content: &str) -> Result<String> {
 
     Ok(format!("{:x}", hasher.finalize()))
 }
+
+/// Options for reading a model, keeping the fields unknown to it when
+/// lenient
+pub(super) fn lenient_options(lenient: bool) -> ParseOptions {
+    let unknown_fields = if lenient {
+        UnknownFields::Capture
+    } else {
+        UnknownFields::Deny
+    };
+
+    ParseOptions::default().with_unknown_fields(unknown_fields)
+}
+
+/// Write content to the given file
+pub(super) fn write_file(path: &PathBuf, content: &str) -> Result<()> {
+    let mut file = File::create(path).with_context(|| {
+        format!("Could not create output file: `{}`", path.display())
+    })?;
+    file.write_all(content.as_bytes())
+        .with_context(|| "Could not write to output file".to_owned())
+}
+
+/// Write content to the given file, or hand it back for stdout if
+/// unspecified
+pub(super) fn write_output(
+    path: Option<&PathBuf>,
+    content: String,
+) -> Result<Option<String>> {
+    match path {
+        Some(path) => write_file(path, &content).map(|_| None),
+        None => Ok(Some(content)),
+    }
+}
+
+/// A model as json for `.json` files and yaml otherwise
+pub(super) fn model_content(
+    path: &Path,
+    root: &serde_json::Value,
+) -> Result<String> {
+    let content = if path.extension().is_some_and(|ext| ext == "json") {
+        serde_json::to_string_pretty(root)?
+    } else {
+        serde_yaml::to_string(root)?
+    };
+
+    Ok(content)
+}
+
+/// Write a model as json for `.json` files and yaml otherwise
+pub(super) fn write_model(
+    path: &PathBuf,
+    root: &serde_json::Value,
+) -> Result<()> {
+    write_file(path, &model_content(path, root)?)
+}
@@ -1,101 +1,142 @@
-use std::{fs::File, io::BufReader, str::FromStr};
+use std::{fmt, fs::read_to_string};
 
-use anyhow::{Context, Result};
 use roscal_lib::{
     assessment::{
         assessment_plan::AssessmentPlan, assessment_results::AssessmentResults,
         poam::PlanOfActionAndMilestones,
     },
     control::{catalog::Catalog, profile::Profile},
+    document::ModelType,
     implementation::{
         component_definition::ComponentDefinition, ssp::SystemSecurityPlan,
     },
+    parse::{ParseError, ParseOptions, ParseWarning, ParseWith},
+    schema::{self, SchemaViolation},
 };
 
 use crate::cli::cli_opts::Validate;
 
-use super::{model::OscalModels, validation::is_valid_model};
+use super::{
+    model::{model_warnings, ModelWarning, OscalModels},
+    utils::lenient_options,
+    validation::is_valid_model,
+    workspace::{Context, Result},
+};
+
+/// Outcome of validating a model file
+#[derive(Debug)]
+pub struct ValidationReport {
+    pub model_type: ModelType,
+    /// Why the file does not parse as its model, if it doesn't
+    pub parse_error: Option<ParseError>,
+    /// Places where the file breaks the official JSON schema
+    pub violations: Vec<SchemaViolation>,
+    /// Unknown fields accepted by a lenient validation
+    pub warnings: Vec<ModelWarning>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.parse_error.is_none() && self.violations.is_empty()
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = model_name(self.model_type);
+        match &self.parse_error {
+            None => writeln!(f, "This is a valid {name} model")?,
+            Some(e) => {
+                writeln!(f, "This is not a valid {name} model")?;
+                writeln!(f, "  {e}")?;
+            }
+        }
 
-pub(super) async fn validate_model(opts: &Validate) -> Result<()> {
-    if !is_valid_model(&opts.model) {
-        std::process::exit(1)
+        if self.violations.is_empty() {
+            return write!(f, "The model conforms to the official JSON schema");
+        }
+
+        write!(
+            f,
+            "The model breaks the official JSON schema in {} place(s):",
+            self.violations.len()
+        )?;
+        for violation in &self.violations {
+            write!(f, "\n  {violation}")?;
+        }
+
+        Ok(())
     }
+}
 
-    let file = File::open(&opts.file).with_context(|| {
+fn model_name(model_type: ModelType) -> &'static str {
+    match model_type {
+        ModelType::AssessmentPlan => "Assessment Plan",
+        ModelType::AssessmentResults => "Assessment Result",
+        ModelType::PlanOfActionAndMilestones => "Plan of Action and Milestones",
+        ModelType::Catalog => "Catalog",
+        ModelType::Profile => "Profile",
+        ModelType::ComponentDefinition => "Component Definition",
+        ModelType::SystemSecurityPlan => "System Security Plan",
+    }
+}
+
+pub(super) async fn validate_model(
+    opts: &Validate,
+) -> Result<ValidationReport> {
+    if let Some(model) = &opts.model {
+        is_valid_model(model)?;
+    }
+    let model = OscalModels::from_opt(opts.model.as_deref(), &opts.file)?;
+
+    let input = read_to_string(&opts.file).with_context(|| {
         format!("Could not open model file: `{}`", &opts.file.display())
     })?;
 
-    let reader = BufReader::new(file);
-
-    match OscalModels::from_str(&opts.model).with_context(|| {
-        "Could not determine the provided OSCAL model".to_string()
-    })? {
+    let options = lenient_options(opts.lenient);
+    let (parse_error, warnings) = match model {
         OscalModels::AssessmentPlan => {
-            let res: Result<AssessmentPlan, serde_json::Error> =
-                serde_json::from_reader(reader);
-            if res.is_ok() {
-                println!("This is a valid Assessment Plan model")
-            } else {
-                println!("This is not a valid Assessment Plan model")
-            }
+            check_model::<AssessmentPlan>(&input, &options)
         }
         OscalModels::AssessmentResults => {
-            let res: Result<AssessmentResults, serde_json::Error> =
-                serde_json::from_reader(reader);
-            if res.is_ok() {
-                println!("This is a valid Assessment Result model")
-            } else {
-                println!("This is not a valid Assessment Result model")
-            }
+            check_model::<AssessmentResults>(&input, &options)
         }
         OscalModels::Poam => {
-            let res: Result<PlanOfActionAndMilestones, serde_json::Error> =
-                serde_json::from_reader(reader);
-            if res.is_ok() {
-                println!("This is a valid Plan of Action and Milestones model")
-            } else {
-                println!(
-                    "This is not a valid Plan of Action and Milestones model"
-                )
-            }
-        }
-        OscalModels::Catalog => {
-            let res: Result<Catalog, serde_json::Error> =
-                serde_json::from_reader(reader);
-            if res.is_ok() {
-                println!("This is a valid Catalog model")
-            } else {
-                println!("This is not a valid Catalog model")
-            }
-        }
-        OscalModels::Profile => {
-            let res: Result<Profile, serde_json::Error> =
-                serde_json::from_reader(reader);
-            if res.is_ok() {
-                println!("This is a valid Profile model")
-            } else {
-                println!("This is not a valid Profile model")
-            }
+            check_model::<PlanOfActionAndMilestones>(&input, &options)
         }
+        OscalModels::Catalog => check_model::<Catalog>(&input, &options),
+        OscalModels::Profile => check_model::<Profile>(&input, &options),
         OscalModels::ComponentDefinition => {
-            let res: Result<ComponentDefinition, serde_json::Error> =
-                serde_json::from_reader(reader);
-            if res.is_ok() {
-                println!("This is a valid Component Definition model")
-            } else {
-                println!("This is not a valid Component Definition model")
-            }
-        }
-        OscalModels::Ssp => {
-            let res: Result<SystemSecurityPlan, serde_json::Error> =
-                serde_json::from_reader(reader);
-            if res.is_ok() {
-                println!("This is a valid System Security Plan model")
-            } else {
-                println!("This is not a valid System Security Plan model")
-            }
+            check_model::<ComponentDefinition>(&input, &options)
         }
+        OscalModels::Ssp => check_model::<SystemSecurityPlan>(&input, &options),
+    };
+
+    Ok(ValidationReport {
+        model_type: (&model).into(),
+        parse_error,
+        violations: check_schema(&model, &input)?,
+        warnings: model_warnings(warnings, &opts.file.to_string_lossy()),
+    })
+}
+
+fn check_model<T: ParseWith>(
+    input: &str,
+    options: &ParseOptions,
+) -> (Option<ParseError>, Vec<ParseWarning>) {
+    match T::from_str_with_warnings(input, options) {
+        Ok((_, warnings)) => (None, warnings),
+        Err(e) => (Some(e), vec![]),
     }
+}
+
+/// Second opinion from the official JSON schema of the model
+fn check_schema(
+    model: &OscalModels,
+    input: &str,
+) -> Result<Vec<SchemaViolation>> {
+    let document: serde_json::Value = serde_yaml::from_str(input)
+        .with_context(|| "Could not read model file as JSON or YAML")?;
 
-    Ok(())
+    Ok(schema::validate(model.into(), &document)?)
 }
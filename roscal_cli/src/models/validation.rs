@@ -1,161 +1,65 @@
 use std::str::FromStr;
 
-use super::model::*;
+use super::{model::*, workspace::CliError};
 
-pub(super) fn is_valid_model(model: &str) -> bool {
+pub(super) fn is_valid_model(model: &str) -> Result<(), CliError> {
     if OscalModels::from_str(model).is_err() {
-        eprintln!("Invalid model provided: {}", model);
-        return false;
+        return Err(CliError::InvalidModelOpt(model.to_owned()));
     }
 
-    true
+    Ok(())
 }
 
-pub(super) fn is_valid_assessment_plan_block(blocks: &Vec<String>) -> bool {
-    let mut col = String::new();
-
+/// Blocks must all name a block of the model, and `all` must stand alone
+fn is_valid_block<T: FromStr>(
+    blocks: &[String],
+    name: &str,
+) -> Result<(), CliError> {
     if blocks.len() > 1 && blocks.iter().any(|v| v == "all") {
-        eprintln!("Multiple block cannot include `all` option");
-        return false;
+        return Err(CliError::MultipleBlocksWithAll);
     }
 
-    blocks
-        .iter()
-        .filter(|v| AssessmentPlanBlock::from_str(v).is_err())
-        .for_each(|v| col.push_str(v));
-
-    if col.is_empty() {
-        true
-    } else {
-        eprintln!("Invalid assessment plan block provided: {:?}", blocks);
-        false
-    }
-}
-
-pub(super) fn is_valid_assessment_results_block(blocks: &Vec<String>) -> bool {
-    let mut col = String::new();
-
-    if blocks.len() > 1 && blocks.iter().any(|v| v == "all") {
-        eprintln!("Multiple block cannot include `all` option");
-        return false;
+    if blocks.iter().any(|v| T::from_str(v).is_err()) {
+        return Err(CliError::InvalidBlocks(name.to_owned(), blocks.to_vec()));
     }
 
-    blocks
-        .iter()
-        .filter(|v| AssessmentResultsBlock::from_str(v).is_err())
-        .for_each(|v| col.push_str(v));
-
-    if col.is_empty() {
-        true
-    } else {
-        eprintln!("Invalid assessment results block provided: {:?}", blocks);
-        false
-    }
+    Ok(())
 }
 
-pub(super) fn is_valid_poam_block(blocks: &Vec<String>) -> bool {
-    let mut col = String::new();
-
-    if blocks.len() > 1 && blocks.iter().any(|v| v == "all") {
-        eprintln!("Multiple block cannot include `all` option");
-        return false;
-    }
-
-    blocks
-        .iter()
-        .filter(|v| PoamBlock::from_str(v).is_err())
-        .for_each(|v| col.push_str(v));
-
-    if col.is_empty() {
-        true
-    } else {
-        eprintln!("Invalid poam block provided: {:?}", blocks);
-        false
-    }
+pub(super) fn is_valid_assessment_plan_block(
+    blocks: &[String],
+) -> Result<(), CliError> {
+    is_valid_block::<AssessmentPlanBlock>(blocks, "assessment plan")
 }
 
-pub(super) fn is_valid_catalog_block(blocks: &Vec<String>) -> bool {
-    let mut col = String::new();
-
-    if blocks.len() > 1 && blocks.iter().any(|v| v == "all") {
-        eprintln!("Multiple block cannot include `all` option");
-        return false;
-    }
-
-    blocks
-        .iter()
-        .filter(|v| CatalogBlock::from_str(v).is_err())
-        .for_each(|v| col.push_str(v));
-
-    if col.is_empty() {
-        true
-    } else {
-        eprintln!("Invalid catalog block provided: {:?}", blocks);
-        false
-    }
+pub(super) fn is_valid_assessment_results_block(
+    blocks: &[String],
+) -> Result<(), CliError> {
+    is_valid_block::<AssessmentResultsBlock>(blocks, "assessment results")
 }
 
-pub(super) fn is_valid_profile_block(blocks: &Vec<String>) -> bool {
-    let mut col = String::new();
-
-    if blocks.len() > 1 && blocks.iter().any(|v| v == "all") {
-        eprintln!("Multiple block cannot include `all` option");
-        return false;
-    }
+pub(super) fn is_valid_poam_block(blocks: &[String]) -> Result<(), CliError> {
+    is_valid_block::<PoamBlock>(blocks, "poam")
+}
 
-    blocks
-        .iter()
-        .filter(|v| ProfileBlock::from_str(v).is_err())
-        .for_each(|v| col.push_str(v));
+pub(super) fn is_valid_catalog_block(
+    blocks: &[String],
+) -> Result<(), CliError> {
+    is_valid_block::<CatalogBlock>(blocks, "catalog")
+}
 
-    if col.is_empty() {
-        true
-    } else {
-        eprintln!("Invalid profile block provided: {:?}", blocks);
-        false
-    }
+pub(super) fn is_valid_profile_block(
+    blocks: &[String],
+) -> Result<(), CliError> {
+    is_valid_block::<ProfileBlock>(blocks, "profile")
 }
 
 pub(super) fn is_valid_component_definition_block(
-    blocks: &Vec<String>,
-) -> bool {
-    let mut col = String::new();
-
-    if blocks.len() > 1 && blocks.iter().any(|v| v == "all") {
-        eprintln!("Multiple block cannot include `all` option");
-        return false;
-    }
-
-    blocks
-        .iter()
-        .filter(|v| ComponentDefinitionBlock::from_str(v).is_err())
-        .for_each(|v| col.push_str(v));
-
-    if col.is_empty() {
-        true
-    } else {
-        eprintln!("Invalid component definition block provided: {:?}", blocks);
-        false
-    }
+    blocks: &[String],
+) -> Result<(), CliError> {
+    is_valid_block::<ComponentDefinitionBlock>(blocks, "component definition")
 }
 
-pub(super) fn is_valid_ssp_block(blocks: &Vec<String>) -> bool {
-    let mut col = String::new();
-
-    if blocks.len() > 1 && blocks.iter().any(|v| v == "all") {
-        eprintln!("Multiple block cannot include `all` option");
-        return false;
-    }
-
-    blocks
-        .iter()
-        .filter(|v| SspBlock::from_str(v).is_err())
-        .for_each(|v| col.push_str(v));
-
-    if col.is_empty() {
-        true
-    } else {
-        eprintln!("Invalid ssp block provided: {:?}", blocks);
-        false
-    }
+pub(super) fn is_valid_ssp_block(blocks: &[String]) -> Result<(), CliError> {
+    is_valid_block::<SspBlock>(blocks, "ssp")
 }
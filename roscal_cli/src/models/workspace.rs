@@ -1,14 +1,40 @@
-use std::path::PathBuf;
+use std::{fmt, path::PathBuf};
 
-use anyhow::Result;
+use roscal_lib::{
+    document::{DocumentError, ModelType},
+    parse::{MarkupMode, ParseOptions},
+    schema::SchemaError,
+    xml::XmlError,
+};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::cli::cli_opts::{Dissect, Merge, Validate};
+use crate::cli::cli_opts::{
+    AddResource, BuildPackage, Convert, Coverage, Dissect, Edit,
+    ExtractResources, ListResources, Merge, Query, RemoveResource, Render,
+    ResolveProfile, Validate, ValidateChain, VerifyPackage, VerifyResources,
+};
 
 use super::{
-    dissect::dissect_workspace, merge::merge_workspace, utils::*,
-    validate::validate_model,
+    chain::{validate_chain, ChainReport},
+    convert::convert_model,
+    coverage::coverage_report,
+    dissect::dissect_workspace,
+    edit::edit_model,
+    imports::ImportError,
+    merge::merge_workspace,
+    merge::MergeOpt,
+    model::ModelWarning,
+    package::{build_package, verify_package, Manifest, PackageReport},
+    query::query_model,
+    render::render_model,
+    resolve::{resolve_profile, Resolution},
+    resources::{
+        add_resource, extract_resources, list_resources, remove_resource,
+        verify_resources, ResourceList, VerifyReport,
+    },
+    utils::*,
+    validate::{validate_model, ValidationReport},
 };
 
 #[derive(Error, Debug)]
@@ -17,28 +43,217 @@ pub enum CliError {
     ParseModel(String, String, String),
     #[error("Could not parse {0} field for model {1} from file: {2}\nCause ---> {3}")]
     ParseBlock(String, String, String, String),
-    #[error("Unknown output format. Use json or yaml")]
+    #[error("Unknown output format. Use json, yaml or xml")]
     UnknownMergeOpt,
+    #[error("Could not determine the format of `{0}`. Use --output-format json, yaml or xml")]
+    UnknownFileFormat(String),
+    #[error("{} is the dissected file. Use --in-place to overwrite it with a backup", .0.display())]
+    OverwriteOriginal(PathBuf),
+    #[error("Backup file already exists: {}. Move it away to merge in place again", .0.display())]
+    BackupExists(PathBuf),
     #[error("Unknown uuid version. Use v4 or v5")]
     UnknownUuidVer,
+    #[error("Unknown report format. Use json, csv or markdown")]
+    UnknownReportOpt,
+    #[error("Unknown baseline model. Use Catalog or Profile")]
+    UnknownBaselineModel,
+    #[error("Unknown render format. Use markdown or html")]
+    UnknownRenderOpt,
+    #[error("Unknown render model. Use Catalog, Ssp or Poam")]
+    UnknownRenderModel,
+    #[error("Unknown query output format. Use json, yaml or table")]
+    UnknownQueryOpt,
+    #[error("Unknown model. Run `roscal show-dissect` for available models")]
+    UnknownModel,
+    #[error("{0}")]
+    InvalidQuery(String),
+    #[error("Could not read edit operations from file: {0}\nCause ---> {1}")]
+    ParseEditOps(String, String),
+    #[error("Edit operation matched nothing: {0}")]
+    EditNoMatch(String),
+    #[error("Unknown version scheme. Use major, minor, patch or date")]
+    UnknownVersionScheme,
+    #[error("Could not bump version `{0}`. Use a major.minor.patch version or the date scheme")]
+    BumpSemver(String),
+    #[error("Unknown convert format. Use json, yaml or xml")]
+    UnknownConvertOpt,
+    #[error("Could not detect the model type of {0}. Specify it with --model")]
+    UndetectedModel(String),
+    #[error("Not a valid {0} model\nCause ---> {1}")]
+    InvalidModel(String, String),
+    #[error("Invalid model provided: {0}")]
+    InvalidModelOpt(String),
+    #[error("Invalid {0} block provided: {1:?}")]
+    InvalidBlocks(String, Vec<String>),
+    #[error("Multiple block cannot include `all` option")]
+    MultipleBlocksWithAll,
+    #[error("File name is too long")]
+    FileNameTooLong,
+    #[error("Invalid file provided: {}", .0.display())]
+    InvalidFile(PathBuf),
+    #[error("Invalid directory provided: {}", .0.display())]
+    InvalidDir(PathBuf),
+    #[error("File integrity check failed as the hash does not match the original, program aborted")]
+    IntegrityCheck,
+    #[error("{0} resource hash(es) could not be verified")]
+    HashMismatch(usize),
+    #[error("{0} issue(s) found in the import chain")]
+    BrokenChain(usize),
+    #[error(transparent)]
+    Import(#[from] ImportError),
+    #[error("Could not resolve import `{1}` of {}: {2}", .0.display())]
+    UnresolvedImport(PathBuf, String, String),
+    #[error("{} is a {1}, expected {2}", .0.display())]
+    WrongModelType(PathBuf, ModelType, String),
+    #[error("{} is not an OSCAL document, expected {1}", .0.display())]
+    NotOscal(PathBuf, String),
+    #[error("{}: {1} is not in {2}", .0.display())]
+    UnknownTarget(PathBuf, String, String),
+    #[error("{}: {1} is not supported. Use `as-is` or `flat` with the `use-first` combination", .0.display())]
+    UnsupportedMerge(PathBuf, String),
+    #[error("Invalid package: {0}")]
+    InvalidPackage(String),
+    #[error("{} is XML, XML documents are not supported in packages", .0.display())]
+    PackageXml(PathBuf),
+    #[error("{0} issue(s) found in the package")]
+    BrokenPackage(usize),
+    #[error("Unknown hash algorithm `{0}`. Use SHA-224, SHA-256, SHA-384, SHA-512, SHA3-224, SHA3-256, SHA3-384 or SHA3-512")]
+    UnknownHashAlgorithm(String),
+    #[error("No back-matter resource with uuid {0}")]
+    UnknownResource(String),
+    #[error("Resource {0} has no embedded base64 payload")]
+    NoPayload(String),
+    #[error("Resource {0} is still referenced at: {}", .1.join(", "))]
+    ResourceInUse(String, Vec<String>),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Yaml(#[from] serde_yaml::Error),
+    #[error(transparent)]
+    Xml(#[from] XmlError),
+    #[error(transparent)]
+    Document(#[from] DocumentError),
+    #[error(transparent)]
+    Schema(#[from] SchemaError),
+    #[error(transparent)]
+    UnknownName(#[from] strum::ParseError),
+    #[error(transparent)]
+    ParseInt(#[from] std::num::ParseIntError),
+    #[error(transparent)]
+    Base64(#[from] base64::DecodeError),
+    #[error("{0}")]
+    Missing(String),
+    #[error("{0}")]
+    Context(String, #[source] Box<CliError>),
+}
+
+/// Result of the workspace operations
+pub type Result<T, E = CliError> = std::result::Result<T, E>;
+
+/// Say what was being done when an operation failed
+pub trait Context<T> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T>;
+
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(
+        self,
+        context: F,
+    ) -> Result<T>;
+}
+
+impl<T, E: Into<CliError>> Context<T> for std::result::Result<T, E> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T> {
+        self.map_err(|e| {
+            CliError::Context(context.to_string(), Box::new(e.into()))
+        })
+    }
+
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(
+        self,
+        context: F,
+    ) -> Result<T> {
+        self.map_err(|e| {
+            CliError::Context(context().to_string(), Box::new(e.into()))
+        })
+    }
+}
+
+impl<T> Context<T> for Option<T> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T> {
+        self.ok_or_else(|| CliError::Missing(context.to_string()))
+    }
+
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(
+        self,
+        context: F,
+    ) -> Result<T> {
+        self.ok_or_else(|| CliError::Missing(context().to_string()))
+    }
 }
 
+/// Workspace operations, usable without the command line
 #[derive(Debug, Clone)]
 pub struct Workspace;
 
 impl Workspace {
-    pub async fn dissect(
-        opts: &Dissect,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        dissect_workspace(opts).await?;
+    /// Split a model file into a workspace of block files
+    pub async fn dissect(opts: &Dissect) -> Result<DissectCtx, CliError> {
+        dissect_workspace(opts).await
+    }
 
-        Ok(())
+    /// Put a workspace back together into a model file
+    pub async fn merge(opts: &Merge) -> Result<MergeCtx, CliError> {
+        merge_workspace(opts).await
     }
 
-    pub async fn merge(opts: &Merge) -> Result<(), Box<dyn std::error::Error>> {
-        merge_workspace(opts).await?;
+    pub async fn edit(opts: &Edit) -> Result<Output, CliError> {
+        edit_model(opts).await
+    }
 
-        Ok(())
+    /// Convert a model to another format, returning the content when no
+    /// output file is given
+    pub async fn convert(opts: &Convert) -> Result<Output, CliError> {
+        convert_model(opts).await
+    }
+
+    /// Resolve a profile into a catalog, reusing cached resolutions
+    pub async fn resolve_profile(
+        opts: &ResolveProfile,
+    ) -> Result<Resolution, CliError> {
+        resolve_profile(opts).await
+    }
+
+    /// Bundle an SSP with everything it references into a tar archive
+    pub async fn build_package(
+        opts: &BuildPackage,
+    ) -> Result<Manifest, CliError> {
+        build_package(opts).await
+    }
+
+    /// Add a back-matter resource for a local file, returning its uuid
+    pub async fn add_resource(opts: &AddResource) -> Result<String, CliError> {
+        add_resource(opts).await
+    }
+
+    pub async fn list_resources(
+        opts: &ListResources,
+    ) -> Result<ResourceList, CliError> {
+        list_resources(opts).await
+    }
+
+    /// Write embedded payloads to disk, returning the uuid and path of each
+    pub async fn extract_resources(
+        opts: &ExtractResources,
+    ) -> Result<Vec<(String, PathBuf)>, CliError> {
+        extract_resources(opts).await
+    }
+
+    /// Remove a back-matter resource no `#uuid` link refers to
+    pub async fn remove_resource(
+        opts: &RemoveResource,
+    ) -> Result<(), CliError> {
+        remove_resource(opts).await
     }
 }
 
@@ -46,13 +261,59 @@ impl Workspace {
 pub struct Validator;
 
 impl Validator {
+    /// Check a model file against its model and official JSON schema
     pub async fn validate(
         opts: &Validate,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        validate_model(opts).await?;
+    ) -> Result<ValidationReport, CliError> {
+        validate_model(opts).await
+    }
 
-        Ok(())
+    /// Follow the imports of a model file and check the documents agree
+    pub async fn validate_chain(
+        opts: &ValidateChain,
+    ) -> Result<ChainReport, CliError> {
+        validate_chain(opts).await
+    }
+
+    /// Recompute the hashes declared by the back-matter resources
+    pub async fn verify_resources(
+        opts: &VerifyResources,
+    ) -> Result<VerifyReport, CliError> {
+        verify_resources(opts).await
+    }
+
+    /// Check the files of a package against its manifest
+    pub async fn verify_package(
+        opts: &VerifyPackage,
+    ) -> Result<PackageReport, CliError> {
+        verify_package(opts).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Reporter;
+
+impl Reporter {
+    pub async fn coverage(opts: &Coverage) -> Result<Output, CliError> {
+        coverage_report(opts).await
     }
+
+    pub async fn render(opts: &Render) -> Result<Output, CliError> {
+        render_model(opts).await
+    }
+
+    pub async fn query(opts: &Query) -> Result<Output, CliError> {
+        query_model(opts).await
+    }
+}
+
+/// Result of a command that writes to a file or stdout
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Output {
+    /// Content for stdout, `None` once written to the output file
+    pub content: Option<String>,
+    /// Unknown fields kept while reading the model
+    pub warnings: Vec<ModelWarning>,
 }
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
@@ -66,9 +327,26 @@ pub struct DissectCtx {
     pub backup: String,
     pub modifiable: String,
     pub hash: String,
+    #[serde(default)]
+    pub parse_markup: bool,
+    #[serde(default)]
+    pub lenient: bool,
+    /// Unknown fields kept while reading the model
+    #[serde(skip)]
+    pub warnings: Vec<ModelWarning>,
 }
 
 impl DissectCtx {
+    pub fn parse_options(&self) -> ParseOptions {
+        let markup_mode = if self.parse_markup {
+            MarkupMode::Html
+        } else {
+            MarkupMode::Preserve
+        };
+
+        lenient_options(self.lenient).with_markup_mode(markup_mode)
+    }
+
     pub fn is_valid_hash(&self) -> Result<bool> {
         let path = format!("{}/backup", &self.backup);
         let hash = gen_hash_from_path(&path);
@@ -83,7 +361,21 @@ pub struct MergeCtx {
     pub hash: String,
     pub rand: String,
     pub output_dir: String,
+    #[serde(default)]
+    pub output_file: String,
     pub dissect_workspace_ref: DissectCtx,
+    /// Format the merged file was written in
+    #[serde(skip)]
+    pub output_format: Option<MergeOpt>,
+    /// Copy of the original file kept by an in-place merge
+    #[serde(skip)]
+    pub original_backup: Option<PathBuf>,
+    /// Location of the manifest of the merge
+    #[serde(skip)]
+    pub manifest: PathBuf,
+    /// Unknown fields kept while reading the blocks
+    #[serde(skip)]
+    pub warnings: Vec<ModelWarning>,
 }
 
 impl MergeCtx {
@@ -0,0 +1,181 @@
+use std::{fs, path::Path};
+
+use assert_cmd::Command;
+
+/// Copy of the chain documents in a temp directory, with edits applied
+fn chain(edits: &[(&str, &str, &str)]) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    for entry in fs::read_dir("tests/data/chain").unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_str().unwrap().to_owned();
+        let mut content = fs::read_to_string(&path).unwrap();
+        for (file, from, to) in edits {
+            if *file == name {
+                assert!(content.contains(from), "{from}");
+                content = content.replace(from, to);
+            }
+        }
+        fs::write(dir.path().join(name), content).unwrap();
+    }
+
+    dir
+}
+
+fn validate_chain(file: &Path) -> (bool, String, String) {
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let output = cmd
+        .env("RUST_BACKTRACE", "0")
+        .args(["validate-chain", "--file", file.to_str().unwrap()])
+        .output()
+        .unwrap();
+
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
+
+#[test]
+fn cli_validate_chain() {
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.args(["validate-chain", "--file", "tests/data/chain/ar.yaml"])
+        .assert()
+        .success()
+        .stdout(
+            "assessment-results tests/data/chain/ar.yaml
+  assessment-plan tests/data/chain/ap.yaml
+    system-security-plan tests/data/chain/ssp.yaml
+      profile tests/data/chain/profile.yaml
+        catalog tests/data/chain/catalog.yaml
+5 document(s) checked, 0 issue(s)
+",
+        );
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.args(["validate-chain", "--file", "tests/data/chain/poam.yaml"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn cli_validate_chain_unknown_references() {
+    let dir = chain(&[
+        ("profile.yaml", "- ac-6\n", "- ac-6\n            - ac-7\n"),
+        ("ssp.yaml", "control-id: ac-6.1", "control-id: ac-2"),
+        (
+            "ap.yaml",
+            "- subject-uuid: 551b9706-d6a4-4d25-8207-f2ccec548b89",
+            "- subject-uuid: 0b1c2d3e-4f50-4a6b-8c7d-9e0f1a2b3c4d",
+        ),
+    ]);
+    let (success, stdout, stderr) = validate_chain(&dir.path().join("ap.yaml"));
+    let path = |file: &str| dir.path().join(file).display().to_string();
+
+    assert!(!success);
+    assert_eq!("3 issue(s) found in the import chain\n", stderr);
+    assert!(stdout.contains(&format!(
+        "ERROR {}: control `ac-7` is not in {}\n",
+        path("profile.yaml"),
+        path("catalog.yaml")
+    )));
+    assert!(stdout.contains(&format!(
+        "ERROR {}: control `ac-2` is not in {}\n",
+        path("ssp.yaml"),
+        path("profile.yaml")
+    )));
+    assert!(stdout.contains(&format!(
+        "ERROR {}: component `0b1c2d3e-4f50-4a6b-8c7d-9e0f1a2b3c4d` is not defined in {}\n",
+        path("ap.yaml"),
+        path("ssp.yaml")
+    )));
+    assert!(stdout.ends_with("4 document(s) checked, 3 issue(s)\n"));
+}
+
+#[test]
+fn cli_validate_chain_unresolved_imports() {
+    let dir = chain(&[
+        ("ssp.yaml", "href: profile.yaml", "href: catalog.yaml"),
+        ("ar.yaml", "href: ./ap.yaml", "href: missing.yaml"),
+        (
+            "poam.yaml",
+            "href: ssp.yaml",
+            "href: https://example.com/oscal/ssp.yaml",
+        ),
+    ]);
+    let path = |file: &str| dir.path().join(file).display().to_string();
+
+    let (success, stdout, _) = validate_chain(&dir.path().join("ssp.yaml"));
+    assert!(!success);
+    assert!(stdout.contains(&format!(
+        "ERROR {}: {} is a catalog, expected profile\n",
+        path("ssp.yaml"),
+        path("catalog.yaml")
+    )));
+
+    let (success, stdout, _) = validate_chain(&dir.path().join("ar.yaml"));
+    assert!(!success);
+    assert!(stdout.contains(&format!(
+        "ERROR {}: could not resolve `missing.yaml`: {} does not exist\n",
+        path("ar.yaml"),
+        path("missing.yaml")
+    )));
+
+    let (success, stdout, _) = validate_chain(&dir.path().join("poam.yaml"));
+    assert!(!success);
+    assert!(stdout.contains(&format!(
+        "ERROR {}: could not resolve `https://example.com/oscal/ssp.yaml`: `https://example.com/oscal/ssp.yaml` can't be resolved to a local file\n",
+        path("poam.yaml"),
+    )));
+}
+
+#[test]
+fn cli_validate_chain_mirror_dir() {
+    let dir = chain(&[(
+        "poam.yaml",
+        "href: ssp.yaml",
+        "href: https://example.com/oscal/ssp.yaml",
+    )]);
+    let mirror = dir.path().join("mirror");
+    let site = mirror.join("example.com/oscal");
+    fs::create_dir_all(&site).unwrap();
+    for file in ["ssp.yaml", "profile.yaml", "catalog.yaml"] {
+        fs::copy(dir.path().join(file), site.join(file)).unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let assert = cmd
+        .args([
+            "validate-chain",
+            "--file",
+            dir.path().join("poam.yaml").to_str().unwrap(),
+            "--mirror-dir",
+            mirror.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let stdout =
+        String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    assert!(stdout.contains(&format!(
+        "  system-security-plan {}\n",
+        site.join("ssp.yaml").display()
+    )));
+    assert!(stdout.ends_with("4 document(s) checked, 0 issue(s)\n"));
+}
+
+#[test]
+fn cli_validate_chain_cycle() {
+    let dir =
+        chain(&[("profile.yaml", "href: catalog.yaml", "href: profile.yaml")]);
+    let path = |file: &str| dir.path().join(file).display().to_string();
+
+    let (success, stdout, _) = validate_chain(&dir.path().join("ssp.yaml"));
+    assert!(!success);
+    assert!(stdout.contains(&format!(
+        "ERROR {}: import cycle {} -> {}\n",
+        path("profile.yaml"),
+        path("profile.yaml"),
+        path("profile.yaml")
+    )));
+}
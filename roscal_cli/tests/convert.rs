@@ -0,0 +1,184 @@
+use std::{
+    fs::{read_to_string, File},
+    io::BufRead,
+};
+
+use assert_cmd::Command;
+use roscal_lib::{
+    control::catalog::Catalog, implementation::ssp::SystemSecurityPlan,
+};
+use tempfile::tempdir;
+
+fn data_dir() -> String {
+    format!(
+        "{}/tests/data",
+        std::env::current_dir().unwrap().to_string_lossy()
+    )
+}
+
+#[test]
+fn cli_convert_model() {
+    let dir = tempdir().unwrap();
+    let data_dir = data_dir();
+    let output_file = dir.path().join("catalog.json");
+
+    Command::cargo_bin("roscal")
+        .unwrap()
+        .args([
+            "convert",
+            "--file",
+            &format!("{data_dir}/catalog.yaml"),
+            "--to",
+            "json",
+            "--compact",
+            "--output-file",
+            output_file.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let output = read_to_string(&output_file).unwrap();
+    assert_eq!(1, output.lines().count());
+    let catalog: Catalog =
+        serde_json::from_reader(File::open(&output_file).unwrap()).unwrap();
+    assert_eq!("74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724", catalog.catalog.uuid);
+
+    let assert = Command::cargo_bin("roscal")
+        .unwrap()
+        .args(["convert", "--to", "yaml"])
+        .write_stdin(output)
+        .assert()
+        .success();
+    let catalog: Catalog =
+        serde_yaml::from_slice(&assert.get_output().stdout).unwrap();
+    assert_eq!("74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724", catalog.catalog.uuid);
+}
+
+#[test]
+fn cli_convert_detect_model() {
+    let assert = Command::cargo_bin("roscal")
+        .unwrap()
+        .args([
+            "convert",
+            "--file",
+            &format!("{}/ssp.yaml", data_dir()),
+            "--to",
+            "json",
+        ])
+        .assert()
+        .success();
+
+    let ssp: SystemSecurityPlan =
+        serde_json::from_slice(&assert.get_output().stdout).unwrap();
+    assert!(!ssp.system_security_plan.uuid.is_empty());
+}
+
+#[test]
+fn cli_convert_wrong_input() {
+    Command::cargo_bin("roscal")
+        .unwrap()
+        .args(["convert", "--to", "json"])
+        .write_stdin("unknown-model:\n  uuid: 1\n")
+        .assert()
+        .failure()
+        .stderr(
+            "Could not detect the model type of stdin. Specify it with --model\n",
+        );
+
+    Command::cargo_bin("roscal")
+        .unwrap()
+        .args(["convert", "--to", "toml"])
+        .write_stdin("catalog:\n")
+        .assert()
+        .failure()
+        .stderr("Unknown convert format. Use json, yaml or xml\n");
+}
+
+#[test]
+fn cli_convert_xml() {
+    let assert = Command::cargo_bin("roscal")
+        .unwrap()
+        .args([
+            "convert",
+            "--file",
+            &format!("{}/catalog.yaml", data_dir()),
+            "--to",
+            "xml",
+        ])
+        .assert()
+        .success();
+
+    let output = String::from_utf8_lossy(&assert.get_output().stdout);
+    assert!(output.starts_with(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<catalog xmlns=\"http://csrc.nist.gov/ns/oscal/1.0\" uuid=\"74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724\">\n"
+    ));
+    assert!(output.ends_with("</catalog>\n"));
+
+    let assert = Command::cargo_bin("roscal")
+        .unwrap()
+        .args(["convert", "--to", "xml", "--compact"])
+        .write_stdin(
+            read_to_string(format!("{}/catalog.yaml", data_dir())).unwrap(),
+        )
+        .assert()
+        .success();
+    assert_eq!(1, assert.get_output().stdout.lines().count());
+}
+
+#[test]
+fn cli_convert_xml_export_only() {
+    let dir = tempdir().unwrap();
+    let output = dir.path().join("catalog.xml");
+    Command::cargo_bin("roscal")
+        .unwrap()
+        .args([
+            "convert",
+            "--file",
+            &format!("{}/catalog.yaml", data_dir()),
+            "--to",
+            "xml",
+            "--output-file",
+            output.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    Command::cargo_bin("roscal")
+        .unwrap()
+        .env("RUST_BACKTRACE", "0")
+        .args(["convert", "--file", output.to_str().unwrap(), "--to", "json"])
+        .assert()
+        .failure()
+        .stderr(format!(
+            "Could not parse catalog model from file: {}\nCause ---> XML documents can't be read, the XML encoding is export only, read the JSON or YAML document it was written from instead\n",
+            output.display()
+        ));
+}
+
+#[test]
+fn cli_convert_lenient() {
+    let catalog = read_to_string(format!("{}/catalog.yaml", data_dir()))
+        .unwrap()
+        .replacen("  metadata:\n", "  x-vendor-id: 42\n  metadata:\n", 1);
+
+    Command::cargo_bin("roscal")
+        .unwrap()
+        .args(["convert", "--to", "json"])
+        .write_stdin(catalog.clone())
+        .assert()
+        .failure();
+
+    let assert = Command::cargo_bin("roscal")
+        .unwrap()
+        .args(["convert", "--to", "json", "--lenient"])
+        .write_stdin(catalog)
+        .assert()
+        .success()
+        .stderr(
+            "Warning: catalog: unknown field `x-vendor-id` kept as an extension in `stdin`\n",
+        );
+
+    let output: serde_json::Value =
+        serde_json::from_slice(&assert.get_output().stdout).unwrap();
+    assert_eq!(42, output["catalog"]["x-vendor-id"]);
+}
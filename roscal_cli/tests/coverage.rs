@@ -0,0 +1,167 @@
+use std::fs::read_to_string;
+
+use assert_cmd::Command;
+use roscal_cli::models::coverage::{
+    ControlCoverage, CoverageReport, StatementCoverage,
+};
+use tempfile::tempdir;
+
+fn data_path(file: &str) -> String {
+    format!(
+        "{}/tests/data/{}",
+        std::env::current_dir().unwrap().to_string_lossy(),
+        file
+    )
+}
+
+#[test]
+fn cli_coverage_json() {
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let output = cmd
+        .args([
+            "coverage",
+            "--baseline",
+            &data_path("baseline.yaml"),
+            "--model",
+            "Catalog",
+            "--ssp",
+            &data_path("ssp.yaml"),
+            "--output-format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).unwrap();
+    let summary = &report["summary"];
+
+    assert_eq!(3, summary["total-controls"]);
+    assert_eq!(1, summary["implemented-controls"]);
+    assert_eq!(2, summary["missing-controls"]);
+    assert_eq!(0, summary["controls-without-by-components"]);
+    assert_eq!(1, summary["implementation-status"]["implemented"]);
+
+    let ac_6_1 = &report["controls"][1];
+    assert_eq!("ac-6.1", ac_6_1["control-id"]);
+    assert_eq!(true, ac_6_1["implemented"]);
+    assert_eq!(3, ac_6_1["statements"].as_array().unwrap().len());
+}
+
+#[test]
+fn cli_coverage_csv_and_markdown() {
+    let dir = tempdir().unwrap();
+
+    for (format, file) in [("csv", "coverage.csv"), ("markdown", "coverage.md")]
+    {
+        let output_file = dir.path().join(file);
+
+        let mut cmd = Command::cargo_bin("roscal").unwrap();
+        cmd.args([
+            "coverage",
+            "--baseline",
+            &data_path("baseline.yaml"),
+            "--model",
+            "Catalog",
+            "--ssp",
+            &data_path("ssp.yaml"),
+            "--output-format",
+            format,
+            "--output-file",
+            output_file.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+        let content = read_to_string(output_file).unwrap();
+        assert!(content.contains("ac-6.2"));
+    }
+}
+
+#[test]
+fn cli_coverage_profile() {
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let output = cmd
+        .args([
+            "coverage",
+            "--baseline",
+            &data_path("chain/profile.yaml"),
+            "--ssp",
+            &data_path("chain/ssp.yaml"),
+            "--output-format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).unwrap();
+    let ids: Vec<&str> = report["controls"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["control-id"].as_str().unwrap())
+        .collect();
+    assert_eq!(vec!["ac-6", "ac-6.1", "ac-6.2"], ids);
+
+    let statements: Vec<&str> = report["controls"][1]["statements"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["statement-id"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        vec!["ac-6.1_smt", "ac-6.1_smt.a", "ac-6.1_smt.b"],
+        statements
+    );
+    assert_eq!(5, report["summary"]["total-statements"]);
+}
+
+#[test]
+fn coverage_csv_quoting() {
+    let report = CoverageReport {
+        controls: vec![ControlCoverage {
+            control_id: "ac-1".to_owned(),
+            implemented: true,
+            has_by_components: true,
+            implementation_status: vec![
+                "planned, partly".to_owned(),
+                "\"other\"".to_owned(),
+            ],
+            statements: vec![StatementCoverage {
+                statement_id: "ac-1_smt\na".to_owned(),
+                implemented: false,
+                has_by_components: false,
+            }],
+        }],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        "control_id,statement_id,implemented,has_by_components,implementation_status
+ac-1,,true,true,\"planned, partly;\"\"other\"\"\"
+ac-1,\"ac-1_smt\na\",false,false,
+",
+        report.to_csv()
+    );
+}
+
+#[test]
+fn cli_coverage_unknown_format() {
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.args([
+        "coverage",
+        "--baseline",
+        &data_path("baseline.yaml"),
+        "--model",
+        "Catalog",
+        "--ssp",
+        &data_path("ssp.yaml"),
+        "--output-format",
+        "xml",
+    ])
+    .assert()
+    .failure();
+}
@@ -129,7 +129,7 @@ fn cli_wrong_file_loc() {
             dir_arg,
         ])
         .assert();
-    assert.stderr("Error: Could not determine the full path of the OSCAL model file: `tests/data/not`\n\nCaused by:\n    No such file or directory (os error 2)\n");
+    assert.stderr("Could not determine the full path of the OSCAL model file: `tests/data/not`: No such file or directory (os error 2)\n");
     dir.close().unwrap()
 }
 
@@ -198,3 +198,32 @@ fn cli_wrong_dissect_block_multiple_with_all() {
     assert.stderr("Multiple block cannot include `all` option\n");
     dir.close().unwrap()
 }
+
+#[test]
+fn cli_dissect_detect_model() {
+    let dir = tempdir().unwrap();
+    let dir_arg = dir.as_ref().to_str().unwrap();
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let assert = cmd
+        .args(&[
+            "dissect",
+            "--file",
+            "tests/data/ssp.yaml",
+            "--blocks",
+            "all",
+            "--output-dir",
+            dir_arg,
+        ])
+        .assert();
+    assert.success();
+
+    let manifest = std::fs::read_dir(dir_arg)
+        .unwrap()
+        .map(|entry| entry.unwrap().path().join("dissect_manifest.yaml"))
+        .find(|path| path.is_file())
+        .unwrap();
+    assert!(std::fs::read_to_string(manifest)
+        .unwrap()
+        .contains("model: Ssp"));
+    dir.close().unwrap()
+}
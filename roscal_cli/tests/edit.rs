@@ -0,0 +1,160 @@
+use std::{fs, io::BufReader};
+
+use assert_cmd::Command;
+use roscal_lib::control::catalog::Catalog;
+use tempfile::tempdir;
+
+fn catalog_file() -> String {
+    format!(
+        "{}/tests/data/catalog.yaml",
+        std::env::current_dir().unwrap().to_string_lossy()
+    )
+}
+
+fn edit(
+    ops: &str,
+    output_file: &std::path::Path,
+) -> assert_cmd::assert::Assert {
+    let ops_file = output_file.with_extension("ops.yaml");
+    fs::write(&ops_file, ops).unwrap();
+
+    Command::cargo_bin("roscal")
+        .unwrap()
+        .args([
+            "edit",
+            "--file",
+            &catalog_file(),
+            "--model",
+            "Catalog",
+            "--ops",
+            ops_file.to_str().unwrap(),
+            "--output-file",
+            output_file.to_str().unwrap(),
+        ])
+        .assert()
+}
+
+#[test]
+fn cli_edit_ops() {
+    let dir = tempdir().unwrap();
+    let output_file = dir.path().join("catalog.json");
+
+    edit(
+        r#"
+- op: set
+  path: catalog.metadata
+  field: title
+  value: Edited Catalog
+- op: add-prop
+  control: s1.1.1
+  prop:
+    name: status
+    value: withdrawn
+- op: add-party
+  party:
+    uuid: 0b2ea3a8-4a3a-4ba4-9bde-0c63c3b3d7c5
+    type: organization
+    name: CI Bot
+- op: remove-control
+  control: s1.1.2
+- op: bump-version
+  scheme: minor
+"#,
+        &output_file,
+    )
+    .success();
+
+    let file = fs::File::open(&output_file).unwrap();
+    let catalog: Catalog =
+        serde_json::from_reader(BufReader::new(file)).unwrap();
+    let metadata = &catalog.catalog.metadata;
+    assert_eq!("Edited Catalog", metadata.title.as_str());
+    assert_eq!("1.2.0", metadata.version);
+    assert_ne!("2023-10-12T00:00:00.000000-04:00", metadata.last_modified);
+    assert_eq!(
+        "CI Bot",
+        metadata.parties.as_ref().unwrap()[0]
+            .name
+            .as_deref()
+            .unwrap()
+    );
+
+    let controls = catalog.catalog.groups.as_ref().unwrap()[0]
+        .groups
+        .as_ref()
+        .unwrap()[0]
+        .controls
+        .as_ref()
+        .unwrap();
+    assert_eq!(1, controls.len());
+    assert!(controls[0]
+        .props
+        .as_ref()
+        .unwrap()
+        .iter()
+        .any(|p| p.name == "status" && p.value == "withdrawn"));
+}
+
+#[test]
+fn cli_edit_rejects_invalid_result() {
+    let dir = tempdir().unwrap();
+    let output_file = dir.path().join("catalog.yaml");
+
+    edit(
+        r#"
+- op: set
+  path: catalog
+  field: uuid
+  value: not-a-uuid
+"#,
+        &output_file,
+    )
+    .failure();
+    assert!(!output_file.exists());
+
+    edit("- op: remove-control\n  control: missing\n", &output_file).failure();
+    assert!(!output_file.exists());
+}
+
+#[test]
+fn cli_edit_lenient() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("catalog.yaml");
+    let catalog = fs::read_to_string(catalog_file()).unwrap().replacen(
+        "  metadata:\n",
+        "  metadata:\n    x-vendor-id: 42\n",
+        1,
+    );
+    fs::write(&file, catalog).unwrap();
+    let ops_file = dir.path().join("ops.yaml");
+    fs::write(
+        &ops_file,
+        "- op: set\n  path: catalog.metadata\n  field: title\n  value: Edited\n",
+    )
+    .unwrap();
+
+    let args = ["edit", "--file", file.to_str().unwrap(), "--ops"];
+    Command::cargo_bin("roscal")
+        .unwrap()
+        .args(args)
+        .arg(&ops_file)
+        .assert()
+        .failure();
+
+    Command::cargo_bin("roscal")
+        .unwrap()
+        .args(args)
+        .arg(&ops_file)
+        .arg("--lenient")
+        .assert()
+        .success()
+        .stderr(format!(
+            "Warning: catalog.metadata: unknown field `x-vendor-id` kept as an extension in `{}`\n",
+            file.display()
+        ));
+
+    let edited: serde_json::Value =
+        serde_yaml::from_str(&fs::read_to_string(&file).unwrap()).unwrap();
+    assert_eq!("Edited", edited["catalog"]["metadata"]["title"]);
+    assert_eq!(42, edited["catalog"]["metadata"]["x-vendor-id"]);
+}
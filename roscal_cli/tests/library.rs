@@ -0,0 +1,170 @@
+use roscal_cli::{
+    cli::cli_opts::{Convert, Dissect, Merge, Validate, ValidateChain},
+    models::workspace::{CliError, Validator, Workspace},
+};
+use roscal_lib::{document::ModelType, parse::ParseWarning};
+use tempfile::tempdir;
+
+fn dissect(
+    model: &str,
+    blocks: &[&str],
+    output_dir: &std::path::Path,
+) -> Dissect {
+    Dissect {
+        file: "tests/data/catalog.yaml".into(),
+        model: Some(model.to_owned()),
+        blocks: blocks.iter().map(|b| b.to_string()).collect(),
+        output_dir: Some(output_dir.to_path_buf()),
+        parse_markup: false,
+        lenient: false,
+    }
+}
+
+#[tokio::test]
+async fn dissect_and_merge() {
+    let dir = tempdir().unwrap();
+
+    let ctx = Workspace::dissect(&dissect("Catalog", &["all"], dir.path()))
+        .await
+        .unwrap();
+    assert_eq!(6, ctx.blocks.len());
+
+    let output_file = dir.path().join("merged.json");
+    let merge = Merge {
+        dir: Some(ctx.root.into()),
+        output_dir: Some(dir.path().to_path_buf()),
+        output_file: Some(output_file.clone()),
+        ..Default::default()
+    };
+    let ctx = Workspace::merge(&merge).await.unwrap();
+    assert_eq!(output_file.to_string_lossy(), ctx.output_file);
+    assert!(output_file.is_file());
+
+    dir.close().unwrap()
+}
+
+#[tokio::test]
+async fn convert_output() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("catalog.yaml");
+    let catalog = std::fs::read_to_string("tests/data/catalog.yaml")
+        .unwrap()
+        .replacen("  metadata:\n", "  x-vendor-id: 42\n  metadata:\n", 1);
+    std::fs::write(&file, catalog).unwrap();
+
+    let convert = Convert {
+        file: Some(file.clone()),
+        to: "json".to_owned(),
+        lenient: true,
+        ..Default::default()
+    };
+    let output = Workspace::convert(&convert).await.unwrap();
+    let content: serde_json::Value =
+        serde_json::from_str(&output.content.unwrap()).unwrap();
+    assert_eq!(42, content["catalog"]["x-vendor-id"]);
+    assert_eq!(1, output.warnings.len());
+    assert_eq!(file.to_string_lossy(), output.warnings[0].source);
+    assert_eq!(
+        ParseWarning::UnknownField {
+            path: "catalog".to_owned(),
+            field: "x-vendor-id".to_owned(),
+        },
+        output.warnings[0].warning
+    );
+
+    let output_file = dir.path().join("catalog.json");
+    let convert = Convert {
+        output_file: Some(output_file.clone()),
+        ..convert
+    };
+    let output = Workspace::convert(&convert).await.unwrap();
+    assert_eq!(None, output.content);
+    assert!(output_file.is_file());
+
+    dir.close().unwrap()
+}
+
+#[tokio::test]
+async fn typed_errors() {
+    let dir = tempdir().unwrap();
+
+    let e = Workspace::dissect(&dissect("invalid", &["all"], dir.path()))
+        .await
+        .unwrap_err();
+    assert!(matches!(e, CliError::InvalidModelOpt(ref m) if m == "invalid"));
+
+    let e =
+        Workspace::dissect(&dissect("Catalog", &["uuid", "not"], dir.path()))
+            .await
+            .unwrap_err();
+    assert!(matches!(e, CliError::InvalidBlocks(..)));
+
+    let e =
+        Workspace::dissect(&dissect("Catalog", &["all", "uuid"], dir.path()))
+            .await
+            .unwrap_err();
+    assert!(matches!(e, CliError::MultipleBlocksWithAll));
+
+    let merge = Merge {
+        dir: Some("tests/data/not".into()),
+        ..Default::default()
+    };
+    let e = Workspace::merge(&merge).await.unwrap_err();
+    assert!(matches!(e, CliError::InvalidDir(_)));
+
+    let validate = Validate {
+        file: "tests/data/not".into(),
+        model: Some("Catalog".to_owned()),
+        lenient: false,
+    };
+    let e = Validator::validate(&validate).await.unwrap_err();
+    let CliError::Context(_, source) = e else {
+        panic!("{e:?}")
+    };
+    assert!(matches!(*source, CliError::Io(_)));
+
+    dir.close().unwrap()
+}
+
+#[tokio::test]
+async fn validation_report() {
+    let validate = Validate {
+        file: "tests/data/catalog.yaml".into(),
+        model: None,
+        lenient: false,
+    };
+    let report = Validator::validate(&validate).await.unwrap();
+    assert_eq!(ModelType::Catalog, report.model_type);
+    assert!(report.is_valid());
+
+    let validate = Validate {
+        file: "tests/data/malformed".into(),
+        model: Some("Catalog".to_owned()),
+        lenient: false,
+    };
+    let report = Validator::validate(&validate).await.unwrap();
+    assert!(!report.is_valid());
+    assert!(report.parse_error.is_some());
+}
+
+#[tokio::test]
+async fn chain_report() {
+    let opts = ValidateChain {
+        file: "tests/data/chain/poam.yaml".into(),
+        mirror_dir: None,
+    };
+    let report = Validator::validate_chain(&opts).await.unwrap();
+    assert!(report.is_valid());
+
+    let model_types: Vec<ModelType> =
+        report.documents.iter().map(|d| d.model_type).collect();
+    assert_eq!(
+        vec![
+            ModelType::PlanOfActionAndMilestones,
+            ModelType::SystemSecurityPlan,
+            ModelType::Profile,
+            ModelType::Catalog
+        ],
+        model_types
+    );
+}
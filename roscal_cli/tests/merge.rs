@@ -1,6 +1,7 @@
 use std::{
     fs::{self, read_to_string, File},
     io::BufReader,
+    path::Path,
 };
 
 use assert_cmd::Command;
@@ -132,7 +133,7 @@ fn cli_merge_modify_content() {
             .assert();
         assert.success();
 
-        let file = File::open(format!("{}/merged.yaml", path)).unwrap();
+        let file = File::open(format!("{}/catalog.yaml", path)).unwrap();
         let reader = BufReader::new(file);
         let res: Catalog = serde_yaml::from_reader(reader).unwrap();
         assert_eq!(
@@ -206,7 +207,7 @@ fn cli_merge_wrong_output_format() {
                 path,
             ])
             .assert();
-        assert.stderr("Error: Unknown output format. Use json or yaml\n");
+        assert.stderr("Unknown output format. Use json, yaml or xml\n");
     }
     dir.close().unwrap()
 }
@@ -254,7 +255,363 @@ fn cli_merge_wrong_uuid() {
                 "v8",
             ])
             .assert();
-        assert.stderr("Error: Unknown uuid version. Use v4 or v5\n");
+        assert.stderr("Unknown uuid version. Use v4 or v5\n");
     }
     dir.close().unwrap()
 }
+
+#[test]
+fn cli_merge_update_metadata() {
+    let dir = tempdir().unwrap();
+    let dir_arg = dir.as_ref().to_str().unwrap();
+    let dissect_dir = format!(
+        "{}/tests/data/catalog.yaml",
+        std::env::current_dir().unwrap().to_string_lossy()
+    );
+    let back_matter_dir = format!(
+        "{}/tests/data/edit_back_matter",
+        std::env::current_dir().unwrap().to_string_lossy()
+    );
+    let back_matter = read_to_string(back_matter_dir).unwrap();
+
+    let mut cmd_dissect = Command::cargo_bin("roscal").unwrap();
+    let assert_dissect = cmd_dissect
+        .args(&[
+            "dissect",
+            "--file",
+            &dissect_dir,
+            "--model",
+            "Catalog",
+            "--blocks",
+            "all",
+            "--output-dir",
+            dir_arg,
+        ])
+        .assert();
+    assert_dissect.success();
+
+    for entry in WalkDir::new(dir_arg).min_depth(1).max_depth(1) {
+        let entry = entry.unwrap();
+        let path = entry.path().to_str().unwrap();
+
+        fs::write(
+            format!("{}/modifiable/back_matter.yaml", path),
+            &back_matter,
+        )
+        .unwrap();
+
+        let mut cmd = Command::cargo_bin("roscal").unwrap();
+        let assert = cmd
+            .args(&[
+                "merge",
+                "--dir",
+                path,
+                "--output-format",
+                "yaml",
+                "--output-dir",
+                path,
+                "--update-metadata",
+                "--bump-version",
+                "minor",
+            ])
+            .assert();
+        assert.success();
+
+        let file = File::open(format!("{}/catalog.yaml", path)).unwrap();
+        let reader = BufReader::new(file);
+        let res: Catalog = serde_yaml::from_reader(reader).unwrap();
+        let metadata = res.catalog.metadata;
+        assert_eq!("1.2.0", metadata.version);
+        assert_ne!("2023-10-12T00:00:00.000000-04:00", metadata.last_modified);
+
+        let revision = metadata.revisions.unwrap().pop().unwrap();
+        assert_eq!("1.2.0", revision.version);
+        assert_eq!("Changed blocks: back-matter", revision.remarks.unwrap());
+    }
+    dir.close().unwrap()
+}
+
+#[test]
+fn cli_merge_wrong_version_scheme() {
+    let dir = tempdir().unwrap();
+    let dir_arg = dir.as_ref().to_str().unwrap();
+    let dissect_dir = format!(
+        "{}/tests/data/catalog.yaml",
+        std::env::current_dir().unwrap().to_string_lossy()
+    );
+
+    let mut cmd_dissect = Command::cargo_bin("roscal").unwrap();
+    let assert_dissect = cmd_dissect
+        .args(&[
+            "dissect",
+            "--file",
+            &dissect_dir,
+            "--model",
+            "Catalog",
+            "--blocks",
+            "all",
+            "--output-dir",
+            dir_arg,
+        ])
+        .assert();
+    assert_dissect.success();
+
+    for entry in WalkDir::new(dir_arg).min_depth(1).max_depth(1) {
+        let entry = entry.unwrap();
+        let path = entry.path().to_str().unwrap();
+
+        let mut cmd = Command::cargo_bin("roscal").unwrap();
+        let assert = cmd
+            .args(&[
+                "merge",
+                "--dir",
+                path,
+                "--output-format",
+                "yaml",
+                "--output-dir",
+                path,
+                "--bump-version",
+                "weekly",
+            ])
+            .assert();
+        assert.stderr(
+            "Unknown version scheme. Use major, minor, patch or date\n",
+        );
+    }
+    dir.close().unwrap()
+}
+
+#[test]
+fn cli_merge_output_file() {
+    let dir = tempdir().unwrap();
+    let dir_arg = dir.as_ref().to_str().unwrap();
+    let dissect_dir = format!(
+        "{}/tests/data/catalog.yaml",
+        std::env::current_dir().unwrap().to_string_lossy()
+    );
+    let output_file = dir.path().join("catalog/catalog.json");
+    fs::create_dir(dir.path().join("catalog")).unwrap();
+
+    let mut cmd_dissect = Command::cargo_bin("roscal").unwrap();
+    let assert_dissect = cmd_dissect
+        .args(&[
+            "dissect",
+            "--file",
+            &dissect_dir,
+            "--model",
+            "Catalog",
+            "--blocks",
+            "all",
+            "--output-dir",
+            dir_arg,
+        ])
+        .assert();
+    assert_dissect.success();
+
+    for entry in WalkDir::new(dir_arg).min_depth(1).max_depth(1) {
+        let entry = entry.unwrap();
+        let path = entry.path().to_str().unwrap();
+        if !path.contains("catalog.yaml") {
+            continue;
+        }
+
+        let mut cmd = Command::cargo_bin("roscal").unwrap();
+        let assert = cmd
+            .args(&["merge", "--dir", path, "--output-dir", path])
+            .assert();
+        assert.success();
+        assert!(Path::new(&format!("{}/catalog.yaml", path)).is_file());
+
+        let mut cmd = Command::cargo_bin("roscal").unwrap();
+        let assert = cmd
+            .args([
+                "merge",
+                "--dir",
+                path,
+                "--output-dir",
+                path,
+                "--output-format",
+                "xml",
+            ])
+            .assert();
+        assert.success();
+        let merged = read_to_string(format!("{}/catalog.xml", path)).unwrap();
+        assert!(merged.contains(
+            r#"<catalog xmlns="http://csrc.nist.gov/ns/oscal/1.0" uuid="74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724">"#
+        ));
+
+        let mut cmd = Command::cargo_bin("roscal").unwrap();
+        let assert = cmd
+            .args(&[
+                "merge",
+                "--dir",
+                path,
+                "--output-dir",
+                path,
+                "--output-file",
+                output_file.to_str().unwrap(),
+            ])
+            .assert();
+        assert.success();
+
+        let file = File::open(&output_file).unwrap();
+        let reader = BufReader::new(file);
+        let res: Catalog = serde_json::from_reader(reader).unwrap();
+        assert_eq!("74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724", res.catalog.uuid);
+    }
+    dir.close().unwrap()
+}
+
+#[test]
+fn cli_merge_in_place() {
+    let dir = tempdir().unwrap();
+    let dir_arg = dir.as_ref().to_str().unwrap();
+    let catalog_file = dir.path().join("catalog.yaml");
+    let uuid_dir = format!(
+        "{}/tests/data/edit_uuid",
+        std::env::current_dir().unwrap().to_string_lossy()
+    );
+    let uuid = read_to_string(uuid_dir).unwrap();
+    fs::copy("tests/data/catalog.yaml", &catalog_file).unwrap();
+
+    let mut cmd_dissect = Command::cargo_bin("roscal").unwrap();
+    let assert_dissect = cmd_dissect
+        .args(&[
+            "dissect",
+            "--file",
+            catalog_file.to_str().unwrap(),
+            "--model",
+            "Catalog",
+            "--blocks",
+            "all",
+            "--output-dir",
+            dir_arg,
+        ])
+        .assert();
+    assert_dissect.success();
+
+    for entry in WalkDir::new(dir_arg).min_depth(1).max_depth(1) {
+        let entry = entry.unwrap();
+        if !entry.path().is_dir() {
+            continue;
+        }
+        let path = entry.path().to_str().unwrap();
+
+        fs::write(format!("{}/modifiable/uuid.yaml", path), &uuid).unwrap();
+
+        let mut cmd = Command::cargo_bin("roscal").unwrap();
+        let assert = cmd
+            .args(&["merge", "--dir", path, "--output-dir", path, "--in-place"])
+            .assert();
+        assert.success();
+
+        let file = File::open(&catalog_file).unwrap();
+        let reader = BufReader::new(file);
+        let res: Catalog = serde_yaml::from_reader(reader).unwrap();
+        assert_eq!("74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c721", res.catalog.uuid);
+        assert_eq!(
+            read_to_string("tests/data/catalog.yaml").unwrap(),
+            read_to_string(dir.path().join("catalog.yaml.bak")).unwrap()
+        );
+
+        // The backup of the original is never replaced
+        let mut cmd = Command::cargo_bin("roscal").unwrap();
+        cmd.env("RUST_BACKTRACE", "0")
+            .args(["merge", "--dir", path, "--output-dir", path, "--in-place"])
+            .assert()
+            .failure()
+            .stderr(format!(
+                "Backup file already exists: {}. Move it away to merge in place again\n",
+                dir.path().join("catalog.yaml.bak").display()
+            ));
+        assert_eq!(
+            read_to_string("tests/data/catalog.yaml").unwrap(),
+            read_to_string(dir.path().join("catalog.yaml.bak")).unwrap()
+        );
+
+        // Nor is the original without --in-place
+        let mut cmd = Command::cargo_bin("roscal").unwrap();
+        cmd.env("RUST_BACKTRACE", "0")
+            .args(["merge", "--dir", path, "--output-dir", dir_arg])
+            .assert()
+            .failure()
+            .stderr(format!(
+                "{} is the dissected file. Use --in-place to overwrite it with a backup\n",
+                catalog_file.canonicalize().unwrap().display()
+            ));
+    }
+    dir.close().unwrap()
+}
+
+#[test]
+fn cli_merge_lenient() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("catalog.yaml");
+    let catalog = read_to_string(format!(
+        "{}/tests/data/catalog.yaml",
+        std::env::current_dir().unwrap().to_string_lossy()
+    ))
+    .unwrap()
+    .replacen("  metadata:\n", "  metadata:\n    x-vendor-id: 42\n", 1);
+    fs::write(&file, catalog).unwrap();
+    let output_dir = dir.path().join("workspace");
+    fs::create_dir(&output_dir).unwrap();
+
+    let args = [
+        "dissect",
+        "--file",
+        file.to_str().unwrap(),
+        "--blocks",
+        "all",
+        "--output-dir",
+        output_dir.to_str().unwrap(),
+    ];
+    Command::cargo_bin("roscal")
+        .unwrap()
+        .args(args)
+        .assert()
+        .failure();
+
+    let assert = Command::cargo_bin("roscal")
+        .unwrap()
+        .args(args)
+        .arg("--lenient")
+        .assert()
+        .success();
+    let stderr =
+        String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.starts_with(
+        "Warning: catalog.metadata: unknown field `x-vendor-id` kept as an extension"
+    ));
+
+    let workspace = WalkDir::new(&output_dir)
+        .min_depth(1)
+        .max_depth(1)
+        .into_iter()
+        .next()
+        .unwrap()
+        .unwrap();
+    let merged = dir.path().join("merged.yaml");
+
+    // The workspace was dissected leniently, so merge is too
+    let assert = Command::cargo_bin("roscal")
+        .unwrap()
+        .args(["merge", "--dir"])
+        .arg(workspace.path())
+        .arg("--output-file")
+        .arg(&merged)
+        .assert()
+        .success();
+    let stderr =
+        String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.starts_with(
+        "Warning: unknown field `x-vendor-id` kept as an extension in `"
+    ));
+    assert!(stderr.contains("metadata.yaml`"));
+
+    let merged: serde_json::Value =
+        serde_yaml::from_str(&read_to_string(&merged).unwrap()).unwrap();
+    assert_eq!(42, merged["catalog"]["metadata"]["x-vendor-id"]);
+
+    dir.close().unwrap()
+}
@@ -0,0 +1,305 @@
+use std::{fs, path::Path};
+
+use assert_cmd::Command;
+use serde_json::Value;
+
+const BACK_MATTER: &str = r#"
+  back-matter:
+    resources:
+      - uuid: 2b4a1b3a-cbc1-4b5c-9dd2-1e8c2b7a0f31
+        title: Access Control Policy
+        rlinks:
+          - href: policy.txt
+"#;
+
+/// Copy of the chain documents in a temp directory, the SSP linking to a
+/// policy attachment
+fn workspace() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    for file in [
+        "catalog.yaml",
+        "profile.yaml",
+        "ap.yaml",
+        "ar.yaml",
+        "poam.yaml",
+    ] {
+        fs::copy(format!("tests/data/chain/{file}"), dir.path().join(file))
+            .unwrap();
+    }
+    let ssp = fs::read_to_string("tests/data/chain/ssp.yaml").unwrap();
+    fs::write(dir.path().join("ssp.yaml"), ssp + BACK_MATTER).unwrap();
+    fs::write(dir.path().join("policy.txt"), "Least privilege.\n").unwrap();
+
+    dir
+}
+
+fn build(dir: &Path, include: &str) -> String {
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let assert = cmd
+        .current_dir(dir)
+        .args([
+            "package",
+            "build",
+            "--ssp",
+            "ssp.yaml",
+            "--include",
+            include,
+            "--output-file",
+            "package.tar",
+        ])
+        .assert()
+        .success();
+
+    String::from_utf8_lossy(&assert.get_output().stdout).into_owned()
+}
+
+fn verify(package: &Path) -> (bool, String) {
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let output = cmd
+        .env("RUST_BACKTRACE", "0")
+        .args(["package", "verify", "--file", package.to_str().unwrap()])
+        .output()
+        .unwrap();
+
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+    )
+}
+
+/// Extract a package with the system tar into a new directory
+fn extract(package: &Path) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let status = std::process::Command::new("tar")
+        .arg("-xf")
+        .arg(package)
+        .arg("-C")
+        .arg(dir.path())
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    dir
+}
+
+#[test]
+fn cli_package_build() {
+    let dir = workspace();
+    let stdout = build(dir.path(), "ap.yaml,ar.yaml,poam.yaml");
+    assert!(
+        stdout.ends_with(
+            "7 file(s) packaged\nYou can locate the package at: package.tar\n"
+        ),
+        "{stdout}"
+    );
+
+    let package = extract(&dir.path().join("package.tar"));
+    let manifest: Value = serde_json::from_str(
+        &fs::read_to_string(package.path().join("manifest.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!("system-security-plan/ssp.yaml", manifest["ssp"]);
+
+    let files = manifest["files"].as_array().unwrap();
+    let paths: Vec<&str> =
+        files.iter().map(|f| f["path"].as_str().unwrap()).collect();
+    assert_eq!(
+        vec![
+            "system-security-plan/ssp.yaml",
+            "profile/profile.yaml",
+            "catalog/catalog.yaml",
+            "resources/policy.txt",
+            "assessment-plan/ap.yaml",
+            "assessment-results/ar.yaml",
+            "plan-of-action-and-milestones/poam.yaml",
+        ],
+        paths
+    );
+    assert_eq!("profile", files[1]["model-type"]);
+    assert_eq!("1.1.2", files[1]["oscal-version"]);
+    assert!(files[3].get("model-type").is_none());
+
+    // Attachments are copied as is
+    assert_eq!(
+        "Least privilege.\n",
+        fs::read_to_string(package.path().join("resources/policy.txt"))
+            .unwrap()
+    );
+
+    // Hrefs between packaged files point inside the package
+    let ssp = fs::read_to_string(
+        package.path().join("system-security-plan/ssp.yaml"),
+    )
+    .unwrap();
+    assert!(ssp.contains("href: ../profile/profile.yaml\n"));
+    assert!(ssp.contains("href: ../resources/policy.txt\n"));
+    let profile =
+        fs::read_to_string(package.path().join("profile/profile.yaml"))
+            .unwrap();
+    assert!(profile.contains("href: ../catalog/catalog.yaml\n"));
+    assert!(profile.contains("'#9d3e7b21-5c4a-4f8e-b6d2-1a7c9e0f3b58'"));
+}
+
+#[test]
+fn cli_package_verify() {
+    let dir = workspace();
+    build(dir.path(), "ap.yaml,ar.yaml,poam.yaml");
+
+    let (success, stdout) = verify(&dir.path().join("package.tar"));
+    assert!(success, "{stdout}");
+    assert!(stdout.contains("OK resources/policy.txt\n"));
+    assert!(
+        stdout.ends_with("7 file(s) verified, 0 issue(s)\n"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn cli_package_long_path() {
+    let dir = workspace();
+    let name = format!("{}.txt", "access-control-policy-".repeat(6));
+    fs::rename(dir.path().join("policy.txt"), dir.path().join(&name)).unwrap();
+    let ssp = fs::read_to_string(dir.path().join("ssp.yaml"))
+        .unwrap()
+        .replace("href: policy.txt", &format!("href: {name}"));
+    fs::write(dir.path().join("ssp.yaml"), ssp).unwrap();
+    build(dir.path(), "ap.yaml");
+
+    let (success, stdout) = verify(&dir.path().join("package.tar"));
+    assert!(success, "{stdout}");
+    assert!(stdout.contains(&format!("OK resources/{name}\n")));
+
+    let package = extract(&dir.path().join("package.tar"));
+    assert_eq!(
+        "Least privilege.\n",
+        fs::read_to_string(package.path().join("resources").join(&name))
+            .unwrap()
+    );
+}
+
+#[test]
+fn cli_package_verify_tampered() {
+    let dir = workspace();
+    build(dir.path(), "ap.yaml");
+
+    // File contents are stored as is, so the text can be altered in place
+    let path = dir.path().join("package.tar");
+    let archive = fs::read(&path).unwrap();
+    let needle = b"Least privilege.";
+    let at = archive
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .unwrap();
+    let mut tampered = archive.clone();
+    tampered[at..at + needle.len()].copy_from_slice(b"Most privilege!!");
+    fs::write(&path, tampered).unwrap();
+
+    let (success, stdout) = verify(&path);
+    assert!(!success);
+    assert!(stdout.contains(&format!(
+        "MISMATCH resources/policy.txt: found SHA-256 {}\n",
+        roscal_lib::hash::HashAlgorithm::Sha256.digest(b"Most privilege!!\n")
+    )));
+    assert!(
+        stdout.ends_with("4 file(s) verified, 1 issue(s)\n"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn cli_package_verify_broken_import() {
+    let dir = workspace();
+    build(dir.path(), "ap.yaml");
+
+    // Repack without the catalog, the manifest still listing it
+    let package = extract(&dir.path().join("package.tar"));
+    fs::remove_file(package.path().join("catalog/catalog.yaml")).unwrap();
+    let repacked = dir.path().join("repacked.tar");
+    let status = std::process::Command::new("tar")
+        .arg("--format=ustar")
+        .arg("-cf")
+        .arg(&repacked)
+        .arg("-C")
+        .arg(package.path())
+        .args([
+            "manifest.json",
+            "system-security-plan",
+            "profile",
+            "resources",
+            "assessment-plan",
+        ])
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let (success, stdout) = verify(&repacked);
+    assert!(!success);
+    assert!(
+        stdout.contains("MISSING catalog/catalog.yaml\n"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("BROKEN profile/profile.yaml: import "));
+}
+
+#[test]
+fn cli_package_build_wrong_model() {
+    let dir = workspace();
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.env("RUST_BACKTRACE", "0")
+        .current_dir(dir.path())
+        .args([
+            "package",
+            "build",
+            "--ssp",
+            "ap.yaml",
+            "--output-file",
+            "package.tar",
+        ])
+        .assert()
+        .failure()
+        .stderr(
+            "ap.yaml is a assessment-plan, expected system-security-plan\n",
+        );
+}
+
+#[test]
+fn cli_package_build_xml_profile() {
+    let dir = workspace();
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.current_dir(dir.path())
+        .args([
+            "convert",
+            "--file",
+            "profile.yaml",
+            "--to",
+            "xml",
+            "--output-file",
+            "profile.xml",
+        ])
+        .assert()
+        .success();
+    let ssp = fs::read_to_string(dir.path().join("ssp.yaml")).unwrap();
+    fs::write(
+        dir.path().join("ssp.yaml"),
+        ssp.replace("href: profile.yaml", "href: profile.xml"),
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.env("RUST_BACKTRACE", "0")
+        .current_dir(dir.path())
+        .args([
+            "package",
+            "build",
+            "--ssp",
+            "ssp.yaml",
+            "--output-file",
+            "package.tar",
+        ])
+        .assert()
+        .failure()
+        .stderr(
+            "profile.xml is XML, XML documents are not supported in packages\n",
+        );
+}
@@ -0,0 +1,64 @@
+use std::fs::read_to_string;
+
+use assert_cmd::Command;
+use tempfile::tempdir;
+
+fn data_dir() -> String {
+    format!(
+        "{}/tests/data",
+        std::env::current_dir().unwrap().to_string_lossy()
+    )
+}
+
+#[test]
+fn cli_query_model() {
+    let dir = tempdir().unwrap();
+    let data_dir = data_dir();
+
+    for format in ["json", "yaml", "table"] {
+        let output_file = dir.path().join(format!("parties.{format}"));
+
+        let mut cmd = Command::cargo_bin("roscal").unwrap();
+        cmd.args([
+            "query",
+            "--file",
+            &format!("{data_dir}/ssp.yaml"),
+            "--model",
+            "Ssp",
+            "--expr",
+            "..parties[type=organization]",
+            "--output-format",
+            format,
+            "--output-file",
+            output_file.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+        let output = read_to_string(output_file).unwrap();
+        assert!(output.contains("Important Federal Agency"));
+        assert!(!output.contains("Owen Stilskin"));
+    }
+
+    let output_file = dir.path().join("parties.table");
+    let table = read_to_string(output_file).unwrap();
+    assert!(table.starts_with("| name | short-name | type | uuid |\n"));
+}
+
+#[test]
+fn cli_query_invalid_expr() {
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.args([
+        "query",
+        "--file",
+        &format!("{}/ssp.yaml", data_dir()),
+        "--model",
+        "Ssp",
+        "--expr",
+        "..parties[type=organization",
+        "--output-format",
+        "json",
+    ])
+    .assert()
+    .failure();
+}
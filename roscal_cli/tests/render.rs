@@ -0,0 +1,91 @@
+use std::fs::read_to_string;
+
+use assert_cmd::Command;
+use tempfile::tempdir;
+
+#[test]
+fn cli_render_models() {
+    let dir = tempdir().unwrap();
+    let data_dir = format!(
+        "{}/tests/data",
+        std::env::current_dir().unwrap().to_string_lossy()
+    );
+
+    for (model, file) in
+        [("Catalog", "catalog"), ("Ssp", "ssp"), ("Poam", "poam")]
+    {
+        for format in ["markdown", "html"] {
+            let output_file = dir.path().join(format!("{file}.{format}"));
+
+            let mut cmd = Command::cargo_bin("roscal").unwrap();
+            cmd.args([
+                "render",
+                "--file",
+                &format!("{data_dir}/{file}.yaml"),
+                "--model",
+                model,
+                "--output-format",
+                format,
+                "--output-file",
+                output_file.to_str().unwrap(),
+            ])
+            .assert()
+            .success();
+
+            assert!(!read_to_string(output_file).unwrap().is_empty());
+        }
+    }
+}
+
+#[test]
+fn cli_render_unsupported_model() {
+    let data_dir = format!(
+        "{}/tests/data",
+        std::env::current_dir().unwrap().to_string_lossy()
+    );
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.args([
+        "render",
+        "--file",
+        &format!("{data_dir}/profile.yaml"),
+        "--model",
+        "Profile",
+        "--output-format",
+        "markdown",
+    ])
+    .assert()
+    .failure();
+}
+
+#[test]
+fn cli_render_detect_model() {
+    let data_dir = format!(
+        "{}/tests/data",
+        std::env::current_dir().unwrap().to_string_lossy()
+    );
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let assert = cmd
+        .args([
+            "render",
+            "--file",
+            &format!("{data_dir}/poam.yaml"),
+            "--output-format",
+            "markdown",
+        ])
+        .assert()
+        .success();
+    assert!(!assert.get_output().stdout.is_empty());
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.args([
+        "render",
+        "--file",
+        &format!("{data_dir}/malformed"),
+        "--output-format",
+        "markdown",
+    ])
+    .assert()
+    .failure();
+}
@@ -0,0 +1,287 @@
+use std::{fs, path::Path};
+
+use assert_cmd::Command;
+use serde_json::Value;
+
+/// Agency overlay on top of the chain profile
+const OVERLAY: &str = r#"
+profile:
+  uuid: 0f6b2d1c-8e4a-4b3f-9a7d-5c2e1b0a9f83
+  metadata:
+    title: IFA Agency Overlay
+    last-modified: "2024-03-01T00:00:00.000000-04:00"
+    version: "1.0"
+    oscal-version: 1.1.2
+  imports:
+    - href: profile.yaml
+      include-controls:
+        - with-ids:
+            - ac-6
+            - ac-6.1
+      exclude-controls:
+        - with-ids:
+            - ac-6.2
+  modify:
+    set-parameters:
+      - param-id: ac-06.01_odp.01
+        values:
+          - security administrators
+    alters:
+      - control-id: ac-6.1
+        removes:
+          - by-id: ac-6.1_gdn
+        adds:
+          - position: before
+            by-id: ac-6.1_smt
+            parts:
+              - id: ac-6.1_overview
+                name: overview
+                prose: Applies to every agency system.
+"#;
+
+/// Copy of the chain documents and the overlay in a temp directory
+fn workspace() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    for file in ["catalog.yaml", "profile.yaml"] {
+        fs::copy(format!("tests/data/chain/{file}"), dir.path().join(file))
+            .unwrap();
+    }
+    fs::write(dir.path().join("overlay.yaml"), OVERLAY).unwrap();
+
+    dir
+}
+
+fn resolve(file: &Path, cache_dir: &Path) -> String {
+    let output = file.with_file_name("resolved.json");
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let assert = cmd
+        .args([
+            "resolve-profile",
+            "--file",
+            file.to_str().unwrap(),
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+            "--output-file",
+            output.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let stdout =
+        String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    stdout
+        .strip_suffix(&format!(
+            "\nYou can locate the resolved catalog at: {}\n",
+            output.display()
+        ))
+        .unwrap()
+        .to_owned()
+}
+
+fn ids(value: &Value, col: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(id)) = map.get("id") {
+                col.push(id.clone());
+            }
+            map.values().for_each(|v| ids(v, col));
+        }
+        Value::Array(items) => items.iter().for_each(|v| ids(v, col)),
+        _ => {}
+    }
+}
+
+#[test]
+fn cli_resolve_profile() {
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let assert = cmd
+        .args(["resolve-profile", "--file", "tests/data/chain/profile.yaml"])
+        .assert()
+        .success();
+
+    let catalog: Value =
+        serde_json::from_slice(&assert.get_output().stdout).unwrap();
+    let catalog = &catalog["catalog"];
+    assert_eq!(
+        "IFA GoodRead Least Privilege Baseline",
+        catalog["metadata"]["title"]
+    );
+
+    let mut col = vec![];
+    ids(&catalog["groups"], &mut col);
+    for id in ["ac", "ac-6", "ac-6.1", "ac-6.2", "ac-6.1_ifa"] {
+        assert!(col.contains(&id.to_owned()), "{id}");
+    }
+    assert!(!col.iter().any(|id| id.starts_with("ac-2")));
+}
+
+#[test]
+fn cli_resolve_profile_overlay() {
+    let dir = workspace();
+    let cache = dir.path().join("cache");
+
+    resolve(&dir.path().join("overlay.yaml"), &cache);
+    let catalog: Value = serde_json::from_str(
+        &fs::read_to_string(dir.path().join("resolved.json")).unwrap(),
+    )
+    .unwrap();
+
+    let ac6 = &catalog["catalog"]["groups"][0]["controls"][0];
+    assert_eq!("ac-6", ac6["id"]);
+    let ac61 = &ac6["controls"][0];
+    assert_eq!("ac-6.1", ac61["id"]);
+    assert_eq!(1, ac6["controls"].as_array().unwrap().len());
+    assert_eq!("security administrators", ac61["params"][0]["values"][0]);
+
+    let parts: Vec<&str> = ac61["parts"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(vec!["ac-6.1_overview", "ac-6.1_smt", "ac-6.1_ifa"], parts);
+}
+
+#[test]
+fn cli_resolve_profile_cache() {
+    let dir = workspace();
+    let cache = dir.path().join("cache");
+    let path = |file: &str| dir.path().join(file).display().to_string();
+    let overlay = dir.path().join("overlay.yaml");
+
+    assert_eq!(
+        format!(
+            "RESOLVED {}\nRESOLVED {}\n2 profile(s) resolved, 0 from cache",
+            path("profile.yaml"),
+            path("overlay.yaml")
+        ),
+        resolve(&overlay, &cache)
+    );
+    assert_eq!(2, fs::read_dir(&cache).unwrap().count());
+    let first = fs::read_to_string(dir.path().join("resolved.json")).unwrap();
+
+    // Nothing changed, the overlay is read back from the cache
+    assert_eq!(
+        format!(
+            "CACHED {}\n0 profile(s) resolved, 1 from cache",
+            path("overlay.yaml")
+        ),
+        resolve(&overlay, &cache)
+    );
+    assert_eq!(
+        first,
+        fs::read_to_string(dir.path().join("resolved.json")).unwrap()
+    );
+
+    // Only the overlay changed, the profile it imports is still cached
+    let content = fs::read_to_string(&overlay)
+        .unwrap()
+        .replace("IFA Agency Overlay", "IFA Agency Overlay v2");
+    fs::write(&overlay, content).unwrap();
+    assert_eq!(
+        format!(
+            "RESOLVED {}\nCACHED {}\n1 profile(s) resolved, 1 from cache",
+            path("overlay.yaml"),
+            path("profile.yaml")
+        ),
+        resolve(&overlay, &cache)
+    );
+
+    // A change to the catalog invalidates everything built on it
+    let catalog = dir.path().join("catalog.yaml");
+    let content = fs::read_to_string(&catalog)
+        .unwrap()
+        .replace("Least Privilege", "Least Privilege (rev)");
+    fs::write(&catalog, content).unwrap();
+    assert!(resolve(&overlay, &cache)
+        .ends_with("2 profile(s) resolved, 0 from cache"));
+}
+
+#[test]
+fn cli_resolve_profile_cycle() {
+    let dir = workspace();
+    let profile = dir.path().join("profile.yaml");
+    let content = fs::read_to_string(&profile)
+        .unwrap()
+        .replace("href: catalog.yaml", "href: overlay.yaml");
+    fs::write(&profile, content).unwrap();
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.env("RUST_BACKTRACE", "0")
+        .args([
+            "resolve-profile",
+            "--file",
+            dir.path().join("overlay.yaml").to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(format!(
+            "Could not follow import `#9d3e7b21-5c4a-4f8e-b6d2-1a7c9e0f3b58` of {}: import cycle {} -> {} -> {}\n",
+            dir.path().join("profile.yaml").display(),
+            dir.path().join("overlay.yaml").display(),
+            dir.path().join("profile.yaml").display(),
+            dir.path().join("overlay.yaml").display(),
+        ));
+}
+
+#[test]
+fn cli_resolve_profile_unknown_target() {
+    let dir = workspace();
+    let overlay = dir.path().join("overlay.yaml");
+    let content = fs::read_to_string(&overlay)
+        .unwrap()
+        .replace("by-id: ac-6.1_smt", "by-id: ac-6.1_missing");
+    fs::write(&overlay, content).unwrap();
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.env("RUST_BACKTRACE", "0")
+        .args(["resolve-profile", "--file", overlay.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(format!(
+            "{}: part or parameter `ac-6.1_missing` is not in control `ac-6.1`\n",
+            overlay.display()
+        ));
+}
+
+#[test]
+fn cli_resolve_profile_flat() {
+    let dir = workspace();
+    let overlay = dir.path().join("overlay.yaml");
+    let content = fs::read_to_string(&overlay)
+        .unwrap()
+        .replace("  modify:\n", "  merge:\n    flat: {}\n  modify:\n");
+    fs::write(&overlay, content).unwrap();
+
+    resolve(&overlay, &dir.path().join("cache"));
+    let catalog: Value = serde_json::from_str(
+        &fs::read_to_string(dir.path().join("resolved.json")).unwrap(),
+    )
+    .unwrap();
+
+    let catalog = &catalog["catalog"];
+    assert!(catalog.get("groups").is_none());
+    assert_eq!("ac-6", catalog["controls"][0]["id"]);
+    assert_eq!("ac-6.1", catalog["controls"][0]["controls"][0]["id"]);
+}
+
+#[test]
+fn cli_resolve_profile_unsupported_merge() {
+    let dir = workspace();
+    let overlay = dir.path().join("overlay.yaml");
+    let content = fs::read_to_string(&overlay).unwrap().replace(
+        "  modify:\n",
+        "  merge:\n    combine:\n      method: merge\n  modify:\n",
+    );
+    fs::write(&overlay, content).unwrap();
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.env("RUST_BACKTRACE", "0")
+        .args(["resolve-profile", "--file", overlay.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(format!(
+            "{}: `combine` method `merge` is not supported. Use `as-is` or `flat` with the `use-first` combination\n",
+            overlay.display()
+        ));
+}
@@ -0,0 +1,304 @@
+use assert_cmd::Command;
+
+#[test]
+fn cli_resources_verify() {
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let assert = cmd
+        .args([
+            "resources",
+            "verify",
+            "--file",
+            "tests/data/resources/verified.yaml",
+        ])
+        .assert()
+        .success();
+
+    let stdout =
+        String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    assert!(stdout.contains(
+        "OK 2b4a1b3a-cbc1-4b5c-9dd2-1e8c2b7a0f31 `policy.txt` SHA-256\n"
+    ));
+    assert!(stdout.contains(
+        "OK 2b4a1b3a-cbc1-4b5c-9dd2-1e8c2b7a0f31 `base64` SHA3-512\n"
+    ));
+    assert!(stdout.contains(
+        "SKIPPED 9f1c7e55-3a57-4d2c-8f0e-5b1d6c9a2e44 `https://example.com/guidance.pdf`: remote link\n"
+    ));
+    assert!(
+        stdout.ends_with("4 hash(es) verified, 0 failed, 1 skipped\n"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn cli_resources_verify_mismatch() {
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let assert = cmd
+        .env("RUST_BACKTRACE", "0")
+        .args([
+            "resources",
+            "verify",
+            "--file",
+            "tests/data/resources/tampered.yaml",
+            "--model",
+            "Catalog",
+        ])
+        .assert()
+        .failure()
+        .stderr("2 resource hash(es) could not be verified\n");
+
+    let stdout =
+        String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    assert!(stdout.contains(&format!(
+        "MISMATCH 2b4a1b3a-cbc1-4b5c-9dd2-1e8c2b7a0f31 `policy.txt` SHA-256: expected {}, found 65eac68356b3c45bcf1369f90bc626588828e2386a8613fc222951cd094c07e6\n",
+        "0".repeat(64)
+    )));
+    assert!(stdout.ends_with("2 hash(es) verified, 2 failed, 1 skipped\n"));
+}
+
+#[test]
+fn cli_resources_verify_wrong_digest_length() {
+    let input = std::fs::read_to_string("tests/data/resources/verified.yaml")
+        .unwrap()
+        .replace("algorithm: SHA3-512", "algorithm: SHA3-256");
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("catalog.yaml");
+    std::fs::write(&file, input).unwrap();
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let assert = cmd
+        .args(["resources", "verify", "--file", file.to_str().unwrap()])
+        .assert()
+        .failure();
+
+    let stderr =
+        String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(
+        stderr.contains(
+            "invalid SHA3-256 digest: expected 64 hex characters, found 128"
+        ),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn cli_resources_verify_mirror_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let site = dir.path().join("example.com");
+    std::fs::create_dir(&site).unwrap();
+    std::fs::copy("tests/data/resources/policy.txt", site.join("guidance.pdf"))
+        .unwrap();
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let assert = cmd
+        .args([
+            "resources",
+            "verify",
+            "--file",
+            "tests/data/resources/verified.yaml",
+            "--mirror-dir",
+            dir.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let stdout =
+        String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    assert!(stdout.contains(
+        "OK 9f1c7e55-3a57-4d2c-8f0e-5b1d6c9a2e44 `https://example.com/guidance.pdf` SHA-256\n"
+    ));
+    assert!(stdout.ends_with("5 hash(es) verified, 0 failed, 0 skipped\n"));
+}
+
+/// Copy of the verified catalog and its linked policy in a temp directory
+fn workspace() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    for file in ["verified.yaml", "policy.txt"] {
+        std::fs::copy(
+            format!("tests/data/resources/{file}"),
+            dir.path().join(file),
+        )
+        .unwrap();
+    }
+
+    dir
+}
+
+#[test]
+fn cli_resources_add() {
+    let dir = workspace();
+    let file = dir.path().join("verified.yaml");
+    std::fs::create_dir(dir.path().join("docs")).unwrap();
+    std::fs::write(dir.path().join("docs/plan.md"), "# Plan\n").unwrap();
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let assert = cmd
+        .args([
+            "resources",
+            "add",
+            "--file",
+            file.to_str().unwrap(),
+            "--source",
+            dir.path().join("docs/plan.md").to_str().unwrap(),
+            "--title",
+            "Contingency plan",
+            "--embed",
+            "--hash-algorithms",
+            "SHA-256,SHA3-256",
+        ])
+        .assert()
+        .success();
+    let stdout =
+        String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    let uuid = stdout.trim().strip_prefix("Added resource ").unwrap();
+
+    let content = std::fs::read_to_string(&file).unwrap();
+    assert!(content.contains(&format!("uuid: {uuid}")));
+    assert!(content.contains("href: docs/plan.md"));
+    assert!(content.contains("media-type: text/markdown"));
+    assert!(content.contains("algorithm: SHA3-256"));
+    assert!(content.contains("filename: plan.md"));
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let assert = cmd
+        .args(["resources", "verify", "--file", file.to_str().unwrap()])
+        .assert()
+        .success();
+    let stdout =
+        String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    assert!(stdout.contains(&format!("OK {uuid} `docs/plan.md` SHA3-256\n")));
+    assert!(stdout.contains(&format!("OK {uuid} `base64` SHA-256\n")));
+    assert!(stdout.ends_with("8 hash(es) verified, 0 failed, 1 skipped\n"));
+}
+
+#[test]
+fn cli_resources_add_unknown_algorithm() {
+    let dir = workspace();
+    let file = dir.path().join("verified.yaml");
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.env("RUST_BACKTRACE", "0")
+        .args([
+            "resources",
+            "add",
+            "--file",
+            file.to_str().unwrap(),
+            "--source",
+            dir.path().join("policy.txt").to_str().unwrap(),
+            "--hash-algorithms",
+            "MD5",
+        ])
+        .assert()
+        .failure()
+        .stderr("Unknown hash algorithm `MD5`. Use SHA-224, SHA-256, SHA-384, SHA-512, SHA3-224, SHA3-256, SHA3-384 or SHA3-512\n");
+}
+
+#[test]
+fn cli_resources_list() {
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.args([
+        "resources",
+        "list",
+        "--file",
+        "tests/data/resources/verified.yaml",
+    ])
+    .assert()
+    .success()
+    .stdout(
+        "| uuid | title | media-type | links | embedded |
+| --- | --- | --- | --- | --- |
+| 2b4a1b3a-cbc1-4b5c-9dd2-1e8c2b7a0f31 | Access control policy | text/plain | policy.txt | policy.txt |
+| 9f1c7e55-3a57-4d2c-8f0e-5b1d6c9a2e44 | Remote guidance | application/pdf | https://example.com/guidance.pdf |  |
+",
+    );
+}
+
+#[test]
+fn cli_resources_extract() {
+    let dir = tempfile::tempdir().unwrap();
+    let output_dir = dir.path().join("payloads");
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.args([
+        "resources",
+        "extract",
+        "--file",
+        "tests/data/resources/verified.yaml",
+        "--output-dir",
+        output_dir.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(format!(
+        "Extracted resource 2b4a1b3a-cbc1-4b5c-9dd2-1e8c2b7a0f31 to {}\n",
+        output_dir.join("policy.txt").display()
+    ));
+    assert_eq!(
+        "Access control policy\n",
+        std::fs::read_to_string(output_dir.join("policy.txt")).unwrap()
+    );
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.env("RUST_BACKTRACE", "0")
+        .args([
+            "resources",
+            "extract",
+            "--file",
+            "tests/data/resources/verified.yaml",
+            "--uuid",
+            "9f1c7e55-3a57-4d2c-8f0e-5b1d6c9a2e44",
+        ])
+        .assert()
+        .failure()
+        .stderr("Resource 9f1c7e55-3a57-4d2c-8f0e-5b1d6c9a2e44 has no embedded base64 payload\n");
+}
+
+#[test]
+fn cli_resources_remove() {
+    let dir = workspace();
+    let file = dir.path().join("verified.yaml");
+    let linked = std::fs::read_to_string(&file).unwrap().replace(
+        "    oscal-version: 1.1.2\n",
+        "    oscal-version: 1.1.2\n    links:\n      - href: '#9f1c7e55-3a57-4d2c-8f0e-5b1d6c9a2e44'\n        rel: reference\n",
+    );
+    std::fs::write(&file, linked).unwrap();
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.env("RUST_BACKTRACE", "0")
+        .args([
+            "resources",
+            "remove",
+            "--file",
+            file.to_str().unwrap(),
+            "--uuid",
+            "9f1c7e55-3a57-4d2c-8f0e-5b1d6c9a2e44",
+        ])
+        .assert()
+        .failure()
+        .stderr("Resource 9f1c7e55-3a57-4d2c-8f0e-5b1d6c9a2e44 is still referenced at: /catalog/metadata/links/0/href\n");
+
+    let output = dir.path().join("catalog.json");
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    cmd.args([
+        "resources",
+        "remove",
+        "--file",
+        file.to_str().unwrap(),
+        "--uuid",
+        "2b4a1b3a-cbc1-4b5c-9dd2-1e8c2b7a0f31",
+        "--output-file",
+        output.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout("Removed resource 2b4a1b3a-cbc1-4b5c-9dd2-1e8c2b7a0f31\n");
+
+    let catalog: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(output).unwrap())
+            .unwrap();
+    let resources = catalog["catalog"]["back-matter"]["resources"]
+        .as_array()
+        .unwrap();
+    assert_eq!(1, resources.len());
+    assert_eq!("9f1c7e55-3a57-4d2c-8f0e-5b1d6c9a2e44", resources[0]["uuid"]);
+}
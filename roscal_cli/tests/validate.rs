@@ -0,0 +1,97 @@
+use std::fs::{read_to_string, write};
+
+use assert_cmd::Command;
+use tempfile::tempdir;
+
+#[test]
+fn cli_validate_models() {
+    let data_dir = format!(
+        "{}/tests/data",
+        std::env::current_dir().unwrap().to_string_lossy()
+    );
+
+    for file in ["ap", "ar", "poam", "catalog", "profile", "compdef", "ssp"] {
+        let mut cmd = Command::cargo_bin("roscal").unwrap();
+        let assert = cmd
+            .args(["validate", "--file", &format!("{data_dir}/{file}.yaml")])
+            .assert()
+            .success();
+
+        let stdout =
+            String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+        assert!(stdout.contains("This is a valid"), "{file}: {stdout}");
+        assert!(
+            stdout.contains("The model conforms to the official JSON schema"),
+            "{file}: {stdout}"
+        );
+    }
+}
+
+#[test]
+fn cli_validate_schema_violations() {
+    let dir = tempdir().unwrap();
+    let data_dir = format!(
+        "{}/tests/data",
+        std::env::current_dir().unwrap().to_string_lossy()
+    );
+
+    let catalog = read_to_string(format!("{data_dir}/catalog.yaml"))
+        .unwrap()
+        .replacen("74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724", "wrong", 1);
+    let file = dir.path().join("catalog.yaml");
+    write(&file, catalog).unwrap();
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let assert = cmd
+        .args(["validate", "--file", file.to_str().unwrap()])
+        .assert()
+        .success();
+
+    let stdout =
+        String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    assert!(stdout.contains("This is not a valid Catalog model"));
+    assert!(stdout.contains(
+        "  catalog.uuid: invalid uuid pattern, found `wrong` at line 2 column 3"
+    ));
+    assert!(stdout
+        .contains("The model breaks the official JSON schema in 1 place(s):"));
+    assert!(stdout.contains("  /catalog/uuid: \"wrong\" does not match"));
+}
+
+#[test]
+fn cli_validate_lenient() {
+    let dir = tempdir().unwrap();
+    let data_dir = format!(
+        "{}/tests/data",
+        std::env::current_dir().unwrap().to_string_lossy()
+    );
+
+    let catalog = read_to_string(format!("{data_dir}/catalog.yaml"))
+        .unwrap()
+        .replacen("  metadata:\n", "  metadata:\n    x-vendor-id: 42\n", 1);
+    let file = dir.path().join("catalog.yaml");
+    write(&file, catalog).unwrap();
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let assert = cmd
+        .args(["validate", "--file", file.to_str().unwrap()])
+        .assert()
+        .success();
+    let stdout =
+        String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    assert!(stdout.contains("This is not a valid Catalog model"));
+
+    let mut cmd = Command::cargo_bin("roscal").unwrap();
+    let assert = cmd
+        .args(["validate", "--file", file.to_str().unwrap(), "--lenient"])
+        .assert()
+        .success()
+        .stderr(format!(
+            "Warning: catalog.metadata: unknown field `x-vendor-id` kept as an extension in `{}`\n",
+            file.display()
+        ));
+    let stdout =
+        String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    assert!(stdout.contains("This is a valid Catalog model"));
+    assert!(stdout.contains("  /catalog/metadata/x-vendor-id: "));
+}
@@ -128,9 +128,6 @@ fn process_ast_common(ast: &mut syn::File) -> &mut syn::File {
                 syn::parse_quote! {
                     #[derivative(PartialEq)]
                 },
-                syn::parse_quote! {
-                    #[serde(deny_unknown_fields)]
-                },
             ]);
 
             (&mut item_struct.fields)
@@ -617,6 +614,10 @@ fn process_ast_common(ast: &mut syn::File) -> &mut syn::File {
                     },
                 )
             } else if ident == "Hash" {
+                // The digest is checked against its algorithm by `RawHash`
+                item_struct.attrs.push(syn::parse_quote! {
+                    #[serde(try_from = "validation::RawHash")]
+                });
                 (&mut item_struct.fields).into_iter().for_each(
                     |ref mut field| {
                         if let Some(ident) = &mut field.ident {
@@ -1758,6 +1759,414 @@ fn process_ast_common(ast: &mut syn::File) -> &mut syn::File {
     ast
 }
 
+/// Replace the markup deserialisers with the typed `Markup` field, the
+/// attributes are added earlier so that every model shares the same rules
+fn process_ast_markup(ast: &mut syn::File) -> &mut syn::File {
+    ast.items.insert(
+        5,
+        syn::parse_quote! {
+            use crate::markup::Markup;
+        },
+    );
+
+    ast.items.iter_mut().for_each(|ref mut item| {
+        if let syn::Item::Struct(item_struct) = item {
+            (&mut item_struct.fields)
+                .into_iter()
+                .for_each(|ref mut field| {
+                    let is_markup = field.attrs.iter().any(|attr| {
+                        let tokens =
+                            quote::ToTokens::to_token_stream(attr).to_string();
+                        tokens.contains("validation::deser_markup")
+                    });
+
+                    if !is_markup {
+                        return;
+                    }
+
+                    field.attrs.retain(|attr| {
+                        let tokens =
+                            quote::ToTokens::to_token_stream(attr).to_string();
+                        !tokens.contains("validation::deser_markup")
+                    });
+
+                    if let syn::Type::Path(typepath) = &field.ty {
+                        if is_optional_type(&typepath.path) {
+                            field.ty = syn::parse_quote!(Option<Markup>);
+                        } else {
+                            field.ty = syn::parse_quote!(Markup);
+                        }
+                    }
+                })
+        }
+    });
+
+    ast
+}
+
+/// Replace the types of fields validated as uuid, datetime, date, uri and
+/// token with the scalar types, which are newtypes under `typed-scalars`
+fn process_ast_scalars(ast: &mut syn::File) -> &mut syn::File {
+    let scalars = [
+        ("uuid", "OscalUuid"),
+        ("dttz", "DateTimeWithTz"),
+        ("date", "OscalDate"),
+        ("uri", "Uri"),
+        ("uri_ref", "UriReference"),
+        ("token", "Token"),
+        ("email", "EmailAddress"),
+    ];
+    let mut used: Vec<&str> = vec![];
+
+    ast.items.iter_mut().for_each(|ref mut item| {
+        if let syn::Item::Struct(item_struct) = item {
+            (&mut item_struct.fields)
+                .into_iter()
+                .for_each(|ref mut field| {
+                    let deser = field.attrs.iter().find_map(|attr| {
+                        let tokens =
+                            quote::ToTokens::to_token_stream(attr).to_string();
+                        let start = tokens.find("validation::deser_")?
+                            + "validation::deser_".len();
+                        let end = tokens[start..].find('"')? + start;
+
+                        Some(tokens[start..end].to_owned())
+                    });
+
+                    let Some(deser) = deser else { return };
+                    let base =
+                        deser.trim_end_matches("_opt").trim_end_matches("_vec");
+                    let Some((_, scalar)) =
+                        scalars.iter().find(|(name, _)| *name == base)
+                    else {
+                        return;
+                    };
+                    let ident =
+                        syn::Ident::new(scalar, proc_macro2::Span::call_site());
+
+                    field.ty = if deser.ends_with("_vec_opt") {
+                        syn::parse_quote!(Option<Vec<#ident>>)
+                    } else if deser.ends_with("_vec") {
+                        syn::parse_quote!(Vec<#ident>)
+                    } else if deser.ends_with("_opt") {
+                        syn::parse_quote!(Option<#ident>)
+                    } else {
+                        syn::parse_quote!(#ident)
+                    };
+
+                    if !used.contains(scalar) {
+                        used.push(scalar)
+                    }
+                })
+        }
+    });
+
+    used.sort();
+    let idents = used
+        .iter()
+        .map(|s| syn::Ident::new(s, proc_macro2::Span::call_site()));
+    ast.items.insert(
+        6,
+        syn::parse_quote! {
+            use crate::scalar::{#(#idents),*};
+        },
+    );
+
+    ast
+}
+
+/// Validate the fields checked by the serde hooks when the builder is built,
+/// so that built models deserialise back
+fn process_ast_builder_validation(ast: &mut syn::File) -> &mut syn::File {
+    let checks = [
+        "base64",
+        "date",
+        "dttz",
+        "email",
+        "hash",
+        "positive_int",
+        "non_neg_int",
+        "token",
+        "uri",
+        "uri_ref",
+        "uuid",
+    ];
+    let items = std::mem::take(&mut ast.items);
+
+    for mut item in items {
+        let mut validate: Option<syn::Item> = None;
+
+        if let syn::Item::Struct(item_struct) = &mut item {
+            let mut stmts: Vec<syn::Stmt> = vec![];
+
+            (&item_struct.fields).into_iter().for_each(|field| {
+                let deser = field.attrs.iter().find_map(|attr| {
+                    let tokens =
+                        quote::ToTokens::to_token_stream(attr).to_string();
+                    let start = tokens.find("validation::deser_")?
+                        + "validation::deser_".len();
+                    let end = tokens[start..].find('"')? + start;
+
+                    Some(tokens[start..end].to_owned())
+                });
+
+                let (Some(deser), Some(ident)) = (deser, &field.ident) else {
+                    return;
+                };
+                let base =
+                    deser.trim_end_matches("_opt").trim_end_matches("_vec");
+                if !checks.contains(&base) {
+                    return;
+                }
+
+                let check = syn::Ident::new(
+                    &format!("check_{base}"),
+                    proc_macro2::Span::call_site(),
+                );
+                let name = ident.to_string();
+                stmts.push(syn::parse_quote! {
+                    validation::#check(#name, &self.#ident)?;
+                });
+            });
+
+            if item_struct.ident == "Hash" {
+                stmts.push(syn::parse_quote! {
+                    validation::check_digest(&self.algorithm, &self.value)?;
+                });
+            }
+
+            if !stmts.is_empty() {
+                item_struct.attrs.iter_mut().for_each(|attr| {
+                    let tokens = quote::ToTokens::to_token_stream(attr).to_string();
+                    if tokens.contains("builder") && tokens.contains("setter") {
+                        *attr = syn::parse_quote! {
+                            #[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
+                        };
+                    }
+                });
+
+                let builder = syn::Ident::new(
+                    &format!("{}Builder", item_struct.ident),
+                    proc_macro2::Span::call_site(),
+                );
+                validate = Some(syn::parse_quote! {
+                    impl #builder {
+                        fn validate(&self) -> Result<(), String> {
+                            #(#stmts)*
+                            Ok(())
+                        }
+                    }
+                });
+            }
+        }
+
+        ast.items.push(item);
+        ast.items.extend(validate);
+    }
+
+    ast
+}
+
+/// Give every struct a flattened map for the fields unknown to the model,
+/// rejected or kept by `validation::deser_extensions` depending on the parse
+/// options, in place of `deny_unknown_fields`
+fn process_ast_extensions(ast: &mut syn::File) -> &mut syn::File {
+    ast.items.insert(
+        5,
+        syn::parse_quote! {
+            use crate::parse::Extensions;
+        },
+    );
+
+    ast.items.iter_mut().for_each(|ref mut item| {
+        if let syn::Item::Struct(item_struct) = item {
+            if let syn::Fields::Named(fields) = &mut item_struct.fields {
+                fields.named.push(syn::parse_quote! {
+                    /// Fields unknown to the model, kept when parsing leniently
+                    #[builder(default)]
+                    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+                    pub extensions: Extensions
+                });
+            }
+        }
+    });
+
+    ast
+}
+
+fn snake_case(ident: &str) -> String {
+    let chars: Vec<char> = ident.chars().collect();
+    let mut out = String::new();
+
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase()
+                || prev.is_numeric()
+                || (prev.is_uppercase() && next_lower)
+            {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+
+    out
+}
+
+fn visit_field(
+    expr: proc_macro2::TokenStream,
+    ty: &syn::Type,
+    types: &[String],
+    suffix: &str,
+) -> Option<proc_macro2::TokenStream> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    let name = segment.ident.to_string();
+
+    if name == "Option" || name == "Vec" {
+        let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        else {
+            return None;
+        };
+        let Some(syn::GenericArgument::Type(inner)) = args.args.first() else {
+            return None;
+        };
+        let body = visit_field(quote::quote!(it), inner, types, suffix)?;
+
+        return Some(if name == "Option" {
+            quote::quote! { if let Some(it) = #expr { #body } }
+        } else {
+            quote::quote! { for it in #expr { #body } }
+        });
+    }
+
+    if !types.contains(&name) {
+        return None;
+    }
+    let method = syn::Ident::new(
+        &format!("visit_{}{suffix}", snake_case(&name)),
+        proc_macro2::Span::call_site(),
+    );
+
+    Some(quote::quote! { v.#method(#expr); })
+}
+
+fn process_ast_visit(ast: &mut syn::File) -> &mut syn::File {
+    let types: Vec<String> = ast
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Struct(item) => Some(item.ident.to_string()),
+            syn::Item::Enum(item) => Some(item.ident.to_string()),
+            _ => None,
+        })
+        .collect();
+
+    let mut visit_methods: Vec<syn::TraitItem> = vec![];
+    let mut visit_walks: Vec<syn::Item> = vec![];
+    let mut visit_mut_methods: Vec<syn::TraitItem> = vec![];
+    let mut visit_mut_walks: Vec<syn::Item> = vec![];
+
+    ast.items.iter().for_each(|item| {
+        let (ident, fields) = match item {
+            syn::Item::Struct(item) => (&item.ident, Some(&item.fields)),
+            syn::Item::Enum(item) => (&item.ident, None),
+            _ => return,
+        };
+        let snake = snake_case(&ident.to_string());
+        let span = proc_macro2::Span::call_site();
+        let visit = syn::Ident::new(&format!("visit_{snake}"), span);
+        let walk = syn::Ident::new(&format!("walk_{snake}"), span);
+        let visit_mut = syn::Ident::new(&format!("visit_{snake}_mut"), span);
+        let walk_mut = syn::Ident::new(&format!("walk_{snake}_mut"), span);
+
+        let mut stmts = vec![];
+        let mut stmts_mut = vec![];
+        fields.into_iter().flatten().for_each(|field| {
+            let Some(name) = &field.ident else {
+                return;
+            };
+            stmts.extend(visit_field(
+                quote::quote!(&node.#name),
+                &field.ty,
+                &types,
+                "",
+            ));
+            stmts_mut.extend(visit_field(
+                quote::quote!(&mut node.#name),
+                &field.ty,
+                &types,
+                "_mut",
+            ));
+        });
+
+        let (v, node) = if stmts.is_empty() {
+            (quote::quote!(_v), quote::quote!(_node))
+        } else {
+            (quote::quote!(v), quote::quote!(node))
+        };
+
+        visit_methods.push(syn::parse_quote! {
+            fn #visit(&mut self, node: &'ast #ident) {
+                #walk(self, node)
+            }
+        });
+        visit_walks.push(syn::parse_quote! {
+            pub fn #walk<'ast, V: Visit<'ast> + ?Sized>(#v: &mut V, #node: &'ast #ident) {
+                #(#stmts)*
+            }
+        });
+        visit_mut_methods.push(syn::parse_quote! {
+            fn #visit_mut(&mut self, node: &mut #ident) {
+                #walk_mut(self, node)
+            }
+        });
+        visit_mut_walks.push(syn::parse_quote! {
+            pub fn #walk_mut<V: VisitMut + ?Sized>(#v: &mut V, #node: &mut #ident) {
+                #(#stmts_mut)*
+            }
+        });
+    });
+
+    ast.items.push(syn::parse_quote! {
+        /// Read only traversal of the model
+        ///
+        /// Every method visits one type and by default walks into its
+        /// children, so an implementation only overrides the types it cares
+        /// about. Call the matching `walk_*` function from an override to keep
+        /// descending.
+        pub mod visit {
+            use super::*;
+
+            pub trait Visit<'ast> {
+                #(#visit_methods)*
+            }
+
+            #(#visit_walks)*
+        }
+    });
+    ast.items.push(syn::parse_quote! {
+        /// In place traversal of the model
+        ///
+        /// Mutable counterpart of [`visit`](super::visit).
+        pub mod visit_mut {
+            use super::*;
+
+            pub trait VisitMut {
+                #(#visit_mut_methods)*
+            }
+
+            #(#visit_mut_walks)*
+        }
+    });
+
+    ast
+}
+
 fn process_ast_assessment_plan(ast: &mut syn::File) -> String {
     let ast = process_ast_common(ast);
 
@@ -1820,7 +2229,10 @@ fn process_ast_assessment_plan(ast: &mut syn::File) -> String {
         }
     });
 
-    prettyplease::unparse(ast).replace("#[derive(ToBeReplaced)]", "")
+    prettyplease::unparse(process_ast_visit(process_ast_builder_validation(
+        process_ast_extensions(process_ast_scalars(process_ast_markup(ast))),
+    )))
+    .replace("#[derive(ToBeReplaced)]", "")
 }
 
 fn process_ast_assessment_results(ast: &mut syn::File) -> String {
@@ -1962,7 +2374,10 @@ fn process_ast_assessment_results(ast: &mut syn::File) -> String {
         }
     });
 
-    prettyplease::unparse(ast).replace("#[derive(ToBeReplaced)]", "")
+    prettyplease::unparse(process_ast_visit(process_ast_builder_validation(
+        process_ast_extensions(process_ast_scalars(process_ast_markup(ast))),
+    )))
+    .replace("#[derive(ToBeReplaced)]", "")
 }
 
 fn process_ast_poam(ast: &mut syn::File) -> String {
@@ -2060,7 +2475,10 @@ fn process_ast_poam(ast: &mut syn::File) -> String {
         }
     });
 
-    prettyplease::unparse(ast).replace("#[derive(ToBeReplaced)]", "")
+    prettyplease::unparse(process_ast_visit(process_ast_builder_validation(
+        process_ast_extensions(process_ast_scalars(process_ast_markup(ast))),
+    )))
+    .replace("#[derive(ToBeReplaced)]", "")
 }
 
 fn process_ast_catalog(ast: &mut syn::File) -> String {
@@ -2108,7 +2526,10 @@ fn process_ast_catalog(ast: &mut syn::File) -> String {
         }
     });
 
-    prettyplease::unparse(ast).replace("#[derive(ToBeReplaced)]", "")
+    prettyplease::unparse(process_ast_visit(process_ast_builder_validation(
+        process_ast_extensions(process_ast_scalars(process_ast_markup(ast))),
+    )))
+    .replace("#[derive(ToBeReplaced)]", "")
 }
 
 fn process_ast_profile(ast: &mut syn::File) -> String {
@@ -2215,7 +2636,10 @@ fn process_ast_profile(ast: &mut syn::File) -> String {
         }
     });
 
-    prettyplease::unparse(ast).replace("#[derive(ToBeReplaced)]", "")
+    prettyplease::unparse(process_ast_visit(process_ast_builder_validation(
+        process_ast_extensions(process_ast_scalars(process_ast_markup(ast))),
+    )))
+    .replace("#[derive(ToBeReplaced)]", "")
 }
 
 fn process_ast_component_definition(ast: &mut syn::File) -> String {
@@ -2333,7 +2757,10 @@ fn process_ast_component_definition(ast: &mut syn::File) -> String {
         }
     });
 
-    prettyplease::unparse(ast).replace("#[derive(ToBeReplaced)]", "")
+    prettyplease::unparse(process_ast_visit(process_ast_builder_validation(
+        process_ast_extensions(process_ast_scalars(process_ast_markup(ast))),
+    )))
+    .replace("#[derive(ToBeReplaced)]", "")
 }
 
 fn process_ast_ssp(ast: &mut syn::File) -> String {
@@ -2586,7 +3013,10 @@ fn process_ast_ssp(ast: &mut syn::File) -> String {
         }
     });
 
-    prettyplease::unparse(ast).replace("#[derive(ToBeReplaced)]", "")
+    prettyplease::unparse(process_ast_visit(process_ast_builder_validation(
+        process_ast_extensions(process_ast_scalars(process_ast_markup(ast))),
+    )))
+    .replace("#[derive(ToBeReplaced)]", "")
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
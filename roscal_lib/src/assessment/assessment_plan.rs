@@ -1,30 +1,35 @@
+use crate::markup::Markup;
+use crate::parse::Extensions;
+use crate::scalar::{DateTimeWithTz, EmailAddress, OscalUuid, Token, Uri, UriReference};
+use crate::validation;
 use derivative::Derivative;
-use strum::EnumString;
 use derive_builder::Builder;
-use serde::{Serialize, Deserialize};
-use crate::validation;
+use serde::{Deserialize, Serialize};
+use strum::EnumString;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessmentPlan {
     #[serde(rename = "$schema")]
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schema: Option<String>,
     pub assessment_plan: SecurityAssessmentPlanSap,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// An assessment plan, such as those provided by a FedRAMP assessor.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SecurityAssessmentPlanSap {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -62,7 +67,18 @@ pub struct SecurityAssessmentPlanSap {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SecurityAssessmentPlanSapBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Identifies the assets used to perform this assessment, such as the assessment team,
@@ -72,21 +88,23 @@ pub struct SecurityAssessmentPlanSap {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessmentAssets {
     pub assessment_platforms: Vec<AssessmentPlatform>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub components: Option<Vec<Component>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Used to represent the toolset used to perform aspects of the assessment.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessmentPlatform {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -96,13 +114,11 @@ pub struct AssessmentPlatform {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// The title or name for the assessment platform.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub uses_components: Option<Vec<UsesComponent>>,
@@ -117,7 +133,18 @@ pub struct AssessmentPlatform {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AssessmentPlatformBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A reference to a local or remote resource, that has a specific relation to the containing
@@ -125,16 +152,15 @@ pub struct AssessmentPlatform {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Link {
     /// A resolvable URL reference to a resource.
     #[serde(
         serialize_with = "validation::ser_uri_ref",
         deserialize_with = "validation::deser_uri_ref"
     )]
-    pub href: String,
+    pub href: UriReference,
     /// A label that indicates the nature of a resource, as a data serialization or format.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -147,7 +173,7 @@ pub struct Link {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub rel: Option<String>,
+    pub rel: Option<Token>,
     /// In case where the href points to a back-matter/resource, this value will indicate the URI
     /// fragment to append to any rlink associated with the resource. This value MUST be URI
     /// encoded.
@@ -157,17 +183,26 @@ pub struct Link {
     /// A textual label to associate with the link, which may be used for presentation in a tool.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub text: Option<String>,
+    pub text: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl LinkBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri_ref("href", &self.href)?;
+        validation::check_token("rel", &self.rel)?;
+        Ok(())
+    }
 }
 
 /// An attribute, characteristic, or quality of the containing object expressed as a
 /// namespace qualified name/value pair.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Property {
     /// A textual label that provides a sub-type or characterization of the property's name.
     #[builder(setter(into, strip_option), default)]
@@ -176,7 +211,7 @@ pub struct Property {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub class: Option<String>,
+    pub class: Option<Token>,
     /// An identifier for relating distinct sets of properties.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -184,14 +219,14 @@ pub struct Property {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub group: Option<String>,
+    pub group: Option<Token>,
     /// A textual label, within a namespace, that uniquely identifies a specific attribute,
     /// characteristic, or quality of the property's containing object.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub name: String,
+    pub name: Token,
     /// A namespace qualifying the property's name. This allows different organizations to
     /// associate distinct semantics with the same name.
     #[builder(setter(into, strip_option), default)]
@@ -200,11 +235,10 @@ pub struct Property {
         serialize_with = "validation::ser_uri_opt",
         deserialize_with = "validation::deser_uri_opt"
     )]
-    pub ns: Option<String>,
+    pub ns: Option<Uri>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A unique identifier for a property.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -213,18 +247,32 @@ pub struct Property {
         deserialize_with = "validation::deser_uuid_opt"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: Option<String>,
+    pub uuid: Option<OscalUuid>,
     /// Indicates the value of the attribute, characteristic, or quality.
     pub value: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PropertyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("class", &self.class)?;
+        validation::check_token("group", &self.group)?;
+        validation::check_token("name", &self.name)?;
+        validation::check_uri("ns", &self.ns)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// The set of components that are used by the assessment platform.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct UsesComponent {
     /// A machine-oriented identifier reference to a component that is implemented as part of an
     /// inventory item.
@@ -233,7 +281,7 @@ pub struct UsesComponent {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub component_uuid: String,
+    pub component_uuid: OscalUuid,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -242,11 +290,21 @@ pub struct UsesComponent {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl UsesComponentBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("component_uuid", &self.component_uuid)?;
+        Ok(())
+    }
 }
 
 /// A reference to a set of persons and/or organizations that have responsibility for
@@ -254,9 +312,8 @@ pub struct UsesComponent {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ResponsibleParty {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -265,33 +322,42 @@ pub struct ResponsibleParty {
         serialize_with = "validation::ser_uuid_vec",
         deserialize_with = "validation::deser_uuid_vec"
     )]
-    pub party_uuids: Vec<String>,
+    pub party_uuids: Vec<OscalUuid>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A reference to a role performed by a party.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub role_id: String,
+    pub role_id: Token,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ResponsiblePartyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("party_uuids", &self.party_uuids)?;
+        validation::check_token("role_id", &self.role_id)?;
+        Ok(())
+    }
 }
 
 /// A defined component that can be part of an implemented system.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Component {
     /// A description of the component, including information about its function.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -304,27 +370,24 @@ pub struct Component {
     /// A summary of the technological or business purpose of the component.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub purpose: Option<String>,
+    pub purpose: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
     /// Describes the operational status of the system component.
     pub status: Status,
     /// A human readable name for the system component.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub title: String,
+    pub title: Markup,
     /// A category describing the purpose of the component.
     #[serde(rename = "type")]
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub component_type: String,
+    pub component_type: Token,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this component elsewhere in this or other OSCAL instances. The locally
     /// defined UUID of the component can be used to reference the data item locally or globally
@@ -336,16 +399,27 @@ pub struct Component {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ComponentBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("component_type", &self.component_type)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Information about the protocol used to provide a service.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ServiceProtocolInformation {
     /// The common name of the protocol, which should be the appropriate "service name" from the
     /// IANA Service Name and Transport Protocol Port Number Registry.
@@ -356,8 +430,7 @@ pub struct ServiceProtocolInformation {
     /// A human readable name for the protocol (e.g., Transport Layer Security).
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this service protocol information elsewhere in this or other OSCAL
     /// instances. The locally defined UUID of the service protocol can be used to reference the
@@ -371,15 +444,24 @@ pub struct ServiceProtocolInformation {
         deserialize_with = "validation::deser_uuid_opt"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: Option<String>,
+    pub uuid: Option<OscalUuid>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ServiceProtocolInformationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Where applicable this is the IPv4 port range on which the service operates.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct PortRange {
     /// Indicates the ending port number in a port range
     #[builder(setter(into, strip_option), default)]
@@ -401,6 +483,18 @@ pub struct PortRange {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transport: Option<Transport>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PortRangeBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_non_neg_int("end", &self.end)?;
+        validation::check_non_neg_int("start", &self.start)?;
+        Ok(())
+    }
 }
 /// Indicates the transport type.
 ///
@@ -477,9 +571,8 @@ pub enum Transport {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ResponsibleRole {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -490,35 +583,47 @@ pub struct ResponsibleRole {
         serialize_with = "validation::ser_uuid_vec_opt",
         deserialize_with = "validation::deser_uuid_vec_opt"
     )]
-    pub party_uuids: Option<Vec<String>>,
+    pub party_uuids: Option<Vec<OscalUuid>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A human-oriented identifier reference to a role performed.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub role_id: String,
+    pub role_id: Token,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ResponsibleRoleBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("party_uuids", &self.party_uuids)?;
+        validation::check_token("role_id", &self.role_id)?;
+        Ok(())
+    }
 }
 
 /// Describes the operational status of the system component.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Status {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// The operational status.
     pub state: State,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 /// The operational status.
 ///
@@ -599,16 +704,14 @@ pub enum State {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SubjectOfAssessment {
     /// A human-readable description of the collection of subjects being included in this
     /// assessment.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub exclude_subjects: Option<Vec<SelectAssessmentSubject>>,
@@ -626,8 +729,7 @@ pub struct SubjectOfAssessment {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// Indicates the type of assessment subject, such as a component, inventory, item, location,
     /// or party represented by this selection statement.
     #[serde(rename = "type")]
@@ -635,16 +737,26 @@ pub struct SubjectOfAssessment {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub subject_of_assessment_type: String,
+    pub subject_of_assessment_type: Token,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SubjectOfAssessmentBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("subject_of_assessment_type", &self.subject_of_assessment_type)?;
+        Ok(())
+    }
 }
 
 /// Identifies a set of assessment subjects to include/exclude by UUID.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SelectAssessmentSubject {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -654,8 +766,7 @@ pub struct SelectAssessmentSubject {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A machine-oriented identifier reference to a component, inventory-item, location, party,
     /// user, or resource using it's UUID.
     #[derivative(PartialEq = "ignore")]
@@ -663,34 +774,51 @@ pub struct SelectAssessmentSubject {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub subject_uuid: String,
+    pub subject_uuid: OscalUuid,
     /// Used to indicate the type of object pointed to by the uuid-ref within a subject.
     #[serde(rename = "type")]
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub select_assessment_subject_type: String,
+    pub select_assessment_subject_type: Token,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SelectAssessmentSubjectBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("subject_uuid", &self.subject_uuid)?;
+        validation::check_token("select_assessment_subject_type", &self.select_assessment_subject_type)?;
+        Ok(())
+    }
 }
 
 /// Include all controls from the imported catalog or profile resources.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
-pub struct IncludeAll {}
+pub struct IncludeAll {
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
 
 /// A collection of resources that may be referenced from within the OSCAL document instance.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct BackMatter {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub resources: Option<Vec<Resource>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A resource associated with content in the containing document instance. A resource may be
@@ -699,9 +827,8 @@ pub struct BackMatter {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Resource {
     /// A resource encoded using the Base64 alphabet defined by RFC 2045.
     #[builder(setter(into, strip_option), default)]
@@ -714,8 +841,7 @@ pub struct Resource {
     /// An optional short summary of the resource used to indicate the purpose of the resource.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub document_ids: Option<Vec<DocumentIdentifier>>,
@@ -724,8 +850,7 @@ pub struct Resource {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rlinks: Option<Vec<ResourceLink>>,
@@ -733,24 +858,33 @@ pub struct Resource {
     /// navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A unique identifier for a resource.
     #[serde(
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ResourceBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A resource encoded using the Base64 alphabet defined by RFC 2045.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Base64 {
     /// Name of the file before it was encoded as Base64 to be embedded in a resource. This is
     /// the name that will be assigned to the file when the file is decoded.
@@ -760,7 +894,7 @@ pub struct Base64 {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub filename: Option<String>,
+    pub filename: Option<Token>,
     /// A label that indicates the nature of a resource, as a data serialization or format.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -770,14 +904,24 @@ pub struct Base64 {
         deserialize_with = "validation::deser_base64"
     )]
     pub value: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl Base64Builder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("filename", &self.filename)?;
+        validation::check_base64("value", &self.value)?;
+        Ok(())
+    }
 }
 
 /// An optional citation consisting of end note text using structured markup.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Citation {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -786,16 +930,17 @@ pub struct Citation {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
     /// A line of citation text.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub text: String,
+    pub text: Markup,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A document identifier qualified by an identifier scheme.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct DocumentIdentifier {
     pub identifier: String,
     /// Qualifies the kind of document identifier using a URI. If the scheme is not provided the
@@ -806,7 +951,18 @@ pub struct DocumentIdentifier {
         serialize_with = "validation::ser_uri_opt",
         deserialize_with = "validation::deser_uri_opt"
     )]
-    pub scheme: Option<String>,
+    pub scheme: Option<Uri>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl DocumentIdentifierBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri("scheme", &self.scheme)?;
+        Ok(())
+    }
 }
 
 /// A URL-based pointer to an external resource with an optional hash for verification and
@@ -814,9 +970,8 @@ pub struct DocumentIdentifier {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ResourceLink {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -826,20 +981,30 @@ pub struct ResourceLink {
         serialize_with = "validation::ser_uri_ref",
         deserialize_with = "validation::deser_uri_ref"
     )]
-    pub href: String,
+    pub href: UriReference,
     /// A label that indicates the nature of a resource, as a data serialization or format.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub media_type: Option<String>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ResourceLinkBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri_ref("href", &self.href)?;
+        Ok(())
+    }
 }
 
 /// A representation of a cryptographic digest generated over a resource using a specified
 /// hash algorithm.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
+#[serde(try_from = "validation::RawHash")]
 pub struct Hash {
     /// The digest method by which a hash is derived.
     pub algorithm: String,
@@ -848,25 +1013,45 @@ pub struct Hash {
         deserialize_with = "validation::deser_hash"
     )]
     pub value: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl HashBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_hash("value", &self.value)?;
+        validation::check_digest(&self.algorithm, &self.value)?;
+        Ok(())
+    }
 }
 
 /// Used by the assessment plan and POA&M to import information about the system.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ImportSystemSecurityPlan {
     /// A resolvable URL reference to the system security plan for the system being assessed.
     #[serde(
         serialize_with = "validation::ser_uri_ref",
         deserialize_with = "validation::deser_uri_ref"
     )]
-    pub href: String,
+    pub href: UriReference,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ImportSystemSecurityPlanBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri_ref("href", &self.href)?;
+        Ok(())
+    }
 }
 
 /// Used to define data objects that are used in the assessment plan, that do not appear in
@@ -876,7 +1061,6 @@ pub struct ImportSystemSecurityPlan {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct LocalDefinitions {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -892,11 +1076,14 @@ pub struct LocalDefinitions {
     pub objectives_and_methods: Option<Vec<AssessmentSpecificControlObjective>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub users: Option<Vec<SystemUser>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Identifies an assessment or related process that can be performed. In the assessment
@@ -906,13 +1093,11 @@ pub struct LocalDefinitions {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Activity {
     /// A human-readable description of this included activity.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -924,8 +1109,7 @@ pub struct Activity {
     pub related_controls: Option<ReviewedControlsAndControlObjectives>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
@@ -935,8 +1119,7 @@ pub struct Activity {
     /// The title for this included activity.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this assessment activity elsewhere in this or other OSCAL instances. The
     /// locally defined UUID of the activity can be used to reference the data item locally or
@@ -948,7 +1131,18 @@ pub struct Activity {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ActivityBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Identifies the controls being assessed and their control objectives.
@@ -957,7 +1151,6 @@ pub struct Activity {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ReviewedControlsAndControlObjectives {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -966,8 +1159,7 @@ pub struct ReviewedControlsAndControlObjectives {
     /// A human-readable description of control objectives.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -976,8 +1168,11 @@ pub struct ReviewedControlsAndControlObjectives {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Identifies the control objectives of the assessment. In the assessment plan, these are
@@ -988,13 +1183,11 @@ pub struct ReviewedControlsAndControlObjectives {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ReferencedControlObjectives {
     /// A human-readable description of this collection of control objectives.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub exclude_objectives: Option<Vec<SelectObjective>>,
@@ -1012,8 +1205,11 @@ pub struct ReferencedControlObjectives {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Used to select a control objective for inclusion/exclusion based on the control
@@ -1021,16 +1217,26 @@ pub struct ReferencedControlObjectives {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SelectObjective {
     /// Points to an assessment objective.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub objective_id: String,
+    pub objective_id: Token,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SelectObjectiveBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("objective_id", &self.objective_id)?;
+        Ok(())
+    }
 }
 
 /// Identifies the controls being assessed. In the assessment plan, these are the planned
@@ -1041,13 +1247,11 @@ pub struct SelectObjective {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessedControls {
     /// A human-readable description of in-scope controls specified for assessment.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub exclude_controls: Option<Vec<SelectControl>>,
@@ -1065,8 +1269,11 @@ pub struct AssessedControls {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Used to select a control for inclusion/exclusion based on one or more control
@@ -1076,9 +1283,8 @@ pub struct AssessedControls {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SelectControl {
     /// A reference to a control with a corresponding id value. When referencing an externally
     /// defined control, the Control Identifier Reference must be used in the context of the
@@ -1087,14 +1293,26 @@ pub struct SelectControl {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub control_id: String,
+    pub control_id: Token,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(
         serialize_with = "validation::ser_token_vec_opt",
         deserialize_with = "validation::deser_token_vec_opt"
     )]
-    pub statement_ids: Option<Vec<String>>,
+    pub statement_ids: Option<Vec<Token>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SelectControlBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("control_id", &self.control_id)?;
+        validation::check_token("statement_ids", &self.statement_ids)?;
+        Ok(())
+    }
 }
 
 /// Identifies an individual step in a series of steps related to an activity, such as an
@@ -1102,13 +1320,11 @@ pub struct SelectControl {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Step {
     /// A human-readable description of this step.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1117,8 +1333,7 @@ pub struct Step {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
@@ -1128,8 +1343,7 @@ pub struct Step {
     /// The title for this step.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this step elsewhere in this or other OSCAL instances. The locally defined
     /// UUID of the step (in a series of steps) can be used to reference the data item locally or
@@ -1141,20 +1355,29 @@ pub struct Step {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl StepBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A single managed inventory item within the system.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct InventoryItem {
     /// A summary of the inventory item stating its purpose within the system.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub implemented_components: Option<Vec<ImplementedComponent>>,
@@ -1166,8 +1389,7 @@ pub struct InventoryItem {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
@@ -1182,16 +1404,26 @@ pub struct InventoryItem {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl InventoryItemBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// The set of components that are implemented in a given system inventory item.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ImplementedComponent {
     /// A machine-oriented identifier reference to a component that is implemented as part of an
     /// inventory item.
@@ -1200,7 +1432,7 @@ pub struct ImplementedComponent {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub component_uuid: String,
+    pub component_uuid: OscalUuid,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1209,11 +1441,21 @@ pub struct ImplementedComponent {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ImplementedComponentBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("component_uuid", &self.component_uuid)?;
+        Ok(())
+    }
 }
 
 /// A local definition of a control objective for this assessment. Uses catalog syntax for
@@ -1221,9 +1463,8 @@ pub struct ImplementedComponent {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessmentSpecificControlObjective {
     /// A reference to a control with a corresponding id value. When referencing an externally
     /// defined control, the Control Identifier Reference must be used in the context of the
@@ -1232,12 +1473,11 @@ pub struct AssessmentSpecificControlObjective {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub control_id: String,
+    pub control_id: Token,
     /// A human-readable description of this control objective.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1247,17 +1487,25 @@ pub struct AssessmentSpecificControlObjective {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AssessmentSpecificControlObjectiveBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("control_id", &self.control_id)?;
+        Ok(())
+    }
 }
 
 /// An annotated, markup-based textual element of a control's or catalog group's definition,
 /// or a child of another part.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Part {
     /// An optional textual providing a sub-type or characterization of the part's name, or a
     /// category to which the part belongs.
@@ -1267,7 +1515,7 @@ pub struct Part {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub class: Option<String>,
+    pub class: Option<Token>,
     /// A unique identifier for the part.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1275,7 +1523,7 @@ pub struct Part {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub id: Option<String>,
+    pub id: Option<Token>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1285,7 +1533,7 @@ pub struct Part {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub name: String,
+    pub name: Token,
     /// An optional namespace qualifying the part's name. This allows different organizations to
     /// associate distinct semantics with the same name.
     #[builder(setter(into, strip_option), default)]
@@ -1294,7 +1542,7 @@ pub struct Part {
         serialize_with = "validation::ser_uri_opt",
         deserialize_with = "validation::deser_uri_opt"
     )]
-    pub ns: Option<String>,
+    pub ns: Option<Uri>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parts: Option<Vec<Part>>,
@@ -1304,23 +1552,34 @@ pub struct Part {
     /// Permits multiple paragraphs, lists, tables etc.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub prose: Option<String>,
+    pub prose: Option<Markup>,
     /// An optional name given to the part, which may be used by a tool for display and
     /// navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PartBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("class", &self.class)?;
+        validation::check_token("id", &self.id)?;
+        validation::check_token("name", &self.name)?;
+        validation::check_uri("ns", &self.ns)?;
+        Ok(())
+    }
 }
 
 /// A type of user that interacts with the system based on an associated role.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SystemUser {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1328,8 +1587,7 @@ pub struct SystemUser {
     /// A summary of the user's purpose within the system.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1338,12 +1596,11 @@ pub struct SystemUser {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(deserialize_with = "validation::deser_token_vec_opt")]
-    pub role_ids: Option<Vec<String>>,
+    pub role_ids: Option<Vec<Token>>,
     /// A short common name, abbreviation, or acronym for the user.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1351,8 +1608,7 @@ pub struct SystemUser {
     /// A name given to the user, which may be used by a tool for display and navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this user class elsewhere in this or other OSCAL instances. The locally
     /// defined UUID of the system user can be used to reference the data item locally or
@@ -1364,7 +1620,19 @@ pub struct SystemUser {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SystemUserBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("role_ids", &self.role_ids)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Identifies a specific system privilege held by the user, along with an associated
@@ -1374,16 +1642,18 @@ pub struct SystemUser {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Privilege {
     /// A summary of the privilege's purpose within the system.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     pub functions_performed: Vec<String>,
     /// A human readable name for the privilege.
     pub title: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Provides information about the containing document, and defines concepts that are shared
@@ -1391,9 +1661,8 @@ pub struct Privilege {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct DocumentMetadata {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1405,7 +1674,7 @@ pub struct DocumentMetadata {
         serialize_with = "validation::ser_dttz",
         deserialize_with = "validation::deser_dttz"
     )]
-    pub last_modified: String,
+    pub last_modified: DateTimeWithTz,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1424,8 +1693,7 @@ pub struct DocumentMetadata {
     pub published: Option<String>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
@@ -1436,18 +1704,27 @@ pub struct DocumentMetadata {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub roles: Option<Vec<Role>>,
     /// A name given to the document, which may be used by a tool for display and navigation.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub title: String,
+    pub title: Markup,
     pub version: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl DocumentMetadataBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("last_modified", &self.last_modified)?;
+        Ok(())
+    }
 }
 
 /// An action applied by a role within a given party to the content.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Action {
     /// The date and time when the action occurred.
     #[builder(setter(into, strip_option), default)]
@@ -1456,7 +1733,7 @@ pub struct Action {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub date: Option<String>,
+    pub date: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1465,8 +1742,7 @@ pub struct Action {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
@@ -1478,7 +1754,7 @@ pub struct Action {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub action_type: String,
+    pub action_type: Token,
     /// A unique identifier that can be used to reference this defined action elsewhere in an
     /// OSCAL document. A UUID should be consistently used for a given location across revisions
     /// of the document.
@@ -1487,7 +1763,20 @@ pub struct Action {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ActionBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("date", &self.date)?;
+        validation::check_token("action_type", &self.action_type)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A physical point of presence, which may be associated with people, organizations, or
@@ -1495,9 +1784,8 @@ pub struct Action {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Location {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1508,7 +1796,7 @@ pub struct Location {
         serialize_with = "validation::ser_email_vec_opt",
         deserialize_with = "validation::deser_email_vec_opt"
     )]
-    pub email_addresses: Option<Vec<String>>,
+    pub email_addresses: Option<Vec<EmailAddress>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1517,39 +1805,49 @@ pub struct Location {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub telephone_numbers: Option<Vec<TelephoneNumber>>,
     /// A name given to the location, which may be used by a tool for display and navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(
         serialize_with = "validation::ser_uri_vec_opt",
         deserialize_with = "validation::deser_uri_vec_opt"
     )]
-    pub urls: Option<Vec<String>>,
+    pub urls: Option<Vec<Uri>>,
     /// A unique ID for the location, for reference.
     #[serde(
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl LocationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_email("email_addresses", &self.email_addresses)?;
+        validation::check_uri("urls", &self.urls)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A postal address for the location.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Address {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1578,15 +1876,24 @@ pub struct Address {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub address_type: Option<String>,
+    pub address_type: Option<Token>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AddressBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("address_type", &self.address_type)?;
+        Ok(())
+    }
 }
 
 /// A telephone service number as defined by ITU-T E.164.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct TelephoneNumber {
     pub number: String,
     /// Indicates the type of phone number.
@@ -1594,6 +1901,10 @@ pub struct TelephoneNumber {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub telephone_number_type: Option<String>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// An organization or person, which may be associated with roles or other concepts within
@@ -1601,9 +1912,8 @@ pub struct TelephoneNumber {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Party {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1614,7 +1924,7 @@ pub struct Party {
         serialize_with = "validation::ser_email_vec_opt",
         deserialize_with = "validation::deser_email_vec_opt"
     )]
-    pub email_addresses: Option<Vec<String>>,
+    pub email_addresses: Option<Vec<EmailAddress>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub external_ids: Option<Vec<PartyExternalIdentifier>>,
@@ -1630,7 +1940,7 @@ pub struct Party {
         serialize_with = "validation::ser_uuid_vec_opt",
         deserialize_with = "validation::deser_uuid_vec_opt"
     )]
-    pub member_of_organizations: Option<Vec<String>>,
+    pub member_of_organizations: Option<Vec<OscalUuid>>,
     /// The full name of the party. This is typically the legal name associated with the party.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1640,8 +1950,7 @@ pub struct Party {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A short common name, abbreviation, or acronym for the party.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1658,16 +1967,27 @@ pub struct Party {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PartyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_email("email_addresses", &self.email_addresses)?;
+        validation::check_uuid("member_of_organizations", &self.member_of_organizations)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// An identifier for a person or organization using a designated scheme. e.g. an Open
 /// Researcher and Contributor ID (ORCID).
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct PartyExternalIdentifier {
     pub id: String,
     /// Indicates the type of external identifier.
@@ -1675,7 +1995,18 @@ pub struct PartyExternalIdentifier {
         serialize_with = "validation::ser_uri",
         deserialize_with = "validation::deser_uri"
     )]
-    pub scheme: String,
+    pub scheme: Uri,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PartyExternalIdentifierBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri("scheme", &self.scheme)?;
+        Ok(())
+    }
 }
 /// A category describing the kind of party the object describes.
 ///
@@ -1739,9 +2070,8 @@ pub enum PartyType {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct RevisionHistoryEntry {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1749,7 +2079,7 @@ pub struct RevisionHistoryEntry {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub last_modified: Option<String>,
+    pub last_modified: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1765,39 +2095,47 @@ pub struct RevisionHistoryEntry {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub published: Option<String>,
+    pub published: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A name given to the document revision, which may be used by a tool for display and
     /// navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     pub version: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl RevisionHistoryEntryBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("last_modified", &self.last_modified)?;
+        validation::check_dttz("published", &self.published)?;
+        Ok(())
+    }
 }
 
 /// Defines a function, which might be assigned to a party in a specific situation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Role {
     /// A summary of the role's purpose and associated responsibilities.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     /// A unique identifier for the role.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub id: String,
+    pub id: Token,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1806,14 +2144,24 @@ pub struct Role {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A short common name, abbreviation, or acronym for the role.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub short_name: Option<String>,
     /// A name given to the role, which may be used by a tool for display and navigation.
     pub title: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl RoleBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("id", &self.id)?;
+        Ok(())
+    }
 }
 
 /// Represents a scheduled event or milestone, which may be associated with a series of
@@ -1821,9 +2169,8 @@ pub struct Role {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Task {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1834,8 +2181,7 @@ pub struct Task {
     /// A human-readable description of this task.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1844,8 +2190,7 @@ pub struct Task {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
@@ -1860,15 +2205,14 @@ pub struct Task {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timing: Option<EventTiming>,
     /// The title for this task.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub title: String,
+    pub title: Markup,
     /// The type of task.
     #[serde(rename = "type")]
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub task_type: String,
+    pub task_type: Token,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this task elsewhere in this or other OSCAL instances. The locally defined
     /// UUID of the task can be used to reference the data item locally or globally (e.g., in an
@@ -1879,16 +2223,27 @@ pub struct Task {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl TaskBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("task_type", &self.task_type)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Identifies an individual activity to be performed as part of a task.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssociatedActivity {
     /// A machine-oriented identifier reference to an activity defined in the list of activities.
     #[derivative(PartialEq = "ignore")]
@@ -1896,7 +2251,7 @@ pub struct AssociatedActivity {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub activity_uuid: String,
+    pub activity_uuid: OscalUuid,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1905,33 +2260,52 @@ pub struct AssociatedActivity {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
     pub subjects: Vec<SubjectOfAssessment>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AssociatedActivityBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("activity_uuid", &self.activity_uuid)?;
+        Ok(())
+    }
 }
 
 /// Used to indicate that a task is dependent on another task.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct TaskDependency {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A machine-oriented identifier reference to a unique task.
     #[derivative(PartialEq = "ignore")]
     #[serde(
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub task_uuid: String,
+    pub task_uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl TaskDependencyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("task_uuid", &self.task_uuid)?;
+        Ok(())
+    }
 }
 
 /// The timing under which the task is intended to occur.
@@ -1940,7 +2314,6 @@ pub struct TaskDependency {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct EventTiming {
     /// The task is intended to occur at the specified frequency.
     #[builder(setter(into, strip_option), default)]
@@ -1954,14 +2327,16 @@ pub struct EventTiming {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub within_date_range: Option<OnDateRangeCondition>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// The task is intended to occur at the specified frequency.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct FrequencyCondition {
     /// The task must occur after the specified period has elapsed.
     #[serde(
@@ -1971,6 +2346,17 @@ pub struct FrequencyCondition {
     pub period: i64,
     /// The unit of time for the period.
     pub unit: TimeUnit,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl FrequencyConditionBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_positive_int("period", &self.period)?;
+        Ok(())
+    }
 }
 /// The unit of time for the period.
 ///
@@ -2034,48 +2420,52 @@ pub enum TimeUnit {
 }
 
 /// The task is intended to occur on the specified date.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct OnDateCondition {
     /// The task must occur on the specified date.
     pub date: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// The task is intended to occur within the specified date range.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct OnDateRangeCondition {
     /// The task must occur on or before the specified date.
     pub end: String,
     /// The task must occur on or after the specified date.
     pub start: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Used to define various terms and conditions under which an assessment, described by the
 /// plan, can be performed. Each child part defines a different type of term or condition.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessmentPlanTermsAndConditions {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parts: Option<Vec<AssessmentPart>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A partition of an assessment plan or results or a child of another part.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessmentPart {
     /// A textual label that provides a sub-type or characterization of the part's name. This can
     /// be used to further distinguish or discriminate between the semantics of multiple parts of
@@ -2086,7 +2476,7 @@ pub struct AssessmentPart {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub class: Option<String>,
+    pub class: Option<Token>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -2095,7 +2485,7 @@ pub struct AssessmentPart {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub name: String,
+    pub name: Token,
     /// A namespace qualifying the part's name. This allows different organizations to associate
     /// distinct semantics with the same name.
     #[builder(setter(into, strip_option), default)]
@@ -2104,7 +2494,7 @@ pub struct AssessmentPart {
         serialize_with = "validation::ser_uri_opt",
         deserialize_with = "validation::deser_uri_opt"
     )]
-    pub ns: Option<String>,
+    pub ns: Option<Uri>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parts: Option<Vec<AssessmentPart>>,
@@ -2118,8 +2508,7 @@ pub struct AssessmentPart {
     /// A name given to the part, which may be used by a tool for display and navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this part elsewhere in this or other OSCAL instances. The locally defined
     /// UUID of the part can be used to reference the data item locally or globally (e.g., in an
@@ -2132,5 +2521,2122 @@ pub struct AssessmentPart {
         deserialize_with = "validation::deser_uuid_opt"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: Option<String>,
+    pub uuid: Option<OscalUuid>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AssessmentPartBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("class", &self.class)?;
+        validation::check_token("name", &self.name)?;
+        validation::check_uri("ns", &self.ns)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
+}
+
+/// Read only traversal of the model
+///
+/// Every method visits one type and by default walks into its
+/// children, so an implementation only overrides the types it cares
+/// about. Call the matching `walk_*` function from an override to keep
+/// descending.
+pub mod visit {
+    use super::*;
+    pub trait Visit<'ast> {
+        fn visit_assessment_plan(&mut self, node: &'ast AssessmentPlan) {
+            walk_assessment_plan(self, node)
+        }
+        fn visit_security_assessment_plan_sap(
+            &mut self,
+            node: &'ast SecurityAssessmentPlanSap,
+        ) {
+            walk_security_assessment_plan_sap(self, node)
+        }
+        fn visit_assessment_assets(&mut self, node: &'ast AssessmentAssets) {
+            walk_assessment_assets(self, node)
+        }
+        fn visit_assessment_platform(&mut self, node: &'ast AssessmentPlatform) {
+            walk_assessment_platform(self, node)
+        }
+        fn visit_link(&mut self, node: &'ast Link) {
+            walk_link(self, node)
+        }
+        fn visit_property(&mut self, node: &'ast Property) {
+            walk_property(self, node)
+        }
+        fn visit_uses_component(&mut self, node: &'ast UsesComponent) {
+            walk_uses_component(self, node)
+        }
+        fn visit_responsible_party(&mut self, node: &'ast ResponsibleParty) {
+            walk_responsible_party(self, node)
+        }
+        fn visit_component(&mut self, node: &'ast Component) {
+            walk_component(self, node)
+        }
+        fn visit_service_protocol_information(
+            &mut self,
+            node: &'ast ServiceProtocolInformation,
+        ) {
+            walk_service_protocol_information(self, node)
+        }
+        fn visit_port_range(&mut self, node: &'ast PortRange) {
+            walk_port_range(self, node)
+        }
+        fn visit_transport(&mut self, node: &'ast Transport) {
+            walk_transport(self, node)
+        }
+        fn visit_responsible_role(&mut self, node: &'ast ResponsibleRole) {
+            walk_responsible_role(self, node)
+        }
+        fn visit_status(&mut self, node: &'ast Status) {
+            walk_status(self, node)
+        }
+        fn visit_state(&mut self, node: &'ast State) {
+            walk_state(self, node)
+        }
+        fn visit_subject_of_assessment(&mut self, node: &'ast SubjectOfAssessment) {
+            walk_subject_of_assessment(self, node)
+        }
+        fn visit_select_assessment_subject(
+            &mut self,
+            node: &'ast SelectAssessmentSubject,
+        ) {
+            walk_select_assessment_subject(self, node)
+        }
+        fn visit_include_all(&mut self, node: &'ast IncludeAll) {
+            walk_include_all(self, node)
+        }
+        fn visit_back_matter(&mut self, node: &'ast BackMatter) {
+            walk_back_matter(self, node)
+        }
+        fn visit_resource(&mut self, node: &'ast Resource) {
+            walk_resource(self, node)
+        }
+        fn visit_base64(&mut self, node: &'ast Base64) {
+            walk_base64(self, node)
+        }
+        fn visit_citation(&mut self, node: &'ast Citation) {
+            walk_citation(self, node)
+        }
+        fn visit_document_identifier(&mut self, node: &'ast DocumentIdentifier) {
+            walk_document_identifier(self, node)
+        }
+        fn visit_resource_link(&mut self, node: &'ast ResourceLink) {
+            walk_resource_link(self, node)
+        }
+        fn visit_hash(&mut self, node: &'ast Hash) {
+            walk_hash(self, node)
+        }
+        fn visit_import_system_security_plan(
+            &mut self,
+            node: &'ast ImportSystemSecurityPlan,
+        ) {
+            walk_import_system_security_plan(self, node)
+        }
+        fn visit_local_definitions(&mut self, node: &'ast LocalDefinitions) {
+            walk_local_definitions(self, node)
+        }
+        fn visit_activity(&mut self, node: &'ast Activity) {
+            walk_activity(self, node)
+        }
+        fn visit_reviewed_controls_and_control_objectives(
+            &mut self,
+            node: &'ast ReviewedControlsAndControlObjectives,
+        ) {
+            walk_reviewed_controls_and_control_objectives(self, node)
+        }
+        fn visit_referenced_control_objectives(
+            &mut self,
+            node: &'ast ReferencedControlObjectives,
+        ) {
+            walk_referenced_control_objectives(self, node)
+        }
+        fn visit_select_objective(&mut self, node: &'ast SelectObjective) {
+            walk_select_objective(self, node)
+        }
+        fn visit_assessed_controls(&mut self, node: &'ast AssessedControls) {
+            walk_assessed_controls(self, node)
+        }
+        fn visit_select_control(&mut self, node: &'ast SelectControl) {
+            walk_select_control(self, node)
+        }
+        fn visit_step(&mut self, node: &'ast Step) {
+            walk_step(self, node)
+        }
+        fn visit_inventory_item(&mut self, node: &'ast InventoryItem) {
+            walk_inventory_item(self, node)
+        }
+        fn visit_implemented_component(&mut self, node: &'ast ImplementedComponent) {
+            walk_implemented_component(self, node)
+        }
+        fn visit_assessment_specific_control_objective(
+            &mut self,
+            node: &'ast AssessmentSpecificControlObjective,
+        ) {
+            walk_assessment_specific_control_objective(self, node)
+        }
+        fn visit_part(&mut self, node: &'ast Part) {
+            walk_part(self, node)
+        }
+        fn visit_system_user(&mut self, node: &'ast SystemUser) {
+            walk_system_user(self, node)
+        }
+        fn visit_privilege(&mut self, node: &'ast Privilege) {
+            walk_privilege(self, node)
+        }
+        fn visit_document_metadata(&mut self, node: &'ast DocumentMetadata) {
+            walk_document_metadata(self, node)
+        }
+        fn visit_action(&mut self, node: &'ast Action) {
+            walk_action(self, node)
+        }
+        fn visit_location(&mut self, node: &'ast Location) {
+            walk_location(self, node)
+        }
+        fn visit_address(&mut self, node: &'ast Address) {
+            walk_address(self, node)
+        }
+        fn visit_telephone_number(&mut self, node: &'ast TelephoneNumber) {
+            walk_telephone_number(self, node)
+        }
+        fn visit_party(&mut self, node: &'ast Party) {
+            walk_party(self, node)
+        }
+        fn visit_party_external_identifier(
+            &mut self,
+            node: &'ast PartyExternalIdentifier,
+        ) {
+            walk_party_external_identifier(self, node)
+        }
+        fn visit_party_type(&mut self, node: &'ast PartyType) {
+            walk_party_type(self, node)
+        }
+        fn visit_revision_history_entry(&mut self, node: &'ast RevisionHistoryEntry) {
+            walk_revision_history_entry(self, node)
+        }
+        fn visit_role(&mut self, node: &'ast Role) {
+            walk_role(self, node)
+        }
+        fn visit_task(&mut self, node: &'ast Task) {
+            walk_task(self, node)
+        }
+        fn visit_associated_activity(&mut self, node: &'ast AssociatedActivity) {
+            walk_associated_activity(self, node)
+        }
+        fn visit_task_dependency(&mut self, node: &'ast TaskDependency) {
+            walk_task_dependency(self, node)
+        }
+        fn visit_event_timing(&mut self, node: &'ast EventTiming) {
+            walk_event_timing(self, node)
+        }
+        fn visit_frequency_condition(&mut self, node: &'ast FrequencyCondition) {
+            walk_frequency_condition(self, node)
+        }
+        fn visit_time_unit(&mut self, node: &'ast TimeUnit) {
+            walk_time_unit(self, node)
+        }
+        fn visit_on_date_condition(&mut self, node: &'ast OnDateCondition) {
+            walk_on_date_condition(self, node)
+        }
+        fn visit_on_date_range_condition(&mut self, node: &'ast OnDateRangeCondition) {
+            walk_on_date_range_condition(self, node)
+        }
+        fn visit_assessment_plan_terms_and_conditions(
+            &mut self,
+            node: &'ast AssessmentPlanTermsAndConditions,
+        ) {
+            walk_assessment_plan_terms_and_conditions(self, node)
+        }
+        fn visit_assessment_part(&mut self, node: &'ast AssessmentPart) {
+            walk_assessment_part(self, node)
+        }
+    }
+    pub fn walk_assessment_plan<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessmentPlan,
+    ) {
+        v.visit_security_assessment_plan_sap(&node.assessment_plan);
+    }
+    pub fn walk_security_assessment_plan_sap<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SecurityAssessmentPlanSap,
+    ) {
+        if let Some(it) = &node.assessment_assets {
+            v.visit_assessment_assets(it);
+        }
+        if let Some(it) = &node.assessment_subjects {
+            for it in it {
+                v.visit_subject_of_assessment(it);
+            }
+        }
+        if let Some(it) = &node.back_matter {
+            v.visit_back_matter(it);
+        }
+        v.visit_import_system_security_plan(&node.import_ssp);
+        if let Some(it) = &node.local_definitions {
+            v.visit_local_definitions(it);
+        }
+        v.visit_document_metadata(&node.metadata);
+        v.visit_reviewed_controls_and_control_objectives(&node.reviewed_controls);
+        if let Some(it) = &node.tasks {
+            for it in it {
+                v.visit_task(it);
+            }
+        }
+        if let Some(it) = &node.terms_and_conditions {
+            v.visit_assessment_plan_terms_and_conditions(it);
+        }
+    }
+    pub fn walk_assessment_assets<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessmentAssets,
+    ) {
+        for it in &node.assessment_platforms {
+            v.visit_assessment_platform(it);
+        }
+        if let Some(it) = &node.components {
+            for it in it {
+                v.visit_component(it);
+            }
+        }
+    }
+    pub fn walk_assessment_platform<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessmentPlatform,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.uses_components {
+            for it in it {
+                v.visit_uses_component(it);
+            }
+        }
+    }
+    pub fn walk_link<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Link) {}
+    pub fn walk_property<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Property,
+    ) {}
+    pub fn walk_uses_component<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast UsesComponent,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+    }
+    pub fn walk_responsible_party<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ResponsibleParty,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_component<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Component,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.protocols {
+            for it in it {
+                v.visit_service_protocol_information(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+        v.visit_status(&node.status);
+    }
+    pub fn walk_service_protocol_information<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ServiceProtocolInformation,
+    ) {
+        if let Some(it) = &node.port_ranges {
+            for it in it {
+                v.visit_port_range(it);
+            }
+        }
+    }
+    pub fn walk_port_range<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast PortRange,
+    ) {
+        if let Some(it) = &node.transport {
+            v.visit_transport(it);
+        }
+    }
+    pub fn walk_transport<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Transport,
+    ) {}
+    pub fn walk_responsible_role<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ResponsibleRole,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_status<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Status) {
+        v.visit_state(&node.state);
+    }
+    pub fn walk_state<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast State) {}
+    pub fn walk_subject_of_assessment<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SubjectOfAssessment,
+    ) {
+        if let Some(it) = &node.exclude_subjects {
+            for it in it {
+                v.visit_select_assessment_subject(it);
+            }
+        }
+        if let Some(it) = &node.include_all {
+            v.visit_include_all(it);
+        }
+        if let Some(it) = &node.include_subjects {
+            for it in it {
+                v.visit_select_assessment_subject(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_select_assessment_subject<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SelectAssessmentSubject,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_include_all<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast IncludeAll,
+    ) {}
+    pub fn walk_back_matter<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast BackMatter,
+    ) {
+        if let Some(it) = &node.resources {
+            for it in it {
+                v.visit_resource(it);
+            }
+        }
+    }
+    pub fn walk_resource<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Resource,
+    ) {
+        if let Some(it) = &node.base64 {
+            v.visit_base64(it);
+        }
+        if let Some(it) = &node.citation {
+            v.visit_citation(it);
+        }
+        if let Some(it) = &node.document_ids {
+            for it in it {
+                v.visit_document_identifier(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.rlinks {
+            for it in it {
+                v.visit_resource_link(it);
+            }
+        }
+    }
+    pub fn walk_base64<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Base64) {}
+    pub fn walk_citation<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Citation,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_document_identifier<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast DocumentIdentifier,
+    ) {}
+    pub fn walk_resource_link<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ResourceLink,
+    ) {
+        if let Some(it) = &node.hashes {
+            for it in it {
+                v.visit_hash(it);
+            }
+        }
+    }
+    pub fn walk_hash<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Hash) {}
+    pub fn walk_import_system_security_plan<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast ImportSystemSecurityPlan,
+    ) {}
+    pub fn walk_local_definitions<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast LocalDefinitions,
+    ) {
+        if let Some(it) = &node.activities {
+            for it in it {
+                v.visit_activity(it);
+            }
+        }
+        if let Some(it) = &node.components {
+            for it in it {
+                v.visit_component(it);
+            }
+        }
+        if let Some(it) = &node.inventory_items {
+            for it in it {
+                v.visit_inventory_item(it);
+            }
+        }
+        if let Some(it) = &node.objectives_and_methods {
+            for it in it {
+                v.visit_assessment_specific_control_objective(it);
+            }
+        }
+        if let Some(it) = &node.users {
+            for it in it {
+                v.visit_system_user(it);
+            }
+        }
+    }
+    pub fn walk_activity<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Activity,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.related_controls {
+            v.visit_reviewed_controls_and_control_objectives(it);
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+        if let Some(it) = &node.steps {
+            for it in it {
+                v.visit_step(it);
+            }
+        }
+    }
+    pub fn walk_reviewed_controls_and_control_objectives<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ReviewedControlsAndControlObjectives,
+    ) {
+        if let Some(it) = &node.control_objective_selections {
+            for it in it {
+                v.visit_referenced_control_objectives(it);
+            }
+        }
+        for it in &node.control_selections {
+            v.visit_assessed_controls(it);
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_referenced_control_objectives<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ReferencedControlObjectives,
+    ) {
+        if let Some(it) = &node.exclude_objectives {
+            for it in it {
+                v.visit_select_objective(it);
+            }
+        }
+        if let Some(it) = &node.include_all {
+            v.visit_include_all(it);
+        }
+        if let Some(it) = &node.include_objectives {
+            for it in it {
+                v.visit_select_objective(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_select_objective<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast SelectObjective,
+    ) {}
+    pub fn walk_assessed_controls<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessedControls,
+    ) {
+        if let Some(it) = &node.exclude_controls {
+            for it in it {
+                v.visit_select_control(it);
+            }
+        }
+        if let Some(it) = &node.include_all {
+            v.visit_include_all(it);
+        }
+        if let Some(it) = &node.include_controls {
+            for it in it {
+                v.visit_select_control(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_select_control<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast SelectControl,
+    ) {}
+    pub fn walk_step<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Step) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+        if let Some(it) = &node.reviewed_controls {
+            v.visit_reviewed_controls_and_control_objectives(it);
+        }
+    }
+    pub fn walk_inventory_item<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast InventoryItem,
+    ) {
+        if let Some(it) = &node.implemented_components {
+            for it in it {
+                v.visit_implemented_component(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+    }
+    pub fn walk_implemented_component<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ImplementedComponent,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+    }
+    pub fn walk_assessment_specific_control_objective<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessmentSpecificControlObjective,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        for it in &node.parts {
+            v.visit_part(it);
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_part<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Part) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.parts {
+            for it in it {
+                v.visit_part(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_system_user<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SystemUser,
+    ) {
+        if let Some(it) = &node.authorized_privileges {
+            for it in it {
+                v.visit_privilege(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_privilege<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Privilege,
+    ) {}
+    pub fn walk_document_metadata<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast DocumentMetadata,
+    ) {
+        if let Some(it) = &node.actions {
+            for it in it {
+                v.visit_action(it);
+            }
+        }
+        if let Some(it) = &node.document_ids {
+            for it in it {
+                v.visit_document_identifier(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.locations {
+            for it in it {
+                v.visit_location(it);
+            }
+        }
+        if let Some(it) = &node.parties {
+            for it in it {
+                v.visit_party(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+        if let Some(it) = &node.revisions {
+            for it in it {
+                v.visit_revision_history_entry(it);
+            }
+        }
+        if let Some(it) = &node.roles {
+            for it in it {
+                v.visit_role(it);
+            }
+        }
+    }
+    pub fn walk_action<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Action) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+    }
+    pub fn walk_location<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Location,
+    ) {
+        if let Some(it) = &node.address {
+            v.visit_address(it);
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number(it);
+            }
+        }
+    }
+    pub fn walk_address<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Address,
+    ) {}
+    pub fn walk_telephone_number<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast TelephoneNumber,
+    ) {}
+    pub fn walk_party<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Party) {
+        if let Some(it) = &node.addresses {
+            for it in it {
+                v.visit_address(it);
+            }
+        }
+        if let Some(it) = &node.external_ids {
+            for it in it {
+                v.visit_party_external_identifier(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number(it);
+            }
+        }
+        v.visit_party_type(&node.party_type);
+    }
+    pub fn walk_party_external_identifier<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast PartyExternalIdentifier,
+    ) {}
+    pub fn walk_party_type<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast PartyType,
+    ) {}
+    pub fn walk_revision_history_entry<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast RevisionHistoryEntry,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_role<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Role) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_task<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Task) {
+        if let Some(it) = &node.associated_activities {
+            for it in it {
+                v.visit_associated_activity(it);
+            }
+        }
+        if let Some(it) = &node.dependencies {
+            for it in it {
+                v.visit_task_dependency(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+        if let Some(it) = &node.subjects {
+            for it in it {
+                v.visit_subject_of_assessment(it);
+            }
+        }
+        if let Some(it) = &node.tasks {
+            for it in it {
+                v.visit_task(it);
+            }
+        }
+        if let Some(it) = &node.timing {
+            v.visit_event_timing(it);
+        }
+    }
+    pub fn walk_associated_activity<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssociatedActivity,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+        for it in &node.subjects {
+            v.visit_subject_of_assessment(it);
+        }
+    }
+    pub fn walk_task_dependency<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast TaskDependency,
+    ) {}
+    pub fn walk_event_timing<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast EventTiming,
+    ) {
+        if let Some(it) = &node.at_frequency {
+            v.visit_frequency_condition(it);
+        }
+        if let Some(it) = &node.on_date {
+            v.visit_on_date_condition(it);
+        }
+        if let Some(it) = &node.within_date_range {
+            v.visit_on_date_range_condition(it);
+        }
+    }
+    pub fn walk_frequency_condition<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast FrequencyCondition,
+    ) {
+        v.visit_time_unit(&node.unit);
+    }
+    pub fn walk_time_unit<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast TimeUnit,
+    ) {}
+    pub fn walk_on_date_condition<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast OnDateCondition,
+    ) {}
+    pub fn walk_on_date_range_condition<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast OnDateRangeCondition,
+    ) {}
+    pub fn walk_assessment_plan_terms_and_conditions<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessmentPlanTermsAndConditions,
+    ) {
+        if let Some(it) = &node.parts {
+            for it in it {
+                v.visit_assessment_part(it);
+            }
+        }
+    }
+    pub fn walk_assessment_part<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessmentPart,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.parts {
+            for it in it {
+                v.visit_assessment_part(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+}
+/// In place traversal of the model
+///
+/// Mutable counterpart of [`visit`](super::visit).
+pub mod visit_mut {
+    use super::*;
+    pub trait VisitMut {
+        fn visit_assessment_plan_mut(&mut self, node: &mut AssessmentPlan) {
+            walk_assessment_plan_mut(self, node)
+        }
+        fn visit_security_assessment_plan_sap_mut(
+            &mut self,
+            node: &mut SecurityAssessmentPlanSap,
+        ) {
+            walk_security_assessment_plan_sap_mut(self, node)
+        }
+        fn visit_assessment_assets_mut(&mut self, node: &mut AssessmentAssets) {
+            walk_assessment_assets_mut(self, node)
+        }
+        fn visit_assessment_platform_mut(&mut self, node: &mut AssessmentPlatform) {
+            walk_assessment_platform_mut(self, node)
+        }
+        fn visit_link_mut(&mut self, node: &mut Link) {
+            walk_link_mut(self, node)
+        }
+        fn visit_property_mut(&mut self, node: &mut Property) {
+            walk_property_mut(self, node)
+        }
+        fn visit_uses_component_mut(&mut self, node: &mut UsesComponent) {
+            walk_uses_component_mut(self, node)
+        }
+        fn visit_responsible_party_mut(&mut self, node: &mut ResponsibleParty) {
+            walk_responsible_party_mut(self, node)
+        }
+        fn visit_component_mut(&mut self, node: &mut Component) {
+            walk_component_mut(self, node)
+        }
+        fn visit_service_protocol_information_mut(
+            &mut self,
+            node: &mut ServiceProtocolInformation,
+        ) {
+            walk_service_protocol_information_mut(self, node)
+        }
+        fn visit_port_range_mut(&mut self, node: &mut PortRange) {
+            walk_port_range_mut(self, node)
+        }
+        fn visit_transport_mut(&mut self, node: &mut Transport) {
+            walk_transport_mut(self, node)
+        }
+        fn visit_responsible_role_mut(&mut self, node: &mut ResponsibleRole) {
+            walk_responsible_role_mut(self, node)
+        }
+        fn visit_status_mut(&mut self, node: &mut Status) {
+            walk_status_mut(self, node)
+        }
+        fn visit_state_mut(&mut self, node: &mut State) {
+            walk_state_mut(self, node)
+        }
+        fn visit_subject_of_assessment_mut(&mut self, node: &mut SubjectOfAssessment) {
+            walk_subject_of_assessment_mut(self, node)
+        }
+        fn visit_select_assessment_subject_mut(
+            &mut self,
+            node: &mut SelectAssessmentSubject,
+        ) {
+            walk_select_assessment_subject_mut(self, node)
+        }
+        fn visit_include_all_mut(&mut self, node: &mut IncludeAll) {
+            walk_include_all_mut(self, node)
+        }
+        fn visit_back_matter_mut(&mut self, node: &mut BackMatter) {
+            walk_back_matter_mut(self, node)
+        }
+        fn visit_resource_mut(&mut self, node: &mut Resource) {
+            walk_resource_mut(self, node)
+        }
+        fn visit_base64_mut(&mut self, node: &mut Base64) {
+            walk_base64_mut(self, node)
+        }
+        fn visit_citation_mut(&mut self, node: &mut Citation) {
+            walk_citation_mut(self, node)
+        }
+        fn visit_document_identifier_mut(&mut self, node: &mut DocumentIdentifier) {
+            walk_document_identifier_mut(self, node)
+        }
+        fn visit_resource_link_mut(&mut self, node: &mut ResourceLink) {
+            walk_resource_link_mut(self, node)
+        }
+        fn visit_hash_mut(&mut self, node: &mut Hash) {
+            walk_hash_mut(self, node)
+        }
+        fn visit_import_system_security_plan_mut(
+            &mut self,
+            node: &mut ImportSystemSecurityPlan,
+        ) {
+            walk_import_system_security_plan_mut(self, node)
+        }
+        fn visit_local_definitions_mut(&mut self, node: &mut LocalDefinitions) {
+            walk_local_definitions_mut(self, node)
+        }
+        fn visit_activity_mut(&mut self, node: &mut Activity) {
+            walk_activity_mut(self, node)
+        }
+        fn visit_reviewed_controls_and_control_objectives_mut(
+            &mut self,
+            node: &mut ReviewedControlsAndControlObjectives,
+        ) {
+            walk_reviewed_controls_and_control_objectives_mut(self, node)
+        }
+        fn visit_referenced_control_objectives_mut(
+            &mut self,
+            node: &mut ReferencedControlObjectives,
+        ) {
+            walk_referenced_control_objectives_mut(self, node)
+        }
+        fn visit_select_objective_mut(&mut self, node: &mut SelectObjective) {
+            walk_select_objective_mut(self, node)
+        }
+        fn visit_assessed_controls_mut(&mut self, node: &mut AssessedControls) {
+            walk_assessed_controls_mut(self, node)
+        }
+        fn visit_select_control_mut(&mut self, node: &mut SelectControl) {
+            walk_select_control_mut(self, node)
+        }
+        fn visit_step_mut(&mut self, node: &mut Step) {
+            walk_step_mut(self, node)
+        }
+        fn visit_inventory_item_mut(&mut self, node: &mut InventoryItem) {
+            walk_inventory_item_mut(self, node)
+        }
+        fn visit_implemented_component_mut(&mut self, node: &mut ImplementedComponent) {
+            walk_implemented_component_mut(self, node)
+        }
+        fn visit_assessment_specific_control_objective_mut(
+            &mut self,
+            node: &mut AssessmentSpecificControlObjective,
+        ) {
+            walk_assessment_specific_control_objective_mut(self, node)
+        }
+        fn visit_part_mut(&mut self, node: &mut Part) {
+            walk_part_mut(self, node)
+        }
+        fn visit_system_user_mut(&mut self, node: &mut SystemUser) {
+            walk_system_user_mut(self, node)
+        }
+        fn visit_privilege_mut(&mut self, node: &mut Privilege) {
+            walk_privilege_mut(self, node)
+        }
+        fn visit_document_metadata_mut(&mut self, node: &mut DocumentMetadata) {
+            walk_document_metadata_mut(self, node)
+        }
+        fn visit_action_mut(&mut self, node: &mut Action) {
+            walk_action_mut(self, node)
+        }
+        fn visit_location_mut(&mut self, node: &mut Location) {
+            walk_location_mut(self, node)
+        }
+        fn visit_address_mut(&mut self, node: &mut Address) {
+            walk_address_mut(self, node)
+        }
+        fn visit_telephone_number_mut(&mut self, node: &mut TelephoneNumber) {
+            walk_telephone_number_mut(self, node)
+        }
+        fn visit_party_mut(&mut self, node: &mut Party) {
+            walk_party_mut(self, node)
+        }
+        fn visit_party_external_identifier_mut(
+            &mut self,
+            node: &mut PartyExternalIdentifier,
+        ) {
+            walk_party_external_identifier_mut(self, node)
+        }
+        fn visit_party_type_mut(&mut self, node: &mut PartyType) {
+            walk_party_type_mut(self, node)
+        }
+        fn visit_revision_history_entry_mut(&mut self, node: &mut RevisionHistoryEntry) {
+            walk_revision_history_entry_mut(self, node)
+        }
+        fn visit_role_mut(&mut self, node: &mut Role) {
+            walk_role_mut(self, node)
+        }
+        fn visit_task_mut(&mut self, node: &mut Task) {
+            walk_task_mut(self, node)
+        }
+        fn visit_associated_activity_mut(&mut self, node: &mut AssociatedActivity) {
+            walk_associated_activity_mut(self, node)
+        }
+        fn visit_task_dependency_mut(&mut self, node: &mut TaskDependency) {
+            walk_task_dependency_mut(self, node)
+        }
+        fn visit_event_timing_mut(&mut self, node: &mut EventTiming) {
+            walk_event_timing_mut(self, node)
+        }
+        fn visit_frequency_condition_mut(&mut self, node: &mut FrequencyCondition) {
+            walk_frequency_condition_mut(self, node)
+        }
+        fn visit_time_unit_mut(&mut self, node: &mut TimeUnit) {
+            walk_time_unit_mut(self, node)
+        }
+        fn visit_on_date_condition_mut(&mut self, node: &mut OnDateCondition) {
+            walk_on_date_condition_mut(self, node)
+        }
+        fn visit_on_date_range_condition_mut(
+            &mut self,
+            node: &mut OnDateRangeCondition,
+        ) {
+            walk_on_date_range_condition_mut(self, node)
+        }
+        fn visit_assessment_plan_terms_and_conditions_mut(
+            &mut self,
+            node: &mut AssessmentPlanTermsAndConditions,
+        ) {
+            walk_assessment_plan_terms_and_conditions_mut(self, node)
+        }
+        fn visit_assessment_part_mut(&mut self, node: &mut AssessmentPart) {
+            walk_assessment_part_mut(self, node)
+        }
+    }
+    pub fn walk_assessment_plan_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessmentPlan,
+    ) {
+        v.visit_security_assessment_plan_sap_mut(&mut node.assessment_plan);
+    }
+    pub fn walk_security_assessment_plan_sap_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut SecurityAssessmentPlanSap,
+    ) {
+        if let Some(it) = &mut node.assessment_assets {
+            v.visit_assessment_assets_mut(it);
+        }
+        if let Some(it) = &mut node.assessment_subjects {
+            for it in it {
+                v.visit_subject_of_assessment_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.back_matter {
+            v.visit_back_matter_mut(it);
+        }
+        v.visit_import_system_security_plan_mut(&mut node.import_ssp);
+        if let Some(it) = &mut node.local_definitions {
+            v.visit_local_definitions_mut(it);
+        }
+        v.visit_document_metadata_mut(&mut node.metadata);
+        v.visit_reviewed_controls_and_control_objectives_mut(
+            &mut node.reviewed_controls,
+        );
+        if let Some(it) = &mut node.tasks {
+            for it in it {
+                v.visit_task_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.terms_and_conditions {
+            v.visit_assessment_plan_terms_and_conditions_mut(it);
+        }
+    }
+    pub fn walk_assessment_assets_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessmentAssets,
+    ) {
+        for it in &mut node.assessment_platforms {
+            v.visit_assessment_platform_mut(it);
+        }
+        if let Some(it) = &mut node.components {
+            for it in it {
+                v.visit_component_mut(it);
+            }
+        }
+    }
+    pub fn walk_assessment_platform_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessmentPlatform,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.uses_components {
+            for it in it {
+                v.visit_uses_component_mut(it);
+            }
+        }
+    }
+    pub fn walk_link_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Link) {}
+    pub fn walk_property_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Property) {}
+    pub fn walk_uses_component_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut UsesComponent,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+    }
+    pub fn walk_responsible_party_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ResponsibleParty,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_component_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Component) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.protocols {
+            for it in it {
+                v.visit_service_protocol_information_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+        v.visit_status_mut(&mut node.status);
+    }
+    pub fn walk_service_protocol_information_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ServiceProtocolInformation,
+    ) {
+        if let Some(it) = &mut node.port_ranges {
+            for it in it {
+                v.visit_port_range_mut(it);
+            }
+        }
+    }
+    pub fn walk_port_range_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PortRange) {
+        if let Some(it) = &mut node.transport {
+            v.visit_transport_mut(it);
+        }
+    }
+    pub fn walk_transport_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Transport) {}
+    pub fn walk_responsible_role_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ResponsibleRole,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_status_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Status) {
+        v.visit_state_mut(&mut node.state);
+    }
+    pub fn walk_state_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut State) {}
+    pub fn walk_subject_of_assessment_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut SubjectOfAssessment,
+    ) {
+        if let Some(it) = &mut node.exclude_subjects {
+            for it in it {
+                v.visit_select_assessment_subject_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.include_all {
+            v.visit_include_all_mut(it);
+        }
+        if let Some(it) = &mut node.include_subjects {
+            for it in it {
+                v.visit_select_assessment_subject_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_select_assessment_subject_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut SelectAssessmentSubject,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_include_all_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut IncludeAll,
+    ) {}
+    pub fn walk_back_matter_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut BackMatter) {
+        if let Some(it) = &mut node.resources {
+            for it in it {
+                v.visit_resource_mut(it);
+            }
+        }
+    }
+    pub fn walk_resource_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Resource) {
+        if let Some(it) = &mut node.base64 {
+            v.visit_base64_mut(it);
+        }
+        if let Some(it) = &mut node.citation {
+            v.visit_citation_mut(it);
+        }
+        if let Some(it) = &mut node.document_ids {
+            for it in it {
+                v.visit_document_identifier_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.rlinks {
+            for it in it {
+                v.visit_resource_link_mut(it);
+            }
+        }
+    }
+    pub fn walk_base64_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Base64) {}
+    pub fn walk_citation_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Citation) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_document_identifier_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut DocumentIdentifier,
+    ) {}
+    pub fn walk_resource_link_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ResourceLink,
+    ) {
+        if let Some(it) = &mut node.hashes {
+            for it in it {
+                v.visit_hash_mut(it);
+            }
+        }
+    }
+    pub fn walk_hash_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Hash) {}
+    pub fn walk_import_system_security_plan_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut ImportSystemSecurityPlan,
+    ) {}
+    pub fn walk_local_definitions_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut LocalDefinitions,
+    ) {
+        if let Some(it) = &mut node.activities {
+            for it in it {
+                v.visit_activity_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.components {
+            for it in it {
+                v.visit_component_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.inventory_items {
+            for it in it {
+                v.visit_inventory_item_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.objectives_and_methods {
+            for it in it {
+                v.visit_assessment_specific_control_objective_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.users {
+            for it in it {
+                v.visit_system_user_mut(it);
+            }
+        }
+    }
+    pub fn walk_activity_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Activity) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.related_controls {
+            v.visit_reviewed_controls_and_control_objectives_mut(it);
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.steps {
+            for it in it {
+                v.visit_step_mut(it);
+            }
+        }
+    }
+    pub fn walk_reviewed_controls_and_control_objectives_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ReviewedControlsAndControlObjectives,
+    ) {
+        if let Some(it) = &mut node.control_objective_selections {
+            for it in it {
+                v.visit_referenced_control_objectives_mut(it);
+            }
+        }
+        for it in &mut node.control_selections {
+            v.visit_assessed_controls_mut(it);
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_referenced_control_objectives_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ReferencedControlObjectives,
+    ) {
+        if let Some(it) = &mut node.exclude_objectives {
+            for it in it {
+                v.visit_select_objective_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.include_all {
+            v.visit_include_all_mut(it);
+        }
+        if let Some(it) = &mut node.include_objectives {
+            for it in it {
+                v.visit_select_objective_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_select_objective_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut SelectObjective,
+    ) {}
+    pub fn walk_assessed_controls_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessedControls,
+    ) {
+        if let Some(it) = &mut node.exclude_controls {
+            for it in it {
+                v.visit_select_control_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.include_all {
+            v.visit_include_all_mut(it);
+        }
+        if let Some(it) = &mut node.include_controls {
+            for it in it {
+                v.visit_select_control_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_select_control_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut SelectControl,
+    ) {}
+    pub fn walk_step_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Step) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.reviewed_controls {
+            v.visit_reviewed_controls_and_control_objectives_mut(it);
+        }
+    }
+    pub fn walk_inventory_item_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut InventoryItem,
+    ) {
+        if let Some(it) = &mut node.implemented_components {
+            for it in it {
+                v.visit_implemented_component_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+    }
+    pub fn walk_implemented_component_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ImplementedComponent,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+    }
+    pub fn walk_assessment_specific_control_objective_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessmentSpecificControlObjective,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        for it in &mut node.parts {
+            v.visit_part_mut(it);
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_part_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Part) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.parts {
+            for it in it {
+                v.visit_part_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_system_user_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SystemUser) {
+        if let Some(it) = &mut node.authorized_privileges {
+            for it in it {
+                v.visit_privilege_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_privilege_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Privilege) {}
+    pub fn walk_document_metadata_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut DocumentMetadata,
+    ) {
+        if let Some(it) = &mut node.actions {
+            for it in it {
+                v.visit_action_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.document_ids {
+            for it in it {
+                v.visit_document_identifier_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.locations {
+            for it in it {
+                v.visit_location_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.parties {
+            for it in it {
+                v.visit_party_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.revisions {
+            for it in it {
+                v.visit_revision_history_entry_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.roles {
+            for it in it {
+                v.visit_role_mut(it);
+            }
+        }
+    }
+    pub fn walk_action_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Action) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+    }
+    pub fn walk_location_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Location) {
+        if let Some(it) = &mut node.address {
+            v.visit_address_mut(it);
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number_mut(it);
+            }
+        }
+    }
+    pub fn walk_address_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Address) {}
+    pub fn walk_telephone_number_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut TelephoneNumber,
+    ) {}
+    pub fn walk_party_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Party) {
+        if let Some(it) = &mut node.addresses {
+            for it in it {
+                v.visit_address_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.external_ids {
+            for it in it {
+                v.visit_party_external_identifier_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number_mut(it);
+            }
+        }
+        v.visit_party_type_mut(&mut node.party_type);
+    }
+    pub fn walk_party_external_identifier_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut PartyExternalIdentifier,
+    ) {}
+    pub fn walk_party_type_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut PartyType) {}
+    pub fn walk_revision_history_entry_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut RevisionHistoryEntry,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_role_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Role) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_task_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Task) {
+        if let Some(it) = &mut node.associated_activities {
+            for it in it {
+                v.visit_associated_activity_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.dependencies {
+            for it in it {
+                v.visit_task_dependency_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.subjects {
+            for it in it {
+                v.visit_subject_of_assessment_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.tasks {
+            for it in it {
+                v.visit_task_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.timing {
+            v.visit_event_timing_mut(it);
+        }
+    }
+    pub fn walk_associated_activity_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssociatedActivity,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+        for it in &mut node.subjects {
+            v.visit_subject_of_assessment_mut(it);
+        }
+    }
+    pub fn walk_task_dependency_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut TaskDependency,
+    ) {}
+    pub fn walk_event_timing_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut EventTiming,
+    ) {
+        if let Some(it) = &mut node.at_frequency {
+            v.visit_frequency_condition_mut(it);
+        }
+        if let Some(it) = &mut node.on_date {
+            v.visit_on_date_condition_mut(it);
+        }
+        if let Some(it) = &mut node.within_date_range {
+            v.visit_on_date_range_condition_mut(it);
+        }
+    }
+    pub fn walk_frequency_condition_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut FrequencyCondition,
+    ) {
+        v.visit_time_unit_mut(&mut node.unit);
+    }
+    pub fn walk_time_unit_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut TimeUnit) {}
+    pub fn walk_on_date_condition_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut OnDateCondition,
+    ) {}
+    pub fn walk_on_date_range_condition_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut OnDateRangeCondition,
+    ) {}
+    pub fn walk_assessment_plan_terms_and_conditions_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessmentPlanTermsAndConditions,
+    ) {
+        if let Some(it) = &mut node.parts {
+            for it in it {
+                v.visit_assessment_part_mut(it);
+            }
+        }
+    }
+    pub fn walk_assessment_part_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessmentPart,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.parts {
+            for it in it {
+                v.visit_assessment_part_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
 }
@@ -1,21 +1,27 @@
+use crate::markup::Markup;
+use crate::parse::Extensions;
+use crate::scalar::{DateTimeWithTz, EmailAddress, OscalUuid, Token, Uri, UriReference};
+use crate::validation;
 use derivative::Derivative;
-use strum::EnumString;
 use derive_builder::Builder;
-use serde::{Serialize, Deserialize};
-use crate::validation;
+use serde::{Deserialize, Serialize};
+use strum::EnumString;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessmentResults {
     #[serde(rename = "$schema")]
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schema: Option<String>,
     pub assessment_results: SecurityAssessmentResultsSar,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Security assessment results, such as those provided by a FedRAMP assessor in the FedRAMP
@@ -23,9 +29,8 @@ pub struct AssessmentResults {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SecurityAssessmentResultsSar {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -49,19 +54,32 @@ pub struct SecurityAssessmentResultsSar {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SecurityAssessmentResultsSarBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A collection of resources that may be referenced from within the OSCAL document instance.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct BackMatter {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub resources: Option<Vec<Resource>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A resource associated with content in the containing document instance. A resource may be
@@ -70,9 +88,8 @@ pub struct BackMatter {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Resource {
     /// A resource encoded using the Base64 alphabet defined by RFC 2045.
     #[builder(setter(into, strip_option), default)]
@@ -85,8 +102,7 @@ pub struct Resource {
     /// An optional short summary of the resource used to indicate the purpose of the resource.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub document_ids: Option<Vec<DocumentIdentifier>>,
@@ -95,8 +111,7 @@ pub struct Resource {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rlinks: Option<Vec<ResourceLink>>,
@@ -104,24 +119,33 @@ pub struct Resource {
     /// navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A unique identifier for a resource.
     #[serde(
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ResourceBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A resource encoded using the Base64 alphabet defined by RFC 2045.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Base64 {
     /// Name of the file before it was encoded as Base64 to be embedded in a resource. This is
     /// the name that will be assigned to the file when the file is decoded.
@@ -131,7 +155,7 @@ pub struct Base64 {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub filename: Option<String>,
+    pub filename: Option<Token>,
     /// A label that indicates the nature of a resource, as a data serialization or format.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -141,14 +165,24 @@ pub struct Base64 {
         deserialize_with = "validation::deser_base64"
     )]
     pub value: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl Base64Builder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("filename", &self.filename)?;
+        validation::check_base64("value", &self.value)?;
+        Ok(())
+    }
 }
 
 /// An optional citation consisting of end note text using structured markup.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Citation {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -157,8 +191,11 @@ pub struct Citation {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
     /// A line of citation text.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub text: String,
+    pub text: Markup,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A reference to a local or remote resource, that has a specific relation to the containing
@@ -166,16 +203,15 @@ pub struct Citation {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Link {
     /// A resolvable URL reference to a resource.
     #[serde(
         serialize_with = "validation::ser_uri_ref",
         deserialize_with = "validation::deser_uri_ref"
     )]
-    pub href: String,
+    pub href: UriReference,
     /// A label that indicates the nature of a resource, as a data serialization or format.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -188,7 +224,7 @@ pub struct Link {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub rel: Option<String>,
+    pub rel: Option<Token>,
     /// In case where the href points to a back-matter/resource, this value will indicate the URI
     /// fragment to append to any rlink associated with the resource. This value MUST be URI
     /// encoded.
@@ -198,17 +234,26 @@ pub struct Link {
     /// A textual label to associate with the link, which may be used for presentation in a tool.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub text: Option<String>,
+    pub text: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl LinkBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri_ref("href", &self.href)?;
+        validation::check_token("rel", &self.rel)?;
+        Ok(())
+    }
 }
 
 /// An attribute, characteristic, or quality of the containing object expressed as a
 /// namespace qualified name/value pair.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Property {
     /// A textual label that provides a sub-type or characterization of the property's name.
     #[builder(setter(into, strip_option), default)]
@@ -217,7 +262,7 @@ pub struct Property {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub class: Option<String>,
+    pub class: Option<Token>,
     /// An identifier for relating distinct sets of properties.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -225,14 +270,14 @@ pub struct Property {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub group: Option<String>,
+    pub group: Option<Token>,
     /// A textual label, within a namespace, that uniquely identifies a specific attribute,
     /// characteristic, or quality of the property's containing object.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub name: String,
+    pub name: Token,
     /// A namespace qualifying the property's name. This allows different organizations to
     /// associate distinct semantics with the same name.
     #[builder(setter(into, strip_option), default)]
@@ -241,11 +286,10 @@ pub struct Property {
         serialize_with = "validation::ser_uri_opt",
         deserialize_with = "validation::deser_uri_opt"
     )]
-    pub ns: Option<String>,
+    pub ns: Option<Uri>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A unique identifier for a property.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -254,17 +298,30 @@ pub struct Property {
         deserialize_with = "validation::deser_uuid_opt"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: Option<String>,
+    pub uuid: Option<OscalUuid>,
     /// Indicates the value of the attribute, characteristic, or quality.
     pub value: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PropertyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("class", &self.class)?;
+        validation::check_token("group", &self.group)?;
+        validation::check_token("name", &self.name)?;
+        validation::check_uri("ns", &self.ns)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A document identifier qualified by an identifier scheme.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct DocumentIdentifier {
     pub identifier: String,
     /// Qualifies the kind of document identifier using a URI. If the scheme is not provided the
@@ -275,7 +332,18 @@ pub struct DocumentIdentifier {
         serialize_with = "validation::ser_uri_opt",
         deserialize_with = "validation::deser_uri_opt"
     )]
-    pub scheme: Option<String>,
+    pub scheme: Option<Uri>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl DocumentIdentifierBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri("scheme", &self.scheme)?;
+        Ok(())
+    }
 }
 
 /// A URL-based pointer to an external resource with an optional hash for verification and
@@ -283,9 +351,8 @@ pub struct DocumentIdentifier {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ResourceLink {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -295,20 +362,30 @@ pub struct ResourceLink {
         serialize_with = "validation::ser_uri_ref",
         deserialize_with = "validation::deser_uri_ref"
     )]
-    pub href: String,
+    pub href: UriReference,
     /// A label that indicates the nature of a resource, as a data serialization or format.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub media_type: Option<String>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ResourceLinkBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri_ref("href", &self.href)?;
+        Ok(())
+    }
 }
 
 /// A representation of a cryptographic digest generated over a resource using a specified
 /// hash algorithm.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
+#[serde(try_from = "validation::RawHash")]
 pub struct Hash {
     /// The digest method by which a hash is derived.
     pub algorithm: String,
@@ -317,26 +394,46 @@ pub struct Hash {
         deserialize_with = "validation::deser_hash"
     )]
     pub value: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl HashBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_hash("value", &self.value)?;
+        validation::check_digest(&self.algorithm, &self.value)?;
+        Ok(())
+    }
 }
 
 /// Used by assessment-results to import information about the original plan for assessing
 /// the system.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ImportAssessmentPlan {
     /// A resolvable URL reference to the assessment plan governing the assessment activities.
     #[serde(
         serialize_with = "validation::ser_uri_ref",
         deserialize_with = "validation::deser_uri_ref"
     )]
-    pub href: String,
+    pub href: UriReference,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ImportAssessmentPlanBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri_ref("href", &self.href)?;
+        Ok(())
+    }
 }
 
 /// Used to define data objects that are used in the assessment plan, that do not appear in
@@ -346,7 +443,6 @@ pub struct ImportAssessmentPlan {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessmentResultsLocalDefinitions {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -356,8 +452,11 @@ pub struct AssessmentResultsLocalDefinitions {
     pub objectives_and_methods: Option<Vec<AssessmentSpecificControlObjective>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Identifies an assessment or related process that can be performed. In the assessment
@@ -367,13 +466,11 @@ pub struct AssessmentResultsLocalDefinitions {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Activity {
     /// A human-readable description of this included activity.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -385,8 +482,7 @@ pub struct Activity {
     pub related_controls: Option<ReviewedControlsAndControlObjectives>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
@@ -396,8 +492,7 @@ pub struct Activity {
     /// The title for this included activity.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this assessment activity elsewhere in this or other OSCAL instances. The
     /// locally defined UUID of the activity can be used to reference the data item locally or
@@ -409,7 +504,18 @@ pub struct Activity {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ActivityBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Identifies the controls being assessed and their control objectives.
@@ -418,7 +524,6 @@ pub struct Activity {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ReviewedControlsAndControlObjectives {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -427,8 +532,7 @@ pub struct ReviewedControlsAndControlObjectives {
     /// A human-readable description of control objectives.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -437,8 +541,11 @@ pub struct ReviewedControlsAndControlObjectives {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Identifies the control objectives of the assessment. In the assessment plan, these are
@@ -449,13 +556,11 @@ pub struct ReviewedControlsAndControlObjectives {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ReferencedControlObjectives {
     /// A human-readable description of this collection of control objectives.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub exclude_objectives: Option<Vec<SelectObjective>>,
@@ -473,8 +578,11 @@ pub struct ReferencedControlObjectives {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Used to select a control objective for inclusion/exclusion based on the control
@@ -482,25 +590,38 @@ pub struct ReferencedControlObjectives {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SelectObjective {
     /// Points to an assessment objective.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub objective_id: String,
+    pub objective_id: Token,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SelectObjectiveBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("objective_id", &self.objective_id)?;
+        Ok(())
+    }
 }
 
 /// Include all controls from the imported catalog or profile resources.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
-pub struct IncludeAll {}
+pub struct IncludeAll {
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
 
 /// Identifies the controls being assessed. In the assessment plan, these are the planned
 /// controls. In the assessment results, these are the actual controls, and reflects any
@@ -510,13 +631,11 @@ pub struct IncludeAll {}
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessedControls {
     /// A human-readable description of in-scope controls specified for assessment.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub exclude_controls: Option<Vec<SelectControl>>,
@@ -534,8 +653,11 @@ pub struct AssessedControls {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Used to select a control for inclusion/exclusion based on one or more control
@@ -545,9 +667,8 @@ pub struct AssessedControls {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SelectControl {
     /// A reference to a control with a corresponding id value. When referencing an externally
     /// defined control, the Control Identifier Reference must be used in the context of the
@@ -556,14 +677,26 @@ pub struct SelectControl {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub control_id: String,
+    pub control_id: Token,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(
         serialize_with = "validation::ser_token_vec_opt",
         deserialize_with = "validation::deser_token_vec_opt"
     )]
-    pub statement_ids: Option<Vec<String>>,
+    pub statement_ids: Option<Vec<Token>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SelectControlBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("control_id", &self.control_id)?;
+        validation::check_token("statement_ids", &self.statement_ids)?;
+        Ok(())
+    }
 }
 
 /// A reference to a role with responsibility for performing a function relative to the
@@ -572,9 +705,8 @@ pub struct SelectControl {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ResponsibleRole {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -585,20 +717,31 @@ pub struct ResponsibleRole {
         serialize_with = "validation::ser_uuid_vec_opt",
         deserialize_with = "validation::deser_uuid_vec_opt"
     )]
-    pub party_uuids: Option<Vec<String>>,
+    pub party_uuids: Option<Vec<OscalUuid>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A human-oriented identifier reference to a role performed.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub role_id: String,
+    pub role_id: Token,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ResponsibleRoleBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("party_uuids", &self.party_uuids)?;
+        validation::check_token("role_id", &self.role_id)?;
+        Ok(())
+    }
 }
 
 /// Identifies an individual step in a series of steps related to an activity, such as an
@@ -606,13 +749,11 @@ pub struct ResponsibleRole {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Step {
     /// A human-readable description of this step.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -621,8 +762,7 @@ pub struct Step {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
@@ -632,8 +772,7 @@ pub struct Step {
     /// The title for this step.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this step elsewhere in this or other OSCAL instances. The locally defined
     /// UUID of the step (in a series of steps) can be used to reference the data item locally or
@@ -645,7 +784,18 @@ pub struct Step {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl StepBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A local definition of a control objective for this assessment. Uses catalog syntax for
@@ -655,7 +805,6 @@ pub struct Step {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessmentSpecificControlObjective {
     /// A reference to a control with a corresponding id value. When referencing an externally
     /// defined control, the Control Identifier Reference must be used in the context of the
@@ -674,17 +823,18 @@ pub struct AssessmentSpecificControlObjective {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// An annotated, markup-based textual element of a control's or catalog group's definition,
 /// or a child of another part.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Part {
     /// An optional textual providing a sub-type or characterization of the part's name, or a
     /// category to which the part belongs.
@@ -694,7 +844,7 @@ pub struct Part {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub class: Option<String>,
+    pub class: Option<Token>,
     /// A unique identifier for the part.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -702,7 +852,7 @@ pub struct Part {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub id: Option<String>,
+    pub id: Option<Token>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -712,7 +862,7 @@ pub struct Part {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub name: String,
+    pub name: Token,
     /// An optional namespace qualifying the part's name. This allows different organizations to
     /// associate distinct semantics with the same name.
     #[builder(setter(into, strip_option), default)]
@@ -721,7 +871,7 @@ pub struct Part {
         serialize_with = "validation::ser_uri_opt",
         deserialize_with = "validation::deser_uri_opt"
     )]
-    pub ns: Option<String>,
+    pub ns: Option<Uri>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parts: Option<Vec<Part>>,
@@ -731,14 +881,26 @@ pub struct Part {
     /// Permits multiple paragraphs, lists, tables etc.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub prose: Option<String>,
+    pub prose: Option<Markup>,
     /// An optional name given to the part, which may be used by a tool for display and
     /// navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PartBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("class", &self.class)?;
+        validation::check_token("id", &self.id)?;
+        validation::check_token("name", &self.name)?;
+        validation::check_uri("ns", &self.ns)?;
+        Ok(())
+    }
 }
 
 /// Provides information about the containing document, and defines concepts that are shared
@@ -746,9 +908,8 @@ pub struct Part {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct DocumentMetadata {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -760,7 +921,7 @@ pub struct DocumentMetadata {
         serialize_with = "validation::ser_dttz",
         deserialize_with = "validation::deser_dttz"
     )]
-    pub last_modified: String,
+    pub last_modified: DateTimeWithTz,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -779,8 +940,7 @@ pub struct DocumentMetadata {
     pub published: Option<String>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
@@ -791,18 +951,27 @@ pub struct DocumentMetadata {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub roles: Option<Vec<Role>>,
     /// A name given to the document, which may be used by a tool for display and navigation.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub title: String,
+    pub title: Markup,
     pub version: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl DocumentMetadataBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("last_modified", &self.last_modified)?;
+        Ok(())
+    }
 }
 
 /// An action applied by a role within a given party to the content.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Action {
     /// The date and time when the action occurred.
     #[builder(setter(into, strip_option), default)]
@@ -811,7 +980,7 @@ pub struct Action {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub date: Option<String>,
+    pub date: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -820,8 +989,7 @@ pub struct Action {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
@@ -833,7 +1001,7 @@ pub struct Action {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub action_type: String,
+    pub action_type: Token,
     /// A unique identifier that can be used to reference this defined action elsewhere in an
     /// OSCAL document. A UUID should be consistently used for a given location across revisions
     /// of the document.
@@ -842,7 +1010,20 @@ pub struct Action {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ActionBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("date", &self.date)?;
+        validation::check_token("action_type", &self.action_type)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A reference to a set of persons and/or organizations that have responsibility for
@@ -850,9 +1031,8 @@ pub struct Action {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ResponsibleParty {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -861,20 +1041,31 @@ pub struct ResponsibleParty {
         serialize_with = "validation::ser_uuid_vec",
         deserialize_with = "validation::deser_uuid_vec"
     )]
-    pub party_uuids: Vec<String>,
+    pub party_uuids: Vec<OscalUuid>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A reference to a role performed by a party.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub role_id: String,
+    pub role_id: Token,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ResponsiblePartyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("party_uuids", &self.party_uuids)?;
+        validation::check_token("role_id", &self.role_id)?;
+        Ok(())
+    }
 }
 
 /// A physical point of presence, which may be associated with people, organizations, or
@@ -882,9 +1073,8 @@ pub struct ResponsibleParty {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Location {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -895,7 +1085,7 @@ pub struct Location {
         serialize_with = "validation::ser_email_vec_opt",
         deserialize_with = "validation::deser_email_vec_opt"
     )]
-    pub email_addresses: Option<Vec<String>>,
+    pub email_addresses: Option<Vec<EmailAddress>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -904,39 +1094,49 @@ pub struct Location {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub telephone_numbers: Option<Vec<TelephoneNumber>>,
     /// A name given to the location, which may be used by a tool for display and navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(
         serialize_with = "validation::ser_uri_vec_opt",
         deserialize_with = "validation::deser_uri_vec_opt"
     )]
-    pub urls: Option<Vec<String>>,
+    pub urls: Option<Vec<Uri>>,
     /// A unique ID for the location, for reference.
     #[serde(
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl LocationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_email("email_addresses", &self.email_addresses)?;
+        validation::check_uri("urls", &self.urls)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A postal address for the location.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Address {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -965,15 +1165,24 @@ pub struct Address {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub address_type: Option<String>,
+    pub address_type: Option<Token>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AddressBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("address_type", &self.address_type)?;
+        Ok(())
+    }
 }
 
 /// A telephone service number as defined by ITU-T E.164.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct TelephoneNumber {
     pub number: String,
     /// Indicates the type of phone number.
@@ -981,6 +1190,10 @@ pub struct TelephoneNumber {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub telephone_number_type: Option<String>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// An organization or person, which may be associated with roles or other concepts within
@@ -988,9 +1201,8 @@ pub struct TelephoneNumber {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Party {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1001,7 +1213,7 @@ pub struct Party {
         serialize_with = "validation::ser_email_vec_opt",
         deserialize_with = "validation::deser_email_vec_opt"
     )]
-    pub email_addresses: Option<Vec<String>>,
+    pub email_addresses: Option<Vec<EmailAddress>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub external_ids: Option<Vec<PartyExternalIdentifier>>,
@@ -1017,7 +1229,7 @@ pub struct Party {
         serialize_with = "validation::ser_uuid_vec_opt",
         deserialize_with = "validation::deser_uuid_vec_opt"
     )]
-    pub member_of_organizations: Option<Vec<String>>,
+    pub member_of_organizations: Option<Vec<OscalUuid>>,
     /// The full name of the party. This is typically the legal name associated with the party.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1027,8 +1239,7 @@ pub struct Party {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A short common name, abbreviation, or acronym for the party.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1045,16 +1256,27 @@ pub struct Party {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PartyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_email("email_addresses", &self.email_addresses)?;
+        validation::check_uuid("member_of_organizations", &self.member_of_organizations)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// An identifier for a person or organization using a designated scheme. e.g. an Open
 /// Researcher and Contributor ID (ORCID).
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct PartyExternalIdentifier {
     pub id: String,
     /// Indicates the type of external identifier.
@@ -1062,7 +1284,18 @@ pub struct PartyExternalIdentifier {
         serialize_with = "validation::ser_uri",
         deserialize_with = "validation::deser_uri"
     )]
-    pub scheme: String,
+    pub scheme: Uri,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PartyExternalIdentifierBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri("scheme", &self.scheme)?;
+        Ok(())
+    }
 }
 /// A category describing the kind of party the object describes.
 ///
@@ -1130,9 +1363,8 @@ pub enum PartyType {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct RevisionHistoryEntry {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1140,7 +1372,7 @@ pub struct RevisionHistoryEntry {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub last_modified: Option<String>,
+    pub last_modified: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1156,39 +1388,47 @@ pub struct RevisionHistoryEntry {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub published: Option<String>,
+    pub published: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A name given to the document revision, which may be used by a tool for display and
     /// navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     pub version: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl RevisionHistoryEntryBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("last_modified", &self.last_modified)?;
+        validation::check_dttz("published", &self.published)?;
+        Ok(())
+    }
 }
 
 /// Defines a function, which might be assigned to a party in a specific situation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Role {
     /// A summary of the role's purpose and associated responsibilities.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     /// A unique identifier for the role.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub id: String,
+    pub id: Token,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1197,14 +1437,24 @@ pub struct Role {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A short common name, abbreviation, or acronym for the role.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub short_name: Option<String>,
     /// A name given to the role, which may be used by a tool for display and navigation.
     pub title: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl RoleBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("id", &self.id)?;
+        Ok(())
+    }
 }
 
 /// Used by the assessment results and POA&M. In the assessment results, this identifies all
@@ -1214,9 +1464,8 @@ pub struct Role {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessmentResult {
     /// A log of all assessment-related actions taken.
     #[builder(setter(into, strip_option), default)]
@@ -1226,8 +1475,7 @@ pub struct AssessmentResult {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub attestations: Option<Vec<AttestationStatements>>,
     /// A human-readable description of this set of test results.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     /// Date/time stamp identifying the end of the evidence collection reflected in these
     /// results. In a continuous motoring scenario, this may contain the same value as start if
     /// appropriate.
@@ -1237,7 +1485,7 @@ pub struct AssessmentResult {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub end: Option<String>,
+    pub end: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub findings: Option<Vec<Finding>>,
@@ -1257,8 +1505,7 @@ pub struct AssessmentResult {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     pub reviewed_controls: ReviewedControlsAndControlObjectives,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1269,10 +1516,9 @@ pub struct AssessmentResult {
         serialize_with = "validation::ser_dttz",
         deserialize_with = "validation::deser_dttz"
     )]
-    pub start: String,
+    pub start: DateTimeWithTz,
     /// The title for this set of results.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub title: String,
+    pub title: Markup,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this set of results in this or other OSCAL instances. The locally defined
     /// UUID of the assessment result can be used to reference the data item locally or globally
@@ -1284,17 +1530,32 @@ pub struct AssessmentResult {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AssessmentResultBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("end", &self.end)?;
+        validation::check_dttz("start", &self.start)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A log of all assessment-related actions taken.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessmentLog {
     pub entries: Vec<AssessmentLogEntry>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Identifies the result of an action and/or task that occurred as part of executing an
@@ -1302,15 +1563,13 @@ pub struct AssessmentLog {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessmentLogEntry {
     /// A human-readable description of this event.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     /// Identifies the end date and time of an event. If the event is a point in time, the start
     /// and end will be the same date and time.
     #[builder(setter(into, strip_option), default)]
@@ -1319,7 +1578,7 @@ pub struct AssessmentLogEntry {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub end: Option<String>,
+    pub end: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1334,19 +1593,17 @@ pub struct AssessmentLogEntry {
     pub related_tasks: Option<Vec<TaskReference>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// Identifies the start date and time of an event.
     #[serde(
         serialize_with = "validation::ser_dttz",
         deserialize_with = "validation::deser_dttz"
     )]
-    pub start: String,
+    pub start: DateTimeWithTz,
     /// The title for this event.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference an assessment event in this or other OSCAL instances. The locally defined
     /// UUID of the assessment log entry can be used to reference the data item locally or
@@ -1358,16 +1615,28 @@ pub struct AssessmentLogEntry {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AssessmentLogEntryBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("end", &self.end)?;
+        validation::check_dttz("start", &self.start)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Used to indicate who created a log entry in what role.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct LoggedBy {
     /// A machine-oriented identifier reference to the party who is making the log entry.
     #[derivative(PartialEq = "ignore")]
@@ -1375,7 +1644,7 @@ pub struct LoggedBy {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub party_uuid: String,
+    pub party_uuid: OscalUuid,
     /// A point to the role-id of the role in which the party is making the log entry.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1383,16 +1652,27 @@ pub struct LoggedBy {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub role_id: Option<String>,
+    pub role_id: Option<Token>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl LoggedByBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("party_uuid", &self.party_uuid)?;
+        validation::check_token("role_id", &self.role_id)?;
+        Ok(())
+    }
 }
 
 /// Identifies an individual task for which the containing object is a consequence of.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct TaskReference {
     /// Used to detail assessment subjects that were identfied by this task.
     #[builder(setter(into, strip_option), default)]
@@ -1406,8 +1686,7 @@ pub struct TaskReference {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
@@ -1420,16 +1699,26 @@ pub struct TaskReference {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub task_uuid: String,
+    pub task_uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl TaskReferenceBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("task_uuid", &self.task_uuid)?;
+        Ok(())
+    }
 }
 
 /// Used to detail assessment subjects that were identfied by this task.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct IdentifiedSubject {
     /// A machine-oriented identifier reference to a unique assessment subject placeholder
     /// defined by this task.
@@ -1438,8 +1727,19 @@ pub struct IdentifiedSubject {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub subject_placeholder_uuid: String,
+    pub subject_placeholder_uuid: OscalUuid,
     pub subjects: Vec<SubjectOfAssessment>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl IdentifiedSubjectBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("subject_placeholder_uuid", &self.subject_placeholder_uuid)?;
+        Ok(())
+    }
 }
 
 /// Identifies system elements being assessed, such as components, inventory items, and
@@ -1450,16 +1750,14 @@ pub struct IdentifiedSubject {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SubjectOfAssessment {
     /// A human-readable description of the collection of subjects being included in this
     /// assessment.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub exclude_subjects: Option<Vec<SelectAssessmentSubject>>,
@@ -1477,8 +1775,7 @@ pub struct SubjectOfAssessment {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// Indicates the type of assessment subject, such as a component, inventory, item, location,
     /// or party represented by this selection statement.
     #[serde(rename = "type")]
@@ -1486,16 +1783,26 @@ pub struct SubjectOfAssessment {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub subject_of_assessment_type: String,
+    pub subject_of_assessment_type: Token,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SubjectOfAssessmentBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("subject_of_assessment_type", &self.subject_of_assessment_type)?;
+        Ok(())
+    }
 }
 
 /// Identifies a set of assessment subjects to include/exclude by UUID.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SelectAssessmentSubject {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1505,8 +1812,7 @@ pub struct SelectAssessmentSubject {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A machine-oriented identifier reference to a component, inventory-item, location, party,
     /// user, or resource using it's UUID.
     #[derivative(PartialEq = "ignore")]
@@ -1514,14 +1820,26 @@ pub struct SelectAssessmentSubject {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub subject_uuid: String,
+    pub subject_uuid: OscalUuid,
     /// Used to indicate the type of object pointed to by the uuid-ref within a subject.
     #[serde(rename = "type")]
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub select_assessment_subject_type: String,
+    pub select_assessment_subject_type: Token,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SelectAssessmentSubjectBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("subject_uuid", &self.subject_uuid)?;
+        validation::check_token("select_assessment_subject_type", &self.select_assessment_subject_type)?;
+        Ok(())
+    }
 }
 
 /// A set of textual statements, typically written by the assessor.
@@ -1530,20 +1848,21 @@ pub struct SelectAssessmentSubject {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AttestationStatements {
     pub parts: Vec<AssessmentPart>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A partition of an assessment plan or results or a child of another part.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessmentPart {
     /// A textual label that provides a sub-type or characterization of the part's name. This can
     /// be used to further distinguish or discriminate between the semantics of multiple parts of
@@ -1554,7 +1873,7 @@ pub struct AssessmentPart {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub class: Option<String>,
+    pub class: Option<Token>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1563,7 +1882,7 @@ pub struct AssessmentPart {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub name: String,
+    pub name: Token,
     /// A namespace qualifying the part's name. This allows different organizations to associate
     /// distinct semantics with the same name.
     #[builder(setter(into, strip_option), default)]
@@ -1572,7 +1891,7 @@ pub struct AssessmentPart {
         serialize_with = "validation::ser_uri_opt",
         deserialize_with = "validation::deser_uri_opt"
     )]
-    pub ns: Option<String>,
+    pub ns: Option<Uri>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parts: Option<Vec<AssessmentPart>>,
@@ -1586,8 +1905,7 @@ pub struct AssessmentPart {
     /// A name given to the part, which may be used by a tool for display and navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this part elsewhere in this or other OSCAL instances. The locally defined
     /// UUID of the part can be used to reference the data item locally or globally (e.g., in an
@@ -1600,20 +1918,32 @@ pub struct AssessmentPart {
         deserialize_with = "validation::deser_uuid_opt"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: Option<String>,
+    pub uuid: Option<OscalUuid>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AssessmentPartBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("class", &self.class)?;
+        validation::check_token("name", &self.name)?;
+        validation::check_uri("ns", &self.ns)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Describes an individual finding.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Finding {
     /// A human-readable description of this finding.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     /// A machine-oriented identifier reference to the implementation statement in the SSP to
     /// which this finding is related.
     #[builder(setter(into, strip_option), default)]
@@ -1622,7 +1952,7 @@ pub struct Finding {
         serialize_with = "validation::ser_uuid_opt",
         deserialize_with = "validation::deser_uuid_opt"
     )]
-    pub implementation_statement_uuid: Option<String>,
+    pub implementation_statement_uuid: Option<OscalUuid>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1640,12 +1970,10 @@ pub struct Finding {
     pub related_risks: Option<Vec<AssociatedRisk>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     pub target: TargetClass,
     /// The title for this finding.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub title: String,
+    pub title: Markup,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this finding in this or other OSCAL instances. The locally defined UUID of
     /// the finding can be used to reference the data item locally or globally (e.g., in an
@@ -1656,7 +1984,19 @@ pub struct Finding {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl FindingBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("implementation_statement_uuid", &self.implementation_statement_uuid)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Identifies the source of the finding, such as a tool, interviewed person, or activity.
@@ -1665,12 +2005,15 @@ pub struct Finding {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Origin {
     pub actors: Vec<OriginatingActor>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub related_tasks: Option<Vec<TaskReference>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// The actor that produces an observation, a finding, or a risk. One or more actor type can
@@ -1678,9 +2021,8 @@ pub struct Origin {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct OriginatingActor {
     /// A machine-oriented identifier reference to the tool or person based on the associated
     /// type.
@@ -1689,7 +2031,7 @@ pub struct OriginatingActor {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub actor_uuid: String,
+    pub actor_uuid: OscalUuid,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1703,10 +2045,22 @@ pub struct OriginatingActor {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub role_id: Option<String>,
+    pub role_id: Option<Token>,
     /// The kind of actor.
     #[serde(rename = "type")]
     pub originating_actor_type: ActorType,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl OriginatingActorBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("actor_uuid", &self.actor_uuid)?;
+        validation::check_token("role_id", &self.role_id)?;
+        Ok(())
+    }
 }
 /// The kind of actor.
 ///
@@ -1803,9 +2157,8 @@ pub enum ActorType {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct FindingRelatedObservation {
     /// A machine-oriented identifier reference to an observation defined in the list of
     /// observations.
@@ -1814,16 +2167,26 @@ pub struct FindingRelatedObservation {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub observation_uuid: String,
+    pub observation_uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl FindingRelatedObservationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("observation_uuid", &self.observation_uuid)?;
+        Ok(())
+    }
 }
 
 /// Relates the finding to a set of referenced risks that were used to determine the finding.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssociatedRisk {
     /// A machine-oriented identifier reference to a risk defined in the list of risks.
     #[derivative(PartialEq = "ignore")]
@@ -1831,7 +2194,18 @@ pub struct AssociatedRisk {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub risk_uuid: String,
+    pub risk_uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AssociatedRiskBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("risk_uuid", &self.risk_uuid)?;
+        Ok(())
+    }
 }
 
 /// Captures an assessor's conclusions regarding the degree to which an objective is
@@ -1839,16 +2213,14 @@ pub struct AssociatedRisk {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct TargetClass {
     /// A human-readable description of the assessor's conclusions regarding the degree to which
     /// an objective is satisfied.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub implementation_status: Option<ImplementationStatus>,
@@ -1860,8 +2232,7 @@ pub struct TargetClass {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A determination of if the objective is satisfied or not within a given system.
     pub status: StatusClass,
     /// A machine-oriented identifier reference for a specific target qualified by the type.
@@ -1869,30 +2240,41 @@ pub struct TargetClass {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub target_id: String,
+    pub target_id: Token,
     /// The title for this objective status.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// Identifies the type of the target.
     #[serde(rename = "type")]
     pub objective_status_type: FindingTargetType,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl TargetClassBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("target_id", &self.target_id)?;
+        Ok(())
+    }
 }
 
 /// Indicates the degree to which the a given control is implemented.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ImplementationStatus {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// Identifies the implementation status of the control or control objective.
     pub state: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 /// Identifies the type of the target.
 ///
@@ -1958,11 +2340,9 @@ pub enum FindingTargetType {
 }
 
 /// A determination of if the objective is satisfied or not within a given system.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct StatusClass {
     /// The reason the objective was given it's status.
     #[builder(setter(into, strip_option), default)]
@@ -1970,10 +2350,13 @@ pub struct StatusClass {
     pub reason: Option<String>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// An indication as to whether the objective is satisfied or not.
     pub state: ObjectiveStatusState,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 /// An indication as to whether the objective is satisfied or not.
 ///
@@ -2071,7 +2454,6 @@ pub enum ObjectiveStatusState {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ResultLocalDefinitions {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2088,6 +2470,10 @@ pub struct ResultLocalDefinitions {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub users: Option<Vec<SystemUser>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Identifies the assets used to perform this assessment, such as the assessment team,
@@ -2097,21 +2483,23 @@ pub struct ResultLocalDefinitions {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessmentAssets {
     pub assessment_platforms: Vec<AssessmentPlatform>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub components: Option<Vec<Component>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Used to represent the toolset used to perform aspects of the assessment.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssessmentPlatform {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2121,13 +2509,11 @@ pub struct AssessmentPlatform {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// The title or name for the assessment platform.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub uses_components: Option<Vec<UsesComponent>>,
@@ -2142,16 +2528,26 @@ pub struct AssessmentPlatform {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AssessmentPlatformBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// The set of components that are used by the assessment platform.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct UsesComponent {
     /// A machine-oriented identifier reference to a component that is implemented as part of an
     /// inventory item.
@@ -2160,7 +2556,7 @@ pub struct UsesComponent {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub component_uuid: String,
+    pub component_uuid: OscalUuid,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -2169,24 +2565,32 @@ pub struct UsesComponent {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl UsesComponentBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("component_uuid", &self.component_uuid)?;
+        Ok(())
+    }
 }
 
 /// A defined component that can be part of an implemented system.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Component {
     /// A description of the component, including information about its function.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -2199,27 +2603,24 @@ pub struct Component {
     /// A summary of the technological or business purpose of the component.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub purpose: Option<String>,
+    pub purpose: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
     /// Describes the operational status of the system component.
     pub status: Status,
     /// A human readable name for the system component.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub title: String,
+    pub title: Markup,
     /// A category describing the purpose of the component.
     #[serde(rename = "type")]
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub component_type: String,
+    pub component_type: Token,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this component elsewhere in this or other OSCAL instances. The locally
     /// defined UUID of the component can be used to reference the data item locally or globally
@@ -2231,16 +2632,27 @@ pub struct Component {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ComponentBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("component_type", &self.component_type)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Information about the protocol used to provide a service.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ServiceProtocolInformation {
     /// The common name of the protocol, which should be the appropriate "service name" from the
     /// IANA Service Name and Transport Protocol Port Number Registry.
@@ -2251,8 +2663,7 @@ pub struct ServiceProtocolInformation {
     /// A human readable name for the protocol (e.g., Transport Layer Security).
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this service protocol information elsewhere in this or other OSCAL
     /// instances. The locally defined UUID of the service protocol can be used to reference the
@@ -2266,15 +2677,24 @@ pub struct ServiceProtocolInformation {
         deserialize_with = "validation::deser_uuid_opt"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: Option<String>,
+    pub uuid: Option<OscalUuid>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ServiceProtocolInformationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Where applicable this is the IPv4 port range on which the service operates.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct PortRange {
     /// Indicates the ending port number in a port range
     #[builder(setter(into, strip_option), default)]
@@ -2296,6 +2716,18 @@ pub struct PortRange {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transport: Option<Transport>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PortRangeBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_non_neg_int("end", &self.end)?;
+        validation::check_non_neg_int("start", &self.start)?;
+        Ok(())
+    }
 }
 /// Indicates the transport type.
 ///
@@ -2387,18 +2819,19 @@ pub enum Transport {
 }
 
 /// Describes the operational status of the system component.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Status {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// The operational status.
     pub state: State,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 /// The operational status.
 ///
@@ -2495,13 +2928,11 @@ pub enum State {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct InventoryItem {
     /// A summary of the inventory item stating its purpose within the system.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub implemented_components: Option<Vec<ImplementedComponent>>,
@@ -2513,8 +2944,7 @@ pub struct InventoryItem {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
@@ -2529,16 +2959,26 @@ pub struct InventoryItem {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl InventoryItemBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// The set of components that are implemented in a given system inventory item.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ImplementedComponent {
     /// A machine-oriented identifier reference to a component that is implemented as part of an
     /// inventory item.
@@ -2547,7 +2987,7 @@ pub struct ImplementedComponent {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub component_uuid: String,
+    pub component_uuid: OscalUuid,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -2556,11 +2996,21 @@ pub struct ImplementedComponent {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ImplementedComponentBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("component_uuid", &self.component_uuid)?;
+        Ok(())
+    }
 }
 
 /// Represents a scheduled event or milestone, which may be associated with a series of
@@ -2568,9 +3018,8 @@ pub struct ImplementedComponent {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Task {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2581,8 +3030,7 @@ pub struct Task {
     /// A human-readable description of this task.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -2591,8 +3039,7 @@ pub struct Task {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
@@ -2607,15 +3054,14 @@ pub struct Task {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timing: Option<EventTiming>,
     /// The title for this task.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub title: String,
+    pub title: Markup,
     /// The type of task.
     #[serde(rename = "type")]
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub task_type: String,
+    pub task_type: Token,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this task elsewhere in this or other OSCAL instances. The locally defined
     /// UUID of the task can be used to reference the data item locally or globally (e.g., in an
@@ -2626,16 +3072,27 @@ pub struct Task {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl TaskBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("task_type", &self.task_type)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Identifies an individual activity to be performed as part of a task.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AssociatedActivity {
     /// A machine-oriented identifier reference to an activity defined in the list of activities.
     #[derivative(PartialEq = "ignore")]
@@ -2643,7 +3100,7 @@ pub struct AssociatedActivity {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub activity_uuid: String,
+    pub activity_uuid: OscalUuid,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -2652,33 +3109,52 @@ pub struct AssociatedActivity {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
     pub subjects: Vec<SubjectOfAssessment>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AssociatedActivityBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("activity_uuid", &self.activity_uuid)?;
+        Ok(())
+    }
 }
 
 /// Used to indicate that a task is dependent on another task.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct TaskDependency {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A machine-oriented identifier reference to a unique task.
     #[derivative(PartialEq = "ignore")]
     #[serde(
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub task_uuid: String,
+    pub task_uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl TaskDependencyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("task_uuid", &self.task_uuid)?;
+        Ok(())
+    }
 }
 
 /// The timing under which the task is intended to occur.
@@ -2687,7 +3163,6 @@ pub struct TaskDependency {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct EventTiming {
     /// The task is intended to occur at the specified frequency.
     #[builder(setter(into, strip_option), default)]
@@ -2701,14 +3176,16 @@ pub struct EventTiming {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub within_date_range: Option<OnDateRangeCondition>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// The task is intended to occur at the specified frequency.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct FrequencyCondition {
     /// The task must occur after the specified period has elapsed.
     #[serde(
@@ -2718,6 +3195,17 @@ pub struct FrequencyCondition {
     pub period: i64,
     /// The unit of time for the period.
     pub unit: TimeUnit,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl FrequencyConditionBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_positive_int("period", &self.period)?;
+        Ok(())
+    }
 }
 /// The unit of time for the period.
 ///
@@ -2785,36 +3273,39 @@ pub enum TimeUnit {
 }
 
 /// The task is intended to occur on the specified date.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct OnDateCondition {
     /// The task must occur on the specified date.
     pub date: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// The task is intended to occur within the specified date range.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct OnDateRangeCondition {
     /// The task must occur on or before the specified date.
     pub end: String,
     /// The task must occur on or after the specified date.
     pub start: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A type of user that interacts with the system based on an associated role.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SystemUser {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2822,8 +3313,7 @@ pub struct SystemUser {
     /// A summary of the user's purpose within the system.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -2832,12 +3322,11 @@ pub struct SystemUser {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(deserialize_with = "validation::deser_token_vec_opt")]
-    pub role_ids: Option<Vec<String>>,
+    pub role_ids: Option<Vec<Token>>,
     /// A short common name, abbreviation, or acronym for the user.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2845,8 +3334,7 @@ pub struct SystemUser {
     /// A name given to the user, which may be used by a tool for display and navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this user class elsewhere in this or other OSCAL instances. The locally
     /// defined UUID of the system user can be used to reference the data item locally or
@@ -2858,7 +3346,19 @@ pub struct SystemUser {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SystemUserBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("role_ids", &self.role_ids)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Identifies a specific system privilege held by the user, along with an associated
@@ -2868,32 +3368,33 @@ pub struct SystemUser {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Privilege {
     /// A summary of the privilege's purpose within the system.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     pub functions_performed: Vec<String>,
     /// A human readable name for the privilege.
     pub title: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Describes an individual observation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Observation {
     /// Date/time stamp identifying when the finding information was collected.
     #[serde(
         serialize_with = "validation::ser_dttz",
         deserialize_with = "validation::deser_dttz"
     )]
-    pub collected: String,
+    pub collected: DateTimeWithTz,
     /// A human-readable description of this assessment observation.
     pub description: String,
     /// Date/time identifying when the finding information is out-of-date and no longer valid.
@@ -2904,7 +3405,7 @@ pub struct Observation {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub expires: Option<String>,
+    pub expires: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -2920,16 +3421,14 @@ pub struct Observation {
     pub relevant_evidence: Option<Vec<RelevantEvidence>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub subjects: Option<Vec<IdentifiesTheSubject>>,
     /// The title for this observation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub types: Option<Vec<String>>,
@@ -2944,15 +3443,26 @@ pub struct Observation {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ObservationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("collected", &self.collected)?;
+        validation::check_dttz("expires", &self.expires)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Links this observation to relevant evidence.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct RelevantEvidence {
     /// A human-readable description of this evidence.
     pub description: String,
@@ -2963,7 +3473,7 @@ pub struct RelevantEvidence {
         serialize_with = "validation::ser_uri_ref_opt",
         deserialize_with = "validation::deser_uri_ref_opt"
     )]
-    pub href: Option<String>,
+    pub href: Option<UriReference>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -2972,8 +3482,18 @@ pub struct RelevantEvidence {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl RelevantEvidenceBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri_ref("href", &self.href)?;
+        Ok(())
+    }
 }
 
 /// A human-oriented identifier reference to a resource. Use type to indicate whether the
@@ -2981,9 +3501,8 @@ pub struct RelevantEvidence {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct IdentifiesTheSubject {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2993,8 +3512,7 @@ pub struct IdentifiesTheSubject {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A machine-oriented identifier reference to a component, inventory-item, location, party,
     /// user, or resource using it's UUID.
     #[derivative(PartialEq = "ignore")]
@@ -3002,7 +3520,7 @@ pub struct IdentifiesTheSubject {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub subject_uuid: String,
+    pub subject_uuid: OscalUuid,
     /// The title or name for the referenced subject.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3010,15 +3528,25 @@ pub struct IdentifiesTheSubject {
     /// Used to indicate the type of object pointed to by the uuid-ref within a subject.
     #[serde(rename = "type")]
     pub identifies_the_subject_type: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl IdentifiesTheSubjectBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("subject_uuid", &self.subject_uuid)?;
+        Ok(())
+    }
 }
 
 /// An identified risk.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct IdentifiedRisk {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3030,11 +3558,10 @@ pub struct IdentifiedRisk {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub deadline: Option<String>,
+    pub deadline: Option<DateTimeWithTz>,
     /// A human-readable summary of the identified risk, to include a statement of how the risk
     /// impacts the system.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -3058,19 +3585,17 @@ pub struct IdentifiedRisk {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub risk_log: Option<RiskLog>,
     /// An summary of impact for how the risk affects the system.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub statement: String,
+    pub statement: Markup,
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub status: String,
+    pub status: Token,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub threat_ids: Option<Vec<ThreatId>>,
     /// The title for this risk.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub title: String,
+    pub title: Markup,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this risk elsewhere in this or other OSCAL instances. The locally defined
     /// UUID of the risk can be used to reference the data item locally or globally (e.g., in an
@@ -3081,15 +3606,26 @@ pub struct IdentifiedRisk {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl IdentifiedRiskBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("deadline", &self.deadline)?;
+        validation::check_token("status", &self.status)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A collection of descriptive data about the containing object from a specific origin.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Characterization {
     pub facets: Vec<Facet>,
     #[builder(setter(into, strip_option), default)]
@@ -3099,14 +3635,16 @@ pub struct Characterization {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// An individual characteristic that is part of a larger set produced by the same actor.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Facet {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3116,14 +3654,13 @@ pub struct Facet {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub name: String,
+    pub name: Token,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// Specifies the naming system under which this risk metric is organized, which allows for
     /// the same names to be used in different systems controlled by different parties. This
     /// avoids the potential of a name clash.
@@ -3131,9 +3668,21 @@ pub struct Facet {
         serialize_with = "validation::ser_uri",
         deserialize_with = "validation::deser_uri"
     )]
-    pub system: String,
+    pub system: Uri,
     /// Indicates the value of the facet.
     pub value: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl FacetBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("name", &self.name)?;
+        validation::check_uri("system", &self.system)?;
+        Ok(())
+    }
 }
 
 /// Describes an existing mitigating factor that may affect the overall determination of the
@@ -3141,13 +3690,11 @@ pub struct Facet {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct MitigatingFactor {
     /// A human-readable description of this mitigating factor.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this implementation statement elsewhere in this or other OSCAL instancess.
     /// The locally defined UUID of the implementation statement can be used to reference the
@@ -3160,7 +3707,7 @@ pub struct MitigatingFactor {
         serialize_with = "validation::ser_uuid_opt",
         deserialize_with = "validation::deser_uuid_opt"
     )]
-    pub implementation_uuid: Option<String>,
+    pub implementation_uuid: Option<OscalUuid>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -3181,7 +3728,19 @@ pub struct MitigatingFactor {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl MitigatingFactorBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("implementation_uuid", &self.implementation_uuid)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Relates the finding to a set of referenced observations that were used to determine the
@@ -3189,9 +3748,8 @@ pub struct MitigatingFactor {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct RiskRelatedObservation {
     /// A machine-oriented identifier reference to an observation defined in the list of
     /// observations.
@@ -3200,27 +3758,36 @@ pub struct RiskRelatedObservation {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub observation_uuid: String,
+    pub observation_uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl RiskRelatedObservationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("observation_uuid", &self.observation_uuid)?;
+        Ok(())
+    }
 }
 
 /// Describes either recommended or an actual plan for addressing the risk.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct RiskResponse {
     /// A human-readable description of this response plan.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     /// Identifies whether this is a recommendation, such as from an assessor or tool, or an
     /// actual plan accepted by the system owner.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub lifecycle: String,
+    pub lifecycle: Token,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -3232,8 +3799,7 @@ pub struct RiskResponse {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub required_assets: Option<Vec<RequiredAsset>>,
@@ -3241,8 +3807,7 @@ pub struct RiskResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tasks: Option<Vec<Task>>,
     /// The title for this response activity.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub title: String,
+    pub title: Markup,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this remediation elsewhere in this or other OSCAL instances. The locally
     /// defined UUID of the risk response can be used to reference the data item locally or
@@ -3254,19 +3819,28 @@ pub struct RiskResponse {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl RiskResponseBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("lifecycle", &self.lifecycle)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Identifies an asset required to achieve remediation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct RequiredAsset {
     /// A human-readable description of this required asset.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -3275,16 +3849,14 @@ pub struct RequiredAsset {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub subjects: Option<Vec<IdentifiesTheSubject>>,
     /// The title for this required asset.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this required asset elsewhere in this or other OSCAL instances. The locally
     /// defined UUID of the asset can be used to reference the data item locally or globally
@@ -3296,17 +3868,30 @@ pub struct RequiredAsset {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl RequiredAssetBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A log of all risk-related tasks taken.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct RiskLog {
     pub entries: Vec<RiskLogEntry>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Identifies an individual risk response that occurred as part of managing an identified
@@ -3314,15 +3899,13 @@ pub struct RiskLog {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct RiskLogEntry {
     /// A human-readable description of what was done regarding the risk.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     /// Identifies the end date and time of the event. If the event is a point in time, the start
     /// and end will be the same date and time.
     #[builder(setter(into, strip_option), default)]
@@ -3331,7 +3914,7 @@ pub struct RiskLogEntry {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub end: Option<String>,
+    pub end: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -3346,26 +3929,24 @@ pub struct RiskLogEntry {
     pub related_responses: Option<Vec<RiskResponseReference>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// Identifies the start date and time of the event.
     #[serde(
         serialize_with = "validation::ser_dttz",
         deserialize_with = "validation::deser_dttz"
     )]
-    pub start: String,
+    pub start: DateTimeWithTz,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub status_change: Option<String>,
+    pub status_change: Option<Token>,
     /// The title for this risk log entry.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this risk log entry elsewhere in this or other OSCAL instances. The locally
     /// defined UUID of the risk log entry can be used to reference the data item locally or
@@ -3377,16 +3958,29 @@ pub struct RiskLogEntry {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl RiskLogEntryBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("end", &self.end)?;
+        validation::check_dttz("start", &self.start)?;
+        validation::check_token("status_change", &self.status_change)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Identifies an individual risk response that this log entry is for.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct RiskResponseReference {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3399,23 +3993,31 @@ pub struct RiskResponseReference {
     pub related_tasks: Option<Vec<TaskReference>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A machine-oriented identifier reference to a unique risk response.
     #[derivative(PartialEq = "ignore")]
     #[serde(
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub response_uuid: String,
+    pub response_uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl RiskResponseReferenceBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("response_uuid", &self.response_uuid)?;
+        Ok(())
+    }
 }
 
 /// A pointer, by ID, to an externally-defined threat.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ThreatId {
     /// An optional location for the threat data, from which this ID originates.
     #[builder(setter(into, strip_option), default)]
@@ -3424,8 +4026,3299 @@ pub struct ThreatId {
         serialize_with = "validation::ser_uri_ref_opt",
         deserialize_with = "validation::deser_uri_ref_opt"
     )]
-    pub href: Option<String>,
+    pub href: Option<UriReference>,
     pub id: String,
     /// Specifies the source of the threat information.
     pub system: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ThreatIdBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri_ref("href", &self.href)?;
+        Ok(())
+    }
+}
+
+/// Read only traversal of the model
+///
+/// Every method visits one type and by default walks into its
+/// children, so an implementation only overrides the types it cares
+/// about. Call the matching `walk_*` function from an override to keep
+/// descending.
+pub mod visit {
+    use super::*;
+    pub trait Visit<'ast> {
+        fn visit_assessment_results(&mut self, node: &'ast AssessmentResults) {
+            walk_assessment_results(self, node)
+        }
+        fn visit_security_assessment_results_sar(
+            &mut self,
+            node: &'ast SecurityAssessmentResultsSar,
+        ) {
+            walk_security_assessment_results_sar(self, node)
+        }
+        fn visit_back_matter(&mut self, node: &'ast BackMatter) {
+            walk_back_matter(self, node)
+        }
+        fn visit_resource(&mut self, node: &'ast Resource) {
+            walk_resource(self, node)
+        }
+        fn visit_base64(&mut self, node: &'ast Base64) {
+            walk_base64(self, node)
+        }
+        fn visit_citation(&mut self, node: &'ast Citation) {
+            walk_citation(self, node)
+        }
+        fn visit_link(&mut self, node: &'ast Link) {
+            walk_link(self, node)
+        }
+        fn visit_property(&mut self, node: &'ast Property) {
+            walk_property(self, node)
+        }
+        fn visit_document_identifier(&mut self, node: &'ast DocumentIdentifier) {
+            walk_document_identifier(self, node)
+        }
+        fn visit_resource_link(&mut self, node: &'ast ResourceLink) {
+            walk_resource_link(self, node)
+        }
+        fn visit_hash(&mut self, node: &'ast Hash) {
+            walk_hash(self, node)
+        }
+        fn visit_import_assessment_plan(&mut self, node: &'ast ImportAssessmentPlan) {
+            walk_import_assessment_plan(self, node)
+        }
+        fn visit_assessment_results_local_definitions(
+            &mut self,
+            node: &'ast AssessmentResultsLocalDefinitions,
+        ) {
+            walk_assessment_results_local_definitions(self, node)
+        }
+        fn visit_activity(&mut self, node: &'ast Activity) {
+            walk_activity(self, node)
+        }
+        fn visit_reviewed_controls_and_control_objectives(
+            &mut self,
+            node: &'ast ReviewedControlsAndControlObjectives,
+        ) {
+            walk_reviewed_controls_and_control_objectives(self, node)
+        }
+        fn visit_referenced_control_objectives(
+            &mut self,
+            node: &'ast ReferencedControlObjectives,
+        ) {
+            walk_referenced_control_objectives(self, node)
+        }
+        fn visit_select_objective(&mut self, node: &'ast SelectObjective) {
+            walk_select_objective(self, node)
+        }
+        fn visit_include_all(&mut self, node: &'ast IncludeAll) {
+            walk_include_all(self, node)
+        }
+        fn visit_assessed_controls(&mut self, node: &'ast AssessedControls) {
+            walk_assessed_controls(self, node)
+        }
+        fn visit_select_control(&mut self, node: &'ast SelectControl) {
+            walk_select_control(self, node)
+        }
+        fn visit_responsible_role(&mut self, node: &'ast ResponsibleRole) {
+            walk_responsible_role(self, node)
+        }
+        fn visit_step(&mut self, node: &'ast Step) {
+            walk_step(self, node)
+        }
+        fn visit_assessment_specific_control_objective(
+            &mut self,
+            node: &'ast AssessmentSpecificControlObjective,
+        ) {
+            walk_assessment_specific_control_objective(self, node)
+        }
+        fn visit_part(&mut self, node: &'ast Part) {
+            walk_part(self, node)
+        }
+        fn visit_document_metadata(&mut self, node: &'ast DocumentMetadata) {
+            walk_document_metadata(self, node)
+        }
+        fn visit_action(&mut self, node: &'ast Action) {
+            walk_action(self, node)
+        }
+        fn visit_responsible_party(&mut self, node: &'ast ResponsibleParty) {
+            walk_responsible_party(self, node)
+        }
+        fn visit_location(&mut self, node: &'ast Location) {
+            walk_location(self, node)
+        }
+        fn visit_address(&mut self, node: &'ast Address) {
+            walk_address(self, node)
+        }
+        fn visit_telephone_number(&mut self, node: &'ast TelephoneNumber) {
+            walk_telephone_number(self, node)
+        }
+        fn visit_party(&mut self, node: &'ast Party) {
+            walk_party(self, node)
+        }
+        fn visit_party_external_identifier(
+            &mut self,
+            node: &'ast PartyExternalIdentifier,
+        ) {
+            walk_party_external_identifier(self, node)
+        }
+        fn visit_party_type(&mut self, node: &'ast PartyType) {
+            walk_party_type(self, node)
+        }
+        fn visit_revision_history_entry(&mut self, node: &'ast RevisionHistoryEntry) {
+            walk_revision_history_entry(self, node)
+        }
+        fn visit_role(&mut self, node: &'ast Role) {
+            walk_role(self, node)
+        }
+        fn visit_assessment_result(&mut self, node: &'ast AssessmentResult) {
+            walk_assessment_result(self, node)
+        }
+        fn visit_assessment_log(&mut self, node: &'ast AssessmentLog) {
+            walk_assessment_log(self, node)
+        }
+        fn visit_assessment_log_entry(&mut self, node: &'ast AssessmentLogEntry) {
+            walk_assessment_log_entry(self, node)
+        }
+        fn visit_logged_by(&mut self, node: &'ast LoggedBy) {
+            walk_logged_by(self, node)
+        }
+        fn visit_task_reference(&mut self, node: &'ast TaskReference) {
+            walk_task_reference(self, node)
+        }
+        fn visit_identified_subject(&mut self, node: &'ast IdentifiedSubject) {
+            walk_identified_subject(self, node)
+        }
+        fn visit_subject_of_assessment(&mut self, node: &'ast SubjectOfAssessment) {
+            walk_subject_of_assessment(self, node)
+        }
+        fn visit_select_assessment_subject(
+            &mut self,
+            node: &'ast SelectAssessmentSubject,
+        ) {
+            walk_select_assessment_subject(self, node)
+        }
+        fn visit_attestation_statements(&mut self, node: &'ast AttestationStatements) {
+            walk_attestation_statements(self, node)
+        }
+        fn visit_assessment_part(&mut self, node: &'ast AssessmentPart) {
+            walk_assessment_part(self, node)
+        }
+        fn visit_finding(&mut self, node: &'ast Finding) {
+            walk_finding(self, node)
+        }
+        fn visit_origin(&mut self, node: &'ast Origin) {
+            walk_origin(self, node)
+        }
+        fn visit_originating_actor(&mut self, node: &'ast OriginatingActor) {
+            walk_originating_actor(self, node)
+        }
+        fn visit_actor_type(&mut self, node: &'ast ActorType) {
+            walk_actor_type(self, node)
+        }
+        fn visit_finding_related_observation(
+            &mut self,
+            node: &'ast FindingRelatedObservation,
+        ) {
+            walk_finding_related_observation(self, node)
+        }
+        fn visit_associated_risk(&mut self, node: &'ast AssociatedRisk) {
+            walk_associated_risk(self, node)
+        }
+        fn visit_target_class(&mut self, node: &'ast TargetClass) {
+            walk_target_class(self, node)
+        }
+        fn visit_implementation_status(&mut self, node: &'ast ImplementationStatus) {
+            walk_implementation_status(self, node)
+        }
+        fn visit_finding_target_type(&mut self, node: &'ast FindingTargetType) {
+            walk_finding_target_type(self, node)
+        }
+        fn visit_status_class(&mut self, node: &'ast StatusClass) {
+            walk_status_class(self, node)
+        }
+        fn visit_objective_status_state(&mut self, node: &'ast ObjectiveStatusState) {
+            walk_objective_status_state(self, node)
+        }
+        fn visit_result_local_definitions(
+            &mut self,
+            node: &'ast ResultLocalDefinitions,
+        ) {
+            walk_result_local_definitions(self, node)
+        }
+        fn visit_assessment_assets(&mut self, node: &'ast AssessmentAssets) {
+            walk_assessment_assets(self, node)
+        }
+        fn visit_assessment_platform(&mut self, node: &'ast AssessmentPlatform) {
+            walk_assessment_platform(self, node)
+        }
+        fn visit_uses_component(&mut self, node: &'ast UsesComponent) {
+            walk_uses_component(self, node)
+        }
+        fn visit_component(&mut self, node: &'ast Component) {
+            walk_component(self, node)
+        }
+        fn visit_service_protocol_information(
+            &mut self,
+            node: &'ast ServiceProtocolInformation,
+        ) {
+            walk_service_protocol_information(self, node)
+        }
+        fn visit_port_range(&mut self, node: &'ast PortRange) {
+            walk_port_range(self, node)
+        }
+        fn visit_transport(&mut self, node: &'ast Transport) {
+            walk_transport(self, node)
+        }
+        fn visit_status(&mut self, node: &'ast Status) {
+            walk_status(self, node)
+        }
+        fn visit_state(&mut self, node: &'ast State) {
+            walk_state(self, node)
+        }
+        fn visit_inventory_item(&mut self, node: &'ast InventoryItem) {
+            walk_inventory_item(self, node)
+        }
+        fn visit_implemented_component(&mut self, node: &'ast ImplementedComponent) {
+            walk_implemented_component(self, node)
+        }
+        fn visit_task(&mut self, node: &'ast Task) {
+            walk_task(self, node)
+        }
+        fn visit_associated_activity(&mut self, node: &'ast AssociatedActivity) {
+            walk_associated_activity(self, node)
+        }
+        fn visit_task_dependency(&mut self, node: &'ast TaskDependency) {
+            walk_task_dependency(self, node)
+        }
+        fn visit_event_timing(&mut self, node: &'ast EventTiming) {
+            walk_event_timing(self, node)
+        }
+        fn visit_frequency_condition(&mut self, node: &'ast FrequencyCondition) {
+            walk_frequency_condition(self, node)
+        }
+        fn visit_time_unit(&mut self, node: &'ast TimeUnit) {
+            walk_time_unit(self, node)
+        }
+        fn visit_on_date_condition(&mut self, node: &'ast OnDateCondition) {
+            walk_on_date_condition(self, node)
+        }
+        fn visit_on_date_range_condition(&mut self, node: &'ast OnDateRangeCondition) {
+            walk_on_date_range_condition(self, node)
+        }
+        fn visit_system_user(&mut self, node: &'ast SystemUser) {
+            walk_system_user(self, node)
+        }
+        fn visit_privilege(&mut self, node: &'ast Privilege) {
+            walk_privilege(self, node)
+        }
+        fn visit_observation(&mut self, node: &'ast Observation) {
+            walk_observation(self, node)
+        }
+        fn visit_relevant_evidence(&mut self, node: &'ast RelevantEvidence) {
+            walk_relevant_evidence(self, node)
+        }
+        fn visit_identifies_the_subject(&mut self, node: &'ast IdentifiesTheSubject) {
+            walk_identifies_the_subject(self, node)
+        }
+        fn visit_identified_risk(&mut self, node: &'ast IdentifiedRisk) {
+            walk_identified_risk(self, node)
+        }
+        fn visit_characterization(&mut self, node: &'ast Characterization) {
+            walk_characterization(self, node)
+        }
+        fn visit_facet(&mut self, node: &'ast Facet) {
+            walk_facet(self, node)
+        }
+        fn visit_mitigating_factor(&mut self, node: &'ast MitigatingFactor) {
+            walk_mitigating_factor(self, node)
+        }
+        fn visit_risk_related_observation(
+            &mut self,
+            node: &'ast RiskRelatedObservation,
+        ) {
+            walk_risk_related_observation(self, node)
+        }
+        fn visit_risk_response(&mut self, node: &'ast RiskResponse) {
+            walk_risk_response(self, node)
+        }
+        fn visit_required_asset(&mut self, node: &'ast RequiredAsset) {
+            walk_required_asset(self, node)
+        }
+        fn visit_risk_log(&mut self, node: &'ast RiskLog) {
+            walk_risk_log(self, node)
+        }
+        fn visit_risk_log_entry(&mut self, node: &'ast RiskLogEntry) {
+            walk_risk_log_entry(self, node)
+        }
+        fn visit_risk_response_reference(&mut self, node: &'ast RiskResponseReference) {
+            walk_risk_response_reference(self, node)
+        }
+        fn visit_threat_id(&mut self, node: &'ast ThreatId) {
+            walk_threat_id(self, node)
+        }
+    }
+    pub fn walk_assessment_results<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessmentResults,
+    ) {
+        v.visit_security_assessment_results_sar(&node.assessment_results);
+    }
+    pub fn walk_security_assessment_results_sar<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SecurityAssessmentResultsSar,
+    ) {
+        if let Some(it) = &node.back_matter {
+            v.visit_back_matter(it);
+        }
+        v.visit_import_assessment_plan(&node.import_ap);
+        if let Some(it) = &node.local_definitions {
+            v.visit_assessment_results_local_definitions(it);
+        }
+        v.visit_document_metadata(&node.metadata);
+        for it in &node.results {
+            v.visit_assessment_result(it);
+        }
+    }
+    pub fn walk_back_matter<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast BackMatter,
+    ) {
+        if let Some(it) = &node.resources {
+            for it in it {
+                v.visit_resource(it);
+            }
+        }
+    }
+    pub fn walk_resource<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Resource,
+    ) {
+        if let Some(it) = &node.base64 {
+            v.visit_base64(it);
+        }
+        if let Some(it) = &node.citation {
+            v.visit_citation(it);
+        }
+        if let Some(it) = &node.document_ids {
+            for it in it {
+                v.visit_document_identifier(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.rlinks {
+            for it in it {
+                v.visit_resource_link(it);
+            }
+        }
+    }
+    pub fn walk_base64<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Base64) {}
+    pub fn walk_citation<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Citation,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_link<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Link) {}
+    pub fn walk_property<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Property,
+    ) {}
+    pub fn walk_document_identifier<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast DocumentIdentifier,
+    ) {}
+    pub fn walk_resource_link<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ResourceLink,
+    ) {
+        if let Some(it) = &node.hashes {
+            for it in it {
+                v.visit_hash(it);
+            }
+        }
+    }
+    pub fn walk_hash<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Hash) {}
+    pub fn walk_import_assessment_plan<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast ImportAssessmentPlan,
+    ) {}
+    pub fn walk_assessment_results_local_definitions<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessmentResultsLocalDefinitions,
+    ) {
+        if let Some(it) = &node.activities {
+            for it in it {
+                v.visit_activity(it);
+            }
+        }
+        if let Some(it) = &node.objectives_and_methods {
+            for it in it {
+                v.visit_assessment_specific_control_objective(it);
+            }
+        }
+    }
+    pub fn walk_activity<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Activity,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.related_controls {
+            v.visit_reviewed_controls_and_control_objectives(it);
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+        if let Some(it) = &node.steps {
+            for it in it {
+                v.visit_step(it);
+            }
+        }
+    }
+    pub fn walk_reviewed_controls_and_control_objectives<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ReviewedControlsAndControlObjectives,
+    ) {
+        if let Some(it) = &node.control_objective_selections {
+            for it in it {
+                v.visit_referenced_control_objectives(it);
+            }
+        }
+        for it in &node.control_selections {
+            v.visit_assessed_controls(it);
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_referenced_control_objectives<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ReferencedControlObjectives,
+    ) {
+        if let Some(it) = &node.exclude_objectives {
+            for it in it {
+                v.visit_select_objective(it);
+            }
+        }
+        if let Some(it) = &node.include_all {
+            v.visit_include_all(it);
+        }
+        if let Some(it) = &node.include_objectives {
+            for it in it {
+                v.visit_select_objective(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_select_objective<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast SelectObjective,
+    ) {}
+    pub fn walk_include_all<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast IncludeAll,
+    ) {}
+    pub fn walk_assessed_controls<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessedControls,
+    ) {
+        if let Some(it) = &node.exclude_controls {
+            for it in it {
+                v.visit_select_control(it);
+            }
+        }
+        if let Some(it) = &node.include_all {
+            v.visit_include_all(it);
+        }
+        if let Some(it) = &node.include_controls {
+            for it in it {
+                v.visit_select_control(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_select_control<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast SelectControl,
+    ) {}
+    pub fn walk_responsible_role<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ResponsibleRole,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_step<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Step) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+        if let Some(it) = &node.reviewed_controls {
+            v.visit_reviewed_controls_and_control_objectives(it);
+        }
+    }
+    pub fn walk_assessment_specific_control_objective<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessmentSpecificControlObjective,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        for it in &node.parts {
+            v.visit_part(it);
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_part<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Part) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.parts {
+            for it in it {
+                v.visit_part(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_document_metadata<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast DocumentMetadata,
+    ) {
+        if let Some(it) = &node.actions {
+            for it in it {
+                v.visit_action(it);
+            }
+        }
+        if let Some(it) = &node.document_ids {
+            for it in it {
+                v.visit_document_identifier(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.locations {
+            for it in it {
+                v.visit_location(it);
+            }
+        }
+        if let Some(it) = &node.parties {
+            for it in it {
+                v.visit_party(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+        if let Some(it) = &node.revisions {
+            for it in it {
+                v.visit_revision_history_entry(it);
+            }
+        }
+        if let Some(it) = &node.roles {
+            for it in it {
+                v.visit_role(it);
+            }
+        }
+    }
+    pub fn walk_action<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Action) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+    }
+    pub fn walk_responsible_party<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ResponsibleParty,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_location<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Location,
+    ) {
+        if let Some(it) = &node.address {
+            v.visit_address(it);
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number(it);
+            }
+        }
+    }
+    pub fn walk_address<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Address,
+    ) {}
+    pub fn walk_telephone_number<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast TelephoneNumber,
+    ) {}
+    pub fn walk_party<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Party) {
+        if let Some(it) = &node.addresses {
+            for it in it {
+                v.visit_address(it);
+            }
+        }
+        if let Some(it) = &node.external_ids {
+            for it in it {
+                v.visit_party_external_identifier(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number(it);
+            }
+        }
+        v.visit_party_type(&node.party_type);
+    }
+    pub fn walk_party_external_identifier<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast PartyExternalIdentifier,
+    ) {}
+    pub fn walk_party_type<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast PartyType,
+    ) {}
+    pub fn walk_revision_history_entry<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast RevisionHistoryEntry,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_role<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Role) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_assessment_result<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessmentResult,
+    ) {
+        if let Some(it) = &node.assessment_log {
+            v.visit_assessment_log(it);
+        }
+        if let Some(it) = &node.attestations {
+            for it in it {
+                v.visit_attestation_statements(it);
+            }
+        }
+        if let Some(it) = &node.findings {
+            for it in it {
+                v.visit_finding(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.local_definitions {
+            v.visit_result_local_definitions(it);
+        }
+        if let Some(it) = &node.observations {
+            for it in it {
+                v.visit_observation(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        v.visit_reviewed_controls_and_control_objectives(&node.reviewed_controls);
+        if let Some(it) = &node.risks {
+            for it in it {
+                v.visit_identified_risk(it);
+            }
+        }
+    }
+    pub fn walk_assessment_log<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessmentLog,
+    ) {
+        for it in &node.entries {
+            v.visit_assessment_log_entry(it);
+        }
+    }
+    pub fn walk_assessment_log_entry<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessmentLogEntry,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.logged_by {
+            for it in it {
+                v.visit_logged_by(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.related_tasks {
+            for it in it {
+                v.visit_task_reference(it);
+            }
+        }
+    }
+    pub fn walk_logged_by<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast LoggedBy,
+    ) {}
+    pub fn walk_task_reference<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast TaskReference,
+    ) {
+        if let Some(it) = &node.identified_subject {
+            v.visit_identified_subject(it);
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+        if let Some(it) = &node.subjects {
+            for it in it {
+                v.visit_subject_of_assessment(it);
+            }
+        }
+    }
+    pub fn walk_identified_subject<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast IdentifiedSubject,
+    ) {
+        for it in &node.subjects {
+            v.visit_subject_of_assessment(it);
+        }
+    }
+    pub fn walk_subject_of_assessment<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SubjectOfAssessment,
+    ) {
+        if let Some(it) = &node.exclude_subjects {
+            for it in it {
+                v.visit_select_assessment_subject(it);
+            }
+        }
+        if let Some(it) = &node.include_all {
+            v.visit_include_all(it);
+        }
+        if let Some(it) = &node.include_subjects {
+            for it in it {
+                v.visit_select_assessment_subject(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_select_assessment_subject<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SelectAssessmentSubject,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_attestation_statements<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AttestationStatements,
+    ) {
+        for it in &node.parts {
+            v.visit_assessment_part(it);
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+    }
+    pub fn walk_assessment_part<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessmentPart,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.parts {
+            for it in it {
+                v.visit_assessment_part(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_finding<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Finding) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.origins {
+            for it in it {
+                v.visit_origin(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.related_observations {
+            for it in it {
+                v.visit_finding_related_observation(it);
+            }
+        }
+        if let Some(it) = &node.related_risks {
+            for it in it {
+                v.visit_associated_risk(it);
+            }
+        }
+        v.visit_target_class(&node.target);
+    }
+    pub fn walk_origin<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Origin) {
+        for it in &node.actors {
+            v.visit_originating_actor(it);
+        }
+        if let Some(it) = &node.related_tasks {
+            for it in it {
+                v.visit_task_reference(it);
+            }
+        }
+    }
+    pub fn walk_originating_actor<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast OriginatingActor,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        v.visit_actor_type(&node.originating_actor_type);
+    }
+    pub fn walk_actor_type<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast ActorType,
+    ) {}
+    pub fn walk_finding_related_observation<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast FindingRelatedObservation,
+    ) {}
+    pub fn walk_associated_risk<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast AssociatedRisk,
+    ) {}
+    pub fn walk_target_class<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast TargetClass,
+    ) {
+        if let Some(it) = &node.implementation_status {
+            v.visit_implementation_status(it);
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        v.visit_status_class(&node.status);
+        v.visit_finding_target_type(&node.objective_status_type);
+    }
+    pub fn walk_implementation_status<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast ImplementationStatus,
+    ) {}
+    pub fn walk_finding_target_type<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast FindingTargetType,
+    ) {}
+    pub fn walk_status_class<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast StatusClass,
+    ) {
+        v.visit_objective_status_state(&node.state);
+    }
+    pub fn walk_objective_status_state<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast ObjectiveStatusState,
+    ) {}
+    pub fn walk_result_local_definitions<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ResultLocalDefinitions,
+    ) {
+        if let Some(it) = &node.assessment_assets {
+            v.visit_assessment_assets(it);
+        }
+        if let Some(it) = &node.components {
+            for it in it {
+                v.visit_component(it);
+            }
+        }
+        if let Some(it) = &node.inventory_items {
+            for it in it {
+                v.visit_inventory_item(it);
+            }
+        }
+        if let Some(it) = &node.tasks {
+            for it in it {
+                v.visit_task(it);
+            }
+        }
+        if let Some(it) = &node.users {
+            for it in it {
+                v.visit_system_user(it);
+            }
+        }
+    }
+    pub fn walk_assessment_assets<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessmentAssets,
+    ) {
+        for it in &node.assessment_platforms {
+            v.visit_assessment_platform(it);
+        }
+        if let Some(it) = &node.components {
+            for it in it {
+                v.visit_component(it);
+            }
+        }
+    }
+    pub fn walk_assessment_platform<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssessmentPlatform,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.uses_components {
+            for it in it {
+                v.visit_uses_component(it);
+            }
+        }
+    }
+    pub fn walk_uses_component<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast UsesComponent,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+    }
+    pub fn walk_component<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Component,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.protocols {
+            for it in it {
+                v.visit_service_protocol_information(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+        v.visit_status(&node.status);
+    }
+    pub fn walk_service_protocol_information<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ServiceProtocolInformation,
+    ) {
+        if let Some(it) = &node.port_ranges {
+            for it in it {
+                v.visit_port_range(it);
+            }
+        }
+    }
+    pub fn walk_port_range<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast PortRange,
+    ) {
+        if let Some(it) = &node.transport {
+            v.visit_transport(it);
+        }
+    }
+    pub fn walk_transport<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Transport,
+    ) {}
+    pub fn walk_status<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Status) {
+        v.visit_state(&node.state);
+    }
+    pub fn walk_state<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast State) {}
+    pub fn walk_inventory_item<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast InventoryItem,
+    ) {
+        if let Some(it) = &node.implemented_components {
+            for it in it {
+                v.visit_implemented_component(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+    }
+    pub fn walk_implemented_component<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ImplementedComponent,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+    }
+    pub fn walk_task<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Task) {
+        if let Some(it) = &node.associated_activities {
+            for it in it {
+                v.visit_associated_activity(it);
+            }
+        }
+        if let Some(it) = &node.dependencies {
+            for it in it {
+                v.visit_task_dependency(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+        if let Some(it) = &node.subjects {
+            for it in it {
+                v.visit_subject_of_assessment(it);
+            }
+        }
+        if let Some(it) = &node.tasks {
+            for it in it {
+                v.visit_task(it);
+            }
+        }
+        if let Some(it) = &node.timing {
+            v.visit_event_timing(it);
+        }
+    }
+    pub fn walk_associated_activity<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AssociatedActivity,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+        for it in &node.subjects {
+            v.visit_subject_of_assessment(it);
+        }
+    }
+    pub fn walk_task_dependency<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast TaskDependency,
+    ) {}
+    pub fn walk_event_timing<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast EventTiming,
+    ) {
+        if let Some(it) = &node.at_frequency {
+            v.visit_frequency_condition(it);
+        }
+        if let Some(it) = &node.on_date {
+            v.visit_on_date_condition(it);
+        }
+        if let Some(it) = &node.within_date_range {
+            v.visit_on_date_range_condition(it);
+        }
+    }
+    pub fn walk_frequency_condition<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast FrequencyCondition,
+    ) {
+        v.visit_time_unit(&node.unit);
+    }
+    pub fn walk_time_unit<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast TimeUnit,
+    ) {}
+    pub fn walk_on_date_condition<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast OnDateCondition,
+    ) {}
+    pub fn walk_on_date_range_condition<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast OnDateRangeCondition,
+    ) {}
+    pub fn walk_system_user<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SystemUser,
+    ) {
+        if let Some(it) = &node.authorized_privileges {
+            for it in it {
+                v.visit_privilege(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_privilege<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Privilege,
+    ) {}
+    pub fn walk_observation<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Observation,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.origins {
+            for it in it {
+                v.visit_origin(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.relevant_evidence {
+            for it in it {
+                v.visit_relevant_evidence(it);
+            }
+        }
+        if let Some(it) = &node.subjects {
+            for it in it {
+                v.visit_identifies_the_subject(it);
+            }
+        }
+    }
+    pub fn walk_relevant_evidence<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast RelevantEvidence,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_identifies_the_subject<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast IdentifiesTheSubject,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_identified_risk<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast IdentifiedRisk,
+    ) {
+        if let Some(it) = &node.characterizations {
+            for it in it {
+                v.visit_characterization(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.mitigating_factors {
+            for it in it {
+                v.visit_mitigating_factor(it);
+            }
+        }
+        if let Some(it) = &node.origins {
+            for it in it {
+                v.visit_origin(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.related_observations {
+            for it in it {
+                v.visit_risk_related_observation(it);
+            }
+        }
+        if let Some(it) = &node.remediations {
+            for it in it {
+                v.visit_risk_response(it);
+            }
+        }
+        if let Some(it) = &node.risk_log {
+            v.visit_risk_log(it);
+        }
+        if let Some(it) = &node.threat_ids {
+            for it in it {
+                v.visit_threat_id(it);
+            }
+        }
+    }
+    pub fn walk_characterization<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Characterization,
+    ) {
+        for it in &node.facets {
+            v.visit_facet(it);
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        v.visit_origin(&node.origin);
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_facet<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Facet) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_mitigating_factor<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast MitigatingFactor,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.subjects {
+            for it in it {
+                v.visit_identifies_the_subject(it);
+            }
+        }
+    }
+    pub fn walk_risk_related_observation<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast RiskRelatedObservation,
+    ) {}
+    pub fn walk_risk_response<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast RiskResponse,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.origins {
+            for it in it {
+                v.visit_origin(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.required_assets {
+            for it in it {
+                v.visit_required_asset(it);
+            }
+        }
+        if let Some(it) = &node.tasks {
+            for it in it {
+                v.visit_task(it);
+            }
+        }
+    }
+    pub fn walk_required_asset<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast RequiredAsset,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.subjects {
+            for it in it {
+                v.visit_identifies_the_subject(it);
+            }
+        }
+    }
+    pub fn walk_risk_log<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RiskLog) {
+        for it in &node.entries {
+            v.visit_risk_log_entry(it);
+        }
+    }
+    pub fn walk_risk_log_entry<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast RiskLogEntry,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.logged_by {
+            for it in it {
+                v.visit_logged_by(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.related_responses {
+            for it in it {
+                v.visit_risk_response_reference(it);
+            }
+        }
+    }
+    pub fn walk_risk_response_reference<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast RiskResponseReference,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.related_tasks {
+            for it in it {
+                v.visit_task_reference(it);
+            }
+        }
+    }
+    pub fn walk_threat_id<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast ThreatId,
+    ) {}
+}
+/// In place traversal of the model
+///
+/// Mutable counterpart of [`visit`](super::visit).
+pub mod visit_mut {
+    use super::*;
+    pub trait VisitMut {
+        fn visit_assessment_results_mut(&mut self, node: &mut AssessmentResults) {
+            walk_assessment_results_mut(self, node)
+        }
+        fn visit_security_assessment_results_sar_mut(
+            &mut self,
+            node: &mut SecurityAssessmentResultsSar,
+        ) {
+            walk_security_assessment_results_sar_mut(self, node)
+        }
+        fn visit_back_matter_mut(&mut self, node: &mut BackMatter) {
+            walk_back_matter_mut(self, node)
+        }
+        fn visit_resource_mut(&mut self, node: &mut Resource) {
+            walk_resource_mut(self, node)
+        }
+        fn visit_base64_mut(&mut self, node: &mut Base64) {
+            walk_base64_mut(self, node)
+        }
+        fn visit_citation_mut(&mut self, node: &mut Citation) {
+            walk_citation_mut(self, node)
+        }
+        fn visit_link_mut(&mut self, node: &mut Link) {
+            walk_link_mut(self, node)
+        }
+        fn visit_property_mut(&mut self, node: &mut Property) {
+            walk_property_mut(self, node)
+        }
+        fn visit_document_identifier_mut(&mut self, node: &mut DocumentIdentifier) {
+            walk_document_identifier_mut(self, node)
+        }
+        fn visit_resource_link_mut(&mut self, node: &mut ResourceLink) {
+            walk_resource_link_mut(self, node)
+        }
+        fn visit_hash_mut(&mut self, node: &mut Hash) {
+            walk_hash_mut(self, node)
+        }
+        fn visit_import_assessment_plan_mut(&mut self, node: &mut ImportAssessmentPlan) {
+            walk_import_assessment_plan_mut(self, node)
+        }
+        fn visit_assessment_results_local_definitions_mut(
+            &mut self,
+            node: &mut AssessmentResultsLocalDefinitions,
+        ) {
+            walk_assessment_results_local_definitions_mut(self, node)
+        }
+        fn visit_activity_mut(&mut self, node: &mut Activity) {
+            walk_activity_mut(self, node)
+        }
+        fn visit_reviewed_controls_and_control_objectives_mut(
+            &mut self,
+            node: &mut ReviewedControlsAndControlObjectives,
+        ) {
+            walk_reviewed_controls_and_control_objectives_mut(self, node)
+        }
+        fn visit_referenced_control_objectives_mut(
+            &mut self,
+            node: &mut ReferencedControlObjectives,
+        ) {
+            walk_referenced_control_objectives_mut(self, node)
+        }
+        fn visit_select_objective_mut(&mut self, node: &mut SelectObjective) {
+            walk_select_objective_mut(self, node)
+        }
+        fn visit_include_all_mut(&mut self, node: &mut IncludeAll) {
+            walk_include_all_mut(self, node)
+        }
+        fn visit_assessed_controls_mut(&mut self, node: &mut AssessedControls) {
+            walk_assessed_controls_mut(self, node)
+        }
+        fn visit_select_control_mut(&mut self, node: &mut SelectControl) {
+            walk_select_control_mut(self, node)
+        }
+        fn visit_responsible_role_mut(&mut self, node: &mut ResponsibleRole) {
+            walk_responsible_role_mut(self, node)
+        }
+        fn visit_step_mut(&mut self, node: &mut Step) {
+            walk_step_mut(self, node)
+        }
+        fn visit_assessment_specific_control_objective_mut(
+            &mut self,
+            node: &mut AssessmentSpecificControlObjective,
+        ) {
+            walk_assessment_specific_control_objective_mut(self, node)
+        }
+        fn visit_part_mut(&mut self, node: &mut Part) {
+            walk_part_mut(self, node)
+        }
+        fn visit_document_metadata_mut(&mut self, node: &mut DocumentMetadata) {
+            walk_document_metadata_mut(self, node)
+        }
+        fn visit_action_mut(&mut self, node: &mut Action) {
+            walk_action_mut(self, node)
+        }
+        fn visit_responsible_party_mut(&mut self, node: &mut ResponsibleParty) {
+            walk_responsible_party_mut(self, node)
+        }
+        fn visit_location_mut(&mut self, node: &mut Location) {
+            walk_location_mut(self, node)
+        }
+        fn visit_address_mut(&mut self, node: &mut Address) {
+            walk_address_mut(self, node)
+        }
+        fn visit_telephone_number_mut(&mut self, node: &mut TelephoneNumber) {
+            walk_telephone_number_mut(self, node)
+        }
+        fn visit_party_mut(&mut self, node: &mut Party) {
+            walk_party_mut(self, node)
+        }
+        fn visit_party_external_identifier_mut(
+            &mut self,
+            node: &mut PartyExternalIdentifier,
+        ) {
+            walk_party_external_identifier_mut(self, node)
+        }
+        fn visit_party_type_mut(&mut self, node: &mut PartyType) {
+            walk_party_type_mut(self, node)
+        }
+        fn visit_revision_history_entry_mut(&mut self, node: &mut RevisionHistoryEntry) {
+            walk_revision_history_entry_mut(self, node)
+        }
+        fn visit_role_mut(&mut self, node: &mut Role) {
+            walk_role_mut(self, node)
+        }
+        fn visit_assessment_result_mut(&mut self, node: &mut AssessmentResult) {
+            walk_assessment_result_mut(self, node)
+        }
+        fn visit_assessment_log_mut(&mut self, node: &mut AssessmentLog) {
+            walk_assessment_log_mut(self, node)
+        }
+        fn visit_assessment_log_entry_mut(&mut self, node: &mut AssessmentLogEntry) {
+            walk_assessment_log_entry_mut(self, node)
+        }
+        fn visit_logged_by_mut(&mut self, node: &mut LoggedBy) {
+            walk_logged_by_mut(self, node)
+        }
+        fn visit_task_reference_mut(&mut self, node: &mut TaskReference) {
+            walk_task_reference_mut(self, node)
+        }
+        fn visit_identified_subject_mut(&mut self, node: &mut IdentifiedSubject) {
+            walk_identified_subject_mut(self, node)
+        }
+        fn visit_subject_of_assessment_mut(&mut self, node: &mut SubjectOfAssessment) {
+            walk_subject_of_assessment_mut(self, node)
+        }
+        fn visit_select_assessment_subject_mut(
+            &mut self,
+            node: &mut SelectAssessmentSubject,
+        ) {
+            walk_select_assessment_subject_mut(self, node)
+        }
+        fn visit_attestation_statements_mut(
+            &mut self,
+            node: &mut AttestationStatements,
+        ) {
+            walk_attestation_statements_mut(self, node)
+        }
+        fn visit_assessment_part_mut(&mut self, node: &mut AssessmentPart) {
+            walk_assessment_part_mut(self, node)
+        }
+        fn visit_finding_mut(&mut self, node: &mut Finding) {
+            walk_finding_mut(self, node)
+        }
+        fn visit_origin_mut(&mut self, node: &mut Origin) {
+            walk_origin_mut(self, node)
+        }
+        fn visit_originating_actor_mut(&mut self, node: &mut OriginatingActor) {
+            walk_originating_actor_mut(self, node)
+        }
+        fn visit_actor_type_mut(&mut self, node: &mut ActorType) {
+            walk_actor_type_mut(self, node)
+        }
+        fn visit_finding_related_observation_mut(
+            &mut self,
+            node: &mut FindingRelatedObservation,
+        ) {
+            walk_finding_related_observation_mut(self, node)
+        }
+        fn visit_associated_risk_mut(&mut self, node: &mut AssociatedRisk) {
+            walk_associated_risk_mut(self, node)
+        }
+        fn visit_target_class_mut(&mut self, node: &mut TargetClass) {
+            walk_target_class_mut(self, node)
+        }
+        fn visit_implementation_status_mut(&mut self, node: &mut ImplementationStatus) {
+            walk_implementation_status_mut(self, node)
+        }
+        fn visit_finding_target_type_mut(&mut self, node: &mut FindingTargetType) {
+            walk_finding_target_type_mut(self, node)
+        }
+        fn visit_status_class_mut(&mut self, node: &mut StatusClass) {
+            walk_status_class_mut(self, node)
+        }
+        fn visit_objective_status_state_mut(&mut self, node: &mut ObjectiveStatusState) {
+            walk_objective_status_state_mut(self, node)
+        }
+        fn visit_result_local_definitions_mut(
+            &mut self,
+            node: &mut ResultLocalDefinitions,
+        ) {
+            walk_result_local_definitions_mut(self, node)
+        }
+        fn visit_assessment_assets_mut(&mut self, node: &mut AssessmentAssets) {
+            walk_assessment_assets_mut(self, node)
+        }
+        fn visit_assessment_platform_mut(&mut self, node: &mut AssessmentPlatform) {
+            walk_assessment_platform_mut(self, node)
+        }
+        fn visit_uses_component_mut(&mut self, node: &mut UsesComponent) {
+            walk_uses_component_mut(self, node)
+        }
+        fn visit_component_mut(&mut self, node: &mut Component) {
+            walk_component_mut(self, node)
+        }
+        fn visit_service_protocol_information_mut(
+            &mut self,
+            node: &mut ServiceProtocolInformation,
+        ) {
+            walk_service_protocol_information_mut(self, node)
+        }
+        fn visit_port_range_mut(&mut self, node: &mut PortRange) {
+            walk_port_range_mut(self, node)
+        }
+        fn visit_transport_mut(&mut self, node: &mut Transport) {
+            walk_transport_mut(self, node)
+        }
+        fn visit_status_mut(&mut self, node: &mut Status) {
+            walk_status_mut(self, node)
+        }
+        fn visit_state_mut(&mut self, node: &mut State) {
+            walk_state_mut(self, node)
+        }
+        fn visit_inventory_item_mut(&mut self, node: &mut InventoryItem) {
+            walk_inventory_item_mut(self, node)
+        }
+        fn visit_implemented_component_mut(&mut self, node: &mut ImplementedComponent) {
+            walk_implemented_component_mut(self, node)
+        }
+        fn visit_task_mut(&mut self, node: &mut Task) {
+            walk_task_mut(self, node)
+        }
+        fn visit_associated_activity_mut(&mut self, node: &mut AssociatedActivity) {
+            walk_associated_activity_mut(self, node)
+        }
+        fn visit_task_dependency_mut(&mut self, node: &mut TaskDependency) {
+            walk_task_dependency_mut(self, node)
+        }
+        fn visit_event_timing_mut(&mut self, node: &mut EventTiming) {
+            walk_event_timing_mut(self, node)
+        }
+        fn visit_frequency_condition_mut(&mut self, node: &mut FrequencyCondition) {
+            walk_frequency_condition_mut(self, node)
+        }
+        fn visit_time_unit_mut(&mut self, node: &mut TimeUnit) {
+            walk_time_unit_mut(self, node)
+        }
+        fn visit_on_date_condition_mut(&mut self, node: &mut OnDateCondition) {
+            walk_on_date_condition_mut(self, node)
+        }
+        fn visit_on_date_range_condition_mut(
+            &mut self,
+            node: &mut OnDateRangeCondition,
+        ) {
+            walk_on_date_range_condition_mut(self, node)
+        }
+        fn visit_system_user_mut(&mut self, node: &mut SystemUser) {
+            walk_system_user_mut(self, node)
+        }
+        fn visit_privilege_mut(&mut self, node: &mut Privilege) {
+            walk_privilege_mut(self, node)
+        }
+        fn visit_observation_mut(&mut self, node: &mut Observation) {
+            walk_observation_mut(self, node)
+        }
+        fn visit_relevant_evidence_mut(&mut self, node: &mut RelevantEvidence) {
+            walk_relevant_evidence_mut(self, node)
+        }
+        fn visit_identifies_the_subject_mut(&mut self, node: &mut IdentifiesTheSubject) {
+            walk_identifies_the_subject_mut(self, node)
+        }
+        fn visit_identified_risk_mut(&mut self, node: &mut IdentifiedRisk) {
+            walk_identified_risk_mut(self, node)
+        }
+        fn visit_characterization_mut(&mut self, node: &mut Characterization) {
+            walk_characterization_mut(self, node)
+        }
+        fn visit_facet_mut(&mut self, node: &mut Facet) {
+            walk_facet_mut(self, node)
+        }
+        fn visit_mitigating_factor_mut(&mut self, node: &mut MitigatingFactor) {
+            walk_mitigating_factor_mut(self, node)
+        }
+        fn visit_risk_related_observation_mut(
+            &mut self,
+            node: &mut RiskRelatedObservation,
+        ) {
+            walk_risk_related_observation_mut(self, node)
+        }
+        fn visit_risk_response_mut(&mut self, node: &mut RiskResponse) {
+            walk_risk_response_mut(self, node)
+        }
+        fn visit_required_asset_mut(&mut self, node: &mut RequiredAsset) {
+            walk_required_asset_mut(self, node)
+        }
+        fn visit_risk_log_mut(&mut self, node: &mut RiskLog) {
+            walk_risk_log_mut(self, node)
+        }
+        fn visit_risk_log_entry_mut(&mut self, node: &mut RiskLogEntry) {
+            walk_risk_log_entry_mut(self, node)
+        }
+        fn visit_risk_response_reference_mut(
+            &mut self,
+            node: &mut RiskResponseReference,
+        ) {
+            walk_risk_response_reference_mut(self, node)
+        }
+        fn visit_threat_id_mut(&mut self, node: &mut ThreatId) {
+            walk_threat_id_mut(self, node)
+        }
+    }
+    pub fn walk_assessment_results_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessmentResults,
+    ) {
+        v.visit_security_assessment_results_sar_mut(&mut node.assessment_results);
+    }
+    pub fn walk_security_assessment_results_sar_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut SecurityAssessmentResultsSar,
+    ) {
+        if let Some(it) = &mut node.back_matter {
+            v.visit_back_matter_mut(it);
+        }
+        v.visit_import_assessment_plan_mut(&mut node.import_ap);
+        if let Some(it) = &mut node.local_definitions {
+            v.visit_assessment_results_local_definitions_mut(it);
+        }
+        v.visit_document_metadata_mut(&mut node.metadata);
+        for it in &mut node.results {
+            v.visit_assessment_result_mut(it);
+        }
+    }
+    pub fn walk_back_matter_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut BackMatter) {
+        if let Some(it) = &mut node.resources {
+            for it in it {
+                v.visit_resource_mut(it);
+            }
+        }
+    }
+    pub fn walk_resource_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Resource) {
+        if let Some(it) = &mut node.base64 {
+            v.visit_base64_mut(it);
+        }
+        if let Some(it) = &mut node.citation {
+            v.visit_citation_mut(it);
+        }
+        if let Some(it) = &mut node.document_ids {
+            for it in it {
+                v.visit_document_identifier_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.rlinks {
+            for it in it {
+                v.visit_resource_link_mut(it);
+            }
+        }
+    }
+    pub fn walk_base64_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Base64) {}
+    pub fn walk_citation_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Citation) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_link_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Link) {}
+    pub fn walk_property_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Property) {}
+    pub fn walk_document_identifier_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut DocumentIdentifier,
+    ) {}
+    pub fn walk_resource_link_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ResourceLink,
+    ) {
+        if let Some(it) = &mut node.hashes {
+            for it in it {
+                v.visit_hash_mut(it);
+            }
+        }
+    }
+    pub fn walk_hash_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Hash) {}
+    pub fn walk_import_assessment_plan_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut ImportAssessmentPlan,
+    ) {}
+    pub fn walk_assessment_results_local_definitions_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessmentResultsLocalDefinitions,
+    ) {
+        if let Some(it) = &mut node.activities {
+            for it in it {
+                v.visit_activity_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.objectives_and_methods {
+            for it in it {
+                v.visit_assessment_specific_control_objective_mut(it);
+            }
+        }
+    }
+    pub fn walk_activity_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Activity) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.related_controls {
+            v.visit_reviewed_controls_and_control_objectives_mut(it);
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.steps {
+            for it in it {
+                v.visit_step_mut(it);
+            }
+        }
+    }
+    pub fn walk_reviewed_controls_and_control_objectives_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ReviewedControlsAndControlObjectives,
+    ) {
+        if let Some(it) = &mut node.control_objective_selections {
+            for it in it {
+                v.visit_referenced_control_objectives_mut(it);
+            }
+        }
+        for it in &mut node.control_selections {
+            v.visit_assessed_controls_mut(it);
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_referenced_control_objectives_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ReferencedControlObjectives,
+    ) {
+        if let Some(it) = &mut node.exclude_objectives {
+            for it in it {
+                v.visit_select_objective_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.include_all {
+            v.visit_include_all_mut(it);
+        }
+        if let Some(it) = &mut node.include_objectives {
+            for it in it {
+                v.visit_select_objective_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_select_objective_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut SelectObjective,
+    ) {}
+    pub fn walk_include_all_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut IncludeAll,
+    ) {}
+    pub fn walk_assessed_controls_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessedControls,
+    ) {
+        if let Some(it) = &mut node.exclude_controls {
+            for it in it {
+                v.visit_select_control_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.include_all {
+            v.visit_include_all_mut(it);
+        }
+        if let Some(it) = &mut node.include_controls {
+            for it in it {
+                v.visit_select_control_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_select_control_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut SelectControl,
+    ) {}
+    pub fn walk_responsible_role_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ResponsibleRole,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_step_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Step) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.reviewed_controls {
+            v.visit_reviewed_controls_and_control_objectives_mut(it);
+        }
+    }
+    pub fn walk_assessment_specific_control_objective_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessmentSpecificControlObjective,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        for it in &mut node.parts {
+            v.visit_part_mut(it);
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_part_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Part) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.parts {
+            for it in it {
+                v.visit_part_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_document_metadata_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut DocumentMetadata,
+    ) {
+        if let Some(it) = &mut node.actions {
+            for it in it {
+                v.visit_action_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.document_ids {
+            for it in it {
+                v.visit_document_identifier_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.locations {
+            for it in it {
+                v.visit_location_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.parties {
+            for it in it {
+                v.visit_party_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.revisions {
+            for it in it {
+                v.visit_revision_history_entry_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.roles {
+            for it in it {
+                v.visit_role_mut(it);
+            }
+        }
+    }
+    pub fn walk_action_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Action) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+    }
+    pub fn walk_responsible_party_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ResponsibleParty,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_location_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Location) {
+        if let Some(it) = &mut node.address {
+            v.visit_address_mut(it);
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number_mut(it);
+            }
+        }
+    }
+    pub fn walk_address_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Address) {}
+    pub fn walk_telephone_number_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut TelephoneNumber,
+    ) {}
+    pub fn walk_party_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Party) {
+        if let Some(it) = &mut node.addresses {
+            for it in it {
+                v.visit_address_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.external_ids {
+            for it in it {
+                v.visit_party_external_identifier_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number_mut(it);
+            }
+        }
+        v.visit_party_type_mut(&mut node.party_type);
+    }
+    pub fn walk_party_external_identifier_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut PartyExternalIdentifier,
+    ) {}
+    pub fn walk_party_type_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut PartyType) {}
+    pub fn walk_revision_history_entry_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut RevisionHistoryEntry,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_role_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Role) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_assessment_result_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessmentResult,
+    ) {
+        if let Some(it) = &mut node.assessment_log {
+            v.visit_assessment_log_mut(it);
+        }
+        if let Some(it) = &mut node.attestations {
+            for it in it {
+                v.visit_attestation_statements_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.findings {
+            for it in it {
+                v.visit_finding_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.local_definitions {
+            v.visit_result_local_definitions_mut(it);
+        }
+        if let Some(it) = &mut node.observations {
+            for it in it {
+                v.visit_observation_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        v.visit_reviewed_controls_and_control_objectives_mut(
+            &mut node.reviewed_controls,
+        );
+        if let Some(it) = &mut node.risks {
+            for it in it {
+                v.visit_identified_risk_mut(it);
+            }
+        }
+    }
+    pub fn walk_assessment_log_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessmentLog,
+    ) {
+        for it in &mut node.entries {
+            v.visit_assessment_log_entry_mut(it);
+        }
+    }
+    pub fn walk_assessment_log_entry_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessmentLogEntry,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.logged_by {
+            for it in it {
+                v.visit_logged_by_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.related_tasks {
+            for it in it {
+                v.visit_task_reference_mut(it);
+            }
+        }
+    }
+    pub fn walk_logged_by_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut LoggedBy) {}
+    pub fn walk_task_reference_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut TaskReference,
+    ) {
+        if let Some(it) = &mut node.identified_subject {
+            v.visit_identified_subject_mut(it);
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.subjects {
+            for it in it {
+                v.visit_subject_of_assessment_mut(it);
+            }
+        }
+    }
+    pub fn walk_identified_subject_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut IdentifiedSubject,
+    ) {
+        for it in &mut node.subjects {
+            v.visit_subject_of_assessment_mut(it);
+        }
+    }
+    pub fn walk_subject_of_assessment_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut SubjectOfAssessment,
+    ) {
+        if let Some(it) = &mut node.exclude_subjects {
+            for it in it {
+                v.visit_select_assessment_subject_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.include_all {
+            v.visit_include_all_mut(it);
+        }
+        if let Some(it) = &mut node.include_subjects {
+            for it in it {
+                v.visit_select_assessment_subject_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_select_assessment_subject_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut SelectAssessmentSubject,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_attestation_statements_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AttestationStatements,
+    ) {
+        for it in &mut node.parts {
+            v.visit_assessment_part_mut(it);
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+    }
+    pub fn walk_assessment_part_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessmentPart,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.parts {
+            for it in it {
+                v.visit_assessment_part_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_finding_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Finding) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.origins {
+            for it in it {
+                v.visit_origin_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.related_observations {
+            for it in it {
+                v.visit_finding_related_observation_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.related_risks {
+            for it in it {
+                v.visit_associated_risk_mut(it);
+            }
+        }
+        v.visit_target_class_mut(&mut node.target);
+    }
+    pub fn walk_origin_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Origin) {
+        for it in &mut node.actors {
+            v.visit_originating_actor_mut(it);
+        }
+        if let Some(it) = &mut node.related_tasks {
+            for it in it {
+                v.visit_task_reference_mut(it);
+            }
+        }
+    }
+    pub fn walk_originating_actor_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut OriginatingActor,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        v.visit_actor_type_mut(&mut node.originating_actor_type);
+    }
+    pub fn walk_actor_type_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut ActorType) {}
+    pub fn walk_finding_related_observation_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut FindingRelatedObservation,
+    ) {}
+    pub fn walk_associated_risk_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut AssociatedRisk,
+    ) {}
+    pub fn walk_target_class_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut TargetClass,
+    ) {
+        if let Some(it) = &mut node.implementation_status {
+            v.visit_implementation_status_mut(it);
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        v.visit_status_class_mut(&mut node.status);
+        v.visit_finding_target_type_mut(&mut node.objective_status_type);
+    }
+    pub fn walk_implementation_status_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut ImplementationStatus,
+    ) {}
+    pub fn walk_finding_target_type_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut FindingTargetType,
+    ) {}
+    pub fn walk_status_class_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut StatusClass,
+    ) {
+        v.visit_objective_status_state_mut(&mut node.state);
+    }
+    pub fn walk_objective_status_state_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut ObjectiveStatusState,
+    ) {}
+    pub fn walk_result_local_definitions_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ResultLocalDefinitions,
+    ) {
+        if let Some(it) = &mut node.assessment_assets {
+            v.visit_assessment_assets_mut(it);
+        }
+        if let Some(it) = &mut node.components {
+            for it in it {
+                v.visit_component_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.inventory_items {
+            for it in it {
+                v.visit_inventory_item_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.tasks {
+            for it in it {
+                v.visit_task_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.users {
+            for it in it {
+                v.visit_system_user_mut(it);
+            }
+        }
+    }
+    pub fn walk_assessment_assets_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessmentAssets,
+    ) {
+        for it in &mut node.assessment_platforms {
+            v.visit_assessment_platform_mut(it);
+        }
+        if let Some(it) = &mut node.components {
+            for it in it {
+                v.visit_component_mut(it);
+            }
+        }
+    }
+    pub fn walk_assessment_platform_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssessmentPlatform,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.uses_components {
+            for it in it {
+                v.visit_uses_component_mut(it);
+            }
+        }
+    }
+    pub fn walk_uses_component_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut UsesComponent,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+    }
+    pub fn walk_component_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Component) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.protocols {
+            for it in it {
+                v.visit_service_protocol_information_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+        v.visit_status_mut(&mut node.status);
+    }
+    pub fn walk_service_protocol_information_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ServiceProtocolInformation,
+    ) {
+        if let Some(it) = &mut node.port_ranges {
+            for it in it {
+                v.visit_port_range_mut(it);
+            }
+        }
+    }
+    pub fn walk_port_range_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PortRange) {
+        if let Some(it) = &mut node.transport {
+            v.visit_transport_mut(it);
+        }
+    }
+    pub fn walk_transport_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Transport) {}
+    pub fn walk_status_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Status) {
+        v.visit_state_mut(&mut node.state);
+    }
+    pub fn walk_state_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut State) {}
+    pub fn walk_inventory_item_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut InventoryItem,
+    ) {
+        if let Some(it) = &mut node.implemented_components {
+            for it in it {
+                v.visit_implemented_component_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+    }
+    pub fn walk_implemented_component_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ImplementedComponent,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+    }
+    pub fn walk_task_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Task) {
+        if let Some(it) = &mut node.associated_activities {
+            for it in it {
+                v.visit_associated_activity_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.dependencies {
+            for it in it {
+                v.visit_task_dependency_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.subjects {
+            for it in it {
+                v.visit_subject_of_assessment_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.tasks {
+            for it in it {
+                v.visit_task_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.timing {
+            v.visit_event_timing_mut(it);
+        }
+    }
+    pub fn walk_associated_activity_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AssociatedActivity,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+        for it in &mut node.subjects {
+            v.visit_subject_of_assessment_mut(it);
+        }
+    }
+    pub fn walk_task_dependency_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut TaskDependency,
+    ) {}
+    pub fn walk_event_timing_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut EventTiming,
+    ) {
+        if let Some(it) = &mut node.at_frequency {
+            v.visit_frequency_condition_mut(it);
+        }
+        if let Some(it) = &mut node.on_date {
+            v.visit_on_date_condition_mut(it);
+        }
+        if let Some(it) = &mut node.within_date_range {
+            v.visit_on_date_range_condition_mut(it);
+        }
+    }
+    pub fn walk_frequency_condition_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut FrequencyCondition,
+    ) {
+        v.visit_time_unit_mut(&mut node.unit);
+    }
+    pub fn walk_time_unit_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut TimeUnit) {}
+    pub fn walk_on_date_condition_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut OnDateCondition,
+    ) {}
+    pub fn walk_on_date_range_condition_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut OnDateRangeCondition,
+    ) {}
+    pub fn walk_system_user_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SystemUser) {
+        if let Some(it) = &mut node.authorized_privileges {
+            for it in it {
+                v.visit_privilege_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_privilege_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Privilege) {}
+    pub fn walk_observation_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut Observation,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.origins {
+            for it in it {
+                v.visit_origin_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.relevant_evidence {
+            for it in it {
+                v.visit_relevant_evidence_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.subjects {
+            for it in it {
+                v.visit_identifies_the_subject_mut(it);
+            }
+        }
+    }
+    pub fn walk_relevant_evidence_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut RelevantEvidence,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_identifies_the_subject_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut IdentifiesTheSubject,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_identified_risk_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut IdentifiedRisk,
+    ) {
+        if let Some(it) = &mut node.characterizations {
+            for it in it {
+                v.visit_characterization_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.mitigating_factors {
+            for it in it {
+                v.visit_mitigating_factor_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.origins {
+            for it in it {
+                v.visit_origin_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.related_observations {
+            for it in it {
+                v.visit_risk_related_observation_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.remediations {
+            for it in it {
+                v.visit_risk_response_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.risk_log {
+            v.visit_risk_log_mut(it);
+        }
+        if let Some(it) = &mut node.threat_ids {
+            for it in it {
+                v.visit_threat_id_mut(it);
+            }
+        }
+    }
+    pub fn walk_characterization_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut Characterization,
+    ) {
+        for it in &mut node.facets {
+            v.visit_facet_mut(it);
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        v.visit_origin_mut(&mut node.origin);
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_facet_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Facet) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_mitigating_factor_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut MitigatingFactor,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.subjects {
+            for it in it {
+                v.visit_identifies_the_subject_mut(it);
+            }
+        }
+    }
+    pub fn walk_risk_related_observation_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut RiskRelatedObservation,
+    ) {}
+    pub fn walk_risk_response_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut RiskResponse,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.origins {
+            for it in it {
+                v.visit_origin_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.required_assets {
+            for it in it {
+                v.visit_required_asset_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.tasks {
+            for it in it {
+                v.visit_task_mut(it);
+            }
+        }
+    }
+    pub fn walk_required_asset_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut RequiredAsset,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.subjects {
+            for it in it {
+                v.visit_identifies_the_subject_mut(it);
+            }
+        }
+    }
+    pub fn walk_risk_log_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RiskLog) {
+        for it in &mut node.entries {
+            v.visit_risk_log_entry_mut(it);
+        }
+    }
+    pub fn walk_risk_log_entry_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut RiskLogEntry,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.logged_by {
+            for it in it {
+                v.visit_logged_by_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.related_responses {
+            for it in it {
+                v.visit_risk_response_reference_mut(it);
+            }
+        }
+    }
+    pub fn walk_risk_response_reference_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut RiskResponseReference,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.related_tasks {
+            for it in it {
+                v.visit_task_reference_mut(it);
+            }
+        }
+    }
+    pub fn walk_threat_id_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut ThreatId) {}
 }
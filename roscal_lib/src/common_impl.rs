@@ -1,4 +1,6 @@
-use crate::{assessment::*, control::*, implementation::*};
+use crate::{
+    assessment::*, control::*, implementation::*, validation::RawHash,
+};
 
 macro_rules! impl_conversion {
     ( $( $t:ty ),* ) => {
@@ -406,3 +408,34 @@ impl_conversion!(
     ssp::SystemUser,
     ssp::TelephoneNumber
 );
+
+macro_rules! impl_hash_try_from {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl TryFrom<RawHash> for $t {
+                type Error = String;
+
+                fn try_from(raw: RawHash) -> Result<Self, Self::Error> {
+                    crate::validation::is_valid_digest(&raw.algorithm, &raw.value)?;
+
+                    Ok(Self {
+                        algorithm: raw.algorithm,
+                        value: raw.value,
+                        extensions: raw.extensions,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+// Digests are checked against their algorithm, which needs both fields
+impl_hash_try_from!(
+    assessment_plan::Hash,
+    assessment_results::Hash,
+    poam::Hash,
+    catalog::Hash,
+    profile::Hash,
+    component_definition::Hash,
+    ssp::Hash
+);
@@ -2,5 +2,7 @@
 
 /// Catalog Model
 pub mod catalog;
+/// Parameter Substitution In Control Prose
+pub mod param;
 /// Profile Model
 pub mod profile;
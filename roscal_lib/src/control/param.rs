@@ -0,0 +1,291 @@
+use std::{collections::HashMap, fmt::Write};
+
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+
+use crate::{
+    control::{
+        catalog::{self, Catalog, Control, ControlGroup, Parameter, Part},
+        profile::{self, ParameterSetting, Profile},
+    },
+    implementation::ssp::SetParameterValue,
+    render::{indent, inline},
+};
+
+lazy_static! {
+    static ref INSERT_PARAM: Regex =
+        Regex::new(r"\{\{\s*insert:\s*param,\s*([^\s}]+)\s*\}\}").unwrap();
+}
+
+/// Placeholders nested deeper than this inside selection choices are left
+/// as they are
+const MAX_PARAM_DEPTH: usize = 8;
+
+/// Resolves `{{ insert: param, <id> }}` placeholders found in control prose.
+///
+/// A parameter resolves to the first of the following that is available:
+/// 1. the values of an SSP `SetParameterValue`
+/// 2. the values of a profile `ParameterSetting`
+/// 3. the default values of the catalog `Parameter`
+/// 4. the selection, rendered as `[Selection (one or more): a; b]`
+/// 5. the label, rendered as `[Assignment: label]`
+///
+/// A profile setting's selection and label take precedence over the ones in
+/// the catalog. Placeholders referencing unknown parameters are left untouched.
+///
+/// ```no_run
+/// use roscal_lib::control::{catalog::Catalog, param::ParamResolver};
+///
+/// fn render(catalog: &Catalog) {
+///     let resolver = ParamResolver::new(catalog);
+///
+///     for control in catalog.catalog.controls.iter().flatten() {
+///         println!("{:?}", resolver.render_statement(control));
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParamResolver<'a> {
+    params: HashMap<&'a str, &'a Parameter>,
+    settings: HashMap<&'a str, &'a ParameterSetting>,
+    set_values: HashMap<&'a str, &'a SetParameterValue>,
+}
+
+impl<'a> ParamResolver<'a> {
+    /// Create a resolver from every parameter defined in the catalog
+    pub fn new(catalog: &'a Catalog) -> Self {
+        let mut resolver = Self::default();
+        let catalog = &catalog.catalog;
+
+        resolver.collect_params(catalog.params.as_ref());
+        for elt in catalog.controls.iter().flatten() {
+            resolver.collect_control_params(elt)
+        }
+        for elt in catalog.groups.iter().flatten() {
+            resolver.collect_group_params(elt)
+        }
+
+        resolver
+    }
+
+    /// Apply the parameter settings of a profile's `modify` block
+    pub fn with_profile(mut self, profile: &'a Profile) -> Self {
+        let settings = profile
+            .profile
+            .modify
+            .iter()
+            .flat_map(|m| m.set_parameters.iter().flatten());
+
+        for elt in settings {
+            self.settings.insert(elt.param_id.as_str(), elt);
+        }
+
+        self
+    }
+
+    /// Apply parameter values set by an SSP, later values take precedence
+    pub fn with_set_parameters(
+        mut self,
+        set_parameters: &'a [SetParameterValue],
+    ) -> Self {
+        for elt in set_parameters {
+            self.set_values.insert(elt.param_id.as_str(), elt);
+        }
+
+        self
+    }
+
+    /// Text substituted for the parameter, or `None` if it is unknown
+    pub fn resolve(&self, param_id: &str) -> Option<String> {
+        self.resolve_with_depth(param_id, 0)
+    }
+
+    /// Replace every parameter placeholder in the prose
+    pub fn render_prose(&self, prose: &str) -> String {
+        self.render_with_depth(prose, 0)
+    }
+
+    /// Render a part's prose followed by its statement items as a Markdown
+    /// list, each item prefixed by its label
+    pub fn render_part(&self, part: &Part) -> String {
+        let mut output = String::new();
+
+        if let Some(prose) = &part.prose {
+            output.push_str(self.render_prose(prose).trim());
+        }
+
+        let mut items = String::new();
+        for elt in part.parts.iter().flatten() {
+            if elt.name == "item" {
+                self.push_item(&mut items, elt, 0)
+            }
+        }
+
+        if !items.is_empty() {
+            if !output.is_empty() {
+                output.push_str("\n\n");
+            }
+            output.push_str(items.trim_end());
+        }
+
+        output
+    }
+
+    /// Render the statement of a control, or `None` if it has no statement
+    pub fn render_statement(&self, control: &Control) -> Option<String> {
+        control
+            .parts
+            .iter()
+            .flatten()
+            .find(|p| p.name == "statement")
+            .map(|p| self.render_part(p))
+    }
+
+    fn collect_params(&mut self, params: Option<&'a Vec<Parameter>>) {
+        for elt in params.into_iter().flatten() {
+            self.params.insert(elt.id.as_str(), elt);
+        }
+    }
+
+    fn collect_control_params(&mut self, control: &'a Control) {
+        self.collect_params(control.params.as_ref());
+
+        for elt in control.controls.iter().flatten() {
+            self.collect_control_params(elt)
+        }
+    }
+
+    fn collect_group_params(&mut self, group: &'a ControlGroup) {
+        self.collect_params(group.params.as_ref());
+
+        for elt in group.controls.iter().flatten() {
+            self.collect_control_params(elt)
+        }
+
+        for elt in group.groups.iter().flatten() {
+            self.collect_group_params(elt)
+        }
+    }
+
+    fn render_with_depth(&self, prose: &str, depth: usize) -> String {
+        if depth > MAX_PARAM_DEPTH {
+            return prose.to_owned();
+        }
+
+        INSERT_PARAM
+            .replace_all(prose, |caps: &Captures| {
+                self.resolve_with_depth(&caps[1], depth)
+                    .unwrap_or_else(|| caps[0].to_owned())
+            })
+            .into_owned()
+    }
+
+    fn resolve_with_depth(
+        &self,
+        param_id: &str,
+        depth: usize,
+    ) -> Option<String> {
+        let param = self.params.get(param_id);
+        let setting = self.settings.get(param_id);
+        let set_value = self.set_values.get(param_id);
+
+        if param.is_none() && setting.is_none() && set_value.is_none() {
+            return None;
+        }
+
+        let values = set_value
+            .map(|s| &s.values)
+            .or(setting.and_then(|s| s.values.as_ref()))
+            .or(param.and_then(|p| p.values.as_ref()))
+            .filter(|v| !v.is_empty());
+        if let Some(values) = values {
+            return Some(values.join(", "));
+        }
+
+        let select = match (setting.and_then(|s| s.select.as_ref()), param) {
+            (Some(select), _) => Some(Selection::from(select)),
+            (None, Some(param)) => param.select.as_ref().map(Selection::from),
+            _ => None,
+        };
+        if let Some(select) = select {
+            let choices: Vec<String> = select
+                .choices
+                .iter()
+                .map(|c| inline(&self.render_with_depth(c, depth + 1)))
+                .collect();
+            let how_many = if select.one_or_more {
+                " (one or more)"
+            } else {
+                ""
+            };
+
+            return Some(format!(
+                "[Selection{how_many}: {}]",
+                choices.join("; ")
+            ));
+        }
+
+        let label = setting
+            .and_then(|s| s.label.as_deref())
+            .or(param.and_then(|p| p.label.as_deref()));
+
+        Some(format!(
+            "[Assignment: {}]",
+            label.map(inline).unwrap_or(param_id.to_owned())
+        ))
+    }
+
+    fn push_item(&self, output: &mut String, part: &Part, depth: usize) {
+        let pad = "  ".repeat(depth);
+        let mut line = String::new();
+
+        let label = part
+            .props
+            .iter()
+            .flatten()
+            .find(|p| p.name == "label" && p.ns.is_none());
+        if let Some(label) = label {
+            line.push_str(&label.value);
+            line.push(' ');
+        }
+        if let Some(prose) = &part.prose {
+            line.push_str(self.render_prose(prose).trim());
+        }
+
+        let _ = writeln!(output, "{pad}- {}", indent(&line, pad.len() + 2));
+
+        for elt in part.parts.iter().flatten() {
+            self.push_item(output, elt, depth + 1)
+        }
+    }
+}
+
+/// Selection normalised over the catalog and profile models
+struct Selection<'a> {
+    choices: Vec<&'a String>,
+    one_or_more: bool,
+}
+
+impl<'a> From<&'a catalog::Selection> for Selection<'a> {
+    fn from(value: &'a catalog::Selection) -> Self {
+        Self {
+            choices: value.choice.iter().flatten().collect(),
+            one_or_more: matches!(
+                value.how_many,
+                Some(catalog::ParameterCardinality::OneOrMore)
+            ),
+        }
+    }
+}
+
+impl<'a> From<&'a profile::Selection> for Selection<'a> {
+    fn from(value: &'a profile::Selection) -> Self {
+        Self {
+            choices: value.choice.iter().flatten().collect(),
+            one_or_more: matches!(
+                value.how_many,
+                Some(profile::ParameterCardinality::OneOrMore)
+            ),
+        }
+    }
+}
@@ -1,29 +1,33 @@
+use crate::markup::Markup;
+use crate::parse::Extensions;
+use crate::scalar::{DateTimeWithTz, EmailAddress, OscalUuid, Token, Uri, UriReference};
+use crate::validation;
 use derivative::Derivative;
-use strum::EnumString;
 use derive_builder::Builder;
-use serde::{Serialize, Deserialize};
-use crate::validation;
+use serde::{Deserialize, Serialize};
+use strum::EnumString;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Profile {
     #[serde(rename = "$schema")]
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schema: Option<String>,
     pub profile: ProfileClass,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Each OSCAL profile is defined by a profile element.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ProfileClass {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -42,19 +46,32 @@ pub struct ProfileClass {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ProfileClassBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A collection of resources that may be referenced from within the OSCAL document instance.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct BackMatter {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub resources: Option<Vec<Resource>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A resource associated with content in the containing document instance. A resource may be
@@ -63,9 +80,8 @@ pub struct BackMatter {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Resource {
     /// A resource encoded using the Base64 alphabet defined by RFC 2045.
     #[builder(setter(into, strip_option), default)]
@@ -78,8 +94,7 @@ pub struct Resource {
     /// An optional short summary of the resource used to indicate the purpose of the resource.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub document_ids: Option<Vec<DocumentIdentifier>>,
@@ -88,8 +103,7 @@ pub struct Resource {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rlinks: Option<Vec<ResourceLink>>,
@@ -97,24 +111,33 @@ pub struct Resource {
     /// navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A unique identifier for a resource.
     #[serde(
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ResourceBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A resource encoded using the Base64 alphabet defined by RFC 2045.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Base64 {
     /// Name of the file before it was encoded as Base64 to be embedded in a resource. This is
     /// the name that will be assigned to the file when the file is decoded.
@@ -124,7 +147,7 @@ pub struct Base64 {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub filename: Option<String>,
+    pub filename: Option<Token>,
     /// A label that indicates the nature of a resource, as a data serialization or format.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -134,14 +157,24 @@ pub struct Base64 {
         deserialize_with = "validation::deser_base64"
     )]
     pub value: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl Base64Builder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("filename", &self.filename)?;
+        validation::check_base64("value", &self.value)?;
+        Ok(())
+    }
 }
 
 /// An optional citation consisting of end note text using structured markup.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Citation {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -150,8 +183,11 @@ pub struct Citation {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
     /// A line of citation text.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub text: String,
+    pub text: Markup,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A reference to a local or remote resource, that has a specific relation to the containing
@@ -159,16 +195,15 @@ pub struct Citation {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Link {
     /// A resolvable URL reference to a resource.
     #[serde(
         serialize_with = "validation::ser_uri_ref",
         deserialize_with = "validation::deser_uri_ref"
     )]
-    pub href: String,
+    pub href: UriReference,
     /// A label that indicates the nature of a resource, as a data serialization or format.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -181,7 +216,7 @@ pub struct Link {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub rel: Option<String>,
+    pub rel: Option<Token>,
     /// In case where the href points to a back-matter/resource, this value will indicate the URI
     /// fragment to append to any rlink associated with the resource. This value MUST be URI
     /// encoded.
@@ -191,17 +226,26 @@ pub struct Link {
     /// A textual label to associate with the link, which may be used for presentation in a tool.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub text: Option<String>,
+    pub text: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl LinkBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri_ref("href", &self.href)?;
+        validation::check_token("rel", &self.rel)?;
+        Ok(())
+    }
 }
 
 /// An attribute, characteristic, or quality of the containing object expressed as a
 /// namespace qualified name/value pair.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Property {
     /// A textual label that provides a sub-type or characterization of the property's name.
     #[builder(setter(into, strip_option), default)]
@@ -210,7 +254,7 @@ pub struct Property {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub class: Option<String>,
+    pub class: Option<Token>,
     /// An identifier for relating distinct sets of properties.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -218,14 +262,14 @@ pub struct Property {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub group: Option<String>,
+    pub group: Option<Token>,
     /// A textual label, within a namespace, that uniquely identifies a specific attribute,
     /// characteristic, or quality of the property's containing object.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub name: String,
+    pub name: Token,
     /// A namespace qualifying the property's name. This allows different organizations to
     /// associate distinct semantics with the same name.
     #[builder(setter(into, strip_option), default)]
@@ -234,11 +278,10 @@ pub struct Property {
         serialize_with = "validation::ser_uri_opt",
         deserialize_with = "validation::deser_uri_opt"
     )]
-    pub ns: Option<String>,
+    pub ns: Option<Uri>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A unique identifier for a property.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -247,17 +290,30 @@ pub struct Property {
         deserialize_with = "validation::deser_uuid_opt"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: Option<String>,
+    pub uuid: Option<OscalUuid>,
     /// Indicates the value of the attribute, characteristic, or quality.
     pub value: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PropertyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("class", &self.class)?;
+        validation::check_token("group", &self.group)?;
+        validation::check_token("name", &self.name)?;
+        validation::check_uri("ns", &self.ns)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A document identifier qualified by an identifier scheme.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct DocumentIdentifier {
     pub identifier: String,
     /// Qualifies the kind of document identifier using a URI. If the scheme is not provided the
@@ -268,7 +324,18 @@ pub struct DocumentIdentifier {
         serialize_with = "validation::ser_uri_opt",
         deserialize_with = "validation::deser_uri_opt"
     )]
-    pub scheme: Option<String>,
+    pub scheme: Option<Uri>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl DocumentIdentifierBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri("scheme", &self.scheme)?;
+        Ok(())
+    }
 }
 
 /// A URL-based pointer to an external resource with an optional hash for verification and
@@ -276,9 +343,8 @@ pub struct DocumentIdentifier {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ResourceLink {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -288,20 +354,30 @@ pub struct ResourceLink {
         serialize_with = "validation::ser_uri_ref",
         deserialize_with = "validation::deser_uri_ref"
     )]
-    pub href: String,
+    pub href: UriReference,
     /// A label that indicates the nature of a resource, as a data serialization or format.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub media_type: Option<String>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ResourceLinkBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri_ref("href", &self.href)?;
+        Ok(())
+    }
 }
 
 /// A representation of a cryptographic digest generated over a resource using a specified
 /// hash algorithm.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
+#[serde(try_from = "validation::RawHash")]
 pub struct Hash {
     /// The digest method by which a hash is derived.
     pub algorithm: String,
@@ -310,6 +386,18 @@ pub struct Hash {
         deserialize_with = "validation::deser_hash"
     )]
     pub value: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl HashBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_hash("value", &self.value)?;
+        validation::check_digest(&self.algorithm, &self.value)?;
+        Ok(())
+    }
 }
 
 /// Designates a referenced source catalog or profile that provides a source of control
@@ -317,9 +405,8 @@ pub struct Hash {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ImportResource {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -329,22 +416,32 @@ pub struct ImportResource {
         serialize_with = "validation::ser_uri_ref",
         deserialize_with = "validation::deser_uri_ref"
     )]
-    pub href: String,
+    pub href: UriReference,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub include_all: Option<IncludeAll>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub include_controls: Option<Vec<SelectControl>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ImportResourceBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri_ref("href", &self.href)?;
+        Ok(())
+    }
 }
 
 /// Select a control or controls from an imported control set.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SelectControl {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -359,20 +456,33 @@ pub struct SelectControl {
         serialize_with = "validation::ser_token_vec_opt",
         deserialize_with = "validation::deser_token_vec_opt"
     )]
-    pub with_ids: Option<Vec<String>>,
+    pub with_ids: Option<Vec<Token>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SelectControlBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("with_ids", &self.with_ids)?;
+        Ok(())
+    }
 }
 
 /// Selecting a set of controls by matching their IDs with a wildcard pattern.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct MatchControlsByPattern {
     /// A glob expression matching the IDs of one or more controls to be selected.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pattern: Option<String>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 /// When a control is included, whether its child (dependent) controls are also included.
 ///
@@ -454,12 +564,15 @@ pub enum IncludeContainedControlsWithControl {
 }
 
 /// Include all controls from the imported catalog or profile resources.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
-pub struct IncludeAll {}
+pub struct IncludeAll {
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
 
 /// Provides structuring directives that instruct how controls are organized after profile
 /// resolution.
@@ -468,7 +581,6 @@ pub struct IncludeAll {}
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct MergeControls {
     /// Indicates that the controls selected should retain their original grouping as defined in
     /// the import source.
@@ -488,20 +600,26 @@ pub struct MergeControls {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub flat: Option<FlatWithoutGrouping>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A Combine element defines how to resolve duplicate instances of the same control (e.g.,
 /// controls with the same ID).
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct CombinationRule {
     /// Declare how clashing controls should be handled.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub method: Option<CombinationMethod>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 /// Declare how clashing controls should be handled.
 ///
@@ -565,7 +683,6 @@ pub enum CombinationMethod {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct CustomGrouping {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -573,15 +690,18 @@ pub struct CustomGrouping {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub insert_controls: Option<Vec<InsertControls>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A group of (selected) controls or of groups of controls.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ControlGroup {
     /// A textual label that provides a sub-type or characterization of the group.
     #[builder(setter(into, strip_option), default)]
@@ -590,7 +710,7 @@ pub struct ControlGroup {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub class: Option<String>,
+    pub class: Option<Token>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub groups: Option<Vec<ControlGroup>>,
@@ -601,7 +721,7 @@ pub struct ControlGroup {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub id: Option<String>,
+    pub id: Option<Token>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub insert_controls: Option<Vec<InsertControls>>,
@@ -618,8 +738,19 @@ pub struct ControlGroup {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
     /// A name to be given to the group for use in display.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub title: String,
+    pub title: Markup,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ControlGroupBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("class", &self.class)?;
+        validation::check_token("id", &self.id)?;
+        Ok(())
+    }
 }
 
 /// Specifies which controls to use in the containing context.
@@ -628,7 +759,6 @@ pub struct ControlGroup {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct InsertControls {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -643,6 +773,10 @@ pub struct InsertControls {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub order: Option<Order>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 /// A designation of how a selection of controls in a profile is to be ordered.
 ///
@@ -728,9 +862,8 @@ pub enum Order {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Parameter {
     /// A textual label that provides a characterization of the type, purpose, use or scope of
     /// the parameter.
@@ -740,7 +873,7 @@ pub struct Parameter {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub class: Option<String>,
+    pub class: Option<Token>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub constraints: Option<Vec<Constraint>>,
@@ -752,7 +885,7 @@ pub struct Parameter {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub depends_on: Option<String>,
+    pub depends_on: Option<Token>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub guidelines: Option<Vec<Guideline>>,
@@ -761,13 +894,12 @@ pub struct Parameter {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub id: String,
+    pub id: Token,
     /// A short, placeholder name for the parameter, which can be used as a substitute for a
     /// value if no value is assigned.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub label: Option<String>,
+    pub label: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -776,63 +908,77 @@ pub struct Parameter {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub select: Option<Selection>,
     /// Describes the purpose and use of a parameter.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub usage: Option<String>,
+    pub usage: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub values: Option<Vec<String>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ParameterBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("class", &self.class)?;
+        validation::check_token("depends_on", &self.depends_on)?;
+        validation::check_token("id", &self.id)?;
+        Ok(())
+    }
 }
 
 /// A formal or informal expression of a constraint or test.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Constraint {
     /// A textual summary of the constraint to be applied.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tests: Option<Vec<ConstraintTest>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A test expression which is expected to be evaluated by a tool.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ConstraintTest {
     /// A formal (executable) expression of a constraint.
     pub expression: String,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A prose statement that provides a recommendation for the use of a parameter.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Guideline {
     /// Prose permits multiple paragraphs, lists, tables etc.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub prose: String,
+    pub prose: Markup,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Presenting a choice among alternatives.
@@ -841,7 +987,6 @@ pub struct Guideline {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Selection {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -851,6 +996,10 @@ pub struct Selection {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub how_many: Option<ParameterCardinality>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 /// Describes the number of selections that must occur. Without this setting, only one value
 /// should be assumed to be permitted.
@@ -935,11 +1084,9 @@ pub enum ParameterCardinality {
 
 /// An annotated, markup-based textual element of a control's or catalog group's definition,
 /// or a child of another part.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Part {
     /// An optional textual providing a sub-type or characterization of the part's name, or a
     /// category to which the part belongs.
@@ -949,7 +1096,7 @@ pub struct Part {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub class: Option<String>,
+    pub class: Option<Token>,
     /// A unique identifier for the part.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -957,7 +1104,7 @@ pub struct Part {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub id: Option<String>,
+    pub id: Option<Token>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -967,7 +1114,7 @@ pub struct Part {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub name: String,
+    pub name: Token,
     /// An optional namespace qualifying the part's name. This allows different organizations to
     /// associate distinct semantics with the same name.
     #[builder(setter(into, strip_option), default)]
@@ -976,7 +1123,7 @@ pub struct Part {
         serialize_with = "validation::ser_uri_opt",
         deserialize_with = "validation::deser_uri_opt"
     )]
-    pub ns: Option<String>,
+    pub ns: Option<Uri>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parts: Option<Vec<Part>>,
@@ -986,32 +1133,46 @@ pub struct Part {
     /// Permits multiple paragraphs, lists, tables etc.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub prose: Option<String>,
+    pub prose: Option<Markup>,
     /// An optional name given to the part, which may be used by a tool for display and
     /// navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PartBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("class", &self.class)?;
+        validation::check_token("id", &self.id)?;
+        validation::check_token("name", &self.name)?;
+        validation::check_uri("ns", &self.ns)?;
+        Ok(())
+    }
 }
 
 /// Directs that controls appear without any grouping structure.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
-pub struct FlatWithoutGrouping {}
+pub struct FlatWithoutGrouping {
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
 
 /// Provides information about the containing document, and defines concepts that are shared
 /// across the document.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct DocumentMetadata {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1023,7 +1184,7 @@ pub struct DocumentMetadata {
         serialize_with = "validation::ser_dttz",
         deserialize_with = "validation::deser_dttz"
     )]
-    pub last_modified: String,
+    pub last_modified: DateTimeWithTz,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1042,8 +1203,7 @@ pub struct DocumentMetadata {
     pub published: Option<String>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
@@ -1054,18 +1214,27 @@ pub struct DocumentMetadata {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub roles: Option<Vec<Role>>,
     /// A name given to the document, which may be used by a tool for display and navigation.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub title: String,
+    pub title: Markup,
     pub version: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl DocumentMetadataBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("last_modified", &self.last_modified)?;
+        Ok(())
+    }
 }
 
 /// An action applied by a role within a given party to the content.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Action {
     /// The date and time when the action occurred.
     #[builder(setter(into, strip_option), default)]
@@ -1074,7 +1243,7 @@ pub struct Action {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub date: Option<String>,
+    pub date: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1083,8 +1252,7 @@ pub struct Action {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
@@ -1096,7 +1264,7 @@ pub struct Action {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub action_type: String,
+    pub action_type: Token,
     /// A unique identifier that can be used to reference this defined action elsewhere in an
     /// OSCAL document. A UUID should be consistently used for a given location across revisions
     /// of the document.
@@ -1105,7 +1273,20 @@ pub struct Action {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ActionBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("date", &self.date)?;
+        validation::check_token("action_type", &self.action_type)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A reference to a set of persons and/or organizations that have responsibility for
@@ -1113,9 +1294,8 @@ pub struct Action {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ResponsibleParty {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1124,20 +1304,31 @@ pub struct ResponsibleParty {
         serialize_with = "validation::ser_uuid_vec",
         deserialize_with = "validation::deser_uuid_vec"
     )]
-    pub party_uuids: Vec<String>,
+    pub party_uuids: Vec<OscalUuid>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A reference to a role performed by a party.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub role_id: String,
+    pub role_id: Token,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ResponsiblePartyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("party_uuids", &self.party_uuids)?;
+        validation::check_token("role_id", &self.role_id)?;
+        Ok(())
+    }
 }
 
 /// A physical point of presence, which may be associated with people, organizations, or
@@ -1145,9 +1336,8 @@ pub struct ResponsibleParty {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Location {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1158,7 +1348,7 @@ pub struct Location {
         serialize_with = "validation::ser_email_vec_opt",
         deserialize_with = "validation::deser_email_vec_opt"
     )]
-    pub email_addresses: Option<Vec<String>>,
+    pub email_addresses: Option<Vec<EmailAddress>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1167,39 +1357,49 @@ pub struct Location {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub telephone_numbers: Option<Vec<TelephoneNumber>>,
     /// A name given to the location, which may be used by a tool for display and navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(
         serialize_with = "validation::ser_uri_vec_opt",
         deserialize_with = "validation::deser_uri_vec_opt"
     )]
-    pub urls: Option<Vec<String>>,
+    pub urls: Option<Vec<Uri>>,
     /// A unique ID for the location, for reference.
     #[serde(
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl LocationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_email("email_addresses", &self.email_addresses)?;
+        validation::check_uri("urls", &self.urls)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A postal address for the location.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Address {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1228,15 +1428,24 @@ pub struct Address {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub address_type: Option<String>,
+    pub address_type: Option<Token>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AddressBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("address_type", &self.address_type)?;
+        Ok(())
+    }
 }
 
 /// A telephone service number as defined by ITU-T E.164.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct TelephoneNumber {
     pub number: String,
     /// Indicates the type of phone number.
@@ -1244,6 +1453,10 @@ pub struct TelephoneNumber {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub telephone_number_type: Option<String>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// An organization or person, which may be associated with roles or other concepts within
@@ -1251,9 +1464,8 @@ pub struct TelephoneNumber {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Party {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1264,7 +1476,7 @@ pub struct Party {
         serialize_with = "validation::ser_email_vec_opt",
         deserialize_with = "validation::deser_email_vec_opt"
     )]
-    pub email_addresses: Option<Vec<String>>,
+    pub email_addresses: Option<Vec<EmailAddress>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub external_ids: Option<Vec<PartyExternalIdentifier>>,
@@ -1280,7 +1492,7 @@ pub struct Party {
         serialize_with = "validation::ser_uuid_vec_opt",
         deserialize_with = "validation::deser_uuid_vec_opt"
     )]
-    pub member_of_organizations: Option<Vec<String>>,
+    pub member_of_organizations: Option<Vec<OscalUuid>>,
     /// The full name of the party. This is typically the legal name associated with the party.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1290,8 +1502,7 @@ pub struct Party {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A short common name, abbreviation, or acronym for the party.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1308,16 +1519,27 @@ pub struct Party {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PartyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_email("email_addresses", &self.email_addresses)?;
+        validation::check_uuid("member_of_organizations", &self.member_of_organizations)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// An identifier for a person or organization using a designated scheme. e.g. an Open
 /// Researcher and Contributor ID (ORCID).
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct PartyExternalIdentifier {
     pub id: String,
     /// Indicates the type of external identifier.
@@ -1325,7 +1547,18 @@ pub struct PartyExternalIdentifier {
         serialize_with = "validation::ser_uri",
         deserialize_with = "validation::deser_uri"
     )]
-    pub scheme: String,
+    pub scheme: Uri,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PartyExternalIdentifierBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri("scheme", &self.scheme)?;
+        Ok(())
+    }
 }
 /// A category describing the kind of party the object describes.
 ///
@@ -1386,9 +1619,8 @@ pub enum PartyType {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct RevisionHistoryEntry {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1396,7 +1628,7 @@ pub struct RevisionHistoryEntry {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub last_modified: Option<String>,
+    pub last_modified: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1412,39 +1644,47 @@ pub struct RevisionHistoryEntry {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub published: Option<String>,
+    pub published: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A name given to the document revision, which may be used by a tool for display and
     /// navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     pub version: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl RevisionHistoryEntryBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("last_modified", &self.last_modified)?;
+        validation::check_dttz("published", &self.published)?;
+        Ok(())
+    }
 }
 
 /// Defines a function, which might be assigned to a party in a specific situation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Role {
     /// A summary of the role's purpose and associated responsibilities.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     /// A unique identifier for the role.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub id: String,
+    pub id: Token,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1453,14 +1693,24 @@ pub struct Role {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A short common name, abbreviation, or acronym for the role.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub short_name: Option<String>,
     /// A name given to the role, which may be used by a tool for display and navigation.
     pub title: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl RoleBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("id", &self.id)?;
+        Ok(())
+    }
 }
 
 /// Set parameters or amend controls in resolution.
@@ -1469,7 +1719,6 @@ pub struct Role {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ModifyControls {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1477,15 +1726,18 @@ pub struct ModifyControls {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub set_parameters: Option<Vec<ParameterSetting>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Specifies changes to be made to an included control when a profile is resolved.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Alteration {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1497,19 +1749,29 @@ pub struct Alteration {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub control_id: String,
+    pub control_id: Token,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub removes: Option<Vec<Removal>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AlterationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("control_id", &self.control_id)?;
+        Ok(())
+    }
 }
 
 /// Specifies contents to be added into controls, in resolution.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Addition {
     /// Target location of the addition.
     #[builder(setter(into, strip_option), default)]
@@ -1518,7 +1780,7 @@ pub struct Addition {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub by_id: Option<String>,
+    pub by_id: Option<Token>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1539,8 +1801,18 @@ pub struct Addition {
     /// A name given to the control, which may be used by a tool for display and navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AdditionBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("by_id", &self.by_id)?;
+        Ok(())
+    }
 }
 /// Where to add the new content with respect to the targeted element (beside it or inside
 /// it).
@@ -1629,9 +1901,8 @@ pub enum Position {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Removal {
     /// Identify items to remove by matching their class.
     #[builder(setter(into, strip_option), default)]
@@ -1640,7 +1911,7 @@ pub struct Removal {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub by_class: Option<String>,
+    pub by_class: Option<Token>,
     /// Identify items to remove indicated by their id.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1648,7 +1919,7 @@ pub struct Removal {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub by_id: Option<String>,
+    pub by_id: Option<Token>,
     /// Identify items to remove by the name of the item's information object name, e.g. title or
     /// prop.
     #[builder(setter(into, strip_option), default)]
@@ -1661,12 +1932,25 @@ pub struct Removal {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub by_name: Option<String>,
+    pub by_name: Option<Token>,
     /// Identify items to remove by the item's ns, which is the namespace associated with a part,
     /// or prop.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub by_ns: Option<String>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl RemovalBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("by_class", &self.by_class)?;
+        validation::check_token("by_id", &self.by_id)?;
+        validation::check_token("by_name", &self.by_name)?;
+        Ok(())
+    }
 }
 /// Identify items to remove by the name of the item's information object name, e.g. title or
 /// prop.
@@ -1758,7 +2042,6 @@ pub enum ItemNameReference {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ParameterSetting {
     /// A textual label that provides a characterization of the parameter.
     #[builder(setter(into, strip_option), default)]
@@ -1798,4 +2081,1474 @@ pub struct ParameterSetting {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub values: Option<Vec<String>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+/// Read only traversal of the model
+///
+/// Every method visits one type and by default walks into its
+/// children, so an implementation only overrides the types it cares
+/// about. Call the matching `walk_*` function from an override to keep
+/// descending.
+pub mod visit {
+    use super::*;
+    pub trait Visit<'ast> {
+        fn visit_profile(&mut self, node: &'ast Profile) {
+            walk_profile(self, node)
+        }
+        fn visit_profile_class(&mut self, node: &'ast ProfileClass) {
+            walk_profile_class(self, node)
+        }
+        fn visit_back_matter(&mut self, node: &'ast BackMatter) {
+            walk_back_matter(self, node)
+        }
+        fn visit_resource(&mut self, node: &'ast Resource) {
+            walk_resource(self, node)
+        }
+        fn visit_base64(&mut self, node: &'ast Base64) {
+            walk_base64(self, node)
+        }
+        fn visit_citation(&mut self, node: &'ast Citation) {
+            walk_citation(self, node)
+        }
+        fn visit_link(&mut self, node: &'ast Link) {
+            walk_link(self, node)
+        }
+        fn visit_property(&mut self, node: &'ast Property) {
+            walk_property(self, node)
+        }
+        fn visit_document_identifier(&mut self, node: &'ast DocumentIdentifier) {
+            walk_document_identifier(self, node)
+        }
+        fn visit_resource_link(&mut self, node: &'ast ResourceLink) {
+            walk_resource_link(self, node)
+        }
+        fn visit_hash(&mut self, node: &'ast Hash) {
+            walk_hash(self, node)
+        }
+        fn visit_import_resource(&mut self, node: &'ast ImportResource) {
+            walk_import_resource(self, node)
+        }
+        fn visit_select_control(&mut self, node: &'ast SelectControl) {
+            walk_select_control(self, node)
+        }
+        fn visit_match_controls_by_pattern(
+            &mut self,
+            node: &'ast MatchControlsByPattern,
+        ) {
+            walk_match_controls_by_pattern(self, node)
+        }
+        fn visit_include_contained_controls_with_control(
+            &mut self,
+            node: &'ast IncludeContainedControlsWithControl,
+        ) {
+            walk_include_contained_controls_with_control(self, node)
+        }
+        fn visit_include_all(&mut self, node: &'ast IncludeAll) {
+            walk_include_all(self, node)
+        }
+        fn visit_merge_controls(&mut self, node: &'ast MergeControls) {
+            walk_merge_controls(self, node)
+        }
+        fn visit_combination_rule(&mut self, node: &'ast CombinationRule) {
+            walk_combination_rule(self, node)
+        }
+        fn visit_combination_method(&mut self, node: &'ast CombinationMethod) {
+            walk_combination_method(self, node)
+        }
+        fn visit_custom_grouping(&mut self, node: &'ast CustomGrouping) {
+            walk_custom_grouping(self, node)
+        }
+        fn visit_control_group(&mut self, node: &'ast ControlGroup) {
+            walk_control_group(self, node)
+        }
+        fn visit_insert_controls(&mut self, node: &'ast InsertControls) {
+            walk_insert_controls(self, node)
+        }
+        fn visit_order(&mut self, node: &'ast Order) {
+            walk_order(self, node)
+        }
+        fn visit_parameter(&mut self, node: &'ast Parameter) {
+            walk_parameter(self, node)
+        }
+        fn visit_constraint(&mut self, node: &'ast Constraint) {
+            walk_constraint(self, node)
+        }
+        fn visit_constraint_test(&mut self, node: &'ast ConstraintTest) {
+            walk_constraint_test(self, node)
+        }
+        fn visit_guideline(&mut self, node: &'ast Guideline) {
+            walk_guideline(self, node)
+        }
+        fn visit_selection(&mut self, node: &'ast Selection) {
+            walk_selection(self, node)
+        }
+        fn visit_parameter_cardinality(&mut self, node: &'ast ParameterCardinality) {
+            walk_parameter_cardinality(self, node)
+        }
+        fn visit_part(&mut self, node: &'ast Part) {
+            walk_part(self, node)
+        }
+        fn visit_flat_without_grouping(&mut self, node: &'ast FlatWithoutGrouping) {
+            walk_flat_without_grouping(self, node)
+        }
+        fn visit_document_metadata(&mut self, node: &'ast DocumentMetadata) {
+            walk_document_metadata(self, node)
+        }
+        fn visit_action(&mut self, node: &'ast Action) {
+            walk_action(self, node)
+        }
+        fn visit_responsible_party(&mut self, node: &'ast ResponsibleParty) {
+            walk_responsible_party(self, node)
+        }
+        fn visit_location(&mut self, node: &'ast Location) {
+            walk_location(self, node)
+        }
+        fn visit_address(&mut self, node: &'ast Address) {
+            walk_address(self, node)
+        }
+        fn visit_telephone_number(&mut self, node: &'ast TelephoneNumber) {
+            walk_telephone_number(self, node)
+        }
+        fn visit_party(&mut self, node: &'ast Party) {
+            walk_party(self, node)
+        }
+        fn visit_party_external_identifier(
+            &mut self,
+            node: &'ast PartyExternalIdentifier,
+        ) {
+            walk_party_external_identifier(self, node)
+        }
+        fn visit_party_type(&mut self, node: &'ast PartyType) {
+            walk_party_type(self, node)
+        }
+        fn visit_revision_history_entry(&mut self, node: &'ast RevisionHistoryEntry) {
+            walk_revision_history_entry(self, node)
+        }
+        fn visit_role(&mut self, node: &'ast Role) {
+            walk_role(self, node)
+        }
+        fn visit_modify_controls(&mut self, node: &'ast ModifyControls) {
+            walk_modify_controls(self, node)
+        }
+        fn visit_alteration(&mut self, node: &'ast Alteration) {
+            walk_alteration(self, node)
+        }
+        fn visit_addition(&mut self, node: &'ast Addition) {
+            walk_addition(self, node)
+        }
+        fn visit_position(&mut self, node: &'ast Position) {
+            walk_position(self, node)
+        }
+        fn visit_removal(&mut self, node: &'ast Removal) {
+            walk_removal(self, node)
+        }
+        fn visit_item_name_reference(&mut self, node: &'ast ItemNameReference) {
+            walk_item_name_reference(self, node)
+        }
+        fn visit_parameter_setting(&mut self, node: &'ast ParameterSetting) {
+            walk_parameter_setting(self, node)
+        }
+    }
+    pub fn walk_profile<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Profile) {
+        v.visit_profile_class(&node.profile);
+    }
+    pub fn walk_profile_class<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ProfileClass,
+    ) {
+        if let Some(it) = &node.back_matter {
+            v.visit_back_matter(it);
+        }
+        for it in &node.imports {
+            v.visit_import_resource(it);
+        }
+        if let Some(it) = &node.merge {
+            v.visit_merge_controls(it);
+        }
+        v.visit_document_metadata(&node.metadata);
+        if let Some(it) = &node.modify {
+            v.visit_modify_controls(it);
+        }
+    }
+    pub fn walk_back_matter<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast BackMatter,
+    ) {
+        if let Some(it) = &node.resources {
+            for it in it {
+                v.visit_resource(it);
+            }
+        }
+    }
+    pub fn walk_resource<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Resource,
+    ) {
+        if let Some(it) = &node.base64 {
+            v.visit_base64(it);
+        }
+        if let Some(it) = &node.citation {
+            v.visit_citation(it);
+        }
+        if let Some(it) = &node.document_ids {
+            for it in it {
+                v.visit_document_identifier(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.rlinks {
+            for it in it {
+                v.visit_resource_link(it);
+            }
+        }
+    }
+    pub fn walk_base64<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Base64) {}
+    pub fn walk_citation<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Citation,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_link<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Link) {}
+    pub fn walk_property<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Property,
+    ) {}
+    pub fn walk_document_identifier<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast DocumentIdentifier,
+    ) {}
+    pub fn walk_resource_link<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ResourceLink,
+    ) {
+        if let Some(it) = &node.hashes {
+            for it in it {
+                v.visit_hash(it);
+            }
+        }
+    }
+    pub fn walk_hash<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Hash) {}
+    pub fn walk_import_resource<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ImportResource,
+    ) {
+        if let Some(it) = &node.exclude_controls {
+            for it in it {
+                v.visit_select_control(it);
+            }
+        }
+        if let Some(it) = &node.include_all {
+            v.visit_include_all(it);
+        }
+        if let Some(it) = &node.include_controls {
+            for it in it {
+                v.visit_select_control(it);
+            }
+        }
+    }
+    pub fn walk_select_control<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SelectControl,
+    ) {
+        if let Some(it) = &node.matching {
+            for it in it {
+                v.visit_match_controls_by_pattern(it);
+            }
+        }
+        if let Some(it) = &node.with_child_controls {
+            v.visit_include_contained_controls_with_control(it);
+        }
+    }
+    pub fn walk_match_controls_by_pattern<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast MatchControlsByPattern,
+    ) {}
+    pub fn walk_include_contained_controls_with_control<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast IncludeContainedControlsWithControl,
+    ) {}
+    pub fn walk_include_all<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast IncludeAll,
+    ) {}
+    pub fn walk_merge_controls<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast MergeControls,
+    ) {
+        if let Some(it) = &node.combine {
+            v.visit_combination_rule(it);
+        }
+        if let Some(it) = &node.custom {
+            v.visit_custom_grouping(it);
+        }
+        if let Some(it) = &node.flat {
+            v.visit_flat_without_grouping(it);
+        }
+    }
+    pub fn walk_combination_rule<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast CombinationRule,
+    ) {
+        if let Some(it) = &node.method {
+            v.visit_combination_method(it);
+        }
+    }
+    pub fn walk_combination_method<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast CombinationMethod,
+    ) {}
+    pub fn walk_custom_grouping<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast CustomGrouping,
+    ) {
+        if let Some(it) = &node.groups {
+            for it in it {
+                v.visit_control_group(it);
+            }
+        }
+        if let Some(it) = &node.insert_controls {
+            for it in it {
+                v.visit_insert_controls(it);
+            }
+        }
+    }
+    pub fn walk_control_group<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ControlGroup,
+    ) {
+        if let Some(it) = &node.groups {
+            for it in it {
+                v.visit_control_group(it);
+            }
+        }
+        if let Some(it) = &node.insert_controls {
+            for it in it {
+                v.visit_insert_controls(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.params {
+            for it in it {
+                v.visit_parameter(it);
+            }
+        }
+        if let Some(it) = &node.parts {
+            for it in it {
+                v.visit_part(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_insert_controls<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast InsertControls,
+    ) {
+        if let Some(it) = &node.exclude_controls {
+            for it in it {
+                v.visit_select_control(it);
+            }
+        }
+        if let Some(it) = &node.include_all {
+            v.visit_include_all(it);
+        }
+        if let Some(it) = &node.include_controls {
+            for it in it {
+                v.visit_select_control(it);
+            }
+        }
+        if let Some(it) = &node.order {
+            v.visit_order(it);
+        }
+    }
+    pub fn walk_order<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Order) {}
+    pub fn walk_parameter<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Parameter,
+    ) {
+        if let Some(it) = &node.constraints {
+            for it in it {
+                v.visit_constraint(it);
+            }
+        }
+        if let Some(it) = &node.guidelines {
+            for it in it {
+                v.visit_guideline(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.select {
+            v.visit_selection(it);
+        }
+    }
+    pub fn walk_constraint<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Constraint,
+    ) {
+        if let Some(it) = &node.tests {
+            for it in it {
+                v.visit_constraint_test(it);
+            }
+        }
+    }
+    pub fn walk_constraint_test<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast ConstraintTest,
+    ) {}
+    pub fn walk_guideline<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Guideline,
+    ) {}
+    pub fn walk_selection<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Selection,
+    ) {
+        if let Some(it) = &node.how_many {
+            v.visit_parameter_cardinality(it);
+        }
+    }
+    pub fn walk_parameter_cardinality<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast ParameterCardinality,
+    ) {}
+    pub fn walk_part<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Part) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.parts {
+            for it in it {
+                v.visit_part(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_flat_without_grouping<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast FlatWithoutGrouping,
+    ) {}
+    pub fn walk_document_metadata<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast DocumentMetadata,
+    ) {
+        if let Some(it) = &node.actions {
+            for it in it {
+                v.visit_action(it);
+            }
+        }
+        if let Some(it) = &node.document_ids {
+            for it in it {
+                v.visit_document_identifier(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.locations {
+            for it in it {
+                v.visit_location(it);
+            }
+        }
+        if let Some(it) = &node.parties {
+            for it in it {
+                v.visit_party(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+        if let Some(it) = &node.revisions {
+            for it in it {
+                v.visit_revision_history_entry(it);
+            }
+        }
+        if let Some(it) = &node.roles {
+            for it in it {
+                v.visit_role(it);
+            }
+        }
+    }
+    pub fn walk_action<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Action) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+    }
+    pub fn walk_responsible_party<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ResponsibleParty,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_location<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Location,
+    ) {
+        if let Some(it) = &node.address {
+            v.visit_address(it);
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number(it);
+            }
+        }
+    }
+    pub fn walk_address<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Address,
+    ) {}
+    pub fn walk_telephone_number<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast TelephoneNumber,
+    ) {}
+    pub fn walk_party<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Party) {
+        if let Some(it) = &node.addresses {
+            for it in it {
+                v.visit_address(it);
+            }
+        }
+        if let Some(it) = &node.external_ids {
+            for it in it {
+                v.visit_party_external_identifier(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number(it);
+            }
+        }
+        v.visit_party_type(&node.party_type);
+    }
+    pub fn walk_party_external_identifier<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast PartyExternalIdentifier,
+    ) {}
+    pub fn walk_party_type<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast PartyType,
+    ) {}
+    pub fn walk_revision_history_entry<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast RevisionHistoryEntry,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_role<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Role) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_modify_controls<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ModifyControls,
+    ) {
+        if let Some(it) = &node.alters {
+            for it in it {
+                v.visit_alteration(it);
+            }
+        }
+        if let Some(it) = &node.set_parameters {
+            for it in it {
+                v.visit_parameter_setting(it);
+            }
+        }
+    }
+    pub fn walk_alteration<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Alteration,
+    ) {
+        if let Some(it) = &node.adds {
+            for it in it {
+                v.visit_addition(it);
+            }
+        }
+        if let Some(it) = &node.removes {
+            for it in it {
+                v.visit_removal(it);
+            }
+        }
+    }
+    pub fn walk_addition<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Addition,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.params {
+            for it in it {
+                v.visit_parameter(it);
+            }
+        }
+        if let Some(it) = &node.parts {
+            for it in it {
+                v.visit_part(it);
+            }
+        }
+        if let Some(it) = &node.position {
+            v.visit_position(it);
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_position<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Position,
+    ) {}
+    pub fn walk_removal<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Removal) {
+        if let Some(it) = &node.by_item_name {
+            v.visit_item_name_reference(it);
+        }
+    }
+    pub fn walk_item_name_reference<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast ItemNameReference,
+    ) {}
+    pub fn walk_parameter_setting<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ParameterSetting,
+    ) {
+        if let Some(it) = &node.constraints {
+            for it in it {
+                v.visit_constraint(it);
+            }
+        }
+        if let Some(it) = &node.guidelines {
+            for it in it {
+                v.visit_guideline(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.select {
+            v.visit_selection(it);
+        }
+    }
+}
+/// In place traversal of the model
+///
+/// Mutable counterpart of [`visit`](super::visit).
+pub mod visit_mut {
+    use super::*;
+    pub trait VisitMut {
+        fn visit_profile_mut(&mut self, node: &mut Profile) {
+            walk_profile_mut(self, node)
+        }
+        fn visit_profile_class_mut(&mut self, node: &mut ProfileClass) {
+            walk_profile_class_mut(self, node)
+        }
+        fn visit_back_matter_mut(&mut self, node: &mut BackMatter) {
+            walk_back_matter_mut(self, node)
+        }
+        fn visit_resource_mut(&mut self, node: &mut Resource) {
+            walk_resource_mut(self, node)
+        }
+        fn visit_base64_mut(&mut self, node: &mut Base64) {
+            walk_base64_mut(self, node)
+        }
+        fn visit_citation_mut(&mut self, node: &mut Citation) {
+            walk_citation_mut(self, node)
+        }
+        fn visit_link_mut(&mut self, node: &mut Link) {
+            walk_link_mut(self, node)
+        }
+        fn visit_property_mut(&mut self, node: &mut Property) {
+            walk_property_mut(self, node)
+        }
+        fn visit_document_identifier_mut(&mut self, node: &mut DocumentIdentifier) {
+            walk_document_identifier_mut(self, node)
+        }
+        fn visit_resource_link_mut(&mut self, node: &mut ResourceLink) {
+            walk_resource_link_mut(self, node)
+        }
+        fn visit_hash_mut(&mut self, node: &mut Hash) {
+            walk_hash_mut(self, node)
+        }
+        fn visit_import_resource_mut(&mut self, node: &mut ImportResource) {
+            walk_import_resource_mut(self, node)
+        }
+        fn visit_select_control_mut(&mut self, node: &mut SelectControl) {
+            walk_select_control_mut(self, node)
+        }
+        fn visit_match_controls_by_pattern_mut(
+            &mut self,
+            node: &mut MatchControlsByPattern,
+        ) {
+            walk_match_controls_by_pattern_mut(self, node)
+        }
+        fn visit_include_contained_controls_with_control_mut(
+            &mut self,
+            node: &mut IncludeContainedControlsWithControl,
+        ) {
+            walk_include_contained_controls_with_control_mut(self, node)
+        }
+        fn visit_include_all_mut(&mut self, node: &mut IncludeAll) {
+            walk_include_all_mut(self, node)
+        }
+        fn visit_merge_controls_mut(&mut self, node: &mut MergeControls) {
+            walk_merge_controls_mut(self, node)
+        }
+        fn visit_combination_rule_mut(&mut self, node: &mut CombinationRule) {
+            walk_combination_rule_mut(self, node)
+        }
+        fn visit_combination_method_mut(&mut self, node: &mut CombinationMethod) {
+            walk_combination_method_mut(self, node)
+        }
+        fn visit_custom_grouping_mut(&mut self, node: &mut CustomGrouping) {
+            walk_custom_grouping_mut(self, node)
+        }
+        fn visit_control_group_mut(&mut self, node: &mut ControlGroup) {
+            walk_control_group_mut(self, node)
+        }
+        fn visit_insert_controls_mut(&mut self, node: &mut InsertControls) {
+            walk_insert_controls_mut(self, node)
+        }
+        fn visit_order_mut(&mut self, node: &mut Order) {
+            walk_order_mut(self, node)
+        }
+        fn visit_parameter_mut(&mut self, node: &mut Parameter) {
+            walk_parameter_mut(self, node)
+        }
+        fn visit_constraint_mut(&mut self, node: &mut Constraint) {
+            walk_constraint_mut(self, node)
+        }
+        fn visit_constraint_test_mut(&mut self, node: &mut ConstraintTest) {
+            walk_constraint_test_mut(self, node)
+        }
+        fn visit_guideline_mut(&mut self, node: &mut Guideline) {
+            walk_guideline_mut(self, node)
+        }
+        fn visit_selection_mut(&mut self, node: &mut Selection) {
+            walk_selection_mut(self, node)
+        }
+        fn visit_parameter_cardinality_mut(&mut self, node: &mut ParameterCardinality) {
+            walk_parameter_cardinality_mut(self, node)
+        }
+        fn visit_part_mut(&mut self, node: &mut Part) {
+            walk_part_mut(self, node)
+        }
+        fn visit_flat_without_grouping_mut(&mut self, node: &mut FlatWithoutGrouping) {
+            walk_flat_without_grouping_mut(self, node)
+        }
+        fn visit_document_metadata_mut(&mut self, node: &mut DocumentMetadata) {
+            walk_document_metadata_mut(self, node)
+        }
+        fn visit_action_mut(&mut self, node: &mut Action) {
+            walk_action_mut(self, node)
+        }
+        fn visit_responsible_party_mut(&mut self, node: &mut ResponsibleParty) {
+            walk_responsible_party_mut(self, node)
+        }
+        fn visit_location_mut(&mut self, node: &mut Location) {
+            walk_location_mut(self, node)
+        }
+        fn visit_address_mut(&mut self, node: &mut Address) {
+            walk_address_mut(self, node)
+        }
+        fn visit_telephone_number_mut(&mut self, node: &mut TelephoneNumber) {
+            walk_telephone_number_mut(self, node)
+        }
+        fn visit_party_mut(&mut self, node: &mut Party) {
+            walk_party_mut(self, node)
+        }
+        fn visit_party_external_identifier_mut(
+            &mut self,
+            node: &mut PartyExternalIdentifier,
+        ) {
+            walk_party_external_identifier_mut(self, node)
+        }
+        fn visit_party_type_mut(&mut self, node: &mut PartyType) {
+            walk_party_type_mut(self, node)
+        }
+        fn visit_revision_history_entry_mut(&mut self, node: &mut RevisionHistoryEntry) {
+            walk_revision_history_entry_mut(self, node)
+        }
+        fn visit_role_mut(&mut self, node: &mut Role) {
+            walk_role_mut(self, node)
+        }
+        fn visit_modify_controls_mut(&mut self, node: &mut ModifyControls) {
+            walk_modify_controls_mut(self, node)
+        }
+        fn visit_alteration_mut(&mut self, node: &mut Alteration) {
+            walk_alteration_mut(self, node)
+        }
+        fn visit_addition_mut(&mut self, node: &mut Addition) {
+            walk_addition_mut(self, node)
+        }
+        fn visit_position_mut(&mut self, node: &mut Position) {
+            walk_position_mut(self, node)
+        }
+        fn visit_removal_mut(&mut self, node: &mut Removal) {
+            walk_removal_mut(self, node)
+        }
+        fn visit_item_name_reference_mut(&mut self, node: &mut ItemNameReference) {
+            walk_item_name_reference_mut(self, node)
+        }
+        fn visit_parameter_setting_mut(&mut self, node: &mut ParameterSetting) {
+            walk_parameter_setting_mut(self, node)
+        }
+    }
+    pub fn walk_profile_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Profile) {
+        v.visit_profile_class_mut(&mut node.profile);
+    }
+    pub fn walk_profile_class_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ProfileClass,
+    ) {
+        if let Some(it) = &mut node.back_matter {
+            v.visit_back_matter_mut(it);
+        }
+        for it in &mut node.imports {
+            v.visit_import_resource_mut(it);
+        }
+        if let Some(it) = &mut node.merge {
+            v.visit_merge_controls_mut(it);
+        }
+        v.visit_document_metadata_mut(&mut node.metadata);
+        if let Some(it) = &mut node.modify {
+            v.visit_modify_controls_mut(it);
+        }
+    }
+    pub fn walk_back_matter_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut BackMatter) {
+        if let Some(it) = &mut node.resources {
+            for it in it {
+                v.visit_resource_mut(it);
+            }
+        }
+    }
+    pub fn walk_resource_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Resource) {
+        if let Some(it) = &mut node.base64 {
+            v.visit_base64_mut(it);
+        }
+        if let Some(it) = &mut node.citation {
+            v.visit_citation_mut(it);
+        }
+        if let Some(it) = &mut node.document_ids {
+            for it in it {
+                v.visit_document_identifier_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.rlinks {
+            for it in it {
+                v.visit_resource_link_mut(it);
+            }
+        }
+    }
+    pub fn walk_base64_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Base64) {}
+    pub fn walk_citation_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Citation) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_link_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Link) {}
+    pub fn walk_property_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Property) {}
+    pub fn walk_document_identifier_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut DocumentIdentifier,
+    ) {}
+    pub fn walk_resource_link_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ResourceLink,
+    ) {
+        if let Some(it) = &mut node.hashes {
+            for it in it {
+                v.visit_hash_mut(it);
+            }
+        }
+    }
+    pub fn walk_hash_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Hash) {}
+    pub fn walk_import_resource_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ImportResource,
+    ) {
+        if let Some(it) = &mut node.exclude_controls {
+            for it in it {
+                v.visit_select_control_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.include_all {
+            v.visit_include_all_mut(it);
+        }
+        if let Some(it) = &mut node.include_controls {
+            for it in it {
+                v.visit_select_control_mut(it);
+            }
+        }
+    }
+    pub fn walk_select_control_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut SelectControl,
+    ) {
+        if let Some(it) = &mut node.matching {
+            for it in it {
+                v.visit_match_controls_by_pattern_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.with_child_controls {
+            v.visit_include_contained_controls_with_control_mut(it);
+        }
+    }
+    pub fn walk_match_controls_by_pattern_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut MatchControlsByPattern,
+    ) {}
+    pub fn walk_include_contained_controls_with_control_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut IncludeContainedControlsWithControl,
+    ) {}
+    pub fn walk_include_all_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut IncludeAll,
+    ) {}
+    pub fn walk_merge_controls_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut MergeControls,
+    ) {
+        if let Some(it) = &mut node.combine {
+            v.visit_combination_rule_mut(it);
+        }
+        if let Some(it) = &mut node.custom {
+            v.visit_custom_grouping_mut(it);
+        }
+        if let Some(it) = &mut node.flat {
+            v.visit_flat_without_grouping_mut(it);
+        }
+    }
+    pub fn walk_combination_rule_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut CombinationRule,
+    ) {
+        if let Some(it) = &mut node.method {
+            v.visit_combination_method_mut(it);
+        }
+    }
+    pub fn walk_combination_method_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut CombinationMethod,
+    ) {}
+    pub fn walk_custom_grouping_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut CustomGrouping,
+    ) {
+        if let Some(it) = &mut node.groups {
+            for it in it {
+                v.visit_control_group_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.insert_controls {
+            for it in it {
+                v.visit_insert_controls_mut(it);
+            }
+        }
+    }
+    pub fn walk_control_group_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ControlGroup,
+    ) {
+        if let Some(it) = &mut node.groups {
+            for it in it {
+                v.visit_control_group_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.insert_controls {
+            for it in it {
+                v.visit_insert_controls_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.params {
+            for it in it {
+                v.visit_parameter_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.parts {
+            for it in it {
+                v.visit_part_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_insert_controls_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut InsertControls,
+    ) {
+        if let Some(it) = &mut node.exclude_controls {
+            for it in it {
+                v.visit_select_control_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.include_all {
+            v.visit_include_all_mut(it);
+        }
+        if let Some(it) = &mut node.include_controls {
+            for it in it {
+                v.visit_select_control_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.order {
+            v.visit_order_mut(it);
+        }
+    }
+    pub fn walk_order_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Order) {}
+    pub fn walk_parameter_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Parameter) {
+        if let Some(it) = &mut node.constraints {
+            for it in it {
+                v.visit_constraint_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.guidelines {
+            for it in it {
+                v.visit_guideline_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.select {
+            v.visit_selection_mut(it);
+        }
+    }
+    pub fn walk_constraint_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Constraint) {
+        if let Some(it) = &mut node.tests {
+            for it in it {
+                v.visit_constraint_test_mut(it);
+            }
+        }
+    }
+    pub fn walk_constraint_test_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut ConstraintTest,
+    ) {}
+    pub fn walk_guideline_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Guideline) {}
+    pub fn walk_selection_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Selection) {
+        if let Some(it) = &mut node.how_many {
+            v.visit_parameter_cardinality_mut(it);
+        }
+    }
+    pub fn walk_parameter_cardinality_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut ParameterCardinality,
+    ) {}
+    pub fn walk_part_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Part) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.parts {
+            for it in it {
+                v.visit_part_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_flat_without_grouping_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut FlatWithoutGrouping,
+    ) {}
+    pub fn walk_document_metadata_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut DocumentMetadata,
+    ) {
+        if let Some(it) = &mut node.actions {
+            for it in it {
+                v.visit_action_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.document_ids {
+            for it in it {
+                v.visit_document_identifier_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.locations {
+            for it in it {
+                v.visit_location_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.parties {
+            for it in it {
+                v.visit_party_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.revisions {
+            for it in it {
+                v.visit_revision_history_entry_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.roles {
+            for it in it {
+                v.visit_role_mut(it);
+            }
+        }
+    }
+    pub fn walk_action_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Action) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+    }
+    pub fn walk_responsible_party_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ResponsibleParty,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_location_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Location) {
+        if let Some(it) = &mut node.address {
+            v.visit_address_mut(it);
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number_mut(it);
+            }
+        }
+    }
+    pub fn walk_address_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Address) {}
+    pub fn walk_telephone_number_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut TelephoneNumber,
+    ) {}
+    pub fn walk_party_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Party) {
+        if let Some(it) = &mut node.addresses {
+            for it in it {
+                v.visit_address_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.external_ids {
+            for it in it {
+                v.visit_party_external_identifier_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number_mut(it);
+            }
+        }
+        v.visit_party_type_mut(&mut node.party_type);
+    }
+    pub fn walk_party_external_identifier_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut PartyExternalIdentifier,
+    ) {}
+    pub fn walk_party_type_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut PartyType) {}
+    pub fn walk_revision_history_entry_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut RevisionHistoryEntry,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_role_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Role) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_modify_controls_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ModifyControls,
+    ) {
+        if let Some(it) = &mut node.alters {
+            for it in it {
+                v.visit_alteration_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.set_parameters {
+            for it in it {
+                v.visit_parameter_setting_mut(it);
+            }
+        }
+    }
+    pub fn walk_alteration_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Alteration) {
+        if let Some(it) = &mut node.adds {
+            for it in it {
+                v.visit_addition_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.removes {
+            for it in it {
+                v.visit_removal_mut(it);
+            }
+        }
+    }
+    pub fn walk_addition_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Addition) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.params {
+            for it in it {
+                v.visit_parameter_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.parts {
+            for it in it {
+                v.visit_part_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.position {
+            v.visit_position_mut(it);
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_position_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Position) {}
+    pub fn walk_removal_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Removal) {
+        if let Some(it) = &mut node.by_item_name {
+            v.visit_item_name_reference_mut(it);
+        }
+    }
+    pub fn walk_item_name_reference_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut ItemNameReference,
+    ) {}
+    pub fn walk_parameter_setting_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ParameterSetting,
+    ) {
+        if let Some(it) = &mut node.constraints {
+            for it in it {
+                v.visit_constraint_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.guidelines {
+            for it in it {
+                v.visit_guideline_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.select {
+            v.visit_selection_mut(it);
+        }
+    }
 }
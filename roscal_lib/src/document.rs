@@ -0,0 +1,314 @@
+//! Model type detection and a document type covering every root model.
+//!
+//! An OSCAL document names its model with the root key in JSON and YAML, or
+//! the root element in XML, so the type can be sniffed before parsing.
+//!
+//! ```
+//! use roscal_lib::{
+//!     document::{AnyOscalDocument, ModelType},
+//!     parse::ParseOptions,
+//! };
+//!
+//! let yaml = r#"
+//! catalog:
+//!   uuid: 74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724
+//!   metadata:
+//!     title: Example catalog
+//!     last-modified: 2023-12-31T23:59:59Z
+//!     version: "1"
+//!     oscal-version: 1.1.2
+//! "#;
+//!
+//! assert_eq!(ModelType::Catalog, ModelType::detect(yaml).unwrap());
+//!
+//! let document = AnyOscalDocument::from_str_with(yaml, &ParseOptions::default()).unwrap();
+//! assert_eq!(ModelType::Catalog, document.model_type());
+//! ```
+
+use std::{collections::BTreeMap, fmt, io::Read};
+
+use quick_xml::{events::Event, Reader};
+use serde::{de::IgnoredAny, Serialize, Serializer};
+
+use crate::{
+    assessment::{
+        assessment_plan::AssessmentPlan, assessment_results::AssessmentResults,
+        poam::PlanOfActionAndMilestones,
+    },
+    control::{catalog::Catalog, profile::Profile},
+    implementation::{
+        component_definition::ComponentDefinition, ssp::SystemSecurityPlan,
+    },
+    parse::{ParseError, ParseOptions, ParseWarning, ParseWith, XML_INPUT},
+};
+
+/// Error returned when a document can't be detected or parsed
+#[derive(Debug)]
+pub enum DocumentError {
+    /// The root key or element is not an OSCAL model
+    UnknownModel,
+    /// The document is XML, which can be detected but not parsed: the XML
+    /// encoding is only written
+    UnsupportedXml(ModelType),
+    /// The document is not well-formed XML
+    Xml(String),
+    /// The document is not valid JSON or YAML for its model
+    Parse(ParseError),
+    /// The document could not be read
+    Io(std::io::Error),
+}
+
+impl fmt::Display for DocumentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DocumentError::UnknownModel => {
+                write!(f, "root of the document is not an OSCAL model")
+            }
+            DocumentError::UnsupportedXml(model_type) => {
+                write!(
+                    f,
+                    "XML {model_type} documents can't be read, {XML_INPUT}"
+                )
+            }
+            DocumentError::Xml(e) => write!(f, "invalid XML: {e}"),
+            DocumentError::Parse(e) => e.fmt(f),
+            DocumentError::Io(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for DocumentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DocumentError::Parse(e) => Some(e),
+            DocumentError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for DocumentError {
+    fn from(e: ParseError) -> Self {
+        DocumentError::Parse(e)
+    }
+}
+
+impl From<serde_yaml::Error> for DocumentError {
+    fn from(e: serde_yaml::Error) -> Self {
+        DocumentError::Parse(e.into())
+    }
+}
+
+/// Type of a root OSCAL model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModelType {
+    AssessmentPlan,
+    AssessmentResults,
+    PlanOfActionAndMilestones,
+    Catalog,
+    Profile,
+    ComponentDefinition,
+    SystemSecurityPlan,
+}
+
+impl ModelType {
+    pub const ALL: [ModelType; 7] = [
+        ModelType::AssessmentPlan,
+        ModelType::AssessmentResults,
+        ModelType::PlanOfActionAndMilestones,
+        ModelType::Catalog,
+        ModelType::Profile,
+        ModelType::ComponentDefinition,
+        ModelType::SystemSecurityPlan,
+    ];
+
+    /// Root key in JSON and YAML, also the root element name in XML
+    pub fn root_key(&self) -> &'static str {
+        match self {
+            ModelType::AssessmentPlan => "assessment-plan",
+            ModelType::AssessmentResults => "assessment-results",
+            ModelType::PlanOfActionAndMilestones => {
+                "plan-of-action-and-milestones"
+            }
+            ModelType::Catalog => "catalog",
+            ModelType::Profile => "profile",
+            ModelType::ComponentDefinition => "component-definition",
+            ModelType::SystemSecurityPlan => "system-security-plan",
+        }
+    }
+
+    pub fn from_root_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.root_key() == key)
+    }
+
+    /// Sniff the model type of a JSON, YAML or XML document
+    pub fn detect(input: &str) -> Result<Self, DocumentError> {
+        if is_xml(input) {
+            return detect_xml(input);
+        }
+
+        // Values are skipped, so only the keys of the root are read
+        let root: BTreeMap<String, IgnoredAny> = serde_yaml::from_str(input)?;
+
+        root.keys()
+            .find_map(|key| Self::from_root_key(key))
+            .ok_or(DocumentError::UnknownModel)
+    }
+}
+
+impl fmt::Display for ModelType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.root_key())
+    }
+}
+
+pub(crate) fn is_xml(input: &str) -> bool {
+    input
+        .trim_start_matches('\u{feff}')
+        .trim_start()
+        .starts_with('<')
+}
+
+fn detect_xml(input: &str) -> Result<ModelType, DocumentError> {
+    let mut reader = Reader::from_str(input.trim_start_matches('\u{feff}'));
+
+    loop {
+        match reader
+            .read_event()
+            .map_err(|e| DocumentError::Xml(e.to_string()))?
+        {
+            Event::Start(start) | Event::Empty(start) => {
+                let name = start.local_name();
+                let name = String::from_utf8_lossy(name.as_ref());
+
+                return ModelType::from_root_key(&name)
+                    .ok_or(DocumentError::UnknownModel);
+            }
+            Event::Eof => return Err(DocumentError::UnknownModel),
+            _ => {}
+        }
+    }
+}
+
+/// Any root OSCAL model
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnyOscalDocument {
+    AssessmentPlan(Box<AssessmentPlan>),
+    AssessmentResults(Box<AssessmentResults>),
+    PlanOfActionAndMilestones(Box<PlanOfActionAndMilestones>),
+    Catalog(Box<Catalog>),
+    Profile(Box<Profile>),
+    ComponentDefinition(Box<ComponentDefinition>),
+    SystemSecurityPlan(Box<SystemSecurityPlan>),
+}
+
+impl AnyOscalDocument {
+    pub fn model_type(&self) -> ModelType {
+        match self {
+            AnyOscalDocument::AssessmentPlan(_) => ModelType::AssessmentPlan,
+            AnyOscalDocument::AssessmentResults(_) => {
+                ModelType::AssessmentResults
+            }
+            AnyOscalDocument::PlanOfActionAndMilestones(_) => {
+                ModelType::PlanOfActionAndMilestones
+            }
+            AnyOscalDocument::Catalog(_) => ModelType::Catalog,
+            AnyOscalDocument::Profile(_) => ModelType::Profile,
+            AnyOscalDocument::ComponentDefinition(_) => {
+                ModelType::ComponentDefinition
+            }
+            AnyOscalDocument::SystemSecurityPlan(_) => {
+                ModelType::SystemSecurityPlan
+            }
+        }
+    }
+
+    /// Detect and parse a JSON or YAML document
+    pub fn from_str_with(
+        input: &str,
+        options: &ParseOptions,
+    ) -> Result<Self, DocumentError> {
+        Self::from_str_with_warnings(input, options).map(|(doc, _)| doc)
+    }
+
+    /// Detect and parse a JSON or YAML document, along with the unknown
+    /// fields kept when parsing leniently
+    pub fn from_str_with_warnings(
+        input: &str,
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<ParseWarning>), DocumentError> {
+        fn parse<T: ParseWith>(
+            input: &str,
+            options: &ParseOptions,
+            wrap: fn(Box<T>) -> AnyOscalDocument,
+        ) -> Result<(AnyOscalDocument, Vec<ParseWarning>), DocumentError>
+        {
+            let (model, warnings) = T::from_str_with_warnings(input, options)?;
+
+            Ok((wrap(Box::new(model)), warnings))
+        }
+
+        let model_type = ModelType::detect(input)?;
+        if is_xml(input) {
+            return Err(DocumentError::UnsupportedXml(model_type));
+        }
+
+        match model_type {
+            ModelType::AssessmentPlan => {
+                parse(input, options, AnyOscalDocument::AssessmentPlan)
+            }
+            ModelType::AssessmentResults => {
+                parse(input, options, AnyOscalDocument::AssessmentResults)
+            }
+            ModelType::PlanOfActionAndMilestones => parse(
+                input,
+                options,
+                AnyOscalDocument::PlanOfActionAndMilestones,
+            ),
+            ModelType::Catalog => {
+                parse(input, options, AnyOscalDocument::Catalog)
+            }
+            ModelType::Profile => {
+                parse(input, options, AnyOscalDocument::Profile)
+            }
+            ModelType::ComponentDefinition => {
+                parse(input, options, AnyOscalDocument::ComponentDefinition)
+            }
+            ModelType::SystemSecurityPlan => {
+                parse(input, options, AnyOscalDocument::SystemSecurityPlan)
+            }
+        }
+    }
+
+    /// Detect and read a JSON or YAML document
+    pub fn from_reader_with<R: Read>(
+        mut reader: R,
+        options: &ParseOptions,
+    ) -> Result<Self, DocumentError> {
+        let mut input = String::new();
+        reader
+            .read_to_string(&mut input)
+            .map_err(DocumentError::Io)?;
+
+        Self::from_str_with(&input, options)
+    }
+}
+
+impl Serialize for AnyOscalDocument {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            AnyOscalDocument::AssessmentPlan(m) => m.serialize(serializer),
+            AnyOscalDocument::AssessmentResults(m) => m.serialize(serializer),
+            AnyOscalDocument::PlanOfActionAndMilestones(m) => {
+                m.serialize(serializer)
+            }
+            AnyOscalDocument::Catalog(m) => m.serialize(serializer),
+            AnyOscalDocument::Profile(m) => m.serialize(serializer),
+            AnyOscalDocument::ComponentDefinition(m) => m.serialize(serializer),
+            AnyOscalDocument::SystemSecurityPlan(m) => m.serialize(serializer),
+        }
+    }
+}
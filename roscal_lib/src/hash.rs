@@ -0,0 +1,98 @@
+//! Digest algorithms named by the `algorithm` of a `Hash`.
+//!
+//! OSCAL lists the SHA-2 and SHA-3 algorithms below and allows others, so a
+//! digest is only checked against its algorithm when the algorithm is known.
+//!
+//! ```
+//! use roscal_lib::hash::HashAlgorithm;
+//!
+//! let algorithm = HashAlgorithm::from_name("SHA-256").unwrap();
+//! assert_eq!(64, algorithm.hex_len());
+//!
+//! let digest = algorithm.digest(b"abc");
+//! assert!(algorithm.matches(b"abc", &digest.to_uppercase()));
+//! ```
+
+use std::fmt;
+
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+
+/// Digest algorithm of a `Hash`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha3_224,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+}
+
+impl HashAlgorithm {
+    pub const ALL: [HashAlgorithm; 8] = [
+        HashAlgorithm::Sha224,
+        HashAlgorithm::Sha256,
+        HashAlgorithm::Sha384,
+        HashAlgorithm::Sha512,
+        HashAlgorithm::Sha3_224,
+        HashAlgorithm::Sha3_256,
+        HashAlgorithm::Sha3_384,
+        HashAlgorithm::Sha3_512,
+    ];
+
+    /// Name used in the `algorithm` of a `Hash`
+    pub fn name(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha224 => "SHA-224",
+            HashAlgorithm::Sha256 => "SHA-256",
+            HashAlgorithm::Sha384 => "SHA-384",
+            HashAlgorithm::Sha512 => "SHA-512",
+            HashAlgorithm::Sha3_224 => "SHA3-224",
+            HashAlgorithm::Sha3_256 => "SHA3-256",
+            HashAlgorithm::Sha3_384 => "SHA3-384",
+            HashAlgorithm::Sha3_512 => "SHA3-512",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.name() == name)
+    }
+
+    /// Length of a digest in hex characters
+    pub fn hex_len(&self) -> usize {
+        match self {
+            HashAlgorithm::Sha224 | HashAlgorithm::Sha3_224 => 56,
+            HashAlgorithm::Sha256 | HashAlgorithm::Sha3_256 => 64,
+            HashAlgorithm::Sha384 | HashAlgorithm::Sha3_384 => 96,
+            HashAlgorithm::Sha512 | HashAlgorithm::Sha3_512 => 128,
+        }
+    }
+
+    /// Lowercase hex digest of the data
+    pub fn digest(&self, data: &[u8]) -> String {
+        match self {
+            HashAlgorithm::Sha224 => format!("{:x}", Sha224::digest(data)),
+            HashAlgorithm::Sha256 => format!("{:x}", Sha256::digest(data)),
+            HashAlgorithm::Sha384 => format!("{:x}", Sha384::digest(data)),
+            HashAlgorithm::Sha512 => format!("{:x}", Sha512::digest(data)),
+            HashAlgorithm::Sha3_224 => format!("{:x}", Sha3_224::digest(data)),
+            HashAlgorithm::Sha3_256 => format!("{:x}", Sha3_256::digest(data)),
+            HashAlgorithm::Sha3_384 => format!("{:x}", Sha3_384::digest(data)),
+            HashAlgorithm::Sha3_512 => format!("{:x}", Sha3_512::digest(data)),
+        }
+    }
+
+    /// Whether the data has the given hex digest, in either case
+    pub fn matches(&self, data: &[u8], digest: &str) -> bool {
+        self.digest(data).eq_ignore_ascii_case(digest)
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
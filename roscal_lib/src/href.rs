@@ -0,0 +1,371 @@
+//! Resolution of the hrefs of imports, links and rlinks to local files.
+//!
+//! An href can be a `#uuid` fragment naming a back-matter resource, a path
+//! relative to the document it appears in, or a URL. Each kind is handled by
+//! an [`HrefResolver`], and resolvers are combined so that a feature following
+//! hrefs only needs one:
+//!
+//! - [`FileSystemResolver`] for relative paths and `file://` URLs
+//! - [`MirrorResolver`] for `http://` and `https://` URLs copied into a local
+//!   mirror directory
+//! - [`BackMatterResolver`] for `#uuid` fragments, whose rlinks are resolved
+//!   by another resolver
+//!
+//! ```
+//! use std::path::Path;
+//!
+//! use roscal_lib::href::{
+//!     standard_resolver, HrefError, HrefResolver, HrefSource, ResourceLinks,
+//! };
+//!
+//! let mut source = HrefSource::new("tests/catalog/yaml/profile.yaml");
+//! source.resources.push(ResourceLinks {
+//!     uuid: "84cbf061-eb87-4ec1-8112-1f529232e907".to_owned(),
+//!     rlinks: vec![(
+//!         "./basic-catalog.yaml".to_owned(),
+//!         Some("application/oscal.catalog+yaml".to_owned()),
+//!     )],
+//! });
+//!
+//! let resolver = standard_resolver(None);
+//! assert_eq!(
+//!     Path::new("tests/catalog/yaml/basic-catalog.yaml"),
+//!     resolver
+//!         .resolve("#84cbf061-eb87-4ec1-8112-1f529232e907", &source)
+//!         .unwrap()
+//! );
+//! assert!(matches!(
+//!     resolver.resolve("https://example.com/catalog.json", &source),
+//!     Err(HrefError::Unsupported(_))
+//! ));
+//! ```
+
+use std::{
+    fmt,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::document::AnyOscalDocument;
+
+/// Error returned when an href can't be resolved to a local file
+#[derive(Debug, Clone, PartialEq)]
+pub enum HrefError {
+    /// The resolver does not handle this kind of href, e.g. a remote URL
+    /// without a mirror
+    Unsupported(String),
+    /// No back-matter resource has the uuid of the fragment
+    UnknownResource(String),
+    /// None of the rlinks of the resource with this uuid could be resolved
+    NoLocalLink(String),
+    /// The href points to a file that does not exist
+    NotFound(PathBuf),
+}
+
+impl fmt::Display for HrefError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HrefError::Unsupported(href) => {
+                write!(f, "`{href}` can't be resolved to a local file")
+            }
+            HrefError::UnknownResource(uuid) => {
+                write!(f, "no back-matter resource with uuid {uuid}")
+            }
+            HrefError::NoLocalLink(uuid) => {
+                write!(f, "resource {uuid} has no local rlink")
+            }
+            HrefError::NotFound(path) => {
+                write!(f, "{} does not exist", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for HrefError {}
+
+/// Rlinks of a back-matter resource, as href and media type
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResourceLinks {
+    pub uuid: String,
+    pub rlinks: Vec<(String, Option<String>)>,
+}
+
+/// The document an href appears in
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HrefSource {
+    /// Location of the document, relative hrefs start from its directory
+    pub path: PathBuf,
+    /// Back-matter resources of the document
+    pub resources: Vec<ResourceLinks>,
+}
+
+macro_rules! resource_links {
+    ($back_matter:expr) => {
+        $back_matter
+            .iter()
+            .flat_map(|b| b.resources.iter().flatten())
+            .map(|r| ResourceLinks {
+                uuid: r.uuid.to_string(),
+                rlinks: r
+                    .rlinks
+                    .iter()
+                    .flatten()
+                    .map(|l| (l.href.to_string(), l.media_type.clone()))
+                    .collect(),
+            })
+            .collect()
+    };
+}
+
+impl HrefSource {
+    /// A document without back-matter resources
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            resources: vec![],
+        }
+    }
+
+    /// A document along with its back-matter resources
+    pub fn from_document(
+        path: impl Into<PathBuf>,
+        document: &AnyOscalDocument,
+    ) -> Self {
+        let resources = match document {
+            AnyOscalDocument::AssessmentPlan(m) => {
+                resource_links!(m.assessment_plan.back_matter)
+            }
+            AnyOscalDocument::AssessmentResults(m) => {
+                resource_links!(m.assessment_results.back_matter)
+            }
+            AnyOscalDocument::PlanOfActionAndMilestones(m) => {
+                resource_links!(m.plan_of_action_and_milestones.back_matter)
+            }
+            AnyOscalDocument::Catalog(m) => {
+                resource_links!(m.catalog.back_matter)
+            }
+            AnyOscalDocument::Profile(m) => {
+                resource_links!(m.profile.back_matter)
+            }
+            AnyOscalDocument::ComponentDefinition(m) => {
+                resource_links!(m.component_definition.back_matter)
+            }
+            AnyOscalDocument::SystemSecurityPlan(m) => {
+                resource_links!(m.system_security_plan.back_matter)
+            }
+        };
+
+        Self {
+            path: path.into(),
+            resources,
+        }
+    }
+
+    /// Directory relative hrefs start from
+    pub fn base_dir(&self) -> &Path {
+        self.path.parent().unwrap_or(Path::new(""))
+    }
+}
+
+/// Resolves hrefs to local files
+pub trait HrefResolver {
+    /// Local file an href of the source document points to. Hrefs of a kind
+    /// the resolver does not handle return [`HrefError::Unsupported`]
+    fn resolve(
+        &self,
+        href: &str,
+        source: &HrefSource,
+    ) -> Result<PathBuf, HrefError>;
+}
+
+impl<R: HrefResolver + ?Sized> HrefResolver for Box<R> {
+    fn resolve(
+        &self,
+        href: &str,
+        source: &HrefSource,
+    ) -> Result<PathBuf, HrefError> {
+        (**self).resolve(href, source)
+    }
+}
+
+impl<R: HrefResolver + ?Sized> HrefResolver for &R {
+    fn resolve(
+        &self,
+        href: &str,
+        source: &HrefSource,
+    ) -> Result<PathBuf, HrefError> {
+        (**self).resolve(href, source)
+    }
+}
+
+/// Tries each resolver in turn, the first one handling the href wins
+impl<R: HrefResolver> HrefResolver for Vec<R> {
+    fn resolve(
+        &self,
+        href: &str,
+        source: &HrefSource,
+    ) -> Result<PathBuf, HrefError> {
+        for resolver in self {
+            match resolver.resolve(href, source) {
+                Err(HrefError::Unsupported(_)) => continue,
+                res => return res,
+            }
+        }
+
+        Err(HrefError::Unsupported(href.to_owned()))
+    }
+}
+
+/// Whether an href starts with a URI scheme such as `https:`
+fn has_scheme(href: &str) -> bool {
+    href.split_once(':').is_some_and(|(scheme, _)| {
+        scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+    })
+}
+
+/// Join without the `.` components
+fn join_clean(base: &Path, path: &str) -> PathBuf {
+    base.join(path)
+        .components()
+        .filter(|c| c != &Component::CurDir)
+        .collect()
+}
+
+fn existing(path: PathBuf) -> Result<PathBuf, HrefError> {
+    match path.is_file() {
+        true => Ok(path),
+        false => Err(HrefError::NotFound(path)),
+    }
+}
+
+/// Resolves paths relative to the source document, and `file://` URLs
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FileSystemResolver;
+
+impl HrefResolver for FileSystemResolver {
+    fn resolve(
+        &self,
+        href: &str,
+        source: &HrefSource,
+    ) -> Result<PathBuf, HrefError> {
+        if let Some(path) = href.strip_prefix("file://") {
+            return existing(PathBuf::from(path));
+        }
+        if href.starts_with('#') || has_scheme(href) {
+            return Err(HrefError::Unsupported(href.to_owned()));
+        }
+
+        existing(join_clean(source.base_dir(), href))
+    }
+}
+
+/// Resolves `http://` and `https://` URLs into an offline mirror directory,
+/// where `https://example.com/dir/file.json` is `<root>/example.com/dir/file.json`
+#[derive(Debug, Clone, Default)]
+pub struct MirrorResolver {
+    pub root: PathBuf,
+}
+
+impl MirrorResolver {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl HrefResolver for MirrorResolver {
+    fn resolve(
+        &self,
+        href: &str,
+        _source: &HrefSource,
+    ) -> Result<PathBuf, HrefError> {
+        let Some(url) = href
+            .strip_prefix("https://")
+            .or_else(|| href.strip_prefix("http://"))
+        else {
+            return Err(HrefError::Unsupported(href.to_owned()));
+        };
+        let url = url.split(['?', '#']).next().unwrap_or_default();
+
+        // Segments can't climb out of the mirror
+        if url.split('/').any(|segment| segment == "..") {
+            return Err(HrefError::Unsupported(href.to_owned()));
+        }
+
+        existing(join_clean(&self.root, url))
+    }
+}
+
+/// Resolves `#uuid` fragments through the rlinks of the back-matter resource
+/// with that uuid. Rlinks are resolved by the inner resolver, which also
+/// handles any other href. JSON and YAML targets are preferred over XML, as
+/// XML documents can't be parsed yet
+#[derive(Debug, Clone, Default)]
+pub struct BackMatterResolver<R> {
+    pub inner: R,
+}
+
+impl<R: HrefResolver> BackMatterResolver<R> {
+    pub fn new(inner: R) -> Self {
+        Self { inner }
+    }
+}
+
+impl<R: HrefResolver> HrefResolver for BackMatterResolver<R> {
+    fn resolve(
+        &self,
+        href: &str,
+        source: &HrefSource,
+    ) -> Result<PathBuf, HrefError> {
+        let Some(uuid) = href.strip_prefix('#') else {
+            return self.inner.resolve(href, source);
+        };
+        let resource = source
+            .resources
+            .iter()
+            .find(|r| r.uuid == uuid)
+            .ok_or_else(|| HrefError::UnknownResource(uuid.to_owned()))?;
+
+        let mut resolved = vec![];
+        let mut first_error = None;
+        for (href, media_type) in &resource.rlinks {
+            match self.inner.resolve(href, source) {
+                Ok(path) => resolved.push((path, media_type)),
+                Err(HrefError::Unsupported(_)) => {}
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        let is_xml = |path: &Path, media_type: &Option<String>| {
+            path.extension().is_some_and(|ext| ext == "xml")
+                || media_type.as_deref().is_some_and(|m| m.ends_with("xml"))
+        };
+
+        resolved
+            .iter()
+            .find(|(path, media_type)| !is_xml(path, media_type))
+            .or(resolved.first())
+            .map(|(path, _)| path.clone())
+            .ok_or_else(|| {
+                first_error
+                    .unwrap_or_else(|| HrefError::NoLocalLink(uuid.to_owned()))
+            })
+    }
+}
+
+/// Back-matter fragments, then the file system, then the mirror directory
+/// if there is one
+pub fn standard_resolver(
+    mirror_dir: Option<PathBuf>,
+) -> BackMatterResolver<Vec<Box<dyn HrefResolver + Send + Sync>>> {
+    let mut resolvers: Vec<Box<dyn HrefResolver + Send + Sync>> =
+        vec![Box::new(FileSystemResolver)];
+    if let Some(root) = mirror_dir {
+        resolvers.push(Box::new(MirrorResolver::new(root)));
+    }
+
+    BackMatterResolver::new(resolvers)
+}
@@ -4,3 +4,5 @@
 pub mod component_definition;
 /// System Security Plan Model
 pub mod ssp;
+/// System Security Plan Generation From Component Definitions
+pub mod ssp_gen;
@@ -1,30 +1,35 @@
+use crate::markup::Markup;
+use crate::parse::Extensions;
+use crate::scalar::{DateTimeWithTz, EmailAddress, OscalDate, OscalUuid, Token, Uri, UriReference};
+use crate::validation;
 use derivative::Derivative;
-use strum::EnumString;
 use derive_builder::Builder;
-use serde::{Serialize, Deserialize};
-use crate::validation;
+use serde::{Deserialize, Serialize};
+use strum::EnumString;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SystemSecurityPlan {
     #[serde(rename = "$schema")]
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schema: Option<String>,
     pub system_security_plan: SystemSecurityPlanSsp,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A system security plan, such as those described in NIST SP 800-18.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SystemSecurityPlanSsp {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -45,19 +50,32 @@ pub struct SystemSecurityPlanSsp {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SystemSecurityPlanSspBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A collection of resources that may be referenced from within the OSCAL document instance.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct BackMatter {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub resources: Option<Vec<Resource>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A resource associated with content in the containing document instance. A resource may be
@@ -66,9 +84,8 @@ pub struct BackMatter {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Resource {
     /// A resource encoded using the Base64 alphabet defined by RFC 2045.
     #[builder(setter(into, strip_option), default)]
@@ -81,8 +98,7 @@ pub struct Resource {
     /// An optional short summary of the resource used to indicate the purpose of the resource.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub document_ids: Option<Vec<DocumentIdentifier>>,
@@ -91,8 +107,7 @@ pub struct Resource {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rlinks: Option<Vec<ResourceLink>>,
@@ -100,24 +115,33 @@ pub struct Resource {
     /// navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A unique identifier for a resource.
     #[serde(
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ResourceBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A resource encoded using the Base64 alphabet defined by RFC 2045.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Base64 {
     /// Name of the file before it was encoded as Base64 to be embedded in a resource. This is
     /// the name that will be assigned to the file when the file is decoded.
@@ -127,7 +151,7 @@ pub struct Base64 {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub filename: Option<String>,
+    pub filename: Option<Token>,
     /// A label that indicates the nature of a resource, as a data serialization or format.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -137,14 +161,24 @@ pub struct Base64 {
         deserialize_with = "validation::deser_base64"
     )]
     pub value: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl Base64Builder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("filename", &self.filename)?;
+        validation::check_base64("value", &self.value)?;
+        Ok(())
+    }
 }
 
 /// An optional citation consisting of end note text using structured markup.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Citation {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -153,8 +187,11 @@ pub struct Citation {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
     /// A line of citation text.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub text: String,
+    pub text: Markup,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A reference to a local or remote resource, that has a specific relation to the containing
@@ -162,16 +199,15 @@ pub struct Citation {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Link {
     /// A resolvable URL reference to a resource.
     #[serde(
         serialize_with = "validation::ser_uri_ref",
         deserialize_with = "validation::deser_uri_ref"
     )]
-    pub href: String,
+    pub href: UriReference,
     /// A label that indicates the nature of a resource, as a data serialization or format.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -184,7 +220,7 @@ pub struct Link {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub rel: Option<String>,
+    pub rel: Option<Token>,
     /// In case where the href points to a back-matter/resource, this value will indicate the URI
     /// fragment to append to any rlink associated with the resource. This value MUST be URI
     /// encoded.
@@ -194,17 +230,26 @@ pub struct Link {
     /// A textual label to associate with the link, which may be used for presentation in a tool.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub text: Option<String>,
+    pub text: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl LinkBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri_ref("href", &self.href)?;
+        validation::check_token("rel", &self.rel)?;
+        Ok(())
+    }
 }
 
 /// An attribute, characteristic, or quality of the containing object expressed as a
 /// namespace qualified name/value pair.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Property {
     /// A textual label that provides a sub-type or characterization of the property's name.
     #[builder(setter(into, strip_option), default)]
@@ -213,7 +258,7 @@ pub struct Property {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub class: Option<String>,
+    pub class: Option<Token>,
     /// An identifier for relating distinct sets of properties.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -221,14 +266,14 @@ pub struct Property {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub group: Option<String>,
+    pub group: Option<Token>,
     /// A textual label, within a namespace, that uniquely identifies a specific attribute,
     /// characteristic, or quality of the property's containing object.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub name: String,
+    pub name: Token,
     /// A namespace qualifying the property's name. This allows different organizations to
     /// associate distinct semantics with the same name.
     #[builder(setter(into, strip_option), default)]
@@ -237,11 +282,10 @@ pub struct Property {
         serialize_with = "validation::ser_uri_opt",
         deserialize_with = "validation::deser_uri_opt"
     )]
-    pub ns: Option<String>,
+    pub ns: Option<Uri>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A unique identifier for a property.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -250,17 +294,30 @@ pub struct Property {
         deserialize_with = "validation::deser_uuid_opt"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: Option<String>,
+    pub uuid: Option<OscalUuid>,
     /// Indicates the value of the attribute, characteristic, or quality.
     pub value: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PropertyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("class", &self.class)?;
+        validation::check_token("group", &self.group)?;
+        validation::check_token("name", &self.name)?;
+        validation::check_uri("ns", &self.ns)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A document identifier qualified by an identifier scheme.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct DocumentIdentifier {
     pub identifier: String,
     /// Qualifies the kind of document identifier using a URI. If the scheme is not provided the
@@ -271,7 +328,18 @@ pub struct DocumentIdentifier {
         serialize_with = "validation::ser_uri_opt",
         deserialize_with = "validation::deser_uri_opt"
     )]
-    pub scheme: Option<String>,
+    pub scheme: Option<Uri>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl DocumentIdentifierBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri("scheme", &self.scheme)?;
+        Ok(())
+    }
 }
 
 /// A URL-based pointer to an external resource with an optional hash for verification and
@@ -279,9 +347,8 @@ pub struct DocumentIdentifier {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ResourceLink {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -291,20 +358,30 @@ pub struct ResourceLink {
         serialize_with = "validation::ser_uri_ref",
         deserialize_with = "validation::deser_uri_ref"
     )]
-    pub href: String,
+    pub href: UriReference,
     /// A label that indicates the nature of a resource, as a data serialization or format.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub media_type: Option<String>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ResourceLinkBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri_ref("href", &self.href)?;
+        Ok(())
+    }
 }
 
 /// A representation of a cryptographic digest generated over a resource using a specified
 /// hash algorithm.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
+#[serde(try_from = "validation::RawHash")]
 pub struct Hash {
     /// The digest method by which a hash is derived.
     pub algorithm: String,
@@ -313,6 +390,18 @@ pub struct Hash {
         deserialize_with = "validation::deser_hash"
     )]
     pub value: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl HashBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_hash("value", &self.value)?;
+        validation::check_digest(&self.algorithm, &self.value)?;
+        Ok(())
+    }
 }
 
 /// Describes how the system satisfies a set of controls.
@@ -321,25 +410,26 @@ pub struct Hash {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ControlImplementation {
     /// A statement describing important things to know about how this set of control
     /// satisfaction documentation is approached.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     pub implemented_requirements: Vec<ControlBasedRequirement>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub set_parameters: Option<Vec<SetParameterValue>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Describes how the system satisfies the requirements of an individual control.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ControlBasedRequirement {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -356,8 +446,7 @@ pub struct ControlBasedRequirement {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
@@ -378,16 +467,26 @@ pub struct ControlBasedRequirement {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ControlBasedRequirementBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Defines how the referenced component implements a set of controls.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ComponentControlImplementation {
     /// A machine-oriented identifier reference to the component that is implemeting a given
     /// control.
@@ -396,7 +495,7 @@ pub struct ComponentControlImplementation {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub component_uuid: String,
+    pub component_uuid: OscalUuid,
     /// An implementation statement that describes how a control or a control statement is
     /// implemented within the referenced system component.
     pub description: String,
@@ -419,8 +518,7 @@ pub struct ComponentControlImplementation {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
@@ -441,23 +539,32 @@ pub struct ComponentControlImplementation {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ComponentControlImplementationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("component_uuid", &self.component_uuid)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Identifies content intended for external consumption, such as with leveraged
 /// organizations.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Export {
     /// An implementation statement that describes the aspects of the control or control
     /// statement implementation that can be available to another system leveraging this system.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -469,25 +576,26 @@ pub struct Export {
     pub provided: Option<Vec<ProvidedControlImplementation>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsibilities: Option<Vec<ControlImplementationResponsibility>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Describes a capability which may be inherited by a leveraging system.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ProvidedControlImplementation {
     /// An implementation statement that describes the aspects of the control or control
     /// statement implementation that can be provided to another system leveraging this system.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -496,8 +604,7 @@ pub struct ProvidedControlImplementation {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
@@ -512,7 +619,18 @@ pub struct ProvidedControlImplementation {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ProvidedControlImplementationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A reference to a role with responsibility for performing a function relative to the
@@ -521,9 +639,8 @@ pub struct ProvidedControlImplementation {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ResponsibleRole {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -534,29 +651,39 @@ pub struct ResponsibleRole {
         serialize_with = "validation::ser_uuid_vec_opt",
         deserialize_with = "validation::deser_uuid_vec_opt"
     )]
-    pub party_uuids: Option<Vec<String>>,
+    pub party_uuids: Option<Vec<OscalUuid>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A human-oriented identifier reference to a role performed.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub role_id: String,
+    pub role_id: Token,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ResponsibleRoleBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("party_uuids", &self.party_uuids)?;
+        validation::check_token("role_id", &self.role_id)?;
+        Ok(())
+    }
 }
 
 /// Describes a control implementation responsibility imposed on a leveraging system.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ControlImplementationResponsibility {
     /// An implementation statement that describes the aspects of the control or control
     /// statement implementation that a leveraging system must implement to satisfy the control
@@ -576,11 +703,10 @@ pub struct ControlImplementationResponsibility {
         serialize_with = "validation::ser_uuid_opt",
         deserialize_with = "validation::deser_uuid_opt"
     )]
-    pub provided_uuid: Option<String>,
+    pub provided_uuid: Option<OscalUuid>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
@@ -595,31 +721,43 @@ pub struct ControlImplementationResponsibility {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ControlImplementationResponsibilityBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("provided_uuid", &self.provided_uuid)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Indicates the degree to which the a given control is implemented.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ImplementationStatus {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// Identifies the implementation status of the control or control objective.
     pub state: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Describes a control implementation inherited by a leveraging system.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct InheritedControlImplementation {
     /// An implementation statement that describes the aspects of a control or control statement
     /// implementation that a leveraging system is inheriting from a leveraged system.
@@ -638,7 +776,7 @@ pub struct InheritedControlImplementation {
         serialize_with = "validation::ser_uuid_opt",
         deserialize_with = "validation::deser_uuid_opt"
     )]
-    pub provided_uuid: Option<String>,
+    pub provided_uuid: Option<OscalUuid>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
@@ -653,16 +791,27 @@ pub struct InheritedControlImplementation {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl InheritedControlImplementationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("provided_uuid", &self.provided_uuid)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Describes how this system satisfies a responsibility imposed by a leveraged system.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SatisfiedControlImplementationResponsibility {
     /// An implementation statement that describes the aspects of a control or control statement
     /// implementation that a leveraging system is implementing based on a requirement from a
@@ -676,8 +825,7 @@ pub struct SatisfiedControlImplementationResponsibility {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A machine-oriented identifier reference to a control implementation that satisfies a
     /// responsibility imposed by a leveraged system.
     #[builder(setter(into, strip_option), default)]
@@ -686,7 +834,7 @@ pub struct SatisfiedControlImplementationResponsibility {
         serialize_with = "validation::ser_uuid_opt",
         deserialize_with = "validation::deser_uuid_opt"
     )]
-    pub responsibility_uuid: Option<String>,
+    pub responsibility_uuid: Option<OscalUuid>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
@@ -701,16 +849,27 @@ pub struct SatisfiedControlImplementationResponsibility {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SatisfiedControlImplementationResponsibilityBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("responsibility_uuid", &self.responsibility_uuid)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Identifies the parameter that will be set by the enclosed value.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SetParameterValue {
     /// A human-oriented reference to a parameter within a control, who's catalog has been
     /// imported into the current implementation context.
@@ -718,21 +877,30 @@ pub struct SetParameterValue {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub param_id: String,
+    pub param_id: Token,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     pub values: Vec<String>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SetParameterValueBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("param_id", &self.param_id)?;
+        Ok(())
+    }
 }
 
 /// Identifies which statements within a control are addressed.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SpecificControlStatement {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -745,8 +913,7 @@ pub struct SpecificControlStatement {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
@@ -755,7 +922,7 @@ pub struct SpecificControlStatement {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub statement_id: String,
+    pub statement_id: Token,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this control statement elsewhere in this or other OSCAL instances. The UUID
     /// of the control statement in the source OSCAL instance is sufficient to reference the data
@@ -765,15 +932,25 @@ pub struct SpecificControlStatement {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SpecificControlStatementBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("statement_id", &self.statement_id)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Used to import the OSCAL profile representing the system's control baseline.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ImportProfile {
     /// A resolvable URL reference to the profile or catalog to use as the system's control
     /// baseline.
@@ -781,11 +958,21 @@ pub struct ImportProfile {
         serialize_with = "validation::ser_uri_ref",
         deserialize_with = "validation::deser_uri_ref"
     )]
-    pub href: String,
+    pub href: UriReference,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ImportProfileBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri_ref("href", &self.href)?;
+        Ok(())
+    }
 }
 
 /// Provides information about the containing document, and defines concepts that are shared
@@ -793,9 +980,8 @@ pub struct ImportProfile {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct DocumentMetadata {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -807,7 +993,7 @@ pub struct DocumentMetadata {
         serialize_with = "validation::ser_dttz",
         deserialize_with = "validation::deser_dttz"
     )]
-    pub last_modified: String,
+    pub last_modified: DateTimeWithTz,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -826,8 +1012,7 @@ pub struct DocumentMetadata {
     pub published: Option<String>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
@@ -838,18 +1023,27 @@ pub struct DocumentMetadata {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub roles: Option<Vec<Role>>,
     /// A name given to the document, which may be used by a tool for display and navigation.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub title: String,
+    pub title: Markup,
     pub version: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl DocumentMetadataBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("last_modified", &self.last_modified)?;
+        Ok(())
+    }
 }
 
 /// An action applied by a role within a given party to the content.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Action {
     /// The date and time when the action occurred.
     #[builder(setter(into, strip_option), default)]
@@ -858,7 +1052,7 @@ pub struct Action {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub date: Option<String>,
+    pub date: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -867,8 +1061,7 @@ pub struct Action {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
@@ -880,7 +1073,7 @@ pub struct Action {
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub action_type: String,
+    pub action_type: Token,
     /// A unique identifier that can be used to reference this defined action elsewhere in an
     /// OSCAL document. A UUID should be consistently used for a given location across revisions
     /// of the document.
@@ -889,7 +1082,20 @@ pub struct Action {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ActionBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("date", &self.date)?;
+        validation::check_token("action_type", &self.action_type)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A reference to a set of persons and/or organizations that have responsibility for
@@ -897,9 +1103,8 @@ pub struct Action {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ResponsibleParty {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -908,20 +1113,31 @@ pub struct ResponsibleParty {
         serialize_with = "validation::ser_uuid_vec",
         deserialize_with = "validation::deser_uuid_vec"
     )]
-    pub party_uuids: Vec<String>,
+    pub party_uuids: Vec<OscalUuid>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A reference to a role performed by a party.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub role_id: String,
+    pub role_id: Token,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ResponsiblePartyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("party_uuids", &self.party_uuids)?;
+        validation::check_token("role_id", &self.role_id)?;
+        Ok(())
+    }
 }
 
 /// A physical point of presence, which may be associated with people, organizations, or
@@ -929,9 +1145,8 @@ pub struct ResponsibleParty {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Location {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -942,7 +1157,7 @@ pub struct Location {
         serialize_with = "validation::ser_email_vec_opt",
         deserialize_with = "validation::deser_email_vec_opt"
     )]
-    pub email_addresses: Option<Vec<String>>,
+    pub email_addresses: Option<Vec<EmailAddress>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -951,39 +1166,49 @@ pub struct Location {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub telephone_numbers: Option<Vec<TelephoneNumber>>,
     /// A name given to the location, which may be used by a tool for display and navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(
         serialize_with = "validation::ser_uri_vec_opt",
         deserialize_with = "validation::deser_uri_vec_opt"
     )]
-    pub urls: Option<Vec<String>>,
+    pub urls: Option<Vec<Uri>>,
     /// A unique ID for the location, for reference.
     #[serde(
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl LocationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_email("email_addresses", &self.email_addresses)?;
+        validation::check_uri("urls", &self.urls)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A postal address for the location.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Address {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1012,15 +1237,24 @@ pub struct Address {
         serialize_with = "validation::ser_token_opt",
         deserialize_with = "validation::deser_token_opt"
     )]
-    pub address_type: Option<String>,
+    pub address_type: Option<Token>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl AddressBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("address_type", &self.address_type)?;
+        Ok(())
+    }
 }
 
 /// A telephone service number as defined by ITU-T E.164.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct TelephoneNumber {
     pub number: String,
     /// Indicates the type of phone number.
@@ -1028,6 +1262,10 @@ pub struct TelephoneNumber {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub telephone_number_type: Option<String>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// An organization or person, which may be associated with roles or other concepts within
@@ -1035,9 +1273,8 @@ pub struct TelephoneNumber {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Party {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1048,7 +1285,7 @@ pub struct Party {
         serialize_with = "validation::ser_email_vec_opt",
         deserialize_with = "validation::deser_email_vec_opt"
     )]
-    pub email_addresses: Option<Vec<String>>,
+    pub email_addresses: Option<Vec<EmailAddress>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub external_ids: Option<Vec<PartyExternalIdentifier>>,
@@ -1064,7 +1301,7 @@ pub struct Party {
         serialize_with = "validation::ser_uuid_vec_opt",
         deserialize_with = "validation::deser_uuid_vec_opt"
     )]
-    pub member_of_organizations: Option<Vec<String>>,
+    pub member_of_organizations: Option<Vec<OscalUuid>>,
     /// The full name of the party. This is typically the legal name associated with the party.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1074,8 +1311,7 @@ pub struct Party {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A short common name, abbreviation, or acronym for the party.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1092,16 +1328,27 @@ pub struct Party {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PartyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_email("email_addresses", &self.email_addresses)?;
+        validation::check_uuid("member_of_organizations", &self.member_of_organizations)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// An identifier for a person or organization using a designated scheme. e.g. an Open
 /// Researcher and Contributor ID (ORCID).
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct PartyExternalIdentifier {
     pub id: String,
     /// Indicates the type of external identifier.
@@ -1109,7 +1356,18 @@ pub struct PartyExternalIdentifier {
         serialize_with = "validation::ser_uri",
         deserialize_with = "validation::deser_uri"
     )]
-    pub scheme: String,
+    pub scheme: Uri,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PartyExternalIdentifierBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri("scheme", &self.scheme)?;
+        Ok(())
+    }
 }
 /// A category describing the kind of party the object describes.
 ///
@@ -1201,9 +1459,8 @@ pub enum PartyType {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct RevisionHistoryEntry {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1211,7 +1468,7 @@ pub struct RevisionHistoryEntry {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub last_modified: Option<String>,
+    pub last_modified: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1227,39 +1484,47 @@ pub struct RevisionHistoryEntry {
         serialize_with = "validation::ser_dttz_opt",
         deserialize_with = "validation::deser_dttz_opt"
     )]
-    pub published: Option<String>,
+    pub published: Option<DateTimeWithTz>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A name given to the document revision, which may be used by a tool for display and
     /// navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     pub version: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl RevisionHistoryEntryBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_dttz("last_modified", &self.last_modified)?;
+        validation::check_dttz("published", &self.published)?;
+        Ok(())
+    }
 }
 
 /// Defines a function, which might be assigned to a party in a specific situation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Role {
     /// A summary of the role's purpose and associated responsibilities.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     /// A unique identifier for the role.
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub id: String,
+    pub id: Token,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1268,14 +1533,24 @@ pub struct Role {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A short common name, abbreviation, or acronym for the role.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub short_name: Option<String>,
     /// A name given to the role, which may be used by a tool for display and navigation.
     pub title: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl RoleBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("id", &self.id)?;
+        Ok(())
+    }
 }
 
 /// Contains the characteristics of the system, such as its name, purpose, and security
@@ -1283,9 +1558,8 @@ pub struct Role {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SystemCharacteristics {
     pub authorization_boundary: AuthorizationBoundary,
     #[builder(setter(into, strip_option), default)]
@@ -1297,10 +1571,9 @@ pub struct SystemCharacteristics {
         serialize_with = "validation::ser_date_opt",
         deserialize_with = "validation::deser_date_opt"
     )]
-    pub date_authorized: Option<String>,
+    pub date_authorized: Option<OscalDate>,
     /// A summary of the system.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1312,8 +1585,7 @@ pub struct SystemCharacteristics {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
@@ -1334,15 +1606,24 @@ pub struct SystemCharacteristics {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub system_name_short: Option<String>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SystemCharacteristicsBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_date("date_authorized", &self.date_authorized)?;
+        Ok(())
+    }
 }
 
 /// A description of this system's authorization boundary, optionally supplemented by
 /// diagrams that illustrate the authorization boundary.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct AuthorizationBoundary {
     /// A summary of the system's authorization boundary.
     pub description: String,
@@ -1357,22 +1638,22 @@ pub struct AuthorizationBoundary {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A graphic that provides a visual representation the system, or some aspect of it.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Diagram {
     /// A brief caption to annotate the diagram.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub caption: Option<String>,
+    pub caption: Option<Markup>,
     /// A summary of the diagram.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1385,8 +1666,7 @@ pub struct Diagram {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this diagram elsewhere in this or other OSCAL instances. The locally defined
     /// UUID of the diagram can be used to reference the data item locally or globally (e.g., in
@@ -1397,16 +1677,25 @@ pub struct Diagram {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl DiagramBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A description of the logical flow of information within the system and across its
 /// boundaries, optionally supplemented by diagrams that illustrate these flows.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct DataFlow {
     /// A summary of the system's data flow.
     pub description: String,
@@ -1421,17 +1710,18 @@ pub struct DataFlow {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A description of the system's network architecture, optionally supplemented by diagrams
 /// that illustrate the network architecture.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct NetworkArchitecture {
     /// A summary of the system's network architecture.
     pub description: String,
@@ -1446,8 +1736,11 @@ pub struct NetworkArchitecture {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// The overall level of expected impact resulting from unauthorized disclosure,
@@ -1457,7 +1750,6 @@ pub struct NetworkArchitecture {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SecurityImpactLevel {
     /// A target-level of availability for the system, based on the sensitivity of information
     /// within the system.
@@ -1468,21 +1760,26 @@ pub struct SecurityImpactLevel {
     /// A target-level of integrity for the system, based on the sensitivity of information
     /// within the system.
     pub security_objective_integrity: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Describes the operational status of the system.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SystemCharacteristicsStatus {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// The current operating status.
     pub state: PurpleState,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 /// The current operating status.
 ///
@@ -1583,9 +1880,8 @@ pub enum PurpleState {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SystemIdentification {
     pub id: String,
     /// Identifies the identification system from which the provided identifier was assigned.
@@ -1595,7 +1891,18 @@ pub struct SystemIdentification {
         serialize_with = "validation::ser_uri_opt",
         deserialize_with = "validation::deser_uri_opt"
     )]
-    pub identifier_type: Option<String>,
+    pub identifier_type: Option<Uri>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SystemIdentificationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uri("identifier_type", &self.identifier_type)?;
+        Ok(())
+    }
 }
 
 /// Contains details about all information types that are stored, processed, or transmitted
@@ -1605,7 +1912,6 @@ pub struct SystemIdentification {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SystemInformation {
     pub information_types: Vec<InformationType>,
     #[builder(setter(into, strip_option), default)]
@@ -1614,6 +1920,10 @@ pub struct SystemInformation {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Contains details about one information type that is stored, processed, or transmitted by
@@ -1621,9 +1931,8 @@ pub struct SystemInformation {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct InformationType {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1661,7 +1970,18 @@ pub struct InformationType {
         deserialize_with = "validation::deser_uuid_opt"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: Option<String>,
+    pub uuid: Option<OscalUuid>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl InformationTypeBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// The expected level of impact resulting from the described information.
@@ -1670,12 +1990,10 @@ pub struct InformationType {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ImpactLevel {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub adjustment_justification: Option<String>,
+    pub adjustment_justification: Option<Markup>,
     pub base: String,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1686,6 +2004,10 @@ pub struct ImpactLevel {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub selected: Option<String>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A set of information type identifiers qualified by the given identification system used,
@@ -1695,13 +2017,16 @@ pub struct ImpactLevel {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct InformationTypeCategorization {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub information_type_ids: Option<Vec<String>>,
     /// Specifies the information type identification system used.
     pub system: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// Provides information as to how the system is implemented.
@@ -1710,7 +2035,6 @@ pub struct InformationTypeCategorization {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SystemImplementation {
     pub components: Vec<Component>,
     #[builder(setter(into, strip_option), default)]
@@ -1727,22 +2051,23 @@ pub struct SystemImplementation {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     pub users: Vec<SystemUser>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 
 /// A defined component that can be part of an implemented system.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Component {
     /// A description of the component, including information about its function.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -1755,27 +2080,24 @@ pub struct Component {
     /// A summary of the technological or business purpose of the component.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub purpose: Option<String>,
+    pub purpose: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<ResponsibleRole>>,
     /// Describes the operational status of the system component.
     pub status: ComponentStatus,
     /// A human readable name for the system component.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub title: String,
+    pub title: Markup,
     /// A category describing the purpose of the component.
     #[serde(rename = "type")]
     #[serde(
         serialize_with = "validation::ser_token",
         deserialize_with = "validation::deser_token"
     )]
-    pub component_type: String,
+    pub component_type: Token,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this component elsewhere in this or other OSCAL instances. The locally
     /// defined UUID of the component can be used to reference the data item locally or globally
@@ -1787,16 +2109,27 @@ pub struct Component {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ComponentBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("component_type", &self.component_type)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Information about the protocol used to provide a service.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ServiceProtocolInformation {
     /// The common name of the protocol, which should be the appropriate "service name" from the
     /// IANA Service Name and Transport Protocol Port Number Registry.
@@ -1807,8 +2140,7 @@ pub struct ServiceProtocolInformation {
     /// A human readable name for the protocol (e.g., Transport Layer Security).
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this service protocol information elsewhere in this or other OSCAL
     /// instances. The locally defined UUID of the service protocol can be used to reference the
@@ -1822,15 +2154,24 @@ pub struct ServiceProtocolInformation {
         deserialize_with = "validation::deser_uuid_opt"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: Option<String>,
+    pub uuid: Option<OscalUuid>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ServiceProtocolInformationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Where applicable this is the IPv4 port range on which the service operates.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct PortRange {
     /// Indicates the ending port number in a port range
     #[builder(setter(into, strip_option), default)]
@@ -1852,6 +2193,18 @@ pub struct PortRange {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transport: Option<Transport>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl PortRangeBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_non_neg_int("end", &self.end)?;
+        validation::check_non_neg_int("start", &self.start)?;
+        Ok(())
+    }
 }
 /// Indicates the transport type.
 ///
@@ -1905,18 +2258,19 @@ pub enum Transport {
 }
 
 /// Describes the operational status of the system component.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(Builder, Derivative)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ComponentStatus {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// The operational status.
     pub state: FluffyState,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
 }
 /// The operational status.
 ///
@@ -1975,13 +2329,11 @@ pub enum FluffyState {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct InventoryItem {
     /// A summary of the inventory item stating its purpose within the system.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub description: String,
+    pub description: Markup,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub implemented_components: Option<Vec<ImplementedComponent>>,
@@ -1993,8 +2345,7 @@ pub struct InventoryItem {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
@@ -2009,16 +2360,26 @@ pub struct InventoryItem {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl InventoryItemBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// The set of components that are implemented in a given system inventory item.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct ImplementedComponent {
     /// A machine-oriented identifier reference to a component that is implemented as part of an
     /// inventory item.
@@ -2027,7 +2388,7 @@ pub struct ImplementedComponent {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub component_uuid: String,
+    pub component_uuid: OscalUuid,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -2036,11 +2397,21 @@ pub struct ImplementedComponent {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<Vec<ResponsibleParty>>,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl ImplementedComponentBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_uuid("component_uuid", &self.component_uuid)?;
+        Ok(())
+    }
 }
 
 /// A description of another authorized system from which this system inherits capabilities
@@ -2049,15 +2420,14 @@ pub struct ImplementedComponent {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct LeveragedAuthorization {
     #[serde(
         serialize_with = "validation::ser_date",
         deserialize_with = "validation::deser_date"
     )]
-    pub date_authorized: String,
+    pub date_authorized: OscalDate,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -2067,17 +2437,15 @@ pub struct LeveragedAuthorization {
         serialize_with = "validation::ser_uuid",
         deserialize_with = "validation::deser_uuid"
     )]
-    pub party_uuid: String,
+    pub party_uuid: OscalUuid,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     /// A human readable name for the leveraged authorization in the context of the system.
-    #[serde(deserialize_with = "validation::deser_markup")]
-    pub title: String,
+    pub title: Markup,
     /// A machine-oriented, globally unique identifier with cross-instance scope and can be used
     /// to reference this leveraged authorization elsewhere in this or other OSCAL instances. The
     /// locally defined UUID of the leveraged authorization can be used to reference the data
@@ -2089,16 +2457,28 @@ pub struct LeveragedAuthorization {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl LeveragedAuthorizationBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_date("date_authorized", &self.date_authorized)?;
+        validation::check_uuid("party_uuid", &self.party_uuid)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// A type of user that interacts with the system based on an associated role.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[derive(Builder, Derivative)]
-#[builder(setter(into, strip_option))]
+#[builder(setter(into, strip_option), build_fn(validate = "Self::validate"))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SystemUser {
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2106,8 +2486,7 @@ pub struct SystemUser {
     /// A summary of the user's purpose within the system.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<Link>>,
@@ -2116,12 +2495,11 @@ pub struct SystemUser {
     pub props: Option<Vec<Property>>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub remarks: Option<String>,
+    pub remarks: Option<Markup>,
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(deserialize_with = "validation::deser_token_vec_opt")]
-    pub role_ids: Option<Vec<String>>,
+    pub role_ids: Option<Vec<Token>>,
     /// A short common name, abbreviation, or acronym for the user.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2129,8 +2507,7 @@ pub struct SystemUser {
     /// A name given to the user, which may be used by a tool for display and navigation.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub title: Option<String>,
+    pub title: Option<Markup>,
     /// A machine-oriented, globally unique identifier with cross-instance scope that can be used
     /// to reference this user class elsewhere in this or other OSCAL instances. The locally
     /// defined UUID of the system user can be used to reference the data item locally or
@@ -2142,7 +2519,19 @@ pub struct SystemUser {
         deserialize_with = "validation::deser_uuid"
     )]
     #[derivative(PartialEq = "ignore")]
-    pub uuid: String,
+    pub uuid: OscalUuid,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+impl SystemUserBuilder {
+    fn validate(&self) -> Result<(), String> {
+        validation::check_token("role_ids", &self.role_ids)?;
+        validation::check_uuid("uuid", &self.uuid)?;
+        Ok(())
+    }
 }
 
 /// Identifies a specific system privilege held by the user, along with an associated
@@ -2152,14 +2541,2185 @@ pub struct SystemUser {
 #[derive(Builder, Derivative)]
 #[builder(setter(into, strip_option))]
 #[derivative(PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Privilege {
     /// A summary of the privilege's purpose within the system.
     #[builder(setter(into, strip_option), default)]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(deserialize_with = "validation::deser_markup_opt")]
-    pub description: Option<String>,
+    pub description: Option<Markup>,
     pub functions_performed: Vec<String>,
     /// A human readable name for the privilege.
     pub title: String,
+    /// Fields unknown to the model, kept when parsing leniently
+    #[builder(default)]
+    #[serde(flatten, deserialize_with = "validation::deser_extensions")]
+    pub extensions: Extensions,
+}
+
+/// Read only traversal of the model
+///
+/// Every method visits one type and by default walks into its
+/// children, so an implementation only overrides the types it cares
+/// about. Call the matching `walk_*` function from an override to keep
+/// descending.
+pub mod visit {
+    use super::*;
+    pub trait Visit<'ast> {
+        fn visit_system_security_plan(&mut self, node: &'ast SystemSecurityPlan) {
+            walk_system_security_plan(self, node)
+        }
+        fn visit_system_security_plan_ssp(&mut self, node: &'ast SystemSecurityPlanSsp) {
+            walk_system_security_plan_ssp(self, node)
+        }
+        fn visit_back_matter(&mut self, node: &'ast BackMatter) {
+            walk_back_matter(self, node)
+        }
+        fn visit_resource(&mut self, node: &'ast Resource) {
+            walk_resource(self, node)
+        }
+        fn visit_base64(&mut self, node: &'ast Base64) {
+            walk_base64(self, node)
+        }
+        fn visit_citation(&mut self, node: &'ast Citation) {
+            walk_citation(self, node)
+        }
+        fn visit_link(&mut self, node: &'ast Link) {
+            walk_link(self, node)
+        }
+        fn visit_property(&mut self, node: &'ast Property) {
+            walk_property(self, node)
+        }
+        fn visit_document_identifier(&mut self, node: &'ast DocumentIdentifier) {
+            walk_document_identifier(self, node)
+        }
+        fn visit_resource_link(&mut self, node: &'ast ResourceLink) {
+            walk_resource_link(self, node)
+        }
+        fn visit_hash(&mut self, node: &'ast Hash) {
+            walk_hash(self, node)
+        }
+        fn visit_control_implementation(&mut self, node: &'ast ControlImplementation) {
+            walk_control_implementation(self, node)
+        }
+        fn visit_control_based_requirement(
+            &mut self,
+            node: &'ast ControlBasedRequirement,
+        ) {
+            walk_control_based_requirement(self, node)
+        }
+        fn visit_component_control_implementation(
+            &mut self,
+            node: &'ast ComponentControlImplementation,
+        ) {
+            walk_component_control_implementation(self, node)
+        }
+        fn visit_export(&mut self, node: &'ast Export) {
+            walk_export(self, node)
+        }
+        fn visit_provided_control_implementation(
+            &mut self,
+            node: &'ast ProvidedControlImplementation,
+        ) {
+            walk_provided_control_implementation(self, node)
+        }
+        fn visit_responsible_role(&mut self, node: &'ast ResponsibleRole) {
+            walk_responsible_role(self, node)
+        }
+        fn visit_control_implementation_responsibility(
+            &mut self,
+            node: &'ast ControlImplementationResponsibility,
+        ) {
+            walk_control_implementation_responsibility(self, node)
+        }
+        fn visit_implementation_status(&mut self, node: &'ast ImplementationStatus) {
+            walk_implementation_status(self, node)
+        }
+        fn visit_inherited_control_implementation(
+            &mut self,
+            node: &'ast InheritedControlImplementation,
+        ) {
+            walk_inherited_control_implementation(self, node)
+        }
+        fn visit_satisfied_control_implementation_responsibility(
+            &mut self,
+            node: &'ast SatisfiedControlImplementationResponsibility,
+        ) {
+            walk_satisfied_control_implementation_responsibility(self, node)
+        }
+        fn visit_set_parameter_value(&mut self, node: &'ast SetParameterValue) {
+            walk_set_parameter_value(self, node)
+        }
+        fn visit_specific_control_statement(
+            &mut self,
+            node: &'ast SpecificControlStatement,
+        ) {
+            walk_specific_control_statement(self, node)
+        }
+        fn visit_import_profile(&mut self, node: &'ast ImportProfile) {
+            walk_import_profile(self, node)
+        }
+        fn visit_document_metadata(&mut self, node: &'ast DocumentMetadata) {
+            walk_document_metadata(self, node)
+        }
+        fn visit_action(&mut self, node: &'ast Action) {
+            walk_action(self, node)
+        }
+        fn visit_responsible_party(&mut self, node: &'ast ResponsibleParty) {
+            walk_responsible_party(self, node)
+        }
+        fn visit_location(&mut self, node: &'ast Location) {
+            walk_location(self, node)
+        }
+        fn visit_address(&mut self, node: &'ast Address) {
+            walk_address(self, node)
+        }
+        fn visit_telephone_number(&mut self, node: &'ast TelephoneNumber) {
+            walk_telephone_number(self, node)
+        }
+        fn visit_party(&mut self, node: &'ast Party) {
+            walk_party(self, node)
+        }
+        fn visit_party_external_identifier(
+            &mut self,
+            node: &'ast PartyExternalIdentifier,
+        ) {
+            walk_party_external_identifier(self, node)
+        }
+        fn visit_party_type(&mut self, node: &'ast PartyType) {
+            walk_party_type(self, node)
+        }
+        fn visit_revision_history_entry(&mut self, node: &'ast RevisionHistoryEntry) {
+            walk_revision_history_entry(self, node)
+        }
+        fn visit_role(&mut self, node: &'ast Role) {
+            walk_role(self, node)
+        }
+        fn visit_system_characteristics(&mut self, node: &'ast SystemCharacteristics) {
+            walk_system_characteristics(self, node)
+        }
+        fn visit_authorization_boundary(&mut self, node: &'ast AuthorizationBoundary) {
+            walk_authorization_boundary(self, node)
+        }
+        fn visit_diagram(&mut self, node: &'ast Diagram) {
+            walk_diagram(self, node)
+        }
+        fn visit_data_flow(&mut self, node: &'ast DataFlow) {
+            walk_data_flow(self, node)
+        }
+        fn visit_network_architecture(&mut self, node: &'ast NetworkArchitecture) {
+            walk_network_architecture(self, node)
+        }
+        fn visit_security_impact_level(&mut self, node: &'ast SecurityImpactLevel) {
+            walk_security_impact_level(self, node)
+        }
+        fn visit_system_characteristics_status(
+            &mut self,
+            node: &'ast SystemCharacteristicsStatus,
+        ) {
+            walk_system_characteristics_status(self, node)
+        }
+        fn visit_purple_state(&mut self, node: &'ast PurpleState) {
+            walk_purple_state(self, node)
+        }
+        fn visit_system_identification(&mut self, node: &'ast SystemIdentification) {
+            walk_system_identification(self, node)
+        }
+        fn visit_system_information(&mut self, node: &'ast SystemInformation) {
+            walk_system_information(self, node)
+        }
+        fn visit_information_type(&mut self, node: &'ast InformationType) {
+            walk_information_type(self, node)
+        }
+        fn visit_impact_level(&mut self, node: &'ast ImpactLevel) {
+            walk_impact_level(self, node)
+        }
+        fn visit_information_type_categorization(
+            &mut self,
+            node: &'ast InformationTypeCategorization,
+        ) {
+            walk_information_type_categorization(self, node)
+        }
+        fn visit_system_implementation(&mut self, node: &'ast SystemImplementation) {
+            walk_system_implementation(self, node)
+        }
+        fn visit_component(&mut self, node: &'ast Component) {
+            walk_component(self, node)
+        }
+        fn visit_service_protocol_information(
+            &mut self,
+            node: &'ast ServiceProtocolInformation,
+        ) {
+            walk_service_protocol_information(self, node)
+        }
+        fn visit_port_range(&mut self, node: &'ast PortRange) {
+            walk_port_range(self, node)
+        }
+        fn visit_transport(&mut self, node: &'ast Transport) {
+            walk_transport(self, node)
+        }
+        fn visit_component_status(&mut self, node: &'ast ComponentStatus) {
+            walk_component_status(self, node)
+        }
+        fn visit_fluffy_state(&mut self, node: &'ast FluffyState) {
+            walk_fluffy_state(self, node)
+        }
+        fn visit_inventory_item(&mut self, node: &'ast InventoryItem) {
+            walk_inventory_item(self, node)
+        }
+        fn visit_implemented_component(&mut self, node: &'ast ImplementedComponent) {
+            walk_implemented_component(self, node)
+        }
+        fn visit_leveraged_authorization(&mut self, node: &'ast LeveragedAuthorization) {
+            walk_leveraged_authorization(self, node)
+        }
+        fn visit_system_user(&mut self, node: &'ast SystemUser) {
+            walk_system_user(self, node)
+        }
+        fn visit_privilege(&mut self, node: &'ast Privilege) {
+            walk_privilege(self, node)
+        }
+    }
+    pub fn walk_system_security_plan<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SystemSecurityPlan,
+    ) {
+        v.visit_system_security_plan_ssp(&node.system_security_plan);
+    }
+    pub fn walk_system_security_plan_ssp<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SystemSecurityPlanSsp,
+    ) {
+        if let Some(it) = &node.back_matter {
+            v.visit_back_matter(it);
+        }
+        v.visit_control_implementation(&node.control_implementation);
+        v.visit_import_profile(&node.import_profile);
+        v.visit_document_metadata(&node.metadata);
+        v.visit_system_characteristics(&node.system_characteristics);
+        v.visit_system_implementation(&node.system_implementation);
+    }
+    pub fn walk_back_matter<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast BackMatter,
+    ) {
+        if let Some(it) = &node.resources {
+            for it in it {
+                v.visit_resource(it);
+            }
+        }
+    }
+    pub fn walk_resource<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Resource,
+    ) {
+        if let Some(it) = &node.base64 {
+            v.visit_base64(it);
+        }
+        if let Some(it) = &node.citation {
+            v.visit_citation(it);
+        }
+        if let Some(it) = &node.document_ids {
+            for it in it {
+                v.visit_document_identifier(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.rlinks {
+            for it in it {
+                v.visit_resource_link(it);
+            }
+        }
+    }
+    pub fn walk_base64<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Base64) {}
+    pub fn walk_citation<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Citation,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_link<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Link) {}
+    pub fn walk_property<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Property,
+    ) {}
+    pub fn walk_document_identifier<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast DocumentIdentifier,
+    ) {}
+    pub fn walk_resource_link<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ResourceLink,
+    ) {
+        if let Some(it) = &node.hashes {
+            for it in it {
+                v.visit_hash(it);
+            }
+        }
+    }
+    pub fn walk_hash<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Hash) {}
+    pub fn walk_control_implementation<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ControlImplementation,
+    ) {
+        for it in &node.implemented_requirements {
+            v.visit_control_based_requirement(it);
+        }
+        if let Some(it) = &node.set_parameters {
+            for it in it {
+                v.visit_set_parameter_value(it);
+            }
+        }
+    }
+    pub fn walk_control_based_requirement<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ControlBasedRequirement,
+    ) {
+        if let Some(it) = &node.by_components {
+            for it in it {
+                v.visit_component_control_implementation(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+        if let Some(it) = &node.set_parameters {
+            for it in it {
+                v.visit_set_parameter_value(it);
+            }
+        }
+        if let Some(it) = &node.statements {
+            for it in it {
+                v.visit_specific_control_statement(it);
+            }
+        }
+    }
+    pub fn walk_component_control_implementation<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ComponentControlImplementation,
+    ) {
+        if let Some(it) = &node.export {
+            v.visit_export(it);
+        }
+        if let Some(it) = &node.implementation_status {
+            v.visit_implementation_status(it);
+        }
+        if let Some(it) = &node.inherited {
+            for it in it {
+                v.visit_inherited_control_implementation(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+        if let Some(it) = &node.satisfied {
+            for it in it {
+                v.visit_satisfied_control_implementation_responsibility(it);
+            }
+        }
+        if let Some(it) = &node.set_parameters {
+            for it in it {
+                v.visit_set_parameter_value(it);
+            }
+        }
+    }
+    pub fn walk_export<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Export) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.provided {
+            for it in it {
+                v.visit_provided_control_implementation(it);
+            }
+        }
+        if let Some(it) = &node.responsibilities {
+            for it in it {
+                v.visit_control_implementation_responsibility(it);
+            }
+        }
+    }
+    pub fn walk_provided_control_implementation<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ProvidedControlImplementation,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+    }
+    pub fn walk_responsible_role<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ResponsibleRole,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_control_implementation_responsibility<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ControlImplementationResponsibility,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+    }
+    pub fn walk_implementation_status<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast ImplementationStatus,
+    ) {}
+    pub fn walk_inherited_control_implementation<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast InheritedControlImplementation,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+    }
+    pub fn walk_satisfied_control_implementation_responsibility<
+        'ast,
+        V: Visit<'ast> + ?Sized,
+    >(v: &mut V, node: &'ast SatisfiedControlImplementationResponsibility) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+    }
+    pub fn walk_set_parameter_value<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast SetParameterValue,
+    ) {}
+    pub fn walk_specific_control_statement<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SpecificControlStatement,
+    ) {
+        if let Some(it) = &node.by_components {
+            for it in it {
+                v.visit_component_control_implementation(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+    }
+    pub fn walk_import_profile<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast ImportProfile,
+    ) {}
+    pub fn walk_document_metadata<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast DocumentMetadata,
+    ) {
+        if let Some(it) = &node.actions {
+            for it in it {
+                v.visit_action(it);
+            }
+        }
+        if let Some(it) = &node.document_ids {
+            for it in it {
+                v.visit_document_identifier(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.locations {
+            for it in it {
+                v.visit_location(it);
+            }
+        }
+        if let Some(it) = &node.parties {
+            for it in it {
+                v.visit_party(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+        if let Some(it) = &node.revisions {
+            for it in it {
+                v.visit_revision_history_entry(it);
+            }
+        }
+        if let Some(it) = &node.roles {
+            for it in it {
+                v.visit_role(it);
+            }
+        }
+    }
+    pub fn walk_action<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Action) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+    }
+    pub fn walk_responsible_party<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ResponsibleParty,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_location<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Location,
+    ) {
+        if let Some(it) = &node.address {
+            v.visit_address(it);
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number(it);
+            }
+        }
+    }
+    pub fn walk_address<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Address,
+    ) {}
+    pub fn walk_telephone_number<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast TelephoneNumber,
+    ) {}
+    pub fn walk_party<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Party) {
+        if let Some(it) = &node.addresses {
+            for it in it {
+                v.visit_address(it);
+            }
+        }
+        if let Some(it) = &node.external_ids {
+            for it in it {
+                v.visit_party_external_identifier(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number(it);
+            }
+        }
+        v.visit_party_type(&node.party_type);
+    }
+    pub fn walk_party_external_identifier<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast PartyExternalIdentifier,
+    ) {}
+    pub fn walk_party_type<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast PartyType,
+    ) {}
+    pub fn walk_revision_history_entry<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast RevisionHistoryEntry,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_role<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Role) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_system_characteristics<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SystemCharacteristics,
+    ) {
+        v.visit_authorization_boundary(&node.authorization_boundary);
+        if let Some(it) = &node.data_flow {
+            v.visit_data_flow(it);
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.network_architecture {
+            v.visit_network_architecture(it);
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+        if let Some(it) = &node.security_impact_level {
+            v.visit_security_impact_level(it);
+        }
+        v.visit_system_characteristics_status(&node.status);
+        for it in &node.system_ids {
+            v.visit_system_identification(it);
+        }
+        v.visit_system_information(&node.system_information);
+    }
+    pub fn walk_authorization_boundary<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast AuthorizationBoundary,
+    ) {
+        if let Some(it) = &node.diagrams {
+            for it in it {
+                v.visit_diagram(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_diagram<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Diagram) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_data_flow<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast DataFlow,
+    ) {
+        if let Some(it) = &node.diagrams {
+            for it in it {
+                v.visit_diagram(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_network_architecture<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast NetworkArchitecture,
+    ) {
+        if let Some(it) = &node.diagrams {
+            for it in it {
+                v.visit_diagram(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_security_impact_level<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast SecurityImpactLevel,
+    ) {}
+    pub fn walk_system_characteristics_status<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SystemCharacteristicsStatus,
+    ) {
+        v.visit_purple_state(&node.state);
+    }
+    pub fn walk_purple_state<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast PurpleState,
+    ) {}
+    pub fn walk_system_identification<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast SystemIdentification,
+    ) {}
+    pub fn walk_system_information<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SystemInformation,
+    ) {
+        for it in &node.information_types {
+            v.visit_information_type(it);
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_information_type<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast InformationType,
+    ) {
+        if let Some(it) = &node.availability_impact {
+            v.visit_impact_level(it);
+        }
+        if let Some(it) = &node.categorizations {
+            for it in it {
+                v.visit_information_type_categorization(it);
+            }
+        }
+        if let Some(it) = &node.confidentiality_impact {
+            v.visit_impact_level(it);
+        }
+        if let Some(it) = &node.integrity_impact {
+            v.visit_impact_level(it);
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_impact_level<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ImpactLevel,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_information_type_categorization<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast InformationTypeCategorization,
+    ) {}
+    pub fn walk_system_implementation<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SystemImplementation,
+    ) {
+        for it in &node.components {
+            v.visit_component(it);
+        }
+        if let Some(it) = &node.inventory_items {
+            for it in it {
+                v.visit_inventory_item(it);
+            }
+        }
+        if let Some(it) = &node.leveraged_authorizations {
+            for it in it {
+                v.visit_leveraged_authorization(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        for it in &node.users {
+            v.visit_system_user(it);
+        }
+    }
+    pub fn walk_component<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast Component,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.protocols {
+            for it in it {
+                v.visit_service_protocol_information(it);
+            }
+        }
+        if let Some(it) = &node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role(it);
+            }
+        }
+        v.visit_component_status(&node.status);
+    }
+    pub fn walk_service_protocol_information<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ServiceProtocolInformation,
+    ) {
+        if let Some(it) = &node.port_ranges {
+            for it in it {
+                v.visit_port_range(it);
+            }
+        }
+    }
+    pub fn walk_port_range<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast PortRange,
+    ) {
+        if let Some(it) = &node.transport {
+            v.visit_transport(it);
+        }
+    }
+    pub fn walk_transport<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Transport,
+    ) {}
+    pub fn walk_component_status<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ComponentStatus,
+    ) {
+        v.visit_fluffy_state(&node.state);
+    }
+    pub fn walk_fluffy_state<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast FluffyState,
+    ) {}
+    pub fn walk_inventory_item<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast InventoryItem,
+    ) {
+        if let Some(it) = &node.implemented_components {
+            for it in it {
+                v.visit_implemented_component(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+    }
+    pub fn walk_implemented_component<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast ImplementedComponent,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+        if let Some(it) = &node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party(it);
+            }
+        }
+    }
+    pub fn walk_leveraged_authorization<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast LeveragedAuthorization,
+    ) {
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_system_user<'ast, V: Visit<'ast> + ?Sized>(
+        v: &mut V,
+        node: &'ast SystemUser,
+    ) {
+        if let Some(it) = &node.authorized_privileges {
+            for it in it {
+                v.visit_privilege(it);
+            }
+        }
+        if let Some(it) = &node.links {
+            for it in it {
+                v.visit_link(it);
+            }
+        }
+        if let Some(it) = &node.props {
+            for it in it {
+                v.visit_property(it);
+            }
+        }
+    }
+    pub fn walk_privilege<'ast, V: Visit<'ast> + ?Sized>(
+        _v: &mut V,
+        _node: &'ast Privilege,
+    ) {}
+}
+/// In place traversal of the model
+///
+/// Mutable counterpart of [`visit`](super::visit).
+pub mod visit_mut {
+    use super::*;
+    pub trait VisitMut {
+        fn visit_system_security_plan_mut(&mut self, node: &mut SystemSecurityPlan) {
+            walk_system_security_plan_mut(self, node)
+        }
+        fn visit_system_security_plan_ssp_mut(
+            &mut self,
+            node: &mut SystemSecurityPlanSsp,
+        ) {
+            walk_system_security_plan_ssp_mut(self, node)
+        }
+        fn visit_back_matter_mut(&mut self, node: &mut BackMatter) {
+            walk_back_matter_mut(self, node)
+        }
+        fn visit_resource_mut(&mut self, node: &mut Resource) {
+            walk_resource_mut(self, node)
+        }
+        fn visit_base64_mut(&mut self, node: &mut Base64) {
+            walk_base64_mut(self, node)
+        }
+        fn visit_citation_mut(&mut self, node: &mut Citation) {
+            walk_citation_mut(self, node)
+        }
+        fn visit_link_mut(&mut self, node: &mut Link) {
+            walk_link_mut(self, node)
+        }
+        fn visit_property_mut(&mut self, node: &mut Property) {
+            walk_property_mut(self, node)
+        }
+        fn visit_document_identifier_mut(&mut self, node: &mut DocumentIdentifier) {
+            walk_document_identifier_mut(self, node)
+        }
+        fn visit_resource_link_mut(&mut self, node: &mut ResourceLink) {
+            walk_resource_link_mut(self, node)
+        }
+        fn visit_hash_mut(&mut self, node: &mut Hash) {
+            walk_hash_mut(self, node)
+        }
+        fn visit_control_implementation_mut(
+            &mut self,
+            node: &mut ControlImplementation,
+        ) {
+            walk_control_implementation_mut(self, node)
+        }
+        fn visit_control_based_requirement_mut(
+            &mut self,
+            node: &mut ControlBasedRequirement,
+        ) {
+            walk_control_based_requirement_mut(self, node)
+        }
+        fn visit_component_control_implementation_mut(
+            &mut self,
+            node: &mut ComponentControlImplementation,
+        ) {
+            walk_component_control_implementation_mut(self, node)
+        }
+        fn visit_export_mut(&mut self, node: &mut Export) {
+            walk_export_mut(self, node)
+        }
+        fn visit_provided_control_implementation_mut(
+            &mut self,
+            node: &mut ProvidedControlImplementation,
+        ) {
+            walk_provided_control_implementation_mut(self, node)
+        }
+        fn visit_responsible_role_mut(&mut self, node: &mut ResponsibleRole) {
+            walk_responsible_role_mut(self, node)
+        }
+        fn visit_control_implementation_responsibility_mut(
+            &mut self,
+            node: &mut ControlImplementationResponsibility,
+        ) {
+            walk_control_implementation_responsibility_mut(self, node)
+        }
+        fn visit_implementation_status_mut(&mut self, node: &mut ImplementationStatus) {
+            walk_implementation_status_mut(self, node)
+        }
+        fn visit_inherited_control_implementation_mut(
+            &mut self,
+            node: &mut InheritedControlImplementation,
+        ) {
+            walk_inherited_control_implementation_mut(self, node)
+        }
+        fn visit_satisfied_control_implementation_responsibility_mut(
+            &mut self,
+            node: &mut SatisfiedControlImplementationResponsibility,
+        ) {
+            walk_satisfied_control_implementation_responsibility_mut(self, node)
+        }
+        fn visit_set_parameter_value_mut(&mut self, node: &mut SetParameterValue) {
+            walk_set_parameter_value_mut(self, node)
+        }
+        fn visit_specific_control_statement_mut(
+            &mut self,
+            node: &mut SpecificControlStatement,
+        ) {
+            walk_specific_control_statement_mut(self, node)
+        }
+        fn visit_import_profile_mut(&mut self, node: &mut ImportProfile) {
+            walk_import_profile_mut(self, node)
+        }
+        fn visit_document_metadata_mut(&mut self, node: &mut DocumentMetadata) {
+            walk_document_metadata_mut(self, node)
+        }
+        fn visit_action_mut(&mut self, node: &mut Action) {
+            walk_action_mut(self, node)
+        }
+        fn visit_responsible_party_mut(&mut self, node: &mut ResponsibleParty) {
+            walk_responsible_party_mut(self, node)
+        }
+        fn visit_location_mut(&mut self, node: &mut Location) {
+            walk_location_mut(self, node)
+        }
+        fn visit_address_mut(&mut self, node: &mut Address) {
+            walk_address_mut(self, node)
+        }
+        fn visit_telephone_number_mut(&mut self, node: &mut TelephoneNumber) {
+            walk_telephone_number_mut(self, node)
+        }
+        fn visit_party_mut(&mut self, node: &mut Party) {
+            walk_party_mut(self, node)
+        }
+        fn visit_party_external_identifier_mut(
+            &mut self,
+            node: &mut PartyExternalIdentifier,
+        ) {
+            walk_party_external_identifier_mut(self, node)
+        }
+        fn visit_party_type_mut(&mut self, node: &mut PartyType) {
+            walk_party_type_mut(self, node)
+        }
+        fn visit_revision_history_entry_mut(&mut self, node: &mut RevisionHistoryEntry) {
+            walk_revision_history_entry_mut(self, node)
+        }
+        fn visit_role_mut(&mut self, node: &mut Role) {
+            walk_role_mut(self, node)
+        }
+        fn visit_system_characteristics_mut(
+            &mut self,
+            node: &mut SystemCharacteristics,
+        ) {
+            walk_system_characteristics_mut(self, node)
+        }
+        fn visit_authorization_boundary_mut(
+            &mut self,
+            node: &mut AuthorizationBoundary,
+        ) {
+            walk_authorization_boundary_mut(self, node)
+        }
+        fn visit_diagram_mut(&mut self, node: &mut Diagram) {
+            walk_diagram_mut(self, node)
+        }
+        fn visit_data_flow_mut(&mut self, node: &mut DataFlow) {
+            walk_data_flow_mut(self, node)
+        }
+        fn visit_network_architecture_mut(&mut self, node: &mut NetworkArchitecture) {
+            walk_network_architecture_mut(self, node)
+        }
+        fn visit_security_impact_level_mut(&mut self, node: &mut SecurityImpactLevel) {
+            walk_security_impact_level_mut(self, node)
+        }
+        fn visit_system_characteristics_status_mut(
+            &mut self,
+            node: &mut SystemCharacteristicsStatus,
+        ) {
+            walk_system_characteristics_status_mut(self, node)
+        }
+        fn visit_purple_state_mut(&mut self, node: &mut PurpleState) {
+            walk_purple_state_mut(self, node)
+        }
+        fn visit_system_identification_mut(&mut self, node: &mut SystemIdentification) {
+            walk_system_identification_mut(self, node)
+        }
+        fn visit_system_information_mut(&mut self, node: &mut SystemInformation) {
+            walk_system_information_mut(self, node)
+        }
+        fn visit_information_type_mut(&mut self, node: &mut InformationType) {
+            walk_information_type_mut(self, node)
+        }
+        fn visit_impact_level_mut(&mut self, node: &mut ImpactLevel) {
+            walk_impact_level_mut(self, node)
+        }
+        fn visit_information_type_categorization_mut(
+            &mut self,
+            node: &mut InformationTypeCategorization,
+        ) {
+            walk_information_type_categorization_mut(self, node)
+        }
+        fn visit_system_implementation_mut(&mut self, node: &mut SystemImplementation) {
+            walk_system_implementation_mut(self, node)
+        }
+        fn visit_component_mut(&mut self, node: &mut Component) {
+            walk_component_mut(self, node)
+        }
+        fn visit_service_protocol_information_mut(
+            &mut self,
+            node: &mut ServiceProtocolInformation,
+        ) {
+            walk_service_protocol_information_mut(self, node)
+        }
+        fn visit_port_range_mut(&mut self, node: &mut PortRange) {
+            walk_port_range_mut(self, node)
+        }
+        fn visit_transport_mut(&mut self, node: &mut Transport) {
+            walk_transport_mut(self, node)
+        }
+        fn visit_component_status_mut(&mut self, node: &mut ComponentStatus) {
+            walk_component_status_mut(self, node)
+        }
+        fn visit_fluffy_state_mut(&mut self, node: &mut FluffyState) {
+            walk_fluffy_state_mut(self, node)
+        }
+        fn visit_inventory_item_mut(&mut self, node: &mut InventoryItem) {
+            walk_inventory_item_mut(self, node)
+        }
+        fn visit_implemented_component_mut(&mut self, node: &mut ImplementedComponent) {
+            walk_implemented_component_mut(self, node)
+        }
+        fn visit_leveraged_authorization_mut(
+            &mut self,
+            node: &mut LeveragedAuthorization,
+        ) {
+            walk_leveraged_authorization_mut(self, node)
+        }
+        fn visit_system_user_mut(&mut self, node: &mut SystemUser) {
+            walk_system_user_mut(self, node)
+        }
+        fn visit_privilege_mut(&mut self, node: &mut Privilege) {
+            walk_privilege_mut(self, node)
+        }
+    }
+    pub fn walk_system_security_plan_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut SystemSecurityPlan,
+    ) {
+        v.visit_system_security_plan_ssp_mut(&mut node.system_security_plan);
+    }
+    pub fn walk_system_security_plan_ssp_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut SystemSecurityPlanSsp,
+    ) {
+        if let Some(it) = &mut node.back_matter {
+            v.visit_back_matter_mut(it);
+        }
+        v.visit_control_implementation_mut(&mut node.control_implementation);
+        v.visit_import_profile_mut(&mut node.import_profile);
+        v.visit_document_metadata_mut(&mut node.metadata);
+        v.visit_system_characteristics_mut(&mut node.system_characteristics);
+        v.visit_system_implementation_mut(&mut node.system_implementation);
+    }
+    pub fn walk_back_matter_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut BackMatter) {
+        if let Some(it) = &mut node.resources {
+            for it in it {
+                v.visit_resource_mut(it);
+            }
+        }
+    }
+    pub fn walk_resource_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Resource) {
+        if let Some(it) = &mut node.base64 {
+            v.visit_base64_mut(it);
+        }
+        if let Some(it) = &mut node.citation {
+            v.visit_citation_mut(it);
+        }
+        if let Some(it) = &mut node.document_ids {
+            for it in it {
+                v.visit_document_identifier_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.rlinks {
+            for it in it {
+                v.visit_resource_link_mut(it);
+            }
+        }
+    }
+    pub fn walk_base64_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Base64) {}
+    pub fn walk_citation_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Citation) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_link_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Link) {}
+    pub fn walk_property_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Property) {}
+    pub fn walk_document_identifier_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut DocumentIdentifier,
+    ) {}
+    pub fn walk_resource_link_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ResourceLink,
+    ) {
+        if let Some(it) = &mut node.hashes {
+            for it in it {
+                v.visit_hash_mut(it);
+            }
+        }
+    }
+    pub fn walk_hash_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Hash) {}
+    pub fn walk_control_implementation_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ControlImplementation,
+    ) {
+        for it in &mut node.implemented_requirements {
+            v.visit_control_based_requirement_mut(it);
+        }
+        if let Some(it) = &mut node.set_parameters {
+            for it in it {
+                v.visit_set_parameter_value_mut(it);
+            }
+        }
+    }
+    pub fn walk_control_based_requirement_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ControlBasedRequirement,
+    ) {
+        if let Some(it) = &mut node.by_components {
+            for it in it {
+                v.visit_component_control_implementation_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.set_parameters {
+            for it in it {
+                v.visit_set_parameter_value_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.statements {
+            for it in it {
+                v.visit_specific_control_statement_mut(it);
+            }
+        }
+    }
+    pub fn walk_component_control_implementation_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ComponentControlImplementation,
+    ) {
+        if let Some(it) = &mut node.export {
+            v.visit_export_mut(it);
+        }
+        if let Some(it) = &mut node.implementation_status {
+            v.visit_implementation_status_mut(it);
+        }
+        if let Some(it) = &mut node.inherited {
+            for it in it {
+                v.visit_inherited_control_implementation_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.satisfied {
+            for it in it {
+                v.visit_satisfied_control_implementation_responsibility_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.set_parameters {
+            for it in it {
+                v.visit_set_parameter_value_mut(it);
+            }
+        }
+    }
+    pub fn walk_export_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Export) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.provided {
+            for it in it {
+                v.visit_provided_control_implementation_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsibilities {
+            for it in it {
+                v.visit_control_implementation_responsibility_mut(it);
+            }
+        }
+    }
+    pub fn walk_provided_control_implementation_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ProvidedControlImplementation,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+    }
+    pub fn walk_responsible_role_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ResponsibleRole,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_control_implementation_responsibility_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ControlImplementationResponsibility,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+    }
+    pub fn walk_implementation_status_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut ImplementationStatus,
+    ) {}
+    pub fn walk_inherited_control_implementation_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut InheritedControlImplementation,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+    }
+    pub fn walk_satisfied_control_implementation_responsibility_mut<
+        V: VisitMut + ?Sized,
+    >(v: &mut V, node: &mut SatisfiedControlImplementationResponsibility) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+    }
+    pub fn walk_set_parameter_value_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut SetParameterValue,
+    ) {}
+    pub fn walk_specific_control_statement_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut SpecificControlStatement,
+    ) {
+        if let Some(it) = &mut node.by_components {
+            for it in it {
+                v.visit_component_control_implementation_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+    }
+    pub fn walk_import_profile_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut ImportProfile,
+    ) {}
+    pub fn walk_document_metadata_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut DocumentMetadata,
+    ) {
+        if let Some(it) = &mut node.actions {
+            for it in it {
+                v.visit_action_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.document_ids {
+            for it in it {
+                v.visit_document_identifier_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.locations {
+            for it in it {
+                v.visit_location_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.parties {
+            for it in it {
+                v.visit_party_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.revisions {
+            for it in it {
+                v.visit_revision_history_entry_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.roles {
+            for it in it {
+                v.visit_role_mut(it);
+            }
+        }
+    }
+    pub fn walk_action_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Action) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+    }
+    pub fn walk_responsible_party_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ResponsibleParty,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_location_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Location) {
+        if let Some(it) = &mut node.address {
+            v.visit_address_mut(it);
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number_mut(it);
+            }
+        }
+    }
+    pub fn walk_address_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Address) {}
+    pub fn walk_telephone_number_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut TelephoneNumber,
+    ) {}
+    pub fn walk_party_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Party) {
+        if let Some(it) = &mut node.addresses {
+            for it in it {
+                v.visit_address_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.external_ids {
+            for it in it {
+                v.visit_party_external_identifier_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.telephone_numbers {
+            for it in it {
+                v.visit_telephone_number_mut(it);
+            }
+        }
+        v.visit_party_type_mut(&mut node.party_type);
+    }
+    pub fn walk_party_external_identifier_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut PartyExternalIdentifier,
+    ) {}
+    pub fn walk_party_type_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut PartyType) {}
+    pub fn walk_revision_history_entry_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut RevisionHistoryEntry,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_role_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Role) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_system_characteristics_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut SystemCharacteristics,
+    ) {
+        v.visit_authorization_boundary_mut(&mut node.authorization_boundary);
+        if let Some(it) = &mut node.data_flow {
+            v.visit_data_flow_mut(it);
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.network_architecture {
+            v.visit_network_architecture_mut(it);
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.security_impact_level {
+            v.visit_security_impact_level_mut(it);
+        }
+        v.visit_system_characteristics_status_mut(&mut node.status);
+        for it in &mut node.system_ids {
+            v.visit_system_identification_mut(it);
+        }
+        v.visit_system_information_mut(&mut node.system_information);
+    }
+    pub fn walk_authorization_boundary_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut AuthorizationBoundary,
+    ) {
+        if let Some(it) = &mut node.diagrams {
+            for it in it {
+                v.visit_diagram_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_diagram_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Diagram) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_data_flow_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DataFlow) {
+        if let Some(it) = &mut node.diagrams {
+            for it in it {
+                v.visit_diagram_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_network_architecture_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut NetworkArchitecture,
+    ) {
+        if let Some(it) = &mut node.diagrams {
+            for it in it {
+                v.visit_diagram_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_security_impact_level_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut SecurityImpactLevel,
+    ) {}
+    pub fn walk_system_characteristics_status_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut SystemCharacteristicsStatus,
+    ) {
+        v.visit_purple_state_mut(&mut node.state);
+    }
+    pub fn walk_purple_state_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut PurpleState,
+    ) {}
+    pub fn walk_system_identification_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut SystemIdentification,
+    ) {}
+    pub fn walk_system_information_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut SystemInformation,
+    ) {
+        for it in &mut node.information_types {
+            v.visit_information_type_mut(it);
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_information_type_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut InformationType,
+    ) {
+        if let Some(it) = &mut node.availability_impact {
+            v.visit_impact_level_mut(it);
+        }
+        if let Some(it) = &mut node.categorizations {
+            for it in it {
+                v.visit_information_type_categorization_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.confidentiality_impact {
+            v.visit_impact_level_mut(it);
+        }
+        if let Some(it) = &mut node.integrity_impact {
+            v.visit_impact_level_mut(it);
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_impact_level_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ImpactLevel,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_information_type_categorization_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut InformationTypeCategorization,
+    ) {}
+    pub fn walk_system_implementation_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut SystemImplementation,
+    ) {
+        for it in &mut node.components {
+            v.visit_component_mut(it);
+        }
+        if let Some(it) = &mut node.inventory_items {
+            for it in it {
+                v.visit_inventory_item_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.leveraged_authorizations {
+            for it in it {
+                v.visit_leveraged_authorization_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        for it in &mut node.users {
+            v.visit_system_user_mut(it);
+        }
+    }
+    pub fn walk_component_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Component) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.protocols {
+            for it in it {
+                v.visit_service_protocol_information_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_roles {
+            for it in it {
+                v.visit_responsible_role_mut(it);
+            }
+        }
+        v.visit_component_status_mut(&mut node.status);
+    }
+    pub fn walk_service_protocol_information_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ServiceProtocolInformation,
+    ) {
+        if let Some(it) = &mut node.port_ranges {
+            for it in it {
+                v.visit_port_range_mut(it);
+            }
+        }
+    }
+    pub fn walk_port_range_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PortRange) {
+        if let Some(it) = &mut node.transport {
+            v.visit_transport_mut(it);
+        }
+    }
+    pub fn walk_transport_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Transport) {}
+    pub fn walk_component_status_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ComponentStatus,
+    ) {
+        v.visit_fluffy_state_mut(&mut node.state);
+    }
+    pub fn walk_fluffy_state_mut<V: VisitMut + ?Sized>(
+        _v: &mut V,
+        _node: &mut FluffyState,
+    ) {}
+    pub fn walk_inventory_item_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut InventoryItem,
+    ) {
+        if let Some(it) = &mut node.implemented_components {
+            for it in it {
+                v.visit_implemented_component_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+    }
+    pub fn walk_implemented_component_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut ImplementedComponent,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.responsible_parties {
+            for it in it {
+                v.visit_responsible_party_mut(it);
+            }
+        }
+    }
+    pub fn walk_leveraged_authorization_mut<V: VisitMut + ?Sized>(
+        v: &mut V,
+        node: &mut LeveragedAuthorization,
+    ) {
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_system_user_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SystemUser) {
+        if let Some(it) = &mut node.authorized_privileges {
+            for it in it {
+                v.visit_privilege_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.links {
+            for it in it {
+                v.visit_link_mut(it);
+            }
+        }
+        if let Some(it) = &mut node.props {
+            for it in it {
+                v.visit_property_mut(it);
+            }
+        }
+    }
+    pub fn walk_privilege_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Privilege) {}
 }
@@ -0,0 +1,339 @@
+use crate::{
+    control::catalog::{Catalog, Control, ControlGroup},
+    implementation::{
+        component_definition::{self, ComponentDefinition},
+        ssp::{
+            Component, ComponentControlImplementation, ComponentStatus,
+            ControlBasedRequirement, ControlImplementation, FluffyState, Link,
+            PortRange, Property, ResponsibleRole, ServiceProtocolInformation,
+            SetParameterValue, SpecificControlStatement, Transport,
+        },
+    },
+    markup::Markup,
+    parse::Extensions,
+    scalar,
+};
+
+/// Parts of an SSP generated from a resolved profile catalog and component
+/// definitions
+#[derive(Debug, Clone)]
+pub struct GeneratedImplementation {
+    /// System components to list in the system implementation, one for each
+    /// defined component and keeping its uuid
+    pub components: Vec<Component>,
+    pub control_implementation: ControlImplementation,
+}
+
+/// Generate an SSP control implementation from a resolved profile catalog and
+/// the component definitions describing how each component satisfies its controls.
+///
+/// One implemented requirement is produced for every control selected by the
+/// catalog, in catalog order, including those no component covers. Each
+/// `by-components` entry references one of the generated system components,
+/// which are built from the defined components and keep their uuid, as it
+/// identifies the same component across documents.
+pub fn gen_control_implementation(
+    catalog: &Catalog,
+    component_definitions: &[ComponentDefinition],
+    description: impl Into<Markup>,
+) -> GeneratedImplementation {
+    let mut control_ids = vec![];
+    collect_catalog_control_ids(catalog, &mut control_ids);
+
+    let implemented_requirements = control_ids
+        .iter()
+        .map(|control_id| gen_requirement(control_id, component_definitions))
+        .collect();
+
+    GeneratedImplementation {
+        components: gen_components(component_definitions),
+        control_implementation: ControlImplementation {
+            description: description.into(),
+            implemented_requirements,
+            set_parameters: None,
+            extensions: Extensions::new(),
+        },
+    }
+}
+
+/// System components of the defined components, once each. A component
+/// definition has no status, so they are taken as operational
+fn gen_components(
+    component_definitions: &[ComponentDefinition],
+) -> Vec<Component> {
+    let mut components: Vec<Component> = vec![];
+
+    for component in component_definitions
+        .iter()
+        .filter_map(|c| c.component_definition.components.as_ref())
+        .flatten()
+    {
+        if components.iter().any(|c| c.uuid == component.uuid) {
+            continue;
+        }
+
+        components.push(Component {
+            description: component.description.clone(),
+            links: conv_links(&component.links),
+            props: conv_props(&component.props),
+            protocols: conv_protocols(&component.protocols),
+            purpose: component.purpose.clone(),
+            remarks: component.remarks.clone(),
+            responsible_roles: conv_roles(&component.responsible_roles),
+            status: ComponentStatus {
+                remarks: None,
+                state: FluffyState::Operational,
+                extensions: Extensions::new(),
+            },
+            title: component.title.clone(),
+            component_type: component.component_type.clone(),
+            uuid: component.uuid.clone(),
+            extensions: component.extensions.clone(),
+        });
+    }
+
+    components
+}
+
+fn collect_catalog_control_ids(catalog: &Catalog, ids: &mut Vec<String>) {
+    if let Some(controls) = &catalog.catalog.controls {
+        controls.iter().for_each(|c| collect_control_ids(c, ids));
+    }
+
+    if let Some(groups) = &catalog.catalog.groups {
+        groups
+            .iter()
+            .for_each(|g| collect_group_control_ids(g, ids));
+    }
+}
+
+fn collect_group_control_ids(group: &ControlGroup, ids: &mut Vec<String>) {
+    if let Some(controls) = &group.controls {
+        controls.iter().for_each(|c| collect_control_ids(c, ids));
+    }
+
+    if let Some(groups) = &group.groups {
+        groups
+            .iter()
+            .for_each(|g| collect_group_control_ids(g, ids));
+    }
+}
+
+fn collect_control_ids(control: &Control, ids: &mut Vec<String>) {
+    ids.push(control.id.to_string());
+
+    if let Some(controls) = &control.controls {
+        controls.iter().for_each(|c| collect_control_ids(c, ids));
+    }
+}
+
+fn gen_requirement(
+    control_id: &str,
+    component_definitions: &[ComponentDefinition],
+) -> ControlBasedRequirement {
+    let mut by_components = vec![];
+    let mut statements: Vec<SpecificControlStatement> = vec![];
+
+    for component in component_definitions
+        .iter()
+        .filter_map(|c| c.component_definition.components.as_ref())
+        .flatten()
+    {
+        for set in component.control_implementations.iter().flatten() {
+            for req in set
+                .implemented_requirements
+                .iter()
+                .filter(|r| r.control_id == control_id)
+            {
+                by_components.push(ComponentControlImplementation {
+                    component_uuid: component.uuid.clone(),
+                    description: req.description.clone(),
+                    export: None,
+                    implementation_status: None,
+                    inherited: None,
+                    links: conv_links(&req.links),
+                    props: conv_props(&req.props),
+                    remarks: req.remarks.clone(),
+                    responsible_roles: conv_roles(&req.responsible_roles),
+                    satisfied: None,
+                    set_parameters: merge_set_params(
+                        &set.set_parameters,
+                        &req.set_parameters,
+                    ),
+                    uuid: scalar::new_uuid_v4(),
+                    extensions: Extensions::new(),
+                });
+
+                for stmt in req.statements.iter().flatten() {
+                    let by_component = ComponentControlImplementation {
+                        component_uuid: component.uuid.clone(),
+                        description: stmt.description.to_string(),
+                        export: None,
+                        implementation_status: None,
+                        inherited: None,
+                        links: conv_links(&stmt.links),
+                        props: conv_props(&stmt.props),
+                        remarks: stmt.remarks.clone(),
+                        responsible_roles: conv_roles(&stmt.responsible_roles),
+                        satisfied: None,
+                        set_parameters: None,
+                        uuid: scalar::new_uuid_v4(),
+                        extensions: Extensions::new(),
+                    };
+
+                    if let Some(existing) = statements
+                        .iter_mut()
+                        .find(|s| s.statement_id == stmt.statement_id)
+                    {
+                        existing
+                            .by_components
+                            .get_or_insert_with(Vec::new)
+                            .push(by_component);
+                    } else {
+                        statements.push(SpecificControlStatement {
+                            by_components: Some(vec![by_component]),
+                            links: None,
+                            props: None,
+                            remarks: None,
+                            responsible_roles: None,
+                            statement_id: stmt.statement_id.clone(),
+                            uuid: scalar::new_uuid_v4(),
+                            extensions: Extensions::new(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    ControlBasedRequirement {
+        by_components: (!by_components.is_empty()).then_some(by_components),
+        control_id: control_id.to_owned(),
+        links: None,
+        props: None,
+        remarks: None,
+        responsible_roles: None,
+        set_parameters: None,
+        statements: (!statements.is_empty()).then_some(statements),
+        uuid: scalar::new_uuid_v4(),
+        extensions: Extensions::new(),
+    }
+}
+
+/// Requirement level parameter values take precedence over the ones set on
+/// the enclosing control implementation set.
+fn merge_set_params(
+    set_level: &Option<Vec<component_definition::SetParameterValue>>,
+    req_level: &Option<Vec<component_definition::SetParameterValue>>,
+) -> Option<Vec<SetParameterValue>> {
+    let mut params: Vec<SetParameterValue> = vec![];
+
+    for param in set_level.iter().flatten().chain(req_level.iter().flatten()) {
+        let param = SetParameterValue {
+            param_id: param.param_id.clone(),
+            remarks: param.remarks.clone(),
+            values: param.values.clone(),
+            extensions: param.extensions.clone(),
+        };
+
+        if let Some(existing) =
+            params.iter_mut().find(|p| p.param_id == param.param_id)
+        {
+            *existing = param;
+        } else {
+            params.push(param);
+        }
+    }
+
+    (!params.is_empty()).then_some(params)
+}
+
+fn conv_links(
+    links: &Option<Vec<component_definition::Link>>,
+) -> Option<Vec<Link>> {
+    links.as_ref().map(|links| {
+        links
+            .iter()
+            .map(|l| Link {
+                href: l.href.clone(),
+                media_type: l.media_type.clone(),
+                rel: l.rel.clone(),
+                resource_fragment: l.resource_fragment.clone(),
+                text: l.text.clone(),
+                extensions: l.extensions.clone(),
+            })
+            .collect()
+    })
+}
+
+fn conv_props(
+    props: &Option<Vec<component_definition::Property>>,
+) -> Option<Vec<Property>> {
+    props.as_ref().map(|props| {
+        props
+            .iter()
+            .map(|p| Property {
+                class: p.class.clone(),
+                group: p.group.clone(),
+                name: p.name.clone(),
+                ns: p.ns.clone(),
+                remarks: p.remarks.clone(),
+                uuid: p.uuid.clone(),
+                value: p.value.clone(),
+                extensions: p.extensions.clone(),
+            })
+            .collect()
+    })
+}
+
+fn conv_roles(
+    roles: &Option<Vec<component_definition::ResponsibleRole>>,
+) -> Option<Vec<ResponsibleRole>> {
+    roles.as_ref().map(|roles| {
+        roles
+            .iter()
+            .map(|r| ResponsibleRole {
+                links: conv_links(&r.links),
+                party_uuids: r.party_uuids.clone(),
+                props: conv_props(&r.props),
+                remarks: r.remarks.clone(),
+                role_id: r.role_id.clone(),
+                extensions: r.extensions.clone(),
+            })
+            .collect()
+    })
+}
+
+fn conv_protocols(
+    protocols: &Option<Vec<component_definition::ServiceProtocolInformation>>,
+) -> Option<Vec<ServiceProtocolInformation>> {
+    protocols.as_ref().map(|protocols| {
+        protocols
+            .iter()
+            .map(|p| ServiceProtocolInformation {
+                name: p.name.clone(),
+                port_ranges: p.port_ranges.as_ref().map(|ranges| {
+                    ranges
+                        .iter()
+                        .map(|r| PortRange {
+                            end: r.end,
+                            start: r.start,
+                            transport: r.transport.as_ref().map(|t| match t {
+                                component_definition::Transport::Tcp => {
+                                    Transport::Tcp
+                                }
+                                component_definition::Transport::Udp => {
+                                    Transport::Udp
+                                }
+                            }),
+                            extensions: r.extensions.clone(),
+                        })
+                        .collect()
+                }),
+                title: p.title.clone(),
+                uuid: p.uuid.clone(),
+                extensions: p.extensions.clone(),
+            })
+            .collect()
+    })
+}
@@ -3,7 +3,8 @@
 //! models using the builder pattern, with bi-directional validation enabled on key
 //! fields during serialisation/deserialisation.
 //!
-//! All textual fields are represented as `String` type such as uuid, datetime etc.
+//! Textual fields such as uuid, datetime etc. are checked against their OSCAL
+//! data type, see [`scalar`] for how they are represented.
 //! This library strives to be unopinionated about which library is being used by
 //! the user to build certain data field, therefore it does not re-export some of
 //! the libraries used internally for validation. The libraries for building these types
@@ -11,6 +12,34 @@
 //! can follow the OSCAL specification and choose whichever that conforms to the requirement.
 //! See <https://pages.nist.gov/metaschema/specification/datatypes/>
 //!
+//! Markup fields are not scalars, they use [`markup::Markup`] which keeps
+//! the text as it was read and parses it into a Markdown or XML/HTML AST on
+//! demand.
+//!
+//! The scalar fields use the aliases in [`scalar`], such as
+//! [`scalar::OscalUuid`] and [`scalar::DateTimeWithTz`], which are plain
+//! `String`s by default. Enable the `typed-scalars` feature to turn them into
+//! validated newtypes that convert to and from the `uuid`, `chrono` and
+//! `uriparse` types.
+//!
+//! Every model module has `visit::Visit` and `visit_mut::VisitMut` traits
+//! with a default method per struct and enum, so walking a whole document
+//! only takes overriding the methods of interest, e.g. `visit_property`.
+//!
+//! Every struct also has an `extensions` map. It stays empty unless the
+//! document is parsed with [`parse::UnknownFields::Capture`], which keeps
+//! the fields unknown to the model there instead of failing.
+//!
+//! [`schema::validate`] checks a document against the official OSCAL JSON
+//! schemas bundled with the crate, independently of the serde models.
+//!
+//! Hash digests are checked against their declared algorithm while parsing
+//! and building, and [`hash::HashAlgorithm`] computes them to verify content.
+//!
+//! Hrefs of imports and links are followed to local files with the
+//! [`href::HrefResolver`] implementations, covering back-matter fragments,
+//! relative paths and an offline mirror of remote documents.
+//!
 //! Currently the latest v1.x is being tracked by this library and primarily supports
 //! the roscal_cli tool.
 //!  
@@ -26,14 +55,16 @@
 //!
 //! ```
 //! use roscal_lib::control::catalog::{self, CatalogBuilder, CatalogClassBuilder};
+//! use roscal_lib::scalar::{DateTimeWithTz, OscalUuid};
 //!
 //! fn builder() {
 //!     let mut catalog_builder = CatalogBuilder::default();
 //!     let mut catalog_class_builder = CatalogClassBuilder::default();
 //!
-//!     let uuid = uuid::Uuid::new_v4().to_string();
+//!     let uuid = OscalUuid::try_from(uuid::Uuid::new_v4().to_string()).unwrap();
 //!     let last_modified = chrono::DateTime::parse_from_rfc3339("2023-12-31T23:59:59Z")
-//!     .unwrap().to_string();
+//!     .unwrap().to_rfc3339();
+//!     let last_modified = DateTimeWithTz::try_from(last_modified).unwrap();
 //!     let metadata = catalog::DocumentMetadataBuilder::default()
 //!         .version("1")
 //!         .title("catalog")
@@ -60,9 +91,19 @@
 pub mod assessment;
 mod common_impl;
 pub mod control;
+pub mod document;
+pub mod hash;
+pub mod href;
 pub mod implementation;
+pub mod markup;
+pub mod parse;
+pub mod query;
+pub mod render;
+pub mod scalar;
+pub mod schema;
 mod uuid_impl;
 mod validation;
+pub mod xml;
 
 /// Update UUID v4/v5 if model content has changed
 pub trait UpdateUuid: Sized {
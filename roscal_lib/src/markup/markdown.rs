@@ -0,0 +1,361 @@
+use std::iter::Peekable;
+
+use lazy_static::lazy_static;
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use regex::Regex;
+
+use super::{Block, Inline};
+
+lazy_static! {
+    static ref INSERT: Regex =
+        Regex::new(r"\{\{\s*insert:\s*([^\s,}]+),\s*([^\s}]+)\s*\}\}").unwrap();
+}
+
+type Events<'a> = Peekable<Parser<'a>>;
+
+pub(super) fn parse(source: &str) -> Vec<Block> {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+    let mut events = Parser::new_ext(source, options).peekable();
+
+    parse_blocks(&mut events)
+}
+
+fn is_inline_start(event: &Event) -> bool {
+    match event {
+        Event::Start(tag) => matches!(
+            tag,
+            Tag::Emphasis
+                | Tag::Strong
+                | Tag::Strikethrough
+                | Tag::Link { .. }
+                | Tag::Image { .. }
+        ),
+        Event::Text(_)
+        | Event::Code(_)
+        | Event::InlineHtml(_)
+        | Event::SoftBreak
+        | Event::HardBreak
+        | Event::FootnoteReference(_)
+        | Event::TaskListMarker(_) => true,
+        _ => false,
+    }
+}
+
+/// Parse blocks until the end of the enclosing container is consumed
+fn parse_blocks(events: &mut Events) -> Vec<Block> {
+    let mut blocks = vec![];
+
+    while let Some(event) = events.peek() {
+        // Tight list items hold their inline content without a paragraph
+        if is_inline_start(event) {
+            let content = parse_inlines(events, true);
+            blocks.push(Block::Paragraph(content));
+            continue;
+        }
+
+        let Some(event) = events.next() else { break };
+        match event {
+            Event::End(_) => break,
+            Event::Start(Tag::Paragraph) => {
+                blocks.push(Block::Paragraph(parse_inlines(events, false)))
+            }
+            Event::Start(Tag::Heading { level, .. }) => {
+                blocks.push(Block::Heading {
+                    level: heading_level(level),
+                    content: parse_inlines(events, false),
+                })
+            }
+            Event::Start(Tag::BlockQuote) => {
+                blocks.push(Block::BlockQuote(parse_blocks(events)))
+            }
+            Event::Start(Tag::CodeBlock(_)) => {
+                blocks.push(Block::CodeBlock(collect_text(events)))
+            }
+            Event::Start(Tag::HtmlBlock) => {
+                blocks.push(Block::Html(collect_text(events)))
+            }
+            Event::Start(Tag::List(start)) => {
+                let mut items = vec![];
+                while let Some(event) = events.next() {
+                    match event {
+                        Event::Start(Tag::Item) => {
+                            items.push(parse_blocks(events))
+                        }
+                        _ => break,
+                    }
+                }
+                blocks.push(Block::List {
+                    ordered: start.is_some(),
+                    items,
+                })
+            }
+            Event::Start(Tag::Table(_)) => blocks.push(parse_table(events)),
+            Event::Start(_) => {
+                // Footnotes and metadata have no markup equivalent
+                parse_blocks(events);
+            }
+            Event::Html(html) => blocks.push(Block::Html(html.to_string())),
+            _ => {}
+        }
+    }
+
+    blocks
+}
+
+fn parse_table(events: &mut Events) -> Block {
+    let mut header = vec![];
+    let mut rows = vec![];
+
+    while let Some(event) = events.next() {
+        match event {
+            Event::Start(Tag::TableHead) => header = parse_row(events),
+            Event::Start(Tag::TableRow) => rows.push(parse_row(events)),
+            _ => break,
+        }
+    }
+
+    Block::Table { header, rows }
+}
+
+fn parse_row(events: &mut Events) -> Vec<Vec<Inline>> {
+    let mut cells = vec![];
+
+    while let Some(event) = events.next() {
+        match event {
+            Event::Start(Tag::TableCell) => {
+                cells.push(parse_inlines(events, false))
+            }
+            _ => break,
+        }
+    }
+
+    cells
+}
+
+fn collect_text(events: &mut Events) -> String {
+    let mut text = String::new();
+
+    for event in events.by_ref() {
+        match event {
+            Event::Text(t) | Event::Html(t) => text.push_str(&t),
+            Event::End(TagEnd::CodeBlock | TagEnd::HtmlBlock) => break,
+            _ => {}
+        }
+    }
+
+    text
+}
+
+/// Parse inline content until the end of the enclosing element is consumed,
+/// or until a block element starts when the content is not wrapped
+fn parse_inlines(events: &mut Events, implicit: bool) -> Vec<Inline> {
+    let mut inlines = vec![];
+
+    loop {
+        if implicit && !events.peek().is_some_and(is_inline_start) {
+            break;
+        }
+
+        let Some(event) = events.next() else { break };
+        match event {
+            Event::End(_) => break,
+            Event::Text(text) => push_text(&mut inlines, &text),
+            Event::Code(code) => inlines.push(Inline::Code(code.to_string())),
+            Event::InlineHtml(html) => {
+                inlines.push(Inline::Html(html.to_string()))
+            }
+            Event::SoftBreak => push_text(&mut inlines, "\n"),
+            Event::HardBreak => inlines.push(Inline::LineBreak),
+            Event::Start(Tag::Emphasis) => {
+                inlines.push(Inline::Emphasis(parse_inlines(events, false)))
+            }
+            Event::Start(Tag::Strong) => {
+                inlines.push(Inline::Strong(parse_inlines(events, false)))
+            }
+            Event::Start(Tag::Link { dest_url, .. }) => {
+                inlines.push(Inline::Link {
+                    href: dest_url.to_string(),
+                    content: parse_inlines(events, false),
+                })
+            }
+            Event::Start(Tag::Image { dest_url, .. }) => {
+                let alt = parse_inlines(events, false);
+                inlines.push(Inline::Image {
+                    src: dest_url.to_string(),
+                    alt: super::inline_text(&alt),
+                })
+            }
+            Event::Start(_) => inlines.extend(parse_inlines(events, false)),
+            _ => {}
+        }
+    }
+
+    split_inserts(inlines)
+}
+
+/// Adjacent text events are merged so that inserts split by the parser are
+/// still recognised
+fn push_text(inlines: &mut Vec<Inline>, text: &str) {
+    if let Some(Inline::Text(last)) = inlines.last_mut() {
+        last.push_str(text);
+    } else {
+        inlines.push(Inline::Text(text.to_owned()));
+    }
+}
+
+fn split_inserts(inlines: Vec<Inline>) -> Vec<Inline> {
+    let mut output = vec![];
+
+    for inline in inlines {
+        let Inline::Text(text) = inline else {
+            output.push(inline);
+            continue;
+        };
+
+        let mut last = 0;
+        for caps in INSERT.captures_iter(&text) {
+            let m = caps.get(0).unwrap();
+            if m.start() > last {
+                output.push(Inline::Text(text[last..m.start()].to_owned()));
+            }
+            output.push(Inline::Insert {
+                insert_type: caps[1].to_owned(),
+                id_ref: caps[2].to_owned(),
+            });
+            last = m.end();
+        }
+        if last < text.len() {
+            output.push(Inline::Text(text[last..].to_owned()));
+        }
+    }
+
+    output
+}
+
+fn heading_level(level: HeadingLevel) -> u8 {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+pub(super) fn write(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(write_block)
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+fn write_block(block: &Block) -> String {
+    match block {
+        Block::Paragraph(content) => write_inlines(content),
+        Block::Heading { level, content } => {
+            format!(
+                "{} {}",
+                "#".repeat(*level as usize),
+                write_inlines(content)
+            )
+        }
+        Block::List { ordered, items } => items
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| {
+                let marker = if *ordered {
+                    format!("{}. ", idx + 1)
+                } else {
+                    "- ".to_owned()
+                };
+                let pad = " ".repeat(marker.len());
+                let content = write(item)
+                    .lines()
+                    .enumerate()
+                    .map(|(idx, line)| {
+                        if idx == 0 || line.is_empty() {
+                            line.to_owned()
+                        } else {
+                            format!("{pad}{line}")
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n");
+
+                format!("{marker}{content}")
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+        Block::Table { header, rows } => {
+            let row = |cells: &Vec<Vec<Inline>>| {
+                let cells: Vec<String> = cells
+                    .iter()
+                    .map(|c| write_inlines(c).replace('|', "\\|"))
+                    .collect();
+                format!("| {} |", cells.join(" | "))
+            };
+
+            let mut lines = vec![
+                row(header),
+                format!("|{}", " --- |".repeat(header.len())),
+            ];
+            lines.extend(rows.iter().map(row));
+            lines.join("\n")
+        }
+        Block::CodeBlock(code) => format!("```\n{}\n```", code.trim_end()),
+        Block::BlockQuote(blocks) => write(blocks)
+            .lines()
+            .map(|line| format!("> {line}").trim_end().to_owned())
+            .collect::<Vec<String>>()
+            .join("\n"),
+        Block::Html(html) => html.trim_end().to_owned(),
+    }
+}
+
+fn write_inlines(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => escape(text),
+            Inline::Emphasis(content) => {
+                format!("*{}*", write_inlines(content))
+            }
+            Inline::Strong(content) => {
+                format!("**{}**", write_inlines(content))
+            }
+            Inline::Code(code) => format!("`{code}`"),
+            Inline::Subscript(content) => {
+                format!("~{}~", write_inlines(content))
+            }
+            Inline::Superscript(content) => {
+                format!("^{}^", write_inlines(content))
+            }
+            Inline::Quote(content) => format!("\"{}\"", write_inlines(content)),
+            Inline::Link { href, content } => {
+                format!("[{}]({href})", write_inlines(content))
+            }
+            Inline::Image { src, alt } => format!("![{}]({src})", escape(alt)),
+            Inline::Insert {
+                insert_type,
+                id_ref,
+            } => format!("{{{{ insert: {insert_type}, {id_ref} }}}}"),
+            Inline::LineBreak => "\\\n".to_owned(),
+            Inline::Html(html) => html.clone(),
+        })
+        .collect()
+}
+
+fn escape(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']') {
+            output.push('\\');
+        }
+        output.push(c);
+    }
+
+    output
+}
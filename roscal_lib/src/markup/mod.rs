@@ -0,0 +1,449 @@
+//! Typed OSCAL markup-line and markup-multiline values.
+//!
+//! A [`Markup`] keeps the text exactly as it was read so that documents
+//! serialise back unchanged, and parses it into an AST of [`Block`] and
+//! [`Inline`] elements on demand. Both the Markdown form used by the JSON and
+//! YAML models and the XML/HTML form used by the XML models are understood.
+//!
+//! See <https://pages.nist.gov/metaschema/specification/datatypes/#markup-data-types>
+//!
+//! ```
+//! use roscal_lib::markup::{Block, Inline, Markup};
+//!
+//! let markup = Markup::from("Protect the {{ insert: param, sc-8_prm_1 }} of *data*.");
+//!
+//! assert_eq!(
+//!     vec![Block::Paragraph(vec![
+//!         Inline::Text("Protect the ".to_owned()),
+//!         Inline::Insert {
+//!             insert_type: "param".to_owned(),
+//!             id_ref: "sc-8_prm_1".to_owned(),
+//!         },
+//!         Inline::Text(" of ".to_owned()),
+//!         Inline::Emphasis(vec![Inline::Text("data".to_owned())]),
+//!         Inline::Text(".".to_owned()),
+//!     ])],
+//!     markup.blocks()
+//! );
+//! assert_eq!("<p>Protect the <insert type=\"param\" id-ref=\"sc-8_prm_1\"/> of <em>data</em>.</p>", markup.to_html());
+//! ```
+
+mod markdown;
+mod xml;
+
+use std::{cell::Cell, fmt, ops::Deref};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::parse::{self, MarkupMode};
+
+thread_local! {
+    /// Set while a model is written out as XML, so that every value reaches
+    /// the writer in the same form whatever it was read as
+    static AS_MARKDOWN: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Source form of a markup value
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MarkupFormat {
+    /// OSCAL flavoured Markdown, used by the JSON and YAML models
+    #[default]
+    Markdown,
+    /// OSCAL XML/HTML markup, used by the XML models
+    Xml,
+}
+
+/// A markup-line or markup-multiline value
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Markup {
+    source: String,
+    format: MarkupFormat,
+}
+
+/// Block level markup element
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Block {
+    Paragraph(Vec<Inline>),
+    Heading {
+        level: u8,
+        content: Vec<Inline>,
+    },
+    List {
+        ordered: bool,
+        items: Vec<Vec<Block>>,
+    },
+    Table {
+        header: Vec<Vec<Inline>>,
+        rows: Vec<Vec<Vec<Inline>>>,
+    },
+    CodeBlock(String),
+    BlockQuote(Vec<Block>),
+    /// Raw HTML the markup model has no element for
+    Html(String),
+}
+
+/// Inline markup element
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Inline {
+    Text(String),
+    Emphasis(Vec<Inline>),
+    Strong(Vec<Inline>),
+    Code(String),
+    Subscript(Vec<Inline>),
+    Superscript(Vec<Inline>),
+    Quote(Vec<Inline>),
+    Link {
+        href: String,
+        content: Vec<Inline>,
+    },
+    Image {
+        src: String,
+        alt: String,
+    },
+    /// `{{ insert: <type>, <id-ref> }}` placeholder, e.g. for a parameter
+    Insert {
+        insert_type: String,
+        id_ref: String,
+    },
+    LineBreak,
+    /// Raw inline HTML the markup model has no element for
+    Html(String),
+}
+
+/// Error returned when XML/HTML markup is not well-formed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkupError(String);
+
+impl fmt::Display for MarkupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid markup: {}", self.0)
+    }
+}
+
+impl std::error::Error for MarkupError {}
+
+impl Markup {
+    /// Markup in OSCAL Markdown form
+    pub fn from_markdown(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            format: MarkupFormat::Markdown,
+        }
+    }
+
+    /// Markup in OSCAL XML/HTML form, checked to be well-formed
+    pub fn from_xml(source: impl Into<String>) -> Result<Self, MarkupError> {
+        let source = source.into();
+        xml::parse(&source)?;
+
+        Ok(Self {
+            source,
+            format: MarkupFormat::Xml,
+        })
+    }
+
+    /// Markup of unknown origin, detected as XML/HTML when it starts with a
+    /// well-formed element, Markdown otherwise. Values read from a document
+    /// take the form of its format instead
+    pub fn parse(source: impl Into<String>) -> Self {
+        let source = source.into();
+
+        if source.trim_start().starts_with('<') && xml::parse(&source).is_ok() {
+            Self {
+                source,
+                format: MarkupFormat::Xml,
+            }
+        } else {
+            Self::from_markdown(source)
+        }
+    }
+
+    /// Markup built from an AST, stored in Markdown form
+    pub fn from_blocks(blocks: &[Block]) -> Self {
+        Self::from_markdown(markdown::write(blocks))
+    }
+
+    /// The markup text exactly as it was read
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    pub fn format(&self) -> MarkupFormat {
+        self.format
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.source.trim().is_empty()
+    }
+
+    /// Parse the markup into its AST
+    pub fn blocks(&self) -> Vec<Block> {
+        match self.format {
+            MarkupFormat::Markdown => markdown::parse(&self.source),
+            MarkupFormat::Xml => xml::parse(&self.source).unwrap_or_default(),
+        }
+    }
+
+    /// Render as HTML, parameter inserts are kept as `<insert/>` elements
+    pub fn to_html(&self) -> String {
+        xml::write(&self.blocks())
+    }
+
+    /// Block elements of a markup-multiline value in OSCAL XML, raw HTML is
+    /// written as text so that the document stays well-formed
+    pub(crate) fn to_xml_blocks(&self) -> Vec<String> {
+        self.blocks()
+            .into_iter()
+            .map(|block| xml::write_block(&without_html(block)))
+            .collect()
+    }
+
+    /// Inline content of a markup-line value in OSCAL XML
+    pub(crate) fn to_xml_line(&self) -> String {
+        match self.blocks().as_slice() {
+            [Block::Paragraph(content)] => {
+                xml::write_inlines(&inlines_without_html(content))
+            }
+            _ => xml::write_inlines(&[Inline::Text(self.to_plain_text())]),
+        }
+    }
+
+    /// Render as OSCAL Markdown
+    pub fn to_markdown(&self) -> String {
+        match self.format {
+            MarkupFormat::Markdown => self.source.clone(),
+            MarkupFormat::Xml => markdown::write(&self.blocks()),
+        }
+    }
+
+    /// Render as plain text, parameter inserts are kept as
+    /// `{{ insert: param, <id> }}` placeholders
+    pub fn to_plain_text(&self) -> String {
+        self.blocks()
+            .iter()
+            .map(block_text)
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+}
+
+fn without_html(block: Block) -> Block {
+    let blocks = |blocks: Vec<Block>| -> Vec<Block> {
+        blocks.into_iter().map(without_html).collect()
+    };
+
+    match block {
+        Block::Paragraph(content) => {
+            Block::Paragraph(inlines_without_html(&content))
+        }
+        Block::Heading { level, content } => Block::Heading {
+            level,
+            content: inlines_without_html(&content),
+        },
+        Block::List { ordered, items } => Block::List {
+            ordered,
+            items: items.into_iter().map(blocks).collect(),
+        },
+        Block::Table { header, rows } => Block::Table {
+            header: header.iter().map(|c| inlines_without_html(c)).collect(),
+            rows: rows
+                .iter()
+                .map(|row| {
+                    row.iter().map(|c| inlines_without_html(c)).collect()
+                })
+                .collect(),
+        },
+        Block::BlockQuote(content) => Block::BlockQuote(blocks(content)),
+        Block::Html(html) => {
+            Block::Paragraph(vec![Inline::Text(html.trim_end().to_owned())])
+        }
+        block @ Block::CodeBlock(_) => block,
+    }
+}
+
+fn inlines_without_html(inlines: &[Inline]) -> Vec<Inline> {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Html(html) => Inline::Text(html.clone()),
+            Inline::Emphasis(content) => {
+                Inline::Emphasis(inlines_without_html(content))
+            }
+            Inline::Strong(content) => {
+                Inline::Strong(inlines_without_html(content))
+            }
+            Inline::Subscript(content) => {
+                Inline::Subscript(inlines_without_html(content))
+            }
+            Inline::Superscript(content) => {
+                Inline::Superscript(inlines_without_html(content))
+            }
+            Inline::Quote(content) => {
+                Inline::Quote(inlines_without_html(content))
+            }
+            Inline::Link { href, content } => Inline::Link {
+                href: href.clone(),
+                content: inlines_without_html(content),
+            },
+            inline => inline.clone(),
+        })
+        .collect()
+}
+
+fn block_text(block: &Block) -> String {
+    match block {
+        Block::Paragraph(content) | Block::Heading { content, .. } => {
+            inline_text(content)
+        }
+        Block::List { items, .. } => items
+            .iter()
+            .map(|item| {
+                item.iter()
+                    .map(block_text)
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+        Block::Table { header, rows } => std::iter::once(header)
+            .chain(rows)
+            .map(|row| {
+                row.iter()
+                    .map(|cell| inline_text(cell))
+                    .collect::<Vec<String>>()
+                    .join("\t")
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+        Block::CodeBlock(code) | Block::Html(code) => code.clone(),
+        Block::BlockQuote(blocks) => blocks
+            .iter()
+            .map(block_text)
+            .collect::<Vec<String>>()
+            .join("\n\n"),
+    }
+}
+
+fn inline_text(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) | Inline::Code(text) | Inline::Html(text) => {
+                text.clone()
+            }
+            Inline::Emphasis(content)
+            | Inline::Strong(content)
+            | Inline::Subscript(content)
+            | Inline::Superscript(content)
+            | Inline::Link { content, .. } => inline_text(content),
+            Inline::Quote(content) => format!("\"{}\"", inline_text(content)),
+            Inline::Image { alt, .. } => alt.clone(),
+            Inline::Insert {
+                insert_type,
+                id_ref,
+            } => format!("{{{{ insert: {insert_type}, {id_ref} }}}}"),
+            Inline::LineBreak => "\n".to_owned(),
+        })
+        .collect()
+}
+
+impl fmt::Display for Markup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl Deref for Markup {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.source
+    }
+}
+
+impl AsRef<str> for Markup {
+    fn as_ref(&self) -> &str {
+        &self.source
+    }
+}
+
+impl From<&str> for Markup {
+    fn from(value: &str) -> Self {
+        Self::from_markdown(value)
+    }
+}
+
+impl From<String> for Markup {
+    fn from(value: String) -> Self {
+        Self::from_markdown(value)
+    }
+}
+
+impl From<&Markup> for Markup {
+    fn from(value: &Markup) -> Self {
+        value.clone()
+    }
+}
+
+impl PartialEq<str> for Markup {
+    fn eq(&self, other: &str) -> bool {
+        self.source == other
+    }
+}
+
+impl PartialEq<&str> for Markup {
+    fn eq(&self, other: &&str) -> bool {
+        self.source == *other
+    }
+}
+
+impl PartialEq<Markup> for str {
+    fn eq(&self, other: &Markup) -> bool {
+        self == other.source
+    }
+}
+
+impl PartialEq<Markup> for &str {
+    fn eq(&self, other: &Markup) -> bool {
+        *self == other.source
+    }
+}
+
+/// Run `f` with every markup value serialised in Markdown form
+pub(crate) fn as_markdown<R>(f: impl FnOnce() -> R) -> R {
+    let previous = AS_MARKDOWN.with(|flag| flag.replace(true));
+    let result = f();
+    AS_MARKDOWN.with(|flag| flag.set(previous));
+
+    result
+}
+
+impl Serialize for Markup {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if AS_MARKDOWN.with(Cell::get) {
+            serializer.serialize_str(&self.to_markdown())
+        } else {
+            serializer.serialize_str(&self.source)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Markup {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        // Only JSON and YAML documents are read, both carry Markdown
+        let markup = Self::from_markdown(String::deserialize(deserializer)?);
+
+        match parse::current().markup_mode {
+            MarkupMode::Preserve => Ok(markup),
+            MarkupMode::Html => Ok(Self {
+                source: markup.to_html(),
+                format: MarkupFormat::Xml,
+            }),
+        }
+    }
+}
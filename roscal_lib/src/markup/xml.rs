@@ -0,0 +1,398 @@
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+
+use super::{Block, Inline, MarkupError};
+
+/// Element tree of the markup fragment, built before converting to the AST
+#[derive(Debug)]
+enum Node {
+    Element {
+        name: String,
+        attrs: Vec<(String, String)>,
+        children: Vec<Node>,
+    },
+    Text(String),
+}
+
+/// Open element name, attributes and the children read so far
+type Frame = (String, Vec<(String, String)>, Vec<Node>);
+
+pub(super) fn parse(source: &str) -> Result<Vec<Block>, MarkupError> {
+    let nodes = parse_nodes(source)?;
+
+    Ok(to_blocks(&nodes))
+}
+
+fn parse_nodes(source: &str) -> Result<Vec<Node>, MarkupError> {
+    let mut reader = Reader::from_str(source);
+    let mut stack: Vec<Frame> = vec![(String::new(), vec![], vec![])];
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| MarkupError(e.to_string()))?;
+
+        match event {
+            Event::Start(start) => {
+                let (name, attrs) = element(&start)?;
+                stack.push((name, attrs, vec![]));
+            }
+            Event::Empty(start) => {
+                let (name, attrs) = element(&start)?;
+                push_node(
+                    &mut stack,
+                    Node::Element {
+                        name,
+                        attrs,
+                        children: vec![],
+                    },
+                );
+            }
+            Event::End(end) => {
+                let name = local_name(end.local_name().as_ref());
+                let (open, attrs, children) = match stack.pop() {
+                    Some(frame) if !stack.is_empty() => frame,
+                    _ => {
+                        return Err(MarkupError(format!(
+                            "unexpected closing element `{name}`"
+                        )))
+                    }
+                };
+                if open != name {
+                    return Err(MarkupError(format!(
+                        "expected closing element `{open}`, found `{name}`"
+                    )));
+                }
+                push_node(
+                    &mut stack,
+                    Node::Element {
+                        name,
+                        attrs,
+                        children,
+                    },
+                );
+            }
+            Event::Text(text) => {
+                let text =
+                    text.unescape().map_err(|e| MarkupError(e.to_string()))?;
+                push_node(&mut stack, Node::Text(text.into_owned()));
+            }
+            Event::CData(text) => push_node(
+                &mut stack,
+                Node::Text(String::from_utf8_lossy(&text).into_owned()),
+            ),
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    match stack.pop() {
+        Some((_, _, nodes)) if stack.is_empty() => Ok(nodes),
+        _ => Err(MarkupError("unclosed element".to_owned())),
+    }
+}
+
+fn push_node(stack: &mut [Frame], node: Node) {
+    if let Some((_, _, children)) = stack.last_mut() {
+        children.push(node);
+    }
+}
+
+fn local_name(name: &[u8]) -> String {
+    String::from_utf8_lossy(name).into_owned()
+}
+
+fn element(
+    start: &BytesStart,
+) -> Result<(String, Vec<(String, String)>), MarkupError> {
+    let name = local_name(start.local_name().as_ref());
+    let mut attrs = vec![];
+
+    for attr in start.attributes() {
+        let attr = attr.map_err(|e| MarkupError(e.to_string()))?;
+        let value = attr
+            .unescape_value()
+            .map_err(|e| MarkupError(e.to_string()))?;
+        attrs.push((
+            local_name(attr.key.local_name().as_ref()),
+            value.into_owned(),
+        ));
+    }
+
+    Ok((name, attrs))
+}
+
+fn attr<'a>(attrs: &'a [(String, String)], key: &str) -> &'a str {
+    attrs
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+        .unwrap_or_default()
+}
+
+fn is_block(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::Element { name, .. } if matches!(
+            name.as_str(),
+            "p" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "ul" | "ol"
+                | "table" | "pre" | "blockquote"
+        )
+    )
+}
+
+fn to_blocks(nodes: &[Node]) -> Vec<Block> {
+    let mut blocks = vec![];
+    let mut pending: Vec<&Node> = vec![];
+
+    for node in nodes {
+        if is_block(node) {
+            flush_inlines(&mut blocks, &mut pending);
+            blocks.push(to_block(node));
+        } else {
+            pending.push(node);
+        }
+    }
+    flush_inlines(&mut blocks, &mut pending);
+
+    blocks
+}
+
+/// Inline content outside of a block element, as in markup-line values,
+/// becomes a paragraph unless it is only whitespace
+fn flush_inlines(blocks: &mut Vec<Block>, pending: &mut Vec<&Node>) {
+    let is_blank = pending
+        .iter()
+        .all(|n| matches!(n, Node::Text(t) if t.trim().is_empty()));
+
+    if !is_blank {
+        let inlines = pending.iter().flat_map(|n| to_inlines(n)).collect();
+        blocks.push(Block::Paragraph(inlines));
+    }
+
+    pending.clear();
+}
+
+fn to_block(node: &Node) -> Block {
+    let Node::Element { name, children, .. } = node else {
+        unreachable!("text nodes are never block elements")
+    };
+
+    match name.as_str() {
+        "p" => Block::Paragraph(children_inlines(children)),
+        "ul" | "ol" => Block::List {
+            ordered: name == "ol",
+            items: elements(children, "li").map(to_blocks).collect(),
+        },
+        "table" => {
+            let mut header = vec![];
+            let mut rows = vec![];
+
+            for row in elements(children, "tr") {
+                let is_header = row.iter().any(
+                    |n| matches!(n, Node::Element { name, .. } if name == "th"),
+                );
+                let cells: Vec<Vec<Inline>> = row
+                    .iter()
+                    .filter_map(|n| match n {
+                        Node::Element { name, children, .. }
+                            if name == "th" || name == "td" =>
+                        {
+                            Some(children_inlines(children))
+                        }
+                        _ => None,
+                    })
+                    .collect();
+
+                if is_header && header.is_empty() && rows.is_empty() {
+                    header = cells;
+                } else {
+                    rows.push(cells);
+                }
+            }
+
+            Block::Table { header, rows }
+        }
+        "pre" => Block::CodeBlock(text_of(children)),
+        "blockquote" => Block::BlockQuote(to_blocks(children)),
+        heading => Block::Heading {
+            level: heading[1..].parse().unwrap_or(1),
+            content: children_inlines(children),
+        },
+    }
+}
+
+fn elements<'a>(
+    nodes: &'a [Node],
+    tag: &'a str,
+) -> impl Iterator<Item = &'a [Node]> {
+    nodes.iter().filter_map(move |n| match n {
+        Node::Element { name, children, .. } if name == tag => {
+            Some(children.as_slice())
+        }
+        _ => None,
+    })
+}
+
+fn text_of(nodes: &[Node]) -> String {
+    nodes
+        .iter()
+        .map(|n| match n {
+            Node::Text(text) => text.clone(),
+            Node::Element { children, .. } => text_of(children),
+        })
+        .collect()
+}
+
+fn children_inlines(children: &[Node]) -> Vec<Inline> {
+    children.iter().flat_map(to_inlines).collect()
+}
+
+fn to_inlines(node: &Node) -> Vec<Inline> {
+    let (name, attrs, children) = match node {
+        Node::Text(text) => return vec![Inline::Text(text.clone())],
+        Node::Element {
+            name,
+            attrs,
+            children,
+        } => (name, attrs, children),
+    };
+
+    let inline = match name.as_str() {
+        "em" | "i" => Inline::Emphasis(children_inlines(children)),
+        "strong" | "b" => Inline::Strong(children_inlines(children)),
+        "code" => Inline::Code(text_of(children)),
+        "sub" => Inline::Subscript(children_inlines(children)),
+        "sup" => Inline::Superscript(children_inlines(children)),
+        "q" => Inline::Quote(children_inlines(children)),
+        "a" => Inline::Link {
+            href: attr(attrs, "href").to_owned(),
+            content: children_inlines(children),
+        },
+        "img" => Inline::Image {
+            src: attr(attrs, "src").to_owned(),
+            alt: attr(attrs, "alt").to_owned(),
+        },
+        "insert" => Inline::Insert {
+            insert_type: attr(attrs, "type").to_owned(),
+            id_ref: attr(attrs, "id-ref").to_owned(),
+        },
+        "br" => Inline::LineBreak,
+        _ => return children_inlines(children),
+    };
+
+    vec![inline]
+}
+
+pub(super) fn write(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(write_block)
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub(super) fn write_block(block: &Block) -> String {
+    match block {
+        Block::Paragraph(content) => {
+            format!("<p>{}</p>", write_inlines(content))
+        }
+        Block::Heading { level, content } => {
+            format!("<h{level}>{}</h{level}>", write_inlines(content))
+        }
+        Block::List { ordered, items } => {
+            let tag = if *ordered { "ol" } else { "ul" };
+            let items: String = items
+                .iter()
+                .map(|item| match item.as_slice() {
+                    [Block::Paragraph(content)] => {
+                        format!("<li>{}</li>", write_inlines(content))
+                    }
+                    _ => format!("<li>{}</li>", write(item)),
+                })
+                .collect();
+
+            format!("<{tag}>{items}</{tag}>")
+        }
+        Block::Table { header, rows } => {
+            let row = |cells: &Vec<Vec<Inline>>, tag: &str| {
+                let cells: String = cells
+                    .iter()
+                    .map(|c| format!("<{tag}>{}</{tag}>", write_inlines(c)))
+                    .collect();
+                format!("<tr>{cells}</tr>")
+            };
+
+            let mut output = String::from("<table>");
+            if !header.is_empty() {
+                output.push_str(&row(header, "th"));
+            }
+            for elt in rows {
+                output.push_str(&row(elt, "td"));
+            }
+            output.push_str("</table>");
+            output
+        }
+        Block::CodeBlock(code) => format!("<pre>{}</pre>", escape(code)),
+        Block::BlockQuote(blocks) => {
+            format!("<blockquote>{}</blockquote>", write(blocks))
+        }
+        Block::Html(html) => html.trim_end().to_owned(),
+    }
+}
+
+pub(super) fn write_inlines(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => escape(text),
+            Inline::Emphasis(content) => {
+                format!("<em>{}</em>", write_inlines(content))
+            }
+            Inline::Strong(content) => {
+                format!("<strong>{}</strong>", write_inlines(content))
+            }
+            Inline::Code(code) => format!("<code>{}</code>", escape(code)),
+            Inline::Subscript(content) => {
+                format!("<sub>{}</sub>", write_inlines(content))
+            }
+            Inline::Superscript(content) => {
+                format!("<sup>{}</sup>", write_inlines(content))
+            }
+            Inline::Quote(content) => {
+                format!("<q>{}</q>", write_inlines(content))
+            }
+            Inline::Link { href, content } => format!(
+                "<a href=\"{}\">{}</a>",
+                escape(href),
+                write_inlines(content)
+            ),
+            Inline::Image { src, alt } => {
+                format!(
+                    "<img src=\"{}\" alt=\"{}\"/>",
+                    escape(src),
+                    escape(alt)
+                )
+            }
+            Inline::Insert {
+                insert_type,
+                id_ref,
+            } => format!(
+                "<insert type=\"{}\" id-ref=\"{}\"/>",
+                escape(insert_type),
+                escape(id_ref)
+            ),
+            Inline::LineBreak => "<br/>".to_owned(),
+            Inline::Html(html) => html.clone(),
+        })
+        .collect()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,493 @@
+//! Per-call decode settings for the root OSCAL models.
+//!
+//! The options only apply to the deserialisation they are passed to, so
+//! documents parsed with different settings can be loaded side by side, on
+//! any number of threads.
+//!
+//! ```
+//! use roscal_lib::{
+//!     control::catalog::Catalog,
+//!     parse::{MarkupMode, ParseOptions, ParseWith},
+//! };
+//!
+//! let yaml = r#"
+//! catalog:
+//!   uuid: 74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724
+//!   metadata:
+//!     title: "*Example* catalog"
+//!     last-modified: 2023-12-31T23:59:59Z
+//!     version: "1"
+//!     oscal-version: 1.1.2
+//! "#;
+//!
+//! let options = ParseOptions::default().with_markup_mode(MarkupMode::Html);
+//! let catalog = Catalog::from_str_with(yaml, &options).unwrap();
+//! assert_eq!("<p><em>Example</em> catalog</p>", catalog.catalog.metadata.title);
+//!
+//! let catalog = Catalog::from_str_with(yaml, &ParseOptions::default()).unwrap();
+//! assert_eq!("*Example* catalog", catalog.catalog.metadata.title);
+//! ```
+//!
+//! Fields unknown to the model fail the parse by default. Documents written
+//! by other tools or for newer OSCAL versions can be read leniently instead,
+//! keeping those fields in the `extensions` of the struct they appear in so
+//! they are written back unchanged.
+//!
+//! ```
+//! use roscal_lib::{
+//!     control::catalog::Catalog,
+//!     parse::{ParseOptions, ParseWarning, ParseWith, UnknownFields},
+//! };
+//!
+//! let yaml = r#"
+//! catalog:
+//!   uuid: 74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724
+//!   metadata:
+//!     title: Example catalog
+//!     last-modified: 2023-12-31T23:59:59Z
+//!     version: "1"
+//!     oscal-version: 1.1.2
+//!     x-vendor-id: 42
+//! "#;
+//!
+//! assert!(Catalog::from_str_with(yaml, &ParseOptions::default()).is_err());
+//!
+//! let options = ParseOptions::default().with_unknown_fields(UnknownFields::Capture);
+//! let (catalog, warnings) = Catalog::from_str_with_warnings(yaml, &options).unwrap();
+//! assert_eq!(42, catalog.catalog.metadata.extensions["x-vendor-id"]);
+//! assert_eq!(
+//!     vec![ParseWarning::UnknownField {
+//!         path: "catalog.metadata".to_owned(),
+//!         field: "x-vendor-id".to_owned(),
+//!     }],
+//!     warnings
+//! );
+//! assert_eq!(
+//!     "catalog.metadata: unknown field `x-vendor-id` kept as an extension",
+//!     warnings[0].to_string()
+//! );
+//! ```
+
+mod track;
+
+use std::{cell::RefCell, fmt, io::Read};
+
+use serde::{de::DeserializeOwned, Deserializer, Serialize};
+use serde_path_to_error::Segment;
+
+use crate::{assessment::*, control::*, document::is_xml, implementation::*};
+
+pub(crate) use track::current as path;
+
+thread_local! {
+    static OPTIONS: RefCell<ParseOptions> = RefCell::new(ParseOptions::default());
+    static WARNINGS: RefCell<Vec<ParseWarning>> = const { RefCell::new(vec![]) };
+}
+
+/// Fields of a struct that are not part of its model
+pub type Extensions = serde_json::Map<String, serde_json::Value>;
+
+/// How markup-line and markup-multiline values are decoded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MarkupMode {
+    /// Keep markup exactly as it was read
+    #[default]
+    Preserve,
+    /// Render markup to HTML while decoding
+    Html,
+}
+
+/// How fields unknown to the model are handled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum UnknownFields {
+    /// Fail the parse, as the OSCAL schemas do
+    #[default]
+    Deny,
+    /// Keep them in the `extensions` of the enclosing struct and report a
+    /// [`ParseWarning`] for each
+    Capture,
+}
+
+/// Settings applied while decoding a model
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    pub markup_mode: MarkupMode,
+    pub unknown_fields: UnknownFields,
+}
+
+impl ParseOptions {
+    pub fn with_markup_mode(mut self, markup_mode: MarkupMode) -> Self {
+        self.markup_mode = markup_mode;
+        self
+    }
+
+    pub fn with_unknown_fields(
+        mut self,
+        unknown_fields: UnknownFields,
+    ) -> Self {
+        self.unknown_fields = unknown_fields;
+        self
+    }
+}
+
+/// Something noticed while decoding a model that did not fail it
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseWarning {
+    /// A field unknown to the model was kept as an extension
+    UnknownField {
+        /// Dotted path to the struct holding the field, empty for the
+        /// document root
+        path: String,
+        field: String,
+    },
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseWarning::UnknownField { path, field } => {
+                if !path.is_empty() {
+                    write!(f, "{path}: ")?;
+                }
+                write!(f, "unknown field `{field}` kept as an extension")
+            }
+        }
+    }
+}
+
+/// Error raised when a document does not decode into its model
+///
+/// Unlike the errors of the underlying serde formats, it always carries the
+/// full path to the offending field, e.g.
+/// `system-security-plan.control-implementation.implemented-requirements[12].by-components[0].component-uuid`,
+/// along with the value found there.
+#[derive(Debug)]
+pub struct ParseError {
+    path: String,
+    message: String,
+    value: Option<String>,
+    location: Option<(usize, usize)>,
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+/// Why an XML document is refused as input
+pub(crate) const XML_INPUT: &str = "the XML encoding is export only, read \
+    the JSON or YAML document it was written from instead";
+
+impl ParseError {
+    /// Error for an XML document, which the models can't be read from
+    fn xml() -> Self {
+        Self {
+            path: String::new(),
+            message: format!("XML documents can't be read, {XML_INPUT}"),
+            value: None,
+            location: None,
+            source: None,
+        }
+    }
+
+    fn new(
+        input: &str,
+        error: serde_path_to_error::Error<serde_yaml::Error>,
+    ) -> Self {
+        let mut path = String::new();
+        let mut pointer = String::new();
+        for segment in error.path() {
+            match segment {
+                Segment::Seq { index } => {
+                    path.push_str(&format!("[{index}]"));
+                    pointer.push_str(&format!("/{index}"));
+                }
+                Segment::Map { key } | Segment::Enum { variant: key } => {
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+                    path.push_str(key);
+                    pointer.push_str(&format!(
+                        "/{}",
+                        key.replace('~', "~0").replace('/', "~1")
+                    ));
+                }
+                Segment::Unknown => {}
+            }
+        }
+
+        let inner = error.into_inner();
+        let location = inner.location().map(|l| (l.line(), l.column()));
+        let message = bare_message(&inner, &path, location);
+
+        // The value is looked up again as the error only describes it
+        let value = serde_yaml::from_str::<serde_json::Value>(input)
+            .ok()
+            .and_then(|document| match document.pointer(&pointer)? {
+                serde_json::Value::String(s) => Some(s.to_owned()),
+                v @ (serde_json::Value::Number(_)
+                | serde_json::Value::Bool(_)) => Some(v.to_string()),
+                _ => None,
+            });
+
+        Self {
+            path,
+            message,
+            value,
+            location,
+            source: Some(Box::new(inner)),
+        }
+    }
+
+    /// Dotted path to the offending field, empty for the document root
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// What is wrong, without the path or location
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Offending scalar value, as found in the document
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// One-based line of the error in the document
+    pub fn line(&self) -> Option<usize> {
+        self.location.map(|(line, _)| line)
+    }
+
+    /// One-based column of the error in the document
+    pub fn column(&self) -> Option<usize> {
+        self.location.map(|(_, column)| column)
+    }
+}
+
+/// Message of a serde_yaml error stripped of the path and location it
+/// appends, as both are reported separately
+fn bare_message(
+    error: &serde_yaml::Error,
+    path: &str,
+    location: Option<(usize, usize)>,
+) -> String {
+    let mut message = error.to_string();
+
+    if let Some((line, column)) = location {
+        let suffix = format!(" at line {line} column {column}");
+        if let Some(stripped) = message.strip_suffix(&suffix) {
+            message = stripped.to_owned();
+        }
+    }
+
+    if let Some((prefix, rest)) = message.split_once(": ") {
+        let is_path = path == prefix
+            || path
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.starts_with(['.', '[']));
+        if is_path {
+            message = rest.to_owned();
+        }
+    }
+
+    message
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path)?;
+        }
+        f.write_str(&self.message)?;
+        if let Some(value) = &self.value {
+            write!(f, ", found `{value}`")?;
+        }
+        if let Some((line, column)) = self.location {
+            write!(f, " at line {line} column {column}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|e| e as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl From<serde_yaml::Error> for ParseError {
+    fn from(e: serde_yaml::Error) -> Self {
+        let location = e.location().map(|l| (l.line(), l.column()));
+
+        Self {
+            path: String::new(),
+            message: bare_message(&e, "", location),
+            value: None,
+            location,
+            source: Some(Box::new(e)),
+        }
+    }
+}
+
+impl From<std::io::Error> for ParseError {
+    fn from(e: std::io::Error) -> Self {
+        Self {
+            path: String::new(),
+            message: e.to_string(),
+            value: None,
+            location: None,
+            source: Some(Box::new(e)),
+        }
+    }
+}
+
+/// Options of the deserialisation running on the current thread
+pub(crate) fn current() -> ParseOptions {
+    OPTIONS.with(|options| options.borrow().clone())
+}
+
+/// Report a warning of the deserialisation running on the current thread
+pub(crate) fn warn(warning: ParseWarning) {
+    WARNINGS.with(|warnings| warnings.borrow_mut().push(warning))
+}
+
+/// Restores the options, warnings and path of an enclosing deserialisation
+/// when dropped
+struct OptionsGuard(
+    Option<(ParseOptions, Vec<ParseWarning>, Vec<track::Segment>)>,
+);
+
+impl OptionsGuard {
+    fn set(options: &ParseOptions) -> Self {
+        let options = OPTIONS.with(|current| current.replace(options.clone()));
+        let warnings = WARNINGS.with(|current| current.take());
+        let path = track::replace(vec![]);
+
+        Self(Some((options, warnings, path)))
+    }
+
+    fn take_warnings(&self) -> Vec<ParseWarning> {
+        WARNINGS.with(|current| current.take())
+    }
+}
+
+impl Drop for OptionsGuard {
+    fn drop(&mut self) {
+        if let Some((options, warnings, path)) = self.0.take() {
+            OPTIONS.with(|current| current.replace(options));
+            WARNINGS.with(|current| current.replace(warnings));
+            track::replace(path);
+        }
+    }
+}
+
+/// Parse a JSON or YAML document holding any struct of a model, such as a
+/// block split off a root model, along with the warnings raised
+pub fn from_str_with_warnings<T: DeserializeOwned>(
+    input: &str,
+    options: &ParseOptions,
+) -> Result<(T, Vec<ParseWarning>), ParseError> {
+    if is_xml(input) {
+        return Err(ParseError::xml());
+    }
+
+    let guard = OptionsGuard::set(options);
+    let model = serde_path_to_error::deserialize(track::Tracked::new(
+        serde_yaml::Deserializer::from_str(input),
+    ))
+    .map_err(|e| ParseError::new(input, e))?;
+
+    Ok((model, guard.take_warnings()))
+}
+
+/// Read a JSON or YAML document holding any struct of a model, along with
+/// the warnings raised
+pub fn from_reader_with_warnings<T: DeserializeOwned, R: Read>(
+    mut reader: R,
+    options: &ParseOptions,
+) -> Result<(T, Vec<ParseWarning>), ParseError> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+
+    from_str_with_warnings(&input, options)
+}
+
+/// Decode a root model with per-call [`ParseOptions`]
+pub trait ParseWith: DeserializeOwned + Serialize {
+    /// Deserialise from any serde format, along with the warnings raised
+    fn deserialize_with_warnings<'de, D>(
+        deserializer: D,
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<ParseWarning>), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let guard = OptionsGuard::set(options);
+        let model = Self::deserialize(track::Tracked::new(deserializer))?;
+
+        Ok((model, guard.take_warnings()))
+    }
+
+    /// Deserialise from any serde format
+    fn deserialize_with<'de, D>(
+        deserializer: D,
+        options: &ParseOptions,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize_with_warnings(deserializer, options)
+            .map(|(model, _)| model)
+    }
+
+    /// Read a JSON or YAML document, along with the warnings raised
+    fn from_reader_with_warnings<R: Read>(
+        reader: R,
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<ParseWarning>), ParseError> {
+        crate::parse::from_reader_with_warnings(reader, options)
+    }
+
+    /// Read a JSON or YAML document
+    fn from_reader_with<R: Read>(
+        reader: R,
+        options: &ParseOptions,
+    ) -> Result<Self, ParseError> {
+        Self::from_reader_with_warnings(reader, options).map(|(model, _)| model)
+    }
+
+    /// Parse a JSON or YAML document, along with the warnings raised
+    fn from_str_with_warnings(
+        input: &str,
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<ParseWarning>), ParseError> {
+        crate::parse::from_str_with_warnings(input, options)
+    }
+
+    /// Parse a JSON or YAML document
+    fn from_str_with(
+        input: &str,
+        options: &ParseOptions,
+    ) -> Result<Self, ParseError> {
+        Self::from_str_with_warnings(input, options).map(|(model, _)| model)
+    }
+}
+
+macro_rules! impl_parse_with {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl ParseWith for $t {}
+        )*
+    };
+}
+
+impl_parse_with!(
+    assessment_plan::AssessmentPlan,
+    assessment_results::AssessmentResults,
+    poam::PlanOfActionAndMilestones,
+    catalog::Catalog,
+    profile::Profile,
+    component_definition::ComponentDefinition,
+    ssp::SystemSecurityPlan
+);
@@ -0,0 +1,438 @@
+//! Path of the value being decoded on the current thread.
+//!
+//! Fields unknown to a model reach `deser_extensions` once the struct
+//! holding them has been read, through serde's `flatten`, where the
+//! deserializer no longer says where they are. [`Tracked`] wraps the
+//! deserializer of a parse and keeps the path of every map value and
+//! sequence element it enters on a thread-local stack instead, which
+//! [`current`] reads.
+
+use std::{cell::RefCell, fmt};
+
+use serde::de::{
+    self, DeserializeSeed, Deserializer, EnumAccess, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+
+thread_local! {
+    static PATH: RefCell<Vec<Segment>> = const { RefCell::new(vec![]) };
+    /// Last map key or enum variant read, taken by the access reading it
+    static KEY: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Step from a value to one it holds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Dotted path to the value being decoded, empty for the document root
+pub(crate) fn current() -> String {
+    PATH.with(|path| {
+        let mut dotted = String::new();
+        for segment in path.borrow().iter() {
+            match segment {
+                Segment::Key(key) => {
+                    if !dotted.is_empty() {
+                        dotted.push('.');
+                    }
+                    dotted.push_str(key);
+                }
+                Segment::Index(index) => {
+                    dotted.push_str(&format!("[{index}]"));
+                }
+            }
+        }
+        dotted
+    })
+}
+
+/// Swap the path of the current thread, so that a parse starts from the
+/// document root and an enclosing one carries on where it was
+pub(super) fn replace(path: Vec<Segment>) -> Vec<Segment> {
+    PATH.with(|current| current.replace(path))
+}
+
+/// Keeps a segment on the path until dropped, also when decoding fails
+struct Entered;
+
+impl Entered {
+    fn new(segment: Segment) -> Self {
+        PATH.with(|path| path.borrow_mut().push(segment));
+        Self
+    }
+}
+
+impl Drop for Entered {
+    fn drop(&mut self) {
+        PATH.with(|path| path.borrow_mut().pop());
+    }
+}
+
+fn take_key() -> String {
+    KEY.with(|key| key.take()).unwrap_or_default()
+}
+
+/// Deserializer recording the path of the values it decodes
+pub(super) struct Tracked<D> {
+    deserializer: D,
+    /// Whether the value is a map key or enum variant to record
+    key: bool,
+}
+
+impl<D> Tracked<D> {
+    pub(super) fn new(deserializer: D) -> Self {
+        Self {
+            deserializer,
+            key: false,
+        }
+    }
+
+    fn key(deserializer: D) -> Self {
+        Self {
+            deserializer,
+            key: true,
+        }
+    }
+}
+
+macro_rules! forward_deserialize {
+    ( $( $method:ident ( $( $arg:ident : $ty:ty ),* ) ),* $(,)? ) => {
+        $(
+            fn $method<V: Visitor<'de>>(
+                self,
+                $( $arg: $ty, )*
+                visitor: V,
+            ) -> Result<V::Value, Self::Error> {
+                self.deserializer.$method(
+                    $( $arg, )*
+                    Wrap {
+                        visitor,
+                        key: self.key,
+                    },
+                )
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Tracked<D> {
+    type Error = D::Error;
+
+    forward_deserialize!(
+        deserialize_any(),
+        deserialize_bool(),
+        deserialize_i8(),
+        deserialize_i16(),
+        deserialize_i32(),
+        deserialize_i64(),
+        deserialize_i128(),
+        deserialize_u8(),
+        deserialize_u16(),
+        deserialize_u32(),
+        deserialize_u64(),
+        deserialize_u128(),
+        deserialize_f32(),
+        deserialize_f64(),
+        deserialize_char(),
+        deserialize_str(),
+        deserialize_string(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_option(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str),
+        deserialize_seq(),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_struct(
+            name: &'static str,
+            fields: &'static [&'static str]
+        ),
+        deserialize_enum(
+            name: &'static str,
+            variants: &'static [&'static str]
+        ),
+        deserialize_identifier(),
+        deserialize_ignored_any(),
+    );
+
+    fn is_human_readable(&self) -> bool {
+        self.deserializer.is_human_readable()
+    }
+}
+
+/// Seed decoding through [`Tracked`]
+struct TrackedSeed<S> {
+    seed: S,
+    key: bool,
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for TrackedSeed<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.seed.deserialize(match self.key {
+            true => Tracked::key(deserializer),
+            false => Tracked::new(deserializer),
+        })
+    }
+}
+
+/// Visitor handing out tracked accesses, and recording keys
+struct Wrap<V> {
+    visitor: V,
+    key: bool,
+}
+
+impl<V> Wrap<V> {
+    fn record(&self, key: &str) {
+        if self.key {
+            KEY.with(|current| current.replace(Some(key.to_owned())));
+        }
+    }
+}
+
+macro_rules! forward_visit {
+    ( $( $method:ident ( $ty:ty ) ),* $(,)? ) => {
+        $(
+            fn $method<E: de::Error>(self, v: $ty) -> Result<V::Value, E> {
+                self.visitor.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for Wrap<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.visitor.expecting(f)
+    }
+
+    forward_visit!(
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+        visit_byte_buf(Vec<u8>),
+    );
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<V::Value, E> {
+        self.record(&v.to_string());
+        self.visitor.visit_u64(v)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<V::Value, E> {
+        self.record(v);
+        self.visitor.visit_str(v)
+    }
+
+    fn visit_borrowed_str<E: de::Error>(
+        self,
+        v: &'de str,
+    ) -> Result<V::Value, E> {
+        self.record(v);
+        self.visitor.visit_borrowed_str(v)
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<V::Value, E> {
+        self.record(&v);
+        self.visitor.visit_string(v)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<V::Value, E> {
+        self.visitor.visit_none()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<V::Value, D::Error> {
+        self.visitor.visit_some(Tracked::new(deserializer))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<V::Value, E> {
+        self.visitor.visit_unit()
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<V::Value, D::Error> {
+        self.visitor
+            .visit_newtype_struct(Tracked::new(deserializer))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(
+        self,
+        seq: A,
+    ) -> Result<V::Value, A::Error> {
+        self.visitor.visit_seq(Seq { seq, index: 0 })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(
+        self,
+        map: A,
+    ) -> Result<V::Value, A::Error> {
+        self.visitor.visit_map(Map { map, key: None })
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(
+        self,
+        data: A,
+    ) -> Result<V::Value, A::Error> {
+        self.visitor.visit_enum(Enum(data))
+    }
+}
+
+struct Seq<A> {
+    seq: A,
+    index: usize,
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for Seq<A> {
+    type Error = A::Error;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, A::Error> {
+        let _entered = Entered::new(Segment::Index(self.index));
+        self.index += 1;
+
+        self.seq.next_element_seed(TrackedSeed { seed, key: false })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.seq.size_hint()
+    }
+}
+
+struct Map<A> {
+    map: A,
+    key: Option<String>,
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for Map<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, A::Error> {
+        let key = self.map.next_key_seed(TrackedSeed { seed, key: true })?;
+        self.key = Some(take_key());
+
+        Ok(key)
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<S::Value, A::Error> {
+        let key = self.key.take().unwrap_or_default();
+        let _entered = Entered::new(Segment::Key(key));
+
+        self.map.next_value_seed(TrackedSeed { seed, key: false })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.map.size_hint()
+    }
+}
+
+struct Enum<A>(A);
+
+impl<'de, A: EnumAccess<'de>> EnumAccess<'de> for Enum<A> {
+    type Error = A::Error;
+    type Variant = Variant<A::Variant>;
+
+    fn variant_seed<S: DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<(S::Value, Self::Variant), A::Error> {
+        let (value, access) =
+            self.0.variant_seed(TrackedSeed { seed, key: true })?;
+
+        Ok((
+            value,
+            Variant {
+                access,
+                name: take_key(),
+            },
+        ))
+    }
+}
+
+struct Variant<A> {
+    access: A,
+    name: String,
+}
+
+impl<'de, A: VariantAccess<'de>> VariantAccess<'de> for Variant<A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), A::Error> {
+        self.access.unit_variant()
+    }
+
+    fn newtype_variant_seed<S: DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<S::Value, A::Error> {
+        let _entered = Entered::new(Segment::Key(self.name));
+
+        self.access
+            .newtype_variant_seed(TrackedSeed { seed, key: false })
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        let _entered = Entered::new(Segment::Key(self.name));
+
+        self.access.tuple_variant(
+            len,
+            Wrap {
+                visitor,
+                key: false,
+            },
+        )
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        let _entered = Entered::new(Segment::Key(self.name));
+
+        self.access.struct_variant(
+            fields,
+            Wrap {
+                visitor,
+                key: false,
+            },
+        )
+    }
+}
@@ -0,0 +1,422 @@
+//! Path queries over OSCAL documents.
+//!
+//! A query is a dotted path of OSCAL field names, evaluated against the JSON
+//! form of a model. Array fields are flattened, so `controls` selects every
+//! control rather than the array holding them.
+//!
+//! | Syntax             | Selects                                             |
+//! | ------------------ | --------------------------------------------------- |
+//! | `a.b`              | field `b` of every `a`                              |
+//! | `*`                | every field                                         |
+//! | `..b`              | field `b` at any depth                              |
+//! | `a[0]`             | the first `a` of each parent, counting from zero    |
+//! | `a[b]`             | every `a` that has a `b`                            |
+//! | `a[b=v]`           | every `a` with a `b` equal to `v`                   |
+//! | `a[b!=v]`          | every `a` without a `b` equal to `v`                |
+//!
+//! Predicates take relative paths and can be nested or chained, and values
+//! may be quoted with `'` or `"`. A leading `$` for the document root is
+//! optional.
+//!
+//! ```
+//! use roscal_lib::query::Query;
+//! use serde_json::json;
+//!
+//! let catalog = json!({
+//!     "catalog": {
+//!         "controls": [
+//!             { "id": "ac-1", "props": [{ "name": "status", "value": "withdrawn" }] },
+//!             { "id": "ac-2" }
+//!         ]
+//!     }
+//! });
+//!
+//! let query: Query = "..controls[props[name=status][value=withdrawn]].id"
+//!     .parse()
+//!     .unwrap();
+//!
+//! assert_eq!(vec![&json!("ac-1")], query.select(&catalog));
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// Error returned when a query expression can't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryError {
+    position: usize,
+    message: String,
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid query at position {}: {}",
+            self.position, self.message
+        )
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+/// A parsed path expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    steps: Vec<Step>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Step {
+    descendant: bool,
+    name: Option<String>,
+    predicates: Vec<Predicate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Predicate {
+    Index(usize),
+    Exists(Query),
+    Compare {
+        path: Query,
+        value: String,
+        negate: bool,
+    },
+}
+
+impl Query {
+    pub fn parse(expr: &str) -> Result<Self, QueryError> {
+        let mut parser = Parser {
+            input: expr,
+            pos: 0,
+        };
+
+        parser.skip_ws();
+        if parser.eat("$") && !parser.rest().starts_with("..") {
+            parser.eat(".");
+        }
+        parser.skip_ws();
+
+        let query = if parser.rest().is_empty() {
+            Self { steps: vec![] }
+        } else {
+            parser.path()?
+        };
+
+        parser.skip_ws();
+        match parser.peek() {
+            Some(c) => Err(parser.error(format!("unexpected `{c}`"))),
+            None => Ok(query),
+        }
+    }
+
+    /// Nodes of the document matching the query
+    pub fn select<'a>(&self, root: &'a Value) -> Vec<&'a Value> {
+        self.matches(root)
+            .into_iter()
+            .map(|(_, node)| node)
+            .collect()
+    }
+
+    /// JSON pointers of the nodes matching the query, usable with
+    /// [`Value::pointer_mut`] to edit them in place
+    pub fn pointers(&self, root: &Value) -> Vec<String> {
+        self.matches(root)
+            .into_iter()
+            .map(|(pointer, _)| pointer)
+            .collect()
+    }
+
+    fn matches<'a>(&self, root: &'a Value) -> Vec<(String, &'a Value)> {
+        self.steps
+            .iter()
+            .fold(vec![(String::new(), root)], |nodes, step| {
+                nodes
+                    .into_iter()
+                    .flat_map(|node| step.select(node))
+                    .collect()
+            })
+    }
+
+    /// Serialise a model and return its nodes matching the query
+    pub fn select_model<T: Serialize>(
+        &self,
+        model: &T,
+    ) -> Result<Vec<Value>, serde_json::Error> {
+        let root = serde_json::to_value(model)?;
+
+        Ok(self.select(&root).into_iter().cloned().collect())
+    }
+}
+
+impl FromStr for Query {
+    type Err = QueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Step {
+    fn select<'a>(
+        &self,
+        (pointer, node): (String, &'a Value),
+    ) -> Vec<(String, &'a Value)> {
+        let mut nodes = if self.descendant {
+            let mut objects = vec![];
+            descendants(pointer, node, &mut objects);
+
+            objects
+                .into_iter()
+                .flat_map(|object| self.children(object))
+                .collect()
+        } else {
+            self.children((pointer, node))
+        };
+
+        for predicate in &self.predicates {
+            nodes = predicate.filter(nodes);
+        }
+
+        nodes
+    }
+
+    fn children<'a>(
+        &self,
+        (pointer, node): (String, &'a Value),
+    ) -> Vec<(String, &'a Value)> {
+        let Value::Object(object) = node else {
+            return vec![];
+        };
+
+        let values: Vec<(&String, &Value)> = match &self.name {
+            Some(name) => object.get_key_value(name).into_iter().collect(),
+            None => object.iter().collect(),
+        };
+
+        values
+            .into_iter()
+            .flat_map(|(key, value)| {
+                let pointer = format!("{pointer}/{}", escape(key));
+                match value {
+                    Value::Array(items) => items
+                        .iter()
+                        .enumerate()
+                        .map(|(i, item)| (format!("{pointer}/{i}"), item))
+                        .collect(),
+                    _ => vec![(pointer, value)],
+                }
+            })
+            .collect()
+    }
+}
+
+impl Predicate {
+    fn filter<'a>(
+        &self,
+        nodes: Vec<(String, &'a Value)>,
+    ) -> Vec<(String, &'a Value)> {
+        match self {
+            Self::Index(index) => {
+                nodes.into_iter().nth(*index).into_iter().collect()
+            }
+            Self::Exists(path) => nodes
+                .into_iter()
+                .filter(|(_, node)| !path.select(node).is_empty())
+                .collect(),
+            Self::Compare {
+                path,
+                value,
+                negate,
+            } => nodes
+                .into_iter()
+                .filter(|(_, node)| {
+                    let found = path
+                        .select(node)
+                        .into_iter()
+                        .any(|v| scalar_eq(v, value));
+
+                    found != *negate
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The node itself and every object nested in it
+fn descendants<'a>(
+    pointer: String,
+    node: &'a Value,
+    objects: &mut Vec<(String, &'a Value)>,
+) {
+    match node {
+        Value::Object(object) => {
+            objects.push((pointer.clone(), node));
+            object.iter().for_each(|(k, v)| {
+                descendants(format!("{pointer}/{}", escape(k)), v, objects)
+            });
+        }
+        Value::Array(items) => items.iter().enumerate().for_each(|(i, v)| {
+            descendants(format!("{pointer}/{i}"), v, objects)
+        }),
+        _ => {}
+    }
+}
+
+/// Escape a key as a JSON pointer reference token
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn scalar_eq(node: &Value, value: &str) -> bool {
+    match node {
+        Value::String(s) => s == value,
+        Value::Number(n) => n.to_string() == value,
+        Value::Bool(b) => b.to_string() == value,
+        _ => false,
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str {
+        &self.input[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            return true;
+        }
+
+        false
+    }
+
+    fn skip_ws(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &str {
+        let start = self.pos;
+        let len = self.rest().find(|c| !f(c)).unwrap_or(self.rest().len());
+        self.pos += len;
+
+        &self.input[start..self.pos]
+    }
+
+    fn error(&self, message: impl Into<String>) -> QueryError {
+        QueryError {
+            position: self.pos,
+            message: message.into(),
+        }
+    }
+
+    fn path(&mut self) -> Result<Query, QueryError> {
+        let mut steps = vec![];
+        let mut descendant = self.eat("..");
+
+        loop {
+            steps.push(self.step(descendant)?);
+
+            if self.eat("..") {
+                descendant = true;
+            } else if self.eat(".") {
+                descendant = false;
+            } else {
+                break;
+            }
+        }
+
+        Ok(Query { steps })
+    }
+
+    fn step(&mut self, descendant: bool) -> Result<Step, QueryError> {
+        let name = if self.eat("*") {
+            None
+        } else {
+            let name = self
+                .take_while(|c| c.is_alphanumeric() || c == '-' || c == '_');
+            if name.is_empty() {
+                return Err(self.error("expected a field name"));
+            }
+            Some(name.to_owned())
+        };
+
+        let mut predicates = vec![];
+        while self.eat("[") {
+            predicates.extend(self.predicate()?);
+        }
+
+        Ok(Step {
+            descendant,
+            name,
+            predicates,
+        })
+    }
+
+    fn predicate(&mut self) -> Result<Option<Predicate>, QueryError> {
+        self.skip_ws();
+
+        let predicate = if self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            let index = self.take_while(|c| c.is_ascii_digit());
+            let index =
+                index.parse().map_err(|_| self.error("invalid index"))?;
+            Some(Predicate::Index(index))
+        } else if self
+            .rest()
+            .trim_start_matches('*')
+            .trim_start()
+            .starts_with(']')
+            && self.eat("*")
+        {
+            None
+        } else {
+            let path = self.path()?;
+            self.skip_ws();
+
+            let negate = self.eat("!=");
+            if negate || self.eat("=") {
+                self.skip_ws();
+                Some(Predicate::Compare {
+                    path,
+                    value: self.literal()?,
+                    negate,
+                })
+            } else {
+                Some(Predicate::Exists(path))
+            }
+        };
+
+        self.skip_ws();
+        if !self.eat("]") {
+            return Err(self.error("expected `]`"));
+        }
+
+        Ok(predicate)
+    }
+
+    fn literal(&mut self) -> Result<String, QueryError> {
+        for quote in ['\'', '"'] {
+            if self.eat(&quote.to_string()) {
+                let value = self.take_while(|c| c != quote).to_owned();
+                if !self.eat(&quote.to_string()) {
+                    return Err(self.error("unterminated string"));
+                }
+                return Ok(value);
+            }
+        }
+
+        Ok(self.take_while(|c| c != ']').trim_end().to_owned())
+    }
+}
@@ -0,0 +1,118 @@
+use crate::control::{
+    catalog::{Catalog, Control, ControlGroup, Part, Property},
+    param::ParamResolver,
+};
+
+use super::{
+    html_document, push_block, push_heading, push_metadata, MetadataView,
+    Render,
+};
+
+impl Render for Catalog {
+    fn to_markdown(&self) -> String {
+        let catalog = &self.catalog;
+        let mut output = String::new();
+
+        push_metadata(
+            &mut output,
+            &MetadataView {
+                title: &catalog.metadata.title,
+                version: &catalog.metadata.version,
+                oscal_version: &catalog.metadata.oscal_version,
+                last_modified: &catalog.metadata.last_modified,
+                published: catalog.metadata.published.as_ref(),
+                remarks: catalog.metadata.remarks.as_ref(),
+            },
+        );
+
+        let params = ParamResolver::new(self);
+
+        for elt in catalog.controls.iter().flatten() {
+            push_control(&mut output, elt, 2, &params)
+        }
+
+        for elt in catalog.groups.iter().flatten() {
+            push_group(&mut output, elt, 2, &params)
+        }
+
+        output
+    }
+
+    fn to_html(&self) -> String {
+        html_document(&self.catalog.metadata.title, &self.to_markdown())
+    }
+}
+
+fn label_of(props: Option<&Vec<Property>>) -> Option<&str> {
+    props
+        .into_iter()
+        .flatten()
+        .find(|p| p.name == "label" && p.ns.is_none())
+        .map(|p| p.value.as_str())
+}
+
+fn push_group(
+    output: &mut String,
+    group: &ControlGroup,
+    level: usize,
+    params: &ParamResolver,
+) {
+    let heading = match label_of(group.props.as_ref()) {
+        Some(label) => format!("{label} {}", group.title),
+        None => group.title.to_string(),
+    };
+    push_heading(output, level, &heading);
+
+    for part in group.parts.iter().flatten() {
+        push_part(output, part, level + 1, params)
+    }
+
+    for elt in group.controls.iter().flatten() {
+        push_control(output, elt, level + 1, params)
+    }
+
+    for elt in group.groups.iter().flatten() {
+        push_group(output, elt, level + 1, params)
+    }
+}
+
+fn push_control(
+    output: &mut String,
+    control: &Control,
+    level: usize,
+    params: &ParamResolver,
+) {
+    let label = label_of(control.props.as_ref()).unwrap_or(&control.id);
+    push_heading(output, level, &format!("{label} {}", control.title));
+
+    for part in control.parts.iter().flatten() {
+        push_part(output, part, level + 1, params)
+    }
+
+    for elt in control.controls.iter().flatten() {
+        push_control(output, elt, level + 1, params)
+    }
+}
+
+fn push_part(
+    output: &mut String,
+    part: &Part,
+    level: usize,
+    params: &ParamResolver,
+) {
+    let title = match (&part.title, part.name.as_str()) {
+        (Some(title), _) => title.to_string(),
+        (None, "statement") => "Statement".to_owned(),
+        (None, "guidance") => "Guidance".to_owned(),
+        (None, name) => super::humanize(name),
+    };
+    push_heading(output, level, &title);
+
+    push_block(output, &params.render_part(part));
+
+    for elt in part.parts.iter().flatten() {
+        if elt.name != "item" {
+            push_part(output, elt, level + 1, params)
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Render OSCAL models into human-readable Markdown and standalone HTML
+//! documents.
+//!
+//! Markup blocks are written out in their Markdown form, whichever form they
+//! were read in.
+//!
+//! ```no_run
+//! use roscal_lib::{control::catalog::Catalog, render::Render};
+//!
+//! fn render(catalog: &Catalog) {
+//!     let markdown = catalog.to_markdown();
+//!     let html = catalog.to_html();
+//! }
+//! ```
+
+mod catalog;
+mod poam;
+mod ssp;
+
+use std::fmt::Write;
+
+use pulldown_cmark::{html, Event, Options, Parser};
+
+use crate::markup::Markup;
+
+/// Render an OSCAL model as a human-readable document
+pub trait Render {
+    /// Render as a Markdown document
+    fn to_markdown(&self) -> String;
+    /// Render as a standalone HTML document
+    fn to_html(&self) -> String;
+}
+
+/// Metadata fields shared by all rendered models
+pub(crate) struct MetadataView<'a> {
+    pub title: &'a str,
+    pub version: &'a str,
+    pub oscal_version: &'a str,
+    pub last_modified: &'a str,
+    pub published: Option<&'a String>,
+    pub remarks: Option<&'a Markup>,
+}
+
+pub(crate) fn push_metadata(output: &mut String, metadata: &MetadataView) {
+    let _ = writeln!(output, "# {}\n", inline(metadata.title));
+    let _ = writeln!(output, "- **Version:** {}", metadata.version);
+    let _ = writeln!(output, "- **OSCAL Version:** {}", metadata.oscal_version);
+    let _ = writeln!(output, "- **Last Modified:** {}", metadata.last_modified);
+    if let Some(published) = metadata.published {
+        let _ = writeln!(output, "- **Published:** {published}");
+    }
+    output.push('\n');
+
+    if let Some(remarks) = metadata.remarks {
+        push_markup(output, remarks);
+    }
+}
+
+/// Push a markup-multiline value as its own block, in its Markdown form
+pub(crate) fn push_markup(output: &mut String, markup: &Markup) {
+    push_block(output, &markup.to_markdown())
+}
+
+/// Push already rendered Markdown as its own block
+pub(crate) fn push_block(output: &mut String, markup: &str) {
+    let markup = markup.trim();
+    if !markup.is_empty() {
+        output.push_str(markup);
+        output.push_str("\n\n");
+    }
+}
+
+/// Push a heading clamped to the levels Markdown supports
+pub(crate) fn push_heading(output: &mut String, level: usize, text: &str) {
+    let _ = writeln!(
+        output,
+        "{} {}\n",
+        "#".repeat(level.clamp(1, 6)),
+        inline(text)
+    );
+}
+
+/// Collapse a markup-line value onto a single line
+pub(crate) fn inline(markup: &str) -> String {
+    markup.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Escape a value so it can be placed inside a Markdown table cell
+pub(crate) fn table_cell(markup: &str) -> String {
+    markup.trim().replace('|', "\\|").replace('\n', "<br>")
+}
+
+/// Indent continuation lines of a markup value nested in a list item
+pub(crate) fn indent(markup: &str, width: usize) -> String {
+    let pad = " ".repeat(width);
+
+    markup
+        .trim()
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            if idx == 0 || line.is_empty() {
+                line.to_owned()
+            } else {
+                format!("{pad}{line}")
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Turn a kebab-case token or a Debug formatted enum variant into words
+pub(crate) fn humanize(token: &str) -> String {
+    let mut output = String::new();
+
+    for (idx, c) in token.chars().enumerate() {
+        if c == '-' || c == '_' {
+            output.push(' ');
+        } else if c.is_uppercase() && idx > 0 {
+            output.push(' ');
+            output.extend(c.to_lowercase());
+        } else {
+            output.push(c);
+        }
+    }
+
+    output
+}
+
+pub(crate) fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Wrap the rendered Markdown into a standalone HTML document. Raw HTML in
+/// the Markdown is written out as text, so document content can't inject
+/// markup or scripts into the report
+pub(crate) fn html_document(title: &str, markdown: &str) -> String {
+    let mut body = String::new();
+    let parser =
+        Parser::new_ext(markdown, Options::all()).map(|event| match event {
+            Event::Html(html) | Event::InlineHtml(html) => Event::Text(html),
+            event => event,
+        });
+    html::push_html(&mut body, parser);
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{}</title>
+<style>
+body {{ font-family: sans-serif; max-width: 60em; margin: 2em auto; line-height: 1.5; }}
+table {{ border-collapse: collapse; }}
+th, td {{ border: 1px solid #999; padding: 0.25em 0.5em; text-align: left; vertical-align: top; }}
+</style>
+</head>
+<body>
+{}</body>
+</html>
+"#,
+        escape_html(&inline(title)),
+        body
+    )
+}
@@ -0,0 +1,162 @@
+use std::fmt::Write;
+
+use crate::assessment::poam::{
+    IdentifiedRisk, PlanOfActionAndMilestones, PlanOfActionAndMilestonesPoaM,
+    PoaMItem,
+};
+
+use super::{
+    html_document, humanize, inline, push_block, push_heading, push_markup,
+    push_metadata, MetadataView, Render,
+};
+
+impl Render for PlanOfActionAndMilestones {
+    fn to_markdown(&self) -> String {
+        let poam = &self.plan_of_action_and_milestones;
+        let mut output = String::new();
+
+        push_metadata(
+            &mut output,
+            &MetadataView {
+                title: &poam.metadata.title,
+                version: &poam.metadata.version,
+                oscal_version: &poam.metadata.oscal_version,
+                last_modified: &poam.metadata.last_modified,
+                published: poam.metadata.published.as_ref(),
+                remarks: poam.metadata.remarks.as_ref(),
+            },
+        );
+        if let Some(system_id) = &poam.system_id {
+            let _ = writeln!(output, "- **System ID:** {}", system_id.id);
+        }
+        if let Some(import_ssp) = &poam.import_ssp {
+            let _ = writeln!(
+                output,
+                "- **System Security Plan:** {}",
+                import_ssp.href
+            );
+        }
+        output.push('\n');
+
+        push_heading(&mut output, 2, "POA&M Items");
+        for item in &poam.poam_items {
+            push_item(&mut output, item, poam)
+        }
+
+        if let Some(risks) = &poam.risks {
+            push_heading(&mut output, 2, "Risks");
+            for risk in risks {
+                push_risk(&mut output, risk)
+            }
+        }
+
+        if let Some(findings) = &poam.findings {
+            push_heading(&mut output, 2, "Findings");
+            for finding in findings {
+                push_heading(&mut output, 3, &finding.title);
+                let _ = writeln!(
+                    output,
+                    "- **Target:** {}\n",
+                    finding.target.target_id
+                );
+                push_markup(&mut output, &finding.description);
+            }
+        }
+
+        if let Some(observations) = &poam.observations {
+            push_heading(&mut output, 2, "Observations");
+            for observation in observations {
+                push_heading(
+                    &mut output,
+                    3,
+                    observation.title.as_deref().unwrap_or(&observation.uuid),
+                );
+                let _ = writeln!(
+                    output,
+                    "- **Methods:** {}\n- **Collected:** {}\n",
+                    observation.methods.join(", "),
+                    observation.collected
+                );
+                push_block(&mut output, &observation.description);
+            }
+        }
+
+        output
+    }
+
+    fn to_html(&self) -> String {
+        html_document(
+            &self.plan_of_action_and_milestones.metadata.title,
+            &self.to_markdown(),
+        )
+    }
+}
+
+fn push_item(
+    output: &mut String,
+    item: &PoaMItem,
+    poam: &PlanOfActionAndMilestonesPoaM,
+) {
+    push_heading(output, 3, &item.title);
+    push_markup(output, &item.description);
+
+    for related in item.related_risks.iter().flatten() {
+        let title = poam
+            .risks
+            .iter()
+            .flatten()
+            .find(|r| r.uuid == related.risk_uuid)
+            .map(|r| inline(&r.title))
+            .unwrap_or(related.risk_uuid.to_string());
+        let _ = writeln!(output, "- **Related Risk:** {title}");
+    }
+    for related in item.related_findings.iter().flatten() {
+        let title = poam
+            .findings
+            .iter()
+            .flatten()
+            .find(|f| f.uuid == related.finding_uuid)
+            .map(|f| inline(&f.title))
+            .unwrap_or(related.finding_uuid.to_string());
+        let _ = writeln!(output, "- **Related Finding:** {title}");
+    }
+    for related in item.related_observations.iter().flatten() {
+        let title = poam
+            .observations
+            .iter()
+            .flatten()
+            .find(|o| o.uuid == related.observation_uuid)
+            .and_then(|o| o.title.as_ref())
+            .map(|t| inline(t))
+            .unwrap_or(related.observation_uuid.to_string());
+        let _ = writeln!(output, "- **Related Observation:** {title}");
+    }
+    output.push('\n');
+
+    if let Some(remarks) = &item.remarks {
+        push_markup(output, remarks);
+    }
+}
+
+fn push_risk(output: &mut String, risk: &IdentifiedRisk) {
+    push_heading(output, 3, &risk.title);
+    let _ = writeln!(output, "- **Status:** {}", humanize(&risk.status));
+    if let Some(deadline) = &risk.deadline {
+        let _ = writeln!(output, "- **Deadline:** {deadline}");
+    }
+    output.push('\n');
+
+    push_markup(output, &risk.description);
+    push_heading(output, 4, "Statement");
+    push_markup(output, &risk.statement);
+
+    for remediation in risk.remediations.iter().flatten() {
+        push_heading(output, 4, &format!("Remediation: {}", remediation.title));
+        let _ = writeln!(
+            output,
+            "- **Lifecycle:** {}\n",
+            humanize(&remediation.lifecycle)
+        );
+        push_markup(output, &remediation.description);
+    }
+}
@@ -0,0 +1,313 @@
+use std::fmt::Write;
+
+use crate::implementation::ssp::{
+    ComponentControlImplementation, ControlBasedRequirement, ImpactLevel,
+    SetParameterValue, SystemCharacteristics, SystemImplementation,
+    SystemSecurityPlan,
+};
+
+use super::{
+    html_document, humanize, inline, push_block, push_heading, push_markup,
+    push_metadata, table_cell, MetadataView, Render,
+};
+
+impl Render for SystemSecurityPlan {
+    fn to_markdown(&self) -> String {
+        let ssp = &self.system_security_plan;
+        let mut output = String::new();
+
+        push_metadata(
+            &mut output,
+            &MetadataView {
+                title: &ssp.metadata.title,
+                version: &ssp.metadata.version,
+                oscal_version: &ssp.metadata.oscal_version,
+                last_modified: &ssp.metadata.last_modified,
+                published: ssp.metadata.published.as_ref(),
+                remarks: ssp.metadata.remarks.as_ref(),
+            },
+        );
+        let _ =
+            writeln!(output, "- **Profile:** {}\n", ssp.import_profile.href);
+
+        push_characteristics(&mut output, &ssp.system_characteristics);
+        push_implementation(&mut output, &ssp.system_implementation);
+
+        push_heading(&mut output, 2, "Control Implementation");
+        push_markup(&mut output, &ssp.control_implementation.description);
+        push_set_params(
+            &mut output,
+            ssp.control_implementation.set_parameters.as_ref(),
+        );
+
+        for req in &ssp.control_implementation.implemented_requirements {
+            push_requirement(&mut output, req, &ssp.system_implementation)
+        }
+
+        output
+    }
+
+    fn to_html(&self) -> String {
+        html_document(
+            &self.system_security_plan.metadata.title,
+            &self.to_markdown(),
+        )
+    }
+}
+
+fn impact(level: Option<&ImpactLevel>) -> String {
+    level
+        .map(|l| match &l.adjustment_justification {
+            Some(justification) => format!(
+                "{} (adjusted to {}: {})",
+                l.base,
+                l.selected.as_deref().unwrap_or(&l.base),
+                inline(justification)
+            ),
+            None => l.selected.clone().unwrap_or(l.base.clone()),
+        })
+        .unwrap_or_default()
+}
+
+fn push_characteristics(output: &mut String, chars: &SystemCharacteristics) {
+    push_heading(output, 2, "System Characteristics");
+
+    match &chars.system_name_short {
+        Some(short) => {
+            let _ = writeln!(
+                output,
+                "- **System Name:** {} ({short})",
+                chars.system_name
+            );
+        }
+        None => {
+            let _ =
+                writeln!(output, "- **System Name:** {}", chars.system_name);
+        }
+    }
+    for id in &chars.system_ids {
+        let _ = writeln!(output, "- **System ID:** {}", id.id);
+    }
+    let _ = writeln!(
+        output,
+        "- **Status:** {}",
+        humanize(&format!("{:?}", chars.status.state))
+    );
+    if let Some(date) = &chars.date_authorized {
+        let _ = writeln!(output, "- **Date Authorized:** {date}");
+    }
+    if let Some(level) = &chars.security_sensitivity_level {
+        let _ = writeln!(output, "- **Security Sensitivity Level:** {level}");
+    }
+    output.push('\n');
+
+    push_markup(output, &chars.description);
+
+    if let Some(level) = &chars.security_impact_level {
+        push_heading(output, 3, "Security Impact Level");
+        output.push_str("| Objective | Level |\n| --- | --- |\n");
+        let _ = writeln!(
+            output,
+            "| Confidentiality | {} |",
+            level.security_objective_confidentiality
+        );
+        let _ = writeln!(
+            output,
+            "| Integrity | {} |",
+            level.security_objective_integrity
+        );
+        let _ = writeln!(
+            output,
+            "| Availability | {} |\n",
+            level.security_objective_availability
+        );
+    }
+
+    push_heading(output, 3, "Information Types");
+    output.push_str(
+        "| Title | Confidentiality | Integrity | Availability | Description |\n| --- | --- | --- | --- | --- |\n",
+    );
+    for info in &chars.system_information.information_types {
+        let _ = writeln!(
+            output,
+            "| {} | {} | {} | {} | {} |",
+            table_cell(&info.title),
+            impact(info.confidentiality_impact.as_ref()),
+            impact(info.integrity_impact.as_ref()),
+            impact(info.availability_impact.as_ref()),
+            table_cell(&info.description)
+        );
+    }
+    output.push('\n');
+
+    push_heading(output, 3, "Authorization Boundary");
+    push_block(output, &chars.authorization_boundary.description);
+
+    if let Some(network) = &chars.network_architecture {
+        push_heading(output, 3, "Network Architecture");
+        push_block(output, &network.description);
+    }
+
+    if let Some(data_flow) = &chars.data_flow {
+        push_heading(output, 3, "Data Flow");
+        push_block(output, &data_flow.description);
+    }
+}
+
+fn push_implementation(output: &mut String, imp: &SystemImplementation) {
+    push_heading(output, 2, "System Implementation");
+    if let Some(remarks) = &imp.remarks {
+        push_markup(output, remarks);
+    }
+
+    push_heading(output, 3, "Users");
+    output.push_str("| Title | Roles | Description |\n| --- | --- | --- |\n");
+    for user in &imp.users {
+        let _ = writeln!(
+            output,
+            "| {} | {} | {} |",
+            table_cell(
+                user.title
+                    .as_deref()
+                    .or(user.short_name.as_deref())
+                    .unwrap_or(&user.uuid)
+            ),
+            user.role_ids
+                .as_ref()
+                .map(|r| {
+                    r.iter()
+                        .map(|r| r.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                })
+                .unwrap_or_default(),
+            table_cell(user.description.as_deref().unwrap_or_default())
+        );
+    }
+    output.push('\n');
+
+    push_heading(output, 3, "Components");
+    for component in &imp.components {
+        push_heading(output, 4, &component.title);
+        let _ = writeln!(
+            output,
+            "- **Type:** {}\n- **Status:** {}\n- **UUID:** {}\n",
+            component.component_type,
+            humanize(&format!("{:?}", component.status.state)),
+            component.uuid
+        );
+        push_markup(output, &component.description);
+        if let Some(purpose) = &component.purpose {
+            let _ = writeln!(output, "**Purpose:** {}\n", inline(purpose));
+        }
+    }
+
+    if let Some(items) = &imp.inventory_items {
+        push_heading(output, 3, "Inventory");
+        output.push_str(
+            "| UUID | Implemented Components | Description |\n| --- | --- | --- |\n",
+        );
+        for item in items {
+            let components: Vec<&str> = item
+                .implemented_components
+                .iter()
+                .flatten()
+                .map(|c| component_title(imp, &c.component_uuid))
+                .collect();
+            let _ = writeln!(
+                output,
+                "| {} | {} | {} |",
+                item.uuid,
+                table_cell(&components.join(", ")),
+                table_cell(&item.description)
+            );
+        }
+        output.push('\n');
+    }
+}
+
+fn component_title<'a>(
+    imp: &'a SystemImplementation,
+    uuid: &'a str,
+) -> &'a str {
+    imp.components
+        .iter()
+        .find(|c| c.uuid == uuid)
+        .map(|c| c.title.as_str())
+        .unwrap_or(uuid)
+}
+
+fn push_set_params(
+    output: &mut String,
+    params: Option<&Vec<SetParameterValue>>,
+) {
+    let params = match params {
+        Some(params) if !params.is_empty() => params,
+        _ => return,
+    };
+
+    output.push_str("| Parameter | Values |\n| --- | --- |\n");
+    for param in params {
+        let _ = writeln!(
+            output,
+            "| {} | {} |",
+            param.param_id,
+            table_cell(&param.values.join(", "))
+        );
+    }
+    output.push('\n');
+}
+
+fn push_requirement(
+    output: &mut String,
+    req: &ControlBasedRequirement,
+    imp: &SystemImplementation,
+) {
+    push_heading(output, 3, &req.control_id);
+    if let Some(remarks) = &req.remarks {
+        push_markup(output, remarks);
+    }
+    push_set_params(output, req.set_parameters.as_ref());
+
+    for by_component in req.by_components.iter().flatten() {
+        push_by_component(output, by_component, imp, 4)
+    }
+
+    for statement in req.statements.iter().flatten() {
+        push_heading(
+            output,
+            4,
+            &format!("Statement {}", statement.statement_id),
+        );
+        if let Some(remarks) = &statement.remarks {
+            push_markup(output, remarks);
+        }
+
+        for by_component in statement.by_components.iter().flatten() {
+            push_by_component(output, by_component, imp, 5)
+        }
+    }
+}
+
+fn push_by_component(
+    output: &mut String,
+    by_component: &ComponentControlImplementation,
+    imp: &SystemImplementation,
+    level: usize,
+) {
+    push_heading(
+        output,
+        level,
+        component_title(imp, &by_component.component_uuid),
+    );
+
+    if let Some(status) = &by_component.implementation_status {
+        let _ = writeln!(
+            output,
+            "**Implementation Status:** {}\n",
+            humanize(&status.state)
+        );
+    }
+    push_block(output, &by_component.description);
+    push_set_params(output, by_component.set_parameters.as_ref());
+}
@@ -0,0 +1,64 @@
+//! Scalar types of the OSCAL models.
+//!
+//! By default every scalar is a plain `String` checked by the serde hooks.
+//! With the `typed-scalars` feature enabled they become newtypes with
+//! validated constructors, so invalid values can't be built at all. The
+//! serialised form is the same string either way.
+//!
+//! See <https://pages.nist.gov/metaschema/specification/datatypes/>
+//!
+//! ```toml
+//! [dependencies]
+//! roscal_lib = { version = "0.1.0", features = ["typed-scalars"] }
+//! ```
+
+#[cfg(feature = "typed-scalars")]
+mod typed;
+
+#[cfg(feature = "typed-scalars")]
+pub use typed::*;
+
+/// Version 4 or 5 UUID
+#[cfg(not(feature = "typed-scalars"))]
+pub type OscalUuid = String;
+
+/// RFC 3339 date and time with a required timezone
+#[cfg(not(feature = "typed-scalars"))]
+pub type DateTimeWithTz = String;
+
+/// Date with an optional timezone
+#[cfg(not(feature = "typed-scalars"))]
+pub type OscalDate = String;
+
+/// Absolute URI
+#[cfg(not(feature = "typed-scalars"))]
+pub type Uri = String;
+
+/// URI reference, relative or absolute
+#[cfg(not(feature = "typed-scalars"))]
+pub type UriReference = String;
+
+/// Non-colonized name
+#[cfg(not(feature = "typed-scalars"))]
+pub type Token = String;
+
+#[cfg(not(feature = "typed-scalars"))]
+pub type EmailAddress = String;
+
+/// Random version 4 UUID
+pub(crate) fn new_uuid_v4() -> OscalUuid {
+    Scalar::from_validated(uuid::Uuid::new_v4().to_string())
+}
+
+/// String backed scalar whose value has already been validated
+pub(crate) trait Scalar:
+    std::ops::Deref<Target = str> + serde::Serialize + Sized
+{
+    fn from_validated(value: String) -> Self;
+}
+
+impl Scalar for String {
+    fn from_validated(value: String) -> Self {
+        value
+    }
+}
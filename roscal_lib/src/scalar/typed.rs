@@ -0,0 +1,259 @@
+use std::{fmt, ops::Deref, str::FromStr};
+
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use uriparse::{uri::URI, uri_reference::URIReference};
+
+use crate::validation::{
+    is_valid_date, is_valid_dttz, is_valid_email, is_valid_token, is_valid_uri,
+    is_valid_uri_ref, is_valid_uuid,
+};
+
+use super::Scalar;
+
+/// Error returned when a value does not match its scalar type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScalarError {
+    kind: &'static str,
+    value: String,
+}
+
+impl fmt::Display for ScalarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {} pattern: `{}`", self.kind, self.value)
+    }
+}
+
+impl std::error::Error for ScalarError {}
+
+macro_rules! impl_scalar {
+    ( $( $(#[$doc:meta])* $t:ident, $kind:literal, $is_valid:expr; )* ) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub struct $t(String);
+
+            impl $t {
+                pub fn new(value: impl Into<String>) -> Result<Self, ScalarError> {
+                    let value = value.into();
+                    let is_valid: fn(&str) -> bool = $is_valid;
+
+                    if is_valid(&value) {
+                        Ok(Self(value))
+                    } else {
+                        Err(ScalarError { kind: $kind, value })
+                    }
+                }
+
+                pub fn as_str(&self) -> &str {
+                    &self.0
+                }
+
+                pub fn into_inner(self) -> String {
+                    self.0
+                }
+            }
+
+            impl Scalar for $t {
+                fn from_validated(value: String) -> Self {
+                    Self(value)
+                }
+            }
+
+            impl Deref for $t {
+                type Target = str;
+
+                fn deref(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl AsRef<str> for $t {
+                fn as_ref(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl fmt::Display for $t {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str(&self.0)
+                }
+            }
+
+            impl FromStr for $t {
+                type Err = ScalarError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    Self::new(s)
+                }
+            }
+
+            impl TryFrom<String> for $t {
+                type Error = ScalarError;
+
+                fn try_from(value: String) -> Result<Self, Self::Error> {
+                    Self::new(value)
+                }
+            }
+
+            impl TryFrom<&str> for $t {
+                type Error = ScalarError;
+
+                fn try_from(value: &str) -> Result<Self, Self::Error> {
+                    Self::new(value)
+                }
+            }
+
+            impl From<&$t> for $t {
+                fn from(value: &$t) -> Self {
+                    value.clone()
+                }
+            }
+
+            impl From<$t> for String {
+                fn from(value: $t) -> Self {
+                    value.0
+                }
+            }
+
+            impl PartialEq<str> for $t {
+                fn eq(&self, other: &str) -> bool {
+                    self.0 == other
+                }
+            }
+
+            impl PartialEq<&str> for $t {
+                fn eq(&self, other: &&str) -> bool {
+                    self.0 == *other
+                }
+            }
+
+            impl PartialEq<String> for $t {
+                fn eq(&self, other: &String) -> bool {
+                    &self.0 == other
+                }
+            }
+
+            impl PartialEq<$t> for str {
+                fn eq(&self, other: &$t) -> bool {
+                    self == other.0
+                }
+            }
+
+            impl PartialEq<$t> for &str {
+                fn eq(&self, other: &$t) -> bool {
+                    *self == other.0
+                }
+            }
+
+            impl PartialEq<$t> for String {
+                fn eq(&self, other: &$t) -> bool {
+                    self == &other.0
+                }
+            }
+
+            impl Serialize for $t {
+                fn serialize<S: Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_str(&self.0)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $t {
+                fn deserialize<D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    let value = String::deserialize(deserializer)?;
+
+                    Self::new(value).map_err(de::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(
+    /// Version 4 or 5 UUID
+    OscalUuid, "uuid", is_valid_uuid;
+    /// RFC 3339 date and time with a required timezone
+    DateTimeWithTz, "datetime", is_valid_dttz;
+    /// Date with an optional timezone
+    OscalDate, "date", is_valid_date;
+    /// Absolute URI
+    Uri, "uri", is_valid_uri;
+    /// URI reference, relative or absolute
+    UriReference, "uri reference", |s| is_valid_uri(s) || is_valid_uri_ref(s);
+    /// Non-colonized name
+    Token, "token", is_valid_token;
+    EmailAddress, "email", is_valid_email;
+);
+
+impl OscalUuid {
+    /// Random UUID
+    pub fn new_v4() -> Self {
+        Self(uuid::Uuid::new_v4().to_string())
+    }
+
+    /// Name based UUID
+    pub fn new_v5(namespace: &uuid::Uuid, name: &[u8]) -> Self {
+        Self(uuid::Uuid::new_v5(namespace, name).to_string())
+    }
+
+    pub fn to_uuid(&self) -> uuid::Uuid {
+        uuid::Uuid::parse_str(&self.0).expect("uuid is validated")
+    }
+}
+
+impl TryFrom<uuid::Uuid> for OscalUuid {
+    type Error = ScalarError;
+
+    fn try_from(value: uuid::Uuid) -> Result<Self, Self::Error> {
+        Self::new(value.to_string())
+    }
+}
+
+impl DateTimeWithTz {
+    pub fn to_datetime(&self) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(&self.0).expect("datetime is validated")
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for DateTimeWithTz
+where
+    Tz::Offset: fmt::Display,
+{
+    fn from(value: DateTime<Tz>) -> Self {
+        Self(value.to_rfc3339())
+    }
+}
+
+impl OscalDate {
+    /// The date without its timezone
+    pub fn to_naive_date(&self) -> NaiveDate {
+        NaiveDate::parse_from_str(&self.0[..10], "%Y-%m-%d")
+            .expect("date is validated")
+    }
+}
+
+impl TryFrom<NaiveDate> for OscalDate {
+    type Error = ScalarError;
+
+    fn try_from(value: NaiveDate) -> Result<Self, Self::Error> {
+        Self::new(value.format("%Y-%m-%d").to_string())
+    }
+}
+
+impl Uri {
+    pub fn to_uri(&self) -> URI<'_> {
+        URI::try_from(self.0.as_str()).expect("uri is validated")
+    }
+}
+
+impl UriReference {
+    pub fn to_uri_reference(&self) -> URIReference<'_> {
+        URIReference::try_from(self.0.as_str())
+            .expect("uri reference is validated")
+    }
+}
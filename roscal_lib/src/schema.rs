@@ -0,0 +1,516 @@
+//! Validation against the bundled official OSCAL JSON schemas.
+//!
+//! This is independent of the serde models, so it can serve as a second
+//! opinion on a document. It is not a complete draft-07 validator: only the
+//! keywords the NIST schemas rely on are checked, which are `$ref`, `type`,
+//! `properties`, `required`, `additionalProperties`, `items`, `minItems`,
+//! `enum`, `pattern`, `minimum` and the `allOf`, `anyOf` and `oneOf`
+//! combinators. A value matching none of the branches of a combinator is
+//! reported with the first reason each branch gave.
+//!
+//! The other keywords are ignored:
+//!
+//! - `format` and `contentEncoding`, which the NIST schemas use. The
+//!   `date-time`, `email` and `uri` formats and the `base64` encoding come
+//!   with a pattern, which is checked instead, but `uri-reference` values are
+//!   not checked at all.
+//! - `const`, `not`, `if`, `then`, `else`, `maxItems`, `uniqueItems`,
+//!   `contains`, `additionalItems`, `minLength`, `maxLength`, `maximum`,
+//!   `exclusiveMinimum`, `exclusiveMaximum`, `multipleOf`, `minProperties`,
+//!   `maxProperties`, `patternProperties`, `propertyNames`, `dependencies` and
+//!   `contentMediaType`, which they don't use.
+//!
+//! ```
+//! use roscal_lib::{document::ModelType, schema};
+//! use serde_json::json;
+//!
+//! let catalog = json!({
+//!     "catalog": {
+//!         "uuid": "not-a-uuid",
+//!         "metadata": {
+//!             "title": "Example catalog",
+//!             "last-modified": "2023-12-31T23:59:59Z",
+//!             "version": "1",
+//!             "oscal-version": "1.1.2"
+//!         },
+//!         "colour": "red"
+//!     }
+//! });
+//!
+//! let violations = schema::validate(ModelType::Catalog, &catalog).unwrap();
+//! let pointers: Vec<&str> = violations.iter().map(|v| v.pointer.as_str()).collect();
+//! assert_eq!(vec!["/catalog/uuid", "/catalog/colour"], pointers);
+//! ```
+
+use std::{collections::HashMap, fmt};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde_json::Value;
+
+use crate::document::{DocumentError, ModelType};
+
+lazy_static! {
+    static ref SCHEMAS: HashMap<ModelType, Result<Schema, String>> =
+        ModelType::ALL
+            .into_iter()
+            .map(|model_type| (model_type, Schema::load(model_type)))
+            .collect();
+}
+
+/// Error returned when a document can't be checked against its schema
+#[derive(Debug)]
+pub enum SchemaError {
+    /// The model of the document could not be detected, or the document read
+    Document(DocumentError),
+    /// The bundled schema of the model could not be loaded, for the given
+    /// reason
+    InvalidSchema(ModelType, String),
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaError::Document(e) => e.fmt(f),
+            SchemaError::InvalidSchema(model_type, reason) => {
+                write!(f, "invalid {model_type} schema: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SchemaError::Document(e) => Some(e),
+            SchemaError::InvalidSchema(..) => None,
+        }
+    }
+}
+
+impl From<DocumentError> for SchemaError {
+    fn from(e: DocumentError) -> Self {
+        SchemaError::Document(e)
+    }
+}
+
+/// A place where a document breaks its schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// JSON pointer to the offending value, empty for the document root
+    pub pointer: String,
+    pub message: String,
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.pointer.is_empty() {
+            write!(f, "(root): {}", self.message)
+        } else {
+            write!(f, "{}: {}", self.pointer, self.message)
+        }
+    }
+}
+
+/// Check a document in its JSON form against the schema of its model
+pub fn validate(
+    model_type: ModelType,
+    document: &Value,
+) -> Result<Vec<SchemaViolation>, SchemaError> {
+    let schema = SCHEMAS[&model_type].as_ref().map_err(|reason| {
+        SchemaError::InvalidSchema(model_type, reason.to_owned())
+    })?;
+    let mut violations = vec![];
+    schema.check(&schema.root, document, "", &mut violations);
+
+    Ok(violations)
+}
+
+/// Detect the model of a JSON or YAML document and check it against its
+/// schema
+pub fn validate_str(input: &str) -> Result<Vec<SchemaViolation>, SchemaError> {
+    let model_type = ModelType::detect(input)?;
+    let document: Value =
+        serde_yaml::from_str(input).map_err(DocumentError::from)?;
+
+    validate(model_type, &document)
+}
+
+/// Raw schema of a model, as shipped by NIST
+pub fn schema_source(model_type: ModelType) -> &'static str {
+    match model_type {
+        ModelType::AssessmentPlan => include_str!(
+            "../jsonschema/tracking_latest_models/oscal_assessment-plan_schema.json"
+        ),
+        ModelType::AssessmentResults => include_str!(
+            "../jsonschema/tracking_latest_models/oscal_assessment-results_schema.json"
+        ),
+        ModelType::PlanOfActionAndMilestones => include_str!(
+            "../jsonschema/tracking_latest_models/oscal_poam_schema.json"
+        ),
+        ModelType::Catalog => include_str!(
+            "../jsonschema/tracking_latest_models/oscal_catalog_schema.json"
+        ),
+        ModelType::Profile => include_str!(
+            "../jsonschema/tracking_latest_models/oscal_profile_schema.json"
+        ),
+        ModelType::ComponentDefinition => include_str!(
+            "../jsonschema/tracking_latest_models/oscal_component_schema.json"
+        ),
+        ModelType::SystemSecurityPlan => include_str!(
+            "../jsonschema/tracking_latest_models/oscal_ssp_schema.json"
+        ),
+    }
+}
+
+/// A parsed schema with its `$id` anchors and patterns resolved up front
+struct Schema {
+    root: Value,
+    anchors: HashMap<String, String>,
+    patterns: HashMap<String, Regex>,
+}
+
+impl Schema {
+    fn load(model_type: ModelType) -> Result<Self, String> {
+        let root: Value = serde_json::from_str(schema_source(model_type))
+            .map_err(|e| e.to_string())?;
+        let mut schema = Self {
+            root: Value::Null,
+            anchors: HashMap::new(),
+            patterns: HashMap::new(),
+        };
+        schema.index(&root, "")?;
+        schema.root = root;
+
+        Ok(schema)
+    }
+
+    fn index(&mut self, node: &Value, pointer: &str) -> Result<(), String> {
+        match node {
+            Value::Object(object) => {
+                if let Some(Value::String(id)) = object.get("$id") {
+                    if id.starts_with('#') {
+                        self.anchors.insert(id.to_owned(), pointer.to_owned());
+                    }
+                }
+                if let Some(Value::String(pattern)) = object.get("pattern") {
+                    let regex = Regex::new(pattern).map_err(|e| {
+                        format!("pattern at {pointer} does not compile: {e}")
+                    })?;
+                    self.patterns.insert(pattern.to_owned(), regex);
+                }
+                for (key, child) in object {
+                    self.index(child, &join(pointer, key))?;
+                }
+            }
+            Value::Array(items) => {
+                for (index, child) in items.iter().enumerate() {
+                    self.index(child, &join(pointer, &index.to_string()))?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn resolve(&self, reference: &str) -> Option<&Value> {
+        match reference.strip_prefix('#') {
+            Some(pointer) if pointer.starts_with('/') => {
+                self.root.pointer(pointer)
+            }
+            _ => self
+                .anchors
+                .get(reference)
+                .and_then(|pointer| self.root.pointer(pointer)),
+        }
+    }
+
+    fn check(
+        &self,
+        schema: &Value,
+        value: &Value,
+        pointer: &str,
+        out: &mut Vec<SchemaViolation>,
+    ) {
+        let Value::Object(schema) = schema else {
+            return;
+        };
+
+        // Draft-07 ignores the siblings of a reference
+        if let Some(Value::String(reference)) = schema.get("$ref") {
+            match self.resolve(reference) {
+                Some(target) => self.check(target, value, pointer, out),
+                None => violation(
+                    out,
+                    pointer,
+                    format!("unresolved reference `{reference}`"),
+                ),
+            }
+            return;
+        }
+
+        if let Some(expected) = schema.get("type") {
+            let matches = match expected {
+                Value::Array(types) => types.iter().any(|t| is_type(t, value)),
+                t => is_type(t, value),
+            };
+            if !matches {
+                violation(
+                    out,
+                    pointer,
+                    format!(
+                        "expected {}, found {}",
+                        type_names(expected),
+                        type_name(value)
+                    ),
+                );
+                return;
+            }
+        }
+
+        if let Some(Value::Array(allowed)) = schema.get("enum") {
+            if !allowed.contains(value) {
+                violation(
+                    out,
+                    pointer,
+                    format!(
+                        "{value} is not one of {}",
+                        Value::Array(allowed.clone())
+                    ),
+                );
+            }
+        }
+
+        if let (Some(Value::String(pattern)), Value::String(s)) =
+            (schema.get("pattern"), value)
+        {
+            if self.patterns.get(pattern).is_some_and(|re| !re.is_match(s)) {
+                violation(
+                    out,
+                    pointer,
+                    format!("{value} does not match `{pattern}`"),
+                );
+            }
+        }
+
+        if let (Some(minimum), Some(n)) = (
+            schema.get("minimum").and_then(Value::as_f64),
+            value.as_f64(),
+        ) {
+            if n < minimum {
+                violation(
+                    out,
+                    pointer,
+                    format!("{n} is less than the minimum {minimum}"),
+                );
+            }
+        }
+
+        if let Value::Array(items) = value {
+            if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+                if (items.len() as u64) < min {
+                    violation(
+                        out,
+                        pointer,
+                        format!("expected at least {min} item(s)"),
+                    );
+                }
+            }
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    self.check(
+                        item_schema,
+                        item,
+                        &join(pointer, &index.to_string()),
+                        out,
+                    );
+                }
+            }
+        }
+
+        if let Value::Object(object) = value {
+            self.check_object(schema, object, pointer, out);
+        }
+
+        self.check_combinators(schema, value, pointer, out);
+    }
+
+    fn check_object(
+        &self,
+        schema: &serde_json::Map<String, Value>,
+        object: &serde_json::Map<String, Value>,
+        pointer: &str,
+        out: &mut Vec<SchemaViolation>,
+    ) {
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for key in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(key) {
+                    violation(
+                        out,
+                        pointer,
+                        format!("missing required property `{key}`"),
+                    );
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (key, child) in object {
+            let child_pointer = join(pointer, key);
+            match properties.and_then(|p| p.get(key)) {
+                Some(child_schema) => {
+                    self.check(child_schema, child, &child_pointer, out)
+                }
+                None => match schema.get("additionalProperties") {
+                    Some(Value::Bool(false)) => violation(
+                        out,
+                        &child_pointer,
+                        "additional property is not allowed".to_owned(),
+                    ),
+                    Some(extra @ Value::Object(_)) => {
+                        self.check(extra, child, &child_pointer, out)
+                    }
+                    _ => {}
+                },
+            }
+        }
+    }
+
+    fn check_combinators(
+        &self,
+        schema: &serde_json::Map<String, Value>,
+        value: &Value,
+        pointer: &str,
+        out: &mut Vec<SchemaViolation>,
+    ) {
+        if let Some(Value::Array(all)) = schema.get("allOf") {
+            for branch in all {
+                self.check(branch, value, pointer, out);
+            }
+        }
+
+        // Violations of each branch, to explain a combinator failing
+        let branches = |branches: &[Value]| -> Vec<Vec<SchemaViolation>> {
+            branches
+                .iter()
+                .map(|branch| {
+                    let mut scratch = vec![];
+                    self.check(branch, value, pointer, &mut scratch);
+                    scratch
+                })
+                .collect()
+        };
+
+        if let Some(Value::Array(any)) = schema.get("anyOf") {
+            let results = branches(any);
+            if results.iter().all(|r| !r.is_empty()) {
+                violation(
+                    out,
+                    pointer,
+                    format!(
+                        "does not match any of the allowed schemas ({})",
+                        reasons(&results, pointer)
+                    ),
+                );
+            }
+        }
+
+        if let Some(Value::Array(one)) = schema.get("oneOf") {
+            let results = branches(one);
+            let passing: Vec<String> = results
+                .iter()
+                .enumerate()
+                .filter(|(_, r)| r.is_empty())
+                .map(|(index, _)| (index + 1).to_string())
+                .collect();
+            match passing.len() {
+                1 => {}
+                0 => violation(
+                    out,
+                    pointer,
+                    format!(
+                        "does not match any of the alternatives ({})",
+                        reasons(&results, pointer)
+                    ),
+                ),
+                _ => violation(
+                    out,
+                    pointer,
+                    format!(
+                        "matches alternatives {} instead of one",
+                        passing.join(", ")
+                    ),
+                ),
+            }
+        }
+    }
+}
+
+/// First reason each branch of a combinator gave, numbered from one. The
+/// pointer is only given when the reason is about a nested value
+fn reasons(results: &[Vec<SchemaViolation>], pointer: &str) -> String {
+    results
+        .iter()
+        .enumerate()
+        .filter_map(|(index, violations)| {
+            let first = violations.first()?;
+            Some(match first.pointer == pointer {
+                true => format!("{}: {}", index + 1, first.message),
+                false => format!("{}: {first}", index + 1),
+            })
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+fn violation(out: &mut Vec<SchemaViolation>, pointer: &str, message: String) {
+    out.push(SchemaViolation {
+        pointer: pointer.to_owned(),
+        message,
+    })
+}
+
+/// Append a key to a JSON pointer, escaping `~` and `/`
+fn join(pointer: &str, key: &str) -> String {
+    format!("{pointer}/{}", key.replace('~', "~0").replace('/', "~1"))
+}
+
+fn is_type(expected: &Value, value: &Value) -> bool {
+    match expected.as_str() {
+        Some("object") => value.is_object(),
+        Some("array") => value.is_array(),
+        Some("string") => value.is_string(),
+        Some("number") => value.is_number(),
+        Some("integer") => {
+            value.is_i64()
+                || value.is_u64()
+                || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        Some("boolean") => value.is_boolean(),
+        Some("null") => value.is_null(),
+        _ => true,
+    }
+}
+
+fn type_names(expected: &Value) -> String {
+    match expected {
+        Value::Array(types) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>()
+            .join(" or "),
+        t => t.as_str().unwrap_or_default().to_owned(),
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
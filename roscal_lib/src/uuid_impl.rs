@@ -1,4 +1,10 @@
-use crate::{assessment::*, control::*, implementation::*, UpdateUuid};
+use crate::{
+    assessment::*,
+    control::*,
+    implementation::*,
+    scalar::{self, Scalar},
+    UpdateUuid,
+};
 
 macro_rules! impl_update_uuid {
     ( $( $t:ty ),* ) => {
@@ -6,7 +12,7 @@ macro_rules! impl_update_uuid {
             impl UpdateUuid for $t {
                 fn update_uuid_v4(&mut self, rhs: &Self) -> &mut Self {
                     if self != rhs {
-                        self.uuid = String::from(uuid::Uuid::new_v4());
+                        self.uuid = scalar::new_uuid_v4();
 
                         return self
                     }
@@ -16,10 +22,13 @@ macro_rules! impl_update_uuid {
 
                 fn update_uuid_v5(&mut self, rhs: &Self) -> &mut Self {
                     if self != rhs {
-                        self.uuid = String::from(uuid::Uuid::new_v5(
-                            &uuid::Uuid::NAMESPACE_URL,
-                            b"http://csrc.nist.gov/ns/oscal",
-                        ));
+                        self.uuid = Scalar::from_validated(
+                            uuid::Uuid::new_v5(
+                                &uuid::Uuid::NAMESPACE_URL,
+                                b"http://csrc.nist.gov/ns/oscal",
+                            )
+                            .to_string(),
+                        );
 
                         return self
                     }
@@ -1,60 +1,73 @@
-use std::env;
-
 use chrono::prelude::*;
 use lazy_static::lazy_static;
-use pulldown_cmark::{Options, Parser};
 use regex::Regex;
 use serde::{de, ser::SerializeSeq, Deserialize, Serializer};
 use uriparse::{uri::URI, uri_reference::URIReference};
 
+use crate::{
+    hash::HashAlgorithm,
+    parse::{self, Extensions, ParseWarning, UnknownFields},
+    scalar::Scalar,
+};
+
 lazy_static! {
     static ref BASE64: Regex = Regex::new(r"^[0-9A-Za-z+/]+={0,2}$").unwrap();
     static ref DATE: Regex = Regex::new(r"^(((2000|2400|2800|(19|2[0-9](0[48]|[2468][048]|[13579][26])))-02-29)|(((19|2[0-9])[0-9]{2})-02-(0[1-9]|1[0-9]|2[0-8]))|(((19|2[0-9])[0-9]{2})-(0[13578]|10|12)-(0[1-9]|[12][0-9]|3[01]))|(((19|2[0-9])[0-9]{2})-(0[469]|11)-(0[1-9]|[12][0-9]|30)))(Z|[+-][0-9]{2}:[0-9]{2})?$").unwrap();
     static ref EMAIL: Regex = Regex::new(r"^.+@.+$").unwrap();
     static ref TOKEN: Regex =
         Regex::new(r"^(\p{L}|_)(\p{L}|\p{N}|[.\-_])*$").unwrap();
-    static ref SHA_SHA3_224: Regex = Regex::new(r"^[0-9a-fA-F]{28}$").unwrap();
-    static ref SHA_SHA3_256: Regex = Regex::new(r"^[0-9a-fA-F]{32}$").unwrap();
-    static ref SHA_SHA3_384: Regex = Regex::new(r"^[0-9a-fA-F]{48}$").unwrap();
-    static ref SHA_SHA3_512: Regex = Regex::new(r"^[0-9a-fA-F]{64}$").unwrap();
+    static ref HEX: Regex = Regex::new(r"^[0-9a-fA-F]+$").unwrap();
 }
 
 fn is_valid_based64(pat: &str) -> bool {
     BASE64.is_match(pat)
 }
 
-fn is_valid_date(pat: &str) -> bool {
+pub(crate) fn is_valid_date(pat: &str) -> bool {
     DATE.is_match(pat)
 }
 
-fn is_valid_dttz(pat: &str) -> bool {
+pub(crate) fn is_valid_dttz(pat: &str) -> bool {
     DateTime::parse_from_rfc3339(pat).is_ok()
 }
 
-fn is_valid_email(pat: &str) -> bool {
+pub(crate) fn is_valid_email(pat: &str) -> bool {
     EMAIL.is_match(pat)
 }
 
 fn is_valid_hash(pat: &str) -> bool {
-    SHA_SHA3_224.is_match(pat)
-        || SHA_SHA3_256.is_match(pat)
-        || SHA_SHA3_384.is_match(pat)
-        || SHA_SHA3_512.is_match(pat)
+    HEX.is_match(pat)
+}
+
+/// A digest must be as long as the output of its algorithm, when OSCAL
+/// knows the algorithm
+pub(crate) fn is_valid_digest(
+    algorithm: &str,
+    value: &str,
+) -> Result<(), String> {
+    match HashAlgorithm::from_name(algorithm) {
+        Some(a) if value.len() != a.hex_len() => Err(format!(
+            "invalid {a} digest: expected {} hex characters, found {}",
+            a.hex_len(),
+            value.len()
+        )),
+        _ => Ok(()),
+    }
 }
 
-fn is_valid_token(pat: &str) -> bool {
+pub(crate) fn is_valid_token(pat: &str) -> bool {
     TOKEN.is_match(pat)
 }
 
-fn is_valid_uri(pat: &str) -> bool {
+pub(crate) fn is_valid_uri(pat: &str) -> bool {
     URI::try_from(pat).is_ok()
 }
 
-fn is_valid_uri_ref(pat: &str) -> bool {
+pub(crate) fn is_valid_uri_ref(pat: &str) -> bool {
     URIReference::try_from(pat).is_ok()
 }
 
-fn is_valid_uuid(pat: &str) -> bool {
+pub(crate) fn is_valid_uuid(pat: &str) -> bool {
     let res = uuid::Uuid::try_parse(pat);
     if let Ok(res) = res {
         if let Some(version) = res.clone().get_version() {
@@ -105,8 +118,8 @@ pub(crate) fn ser_date<S: Serializer>(
     }
 }
 
-pub(crate) fn ser_date_opt<S: Serializer>(
-    s: &Option<String>,
+pub(crate) fn ser_date_opt<S: Serializer, T: Scalar>(
+    s: &Option<T>,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
     if let Some(s) = s {
@@ -120,14 +133,16 @@ pub(crate) fn ser_date_opt<S: Serializer>(
     }
 }
 
-pub(crate) fn deser_date<'de, D>(deserializer: D) -> Result<String, D::Error>
+pub(crate) fn deser_date<'de, D, T: Scalar>(
+    deserializer: D,
+) -> Result<T, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match String::deserialize(deserializer) {
         Ok(v) => {
             if is_valid_date(&v) {
-                Ok(v)
+                Ok(T::from_validated(v))
             } else {
                 Err(de::Error::custom("invalid date pattern".to_owned()))
             }
@@ -136,16 +151,16 @@ where
     }
 }
 
-pub(crate) fn deser_date_opt<'de, D>(
+pub(crate) fn deser_date_opt<'de, D, T: Scalar>(
     deserializer: D,
-) -> Result<Option<String>, D::Error>
+) -> Result<Option<T>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match Option::<String>::deserialize(deserializer) {
         Ok(Some(v)) => {
             if is_valid_date(&v) {
-                Ok(Some(v))
+                Ok(Some(T::from_validated(v)))
             } else {
                 Err(de::Error::custom("invalid date pattern".to_owned()))
             }
@@ -166,8 +181,8 @@ pub(crate) fn ser_dttz<S: Serializer>(
     }
 }
 
-pub(crate) fn ser_dttz_opt<S: Serializer>(
-    s: &Option<String>,
+pub(crate) fn ser_dttz_opt<S: Serializer, T: Scalar>(
+    s: &Option<T>,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
     if let Some(s) = s {
@@ -181,14 +196,16 @@ pub(crate) fn ser_dttz_opt<S: Serializer>(
     }
 }
 
-pub(crate) fn deser_dttz<'de, D>(deserializer: D) -> Result<String, D::Error>
+pub(crate) fn deser_dttz<'de, D, T: Scalar>(
+    deserializer: D,
+) -> Result<T, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match String::deserialize(deserializer) {
         Ok(v) => {
             if is_valid_dttz(&v) {
-                Ok(v)
+                Ok(T::from_validated(v))
             } else {
                 Err(de::Error::custom("invalid datetime pattern".to_owned()))
             }
@@ -197,16 +214,16 @@ where
     }
 }
 
-pub(crate) fn deser_dttz_opt<'de, D>(
+pub(crate) fn deser_dttz_opt<'de, D, T: Scalar>(
     deserializer: D,
-) -> Result<Option<String>, D::Error>
+) -> Result<Option<T>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match Option::<String>::deserialize(deserializer) {
         Ok(Some(v)) => {
             if is_valid_dttz(&v) {
-                Ok(Some(v))
+                Ok(Some(T::from_validated(v)))
             } else {
                 Err(de::Error::custom("invalid datetime pattern".to_owned()))
             }
@@ -216,8 +233,8 @@ where
     }
 }
 
-pub(crate) fn ser_email_vec_opt<S: Serializer>(
-    s: &Option<Vec<String>>,
+pub(crate) fn ser_email_vec_opt<S: Serializer, T: Scalar>(
+    s: &Option<Vec<T>>,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
     if let Some(s) = s {
@@ -235,16 +252,16 @@ pub(crate) fn ser_email_vec_opt<S: Serializer>(
     }
 }
 
-pub(crate) fn deser_email_vec_opt<'de, D>(
+pub(crate) fn deser_email_vec_opt<'de, D, T: Scalar>(
     deserializer: D,
-) -> Result<Option<Vec<String>>, D::Error>
+) -> Result<Option<Vec<T>>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match Option::<Vec<String>>::deserialize(deserializer) {
         Ok(Some(v)) => {
             if v.iter().all(|s| is_valid_email(s)) {
-                Ok(Some(v))
+                Ok(Some(v.into_iter().map(T::from_validated).collect()))
             } else {
                 Err(de::Error::custom("invalid email pattern".to_owned()))
             }
@@ -261,7 +278,7 @@ pub(crate) fn ser_hash<S: Serializer>(
     if is_valid_hash(s) {
         serializer.serialize_str(s)
     } else {
-        Err(serde::ser::Error::custom("invalid rlink hash pattern"))
+        Err(serde::ser::Error::custom("invalid hash pattern"))
     }
 }
 
@@ -274,7 +291,7 @@ where
             if is_valid_hash(&v) {
                 Ok(v)
             } else {
-                Err(de::Error::custom("invalid rlink hash pattern".to_owned()))
+                Err(de::Error::custom("invalid hash pattern".to_owned()))
             }
         }
         Err(e) => Err(de::Error::custom(e.to_string())),
@@ -363,8 +380,8 @@ pub(crate) fn ser_token<S: Serializer>(
     }
 }
 
-pub(crate) fn ser_token_opt<S: Serializer>(
-    s: &Option<String>,
+pub(crate) fn ser_token_opt<S: Serializer, T: Scalar>(
+    s: &Option<T>,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
     if let Some(s) = s {
@@ -378,8 +395,8 @@ pub(crate) fn ser_token_opt<S: Serializer>(
     }
 }
 
-pub(crate) fn ser_token_vec_opt<S: Serializer>(
-    s: &Option<Vec<String>>,
+pub(crate) fn ser_token_vec_opt<S: Serializer, T: Scalar>(
+    s: &Option<Vec<T>>,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
     if let Some(s) = s {
@@ -397,14 +414,16 @@ pub(crate) fn ser_token_vec_opt<S: Serializer>(
     }
 }
 
-pub(crate) fn deser_token<'de, D>(deserializer: D) -> Result<String, D::Error>
+pub(crate) fn deser_token<'de, D, T: Scalar>(
+    deserializer: D,
+) -> Result<T, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match String::deserialize(deserializer) {
         Ok(v) => {
             if is_valid_token(&v) {
-                Ok(v)
+                Ok(T::from_validated(v))
             } else {
                 Err(de::Error::custom("invalid token pattern".to_owned()))
             }
@@ -413,16 +432,16 @@ where
     }
 }
 
-pub(crate) fn deser_token_opt<'de, D>(
+pub(crate) fn deser_token_opt<'de, D, T: Scalar>(
     deserializer: D,
-) -> Result<Option<String>, D::Error>
+) -> Result<Option<T>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match Option::<String>::deserialize(deserializer) {
         Ok(Some(v)) => {
             if is_valid_token(&v) {
-                Ok(Some(v))
+                Ok(Some(T::from_validated(v)))
             } else {
                 Err(de::Error::custom("invalid token pattern".to_owned()))
             }
@@ -432,16 +451,16 @@ where
     }
 }
 
-pub(crate) fn deser_token_vec_opt<'de, D>(
+pub(crate) fn deser_token_vec_opt<'de, D, T: Scalar>(
     deserializer: D,
-) -> Result<Option<Vec<String>>, D::Error>
+) -> Result<Option<Vec<T>>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match Option::<Vec<String>>::deserialize(deserializer) {
         Ok(Some(v)) => {
             if v.iter().all(|s| is_valid_token(s)) {
-                Ok(Some(v))
+                Ok(Some(v.into_iter().map(T::from_validated).collect()))
             } else {
                 Err(de::Error::custom("invalid token pattern".to_owned()))
             }
@@ -462,8 +481,8 @@ pub(crate) fn ser_uri<S: Serializer>(
     }
 }
 
-pub(crate) fn ser_uri_opt<S: Serializer>(
-    s: &Option<String>,
+pub(crate) fn ser_uri_opt<S: Serializer, T: Scalar>(
+    s: &Option<T>,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
     if let Some(s) = s {
@@ -477,8 +496,8 @@ pub(crate) fn ser_uri_opt<S: Serializer>(
     }
 }
 
-pub(crate) fn ser_uri_vec_opt<S: Serializer>(
-    s: &Option<Vec<String>>,
+pub(crate) fn ser_uri_vec_opt<S: Serializer, T: Scalar>(
+    s: &Option<Vec<T>>,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
     if let Some(s) = s {
@@ -496,14 +515,16 @@ pub(crate) fn ser_uri_vec_opt<S: Serializer>(
     }
 }
 
-pub(crate) fn deser_uri<'de, D>(deserializer: D) -> Result<String, D::Error>
+pub(crate) fn deser_uri<'de, D, T: Scalar>(
+    deserializer: D,
+) -> Result<T, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match String::deserialize(deserializer) {
         Ok(v) => {
             if is_valid_uri(&v) {
-                Ok(v)
+                Ok(T::from_validated(v))
             } else {
                 Err(de::Error::custom("invalid uri pattern".to_owned()))
             }
@@ -512,16 +533,16 @@ where
     }
 }
 
-pub(crate) fn deser_uri_opt<'de, D>(
+pub(crate) fn deser_uri_opt<'de, D, T: Scalar>(
     deserializer: D,
-) -> Result<Option<String>, D::Error>
+) -> Result<Option<T>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match Option::<String>::deserialize(deserializer) {
         Ok(Some(v)) => {
             if is_valid_uri(&v) {
-                Ok(Some(v))
+                Ok(Some(T::from_validated(v)))
             } else {
                 Err(de::Error::custom("invalid uri pattern".to_owned()))
             }
@@ -531,16 +552,16 @@ where
     }
 }
 
-pub(crate) fn deser_uri_vec_opt<'de, D>(
+pub(crate) fn deser_uri_vec_opt<'de, D, T: Scalar>(
     deserializer: D,
-) -> Result<Option<Vec<String>>, D::Error>
+) -> Result<Option<Vec<T>>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match Option::<Vec<String>>::deserialize(deserializer) {
         Ok(Some(v)) => {
             if v.iter().all(|s| is_valid_uri(s)) {
-                Ok(Some(v))
+                Ok(Some(v.into_iter().map(T::from_validated).collect()))
             } else {
                 Err(de::Error::custom("invalid uri pattern".to_owned()))
             }
@@ -561,8 +582,8 @@ pub(crate) fn ser_uri_ref<S: Serializer>(
     }
 }
 
-pub(crate) fn ser_uri_ref_opt<S: Serializer>(
-    s: &Option<String>,
+pub(crate) fn ser_uri_ref_opt<S: Serializer, T: Scalar>(
+    s: &Option<T>,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
     if let Some(s) = s {
@@ -576,14 +597,16 @@ pub(crate) fn ser_uri_ref_opt<S: Serializer>(
     }
 }
 
-pub(crate) fn deser_uri_ref<'de, D>(deserializer: D) -> Result<String, D::Error>
+pub(crate) fn deser_uri_ref<'de, D, T: Scalar>(
+    deserializer: D,
+) -> Result<T, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match String::deserialize(deserializer) {
         Ok(v) => {
             if is_valid_uri(&v) || is_valid_uri_ref(&v) {
-                Ok(v)
+                Ok(T::from_validated(v))
             } else {
                 Err(de::Error::custom(
                     "invalid uri reference pattern".to_owned(),
@@ -594,16 +617,16 @@ where
     }
 }
 
-pub(crate) fn deser_uri_ref_opt<'de, D>(
+pub(crate) fn deser_uri_ref_opt<'de, D, T: Scalar>(
     deserializer: D,
-) -> Result<Option<String>, D::Error>
+) -> Result<Option<T>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match Option::<String>::deserialize(deserializer) {
         Ok(Some(v)) => {
             if is_valid_uri(&v) || is_valid_uri_ref(&v) {
-                Ok(Some(v))
+                Ok(Some(T::from_validated(v)))
             } else {
                 Err(de::Error::custom(
                     "invalid uri reference pattern".to_owned(),
@@ -626,8 +649,8 @@ pub(crate) fn ser_uuid<S: Serializer>(
     }
 }
 
-pub(crate) fn ser_uuid_vec<S: Serializer>(
-    s: &Vec<String>,
+pub(crate) fn ser_uuid_vec<S: Serializer, T: Scalar>(
+    s: &Vec<T>,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
     if s.iter().all(|s| is_valid_uuid(s)) {
@@ -641,8 +664,8 @@ pub(crate) fn ser_uuid_vec<S: Serializer>(
     }
 }
 
-pub(crate) fn ser_uuid_opt<S: Serializer>(
-    s: &Option<String>,
+pub(crate) fn ser_uuid_opt<S: Serializer, T: Scalar>(
+    s: &Option<T>,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
     if let Some(s) = s {
@@ -656,8 +679,8 @@ pub(crate) fn ser_uuid_opt<S: Serializer>(
     }
 }
 
-pub(crate) fn ser_uuid_vec_opt<S: Serializer>(
-    s: &Option<Vec<String>>,
+pub(crate) fn ser_uuid_vec_opt<S: Serializer, T: Scalar>(
+    s: &Option<Vec<T>>,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
     if let Some(s) = s {
@@ -675,14 +698,16 @@ pub(crate) fn ser_uuid_vec_opt<S: Serializer>(
     }
 }
 
-pub(crate) fn deser_uuid<'de, D>(deserializer: D) -> Result<String, D::Error>
+pub(crate) fn deser_uuid<'de, D, T: Scalar>(
+    deserializer: D,
+) -> Result<T, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match String::deserialize(deserializer) {
         Ok(v) => {
             if is_valid_uuid(&v) {
-                Ok(v)
+                Ok(T::from_validated(v))
             } else {
                 Err(de::Error::custom("invalid uuid pattern".to_owned()))
             }
@@ -691,16 +716,16 @@ where
     }
 }
 
-pub(crate) fn deser_uuid_opt<'de, D>(
+pub(crate) fn deser_uuid_opt<'de, D, T: Scalar>(
     deserializer: D,
-) -> Result<Option<String>, D::Error>
+) -> Result<Option<T>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match Option::<String>::deserialize(deserializer) {
         Ok(Some(v)) => {
             if is_valid_uuid(&v) {
-                Ok(Some(v))
+                Ok(Some(T::from_validated(v)))
             } else {
                 Err(de::Error::custom("invalid uuid pattern".to_owned()))
             }
@@ -710,16 +735,16 @@ where
     }
 }
 
-pub(crate) fn deser_uuid_vec<'de, D>(
+pub(crate) fn deser_uuid_vec<'de, D, T: Scalar>(
     deserializer: D,
-) -> Result<Vec<String>, D::Error>
+) -> Result<Vec<T>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match Vec::<String>::deserialize(deserializer) {
         Ok(v) => {
             if v.iter().all(|s| is_valid_uuid(s)) {
-                Ok(v)
+                Ok(v.into_iter().map(T::from_validated).collect())
             } else {
                 Err(de::Error::custom("invalid uuid pattern".to_owned()))
             }
@@ -728,16 +753,16 @@ where
     }
 }
 
-pub(crate) fn deser_uuid_vec_opt<'de, D>(
+pub(crate) fn deser_uuid_vec_opt<'de, D, T: Scalar>(
     deserializer: D,
-) -> Result<Option<Vec<String>>, D::Error>
+) -> Result<Option<Vec<T>>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     match Option::<Vec<String>>::deserialize(deserializer) {
         Ok(Some(v)) => {
             if v.iter().all(|s| is_valid_uuid(s)) {
-                Ok(Some(v))
+                Ok(Some(v.into_iter().map(T::from_validated).collect()))
             } else {
                 Err(de::Error::custom("invalid uuid pattern".to_owned()))
             }
@@ -747,50 +772,127 @@ where
     }
 }
 
-fn parse_markup(input: String) -> String {
-    let parser = Parser::new_ext(&input, Options::all());
-
-    let mut output = String::new();
-    pulldown_cmark::html::push_html(&mut output, parser);
-
-    output
-}
-
-pub(crate) fn deser_markup<'de, D>(deserializer: D) -> Result<String, D::Error>
+/// Fields left over by the model, rejected or kept depending on the
+/// [`UnknownFields`] of the current parse
+pub(crate) fn deser_extensions<'de, D>(
+    deserializer: D,
+) -> Result<Extensions, D::Error>
 where
-    D: serde::de::Deserializer<'de>,
+    D: de::Deserializer<'de>,
 {
-    let mode = env::var("OSCAL_MARKUP_RENDER_MODE").unwrap_or_default();
+    let extensions = Extensions::deserialize(deserializer)?;
 
-    match String::deserialize(deserializer) {
-        Ok(v) => {
-            if mode.eq("ENABLED") {
-                Ok(parse_markup(v))
-            } else {
-                Ok(v)
+    match parse::current().unknown_fields {
+        UnknownFields::Deny => match extensions.keys().next() {
+            Some(field) => {
+                Err(de::Error::custom(format!("unknown field `{field}`")))
             }
+            None => Ok(extensions),
+        },
+        UnknownFields::Capture => {
+            for field in extensions.keys() {
+                parse::warn(ParseWarning::UnknownField {
+                    path: parse::path(),
+                    field: field.to_owned(),
+                })
+            }
+            Ok(extensions)
         }
-        Err(e) => Err(de::Error::custom(e.to_string())),
     }
 }
 
-pub(crate) fn deser_markup_opt<'de, D>(
-    deserializer: D,
-) -> Result<Option<String>, D::Error>
+/// Values held by a builder field, whether required, optional or repeated
+pub(crate) trait FieldValues<T: ?Sized> {
+    fn field_values(&self) -> Vec<&T>;
+}
+
+impl<S: Scalar> FieldValues<str> for S {
+    fn field_values(&self) -> Vec<&str> {
+        vec![&**self]
+    }
+}
+
+impl FieldValues<i64> for i64 {
+    fn field_values(&self) -> Vec<&i64> {
+        vec![self]
+    }
+}
+
+impl<T: ?Sized, V: FieldValues<T>> FieldValues<T> for Option<V> {
+    fn field_values(&self) -> Vec<&T> {
+        self.iter().flat_map(|v| v.field_values()).collect()
+    }
+}
+
+impl<T: ?Sized, V: FieldValues<T>> FieldValues<T> for Vec<V> {
+    fn field_values(&self) -> Vec<&T> {
+        self.iter().flat_map(|v| v.field_values()).collect()
+    }
+}
+
+fn check_field<T, V>(
+    field: &str,
+    value: &V,
+    kind: &str,
+    is_valid: fn(&T) -> bool,
+) -> Result<(), String>
 where
-    D: serde::de::Deserializer<'de>,
+    T: ?Sized + std::fmt::Display,
+    V: FieldValues<T> + ?Sized,
 {
-    let mode = env::var("OSCAL_MARKUP_RENDER_MODE").unwrap_or_default();
-
-    match Option::<String>::deserialize(deserializer) {
-        Ok(Some(v)) => {
-            if mode.eq("ENABLED") {
-                Ok(Some(parse_markup(v)))
-            } else {
-                Ok(Some(v))
-            }
+    match value.field_values().into_iter().find(|v| !is_valid(v)) {
+        Some(v) => {
+            Err(format!("invalid {kind} pattern for field `{field}`: `{v}`"))
         }
-        Ok(None) => Ok(None),
-        Err(e) => Err(de::Error::custom(e.to_string())),
+        None => Ok(()),
     }
 }
+
+macro_rules! impl_check_field {
+    ( $( $name:ident, $t:ty, $kind:literal, $is_valid:expr; )* ) => {
+        $(
+            pub(crate) fn $name<V: FieldValues<$t> + ?Sized>(
+                field: &str,
+                value: &V,
+            ) -> Result<(), String> {
+                check_field(field, value, $kind, $is_valid)
+            }
+        )*
+    };
+}
+
+/// Fields of a `Hash`, checked together before the model type is built
+#[derive(Deserialize)]
+pub(crate) struct RawHash {
+    pub(crate) algorithm: String,
+    #[serde(deserialize_with = "deser_hash")]
+    pub(crate) value: String,
+    #[serde(flatten, deserialize_with = "deser_extensions")]
+    pub(crate) extensions: Extensions,
+}
+
+pub(crate) fn check_digest(
+    algorithm: &Option<String>,
+    value: &Option<String>,
+) -> Result<(), String> {
+    match (algorithm, value) {
+        (Some(algorithm), Some(value)) => is_valid_digest(algorithm, value),
+        _ => Ok(()),
+    }
+}
+
+// Builder-time counterparts of the deserialisers above, called from the
+// `validate` function of the generated builders
+impl_check_field!(
+    check_base64, str, "base64", is_valid_based64;
+    check_date, str, "date", is_valid_date;
+    check_dttz, str, "datetime", is_valid_dttz;
+    check_email, str, "email", is_valid_email;
+    check_hash, str, "hash", is_valid_hash;
+    check_positive_int, i64, "positive integer", |v| *v >= 1;
+    check_non_neg_int, i64, "non negative integer", |v| *v >= 0;
+    check_token, str, "token", is_valid_token;
+    check_uri, str, "uri", is_valid_uri;
+    check_uri_ref, str, "uri reference", |v| is_valid_uri(v) || is_valid_uri_ref(v);
+    check_uuid, str, "uuid", is_valid_uuid;
+);
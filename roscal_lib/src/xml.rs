@@ -0,0 +1,592 @@
+//! Encoding of the root models as OSCAL XML.
+//!
+//! The models only derive the JSON and YAML forms, so the XML form is written
+//! from the serialised JSON tree following the OSCAL XML conventions: flags
+//! become attributes, fields and assemblies become elements, arrays become
+//! repeated elements named after their members, and markup values are written
+//! as OSCAL XML markup in the `http://csrc.nist.gov/ns/oscal/1.0` namespace.
+//!
+//! The encoding is export only: XML documents are detected by
+//! [`ModelType::detect`](crate::document::ModelType::detect) but parsing one
+//! fails with [`DocumentError::UnsupportedXml`](crate::document::DocumentError::UnsupportedXml),
+//! or a [`ParseError`](crate::parse::ParseError) saying so for a single model.
+//!
+//! ```
+//! use roscal_lib::{document::AnyOscalDocument, parse::ParseOptions, xml};
+//!
+//! let yaml = r#"
+//! catalog:
+//!   uuid: 74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724
+//!   metadata:
+//!     title: Example *catalog*
+//!     last-modified: 2023-12-31T23:59:59Z
+//!     version: "1"
+//!     oscal-version: 1.1.2
+//!     props:
+//!       - name: marking
+//!         value: public
+//! "#;
+//!
+//! let document = AnyOscalDocument::from_str_with(yaml, &ParseOptions::default()).unwrap();
+//! let output = xml::to_string(&document).unwrap();
+//!
+//! assert!(output.contains(r#"<catalog xmlns="http://csrc.nist.gov/ns/oscal/1.0" uuid="74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724">"#));
+//! assert!(output.contains("<title>Example <em>catalog</em></title>"));
+//! assert!(output.contains(r#"<prop name="marking" value="public"/>"#));
+//! ```
+
+use std::fmt;
+
+use quick_xml::escape::escape;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{
+    document::ModelType,
+    markup::{self, Markup},
+};
+
+/// OSCAL XML namespace of every model element
+pub const NAMESPACE: &str = "http://csrc.nist.gov/ns/oscal/1.0";
+
+/// Error returned when a model can't be written as XML
+#[derive(Debug)]
+pub enum XmlError {
+    /// The value does not serialise to a single OSCAL root model
+    UnknownModel,
+    /// The value could not be serialised
+    Serialize(serde_json::Error),
+}
+
+impl fmt::Display for XmlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            XmlError::UnknownModel => {
+                write!(f, "root of the document is not an OSCAL model")
+            }
+            XmlError::Serialize(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for XmlError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            XmlError::Serialize(e) => Some(e),
+            XmlError::UnknownModel => None,
+        }
+    }
+}
+
+/// Scalar properties written as elements rather than attributes
+const FIELDS: &[&str] = &[
+    "adjustment-justification",
+    "as-is",
+    "base",
+    "caption",
+    "city",
+    "collected",
+    "country",
+    "date-authorized",
+    "deadline",
+    "description",
+    "end",
+    "expires",
+    "implementation-statement-uuid",
+    "label",
+    "last-modified",
+    "oscal-version",
+    "postal-code",
+    "published",
+    "purpose",
+    "remarks",
+    "security-objective-availability",
+    "security-objective-confidentiality",
+    "security-objective-integrity",
+    "security-sensitivity-level",
+    "selected",
+    "short-name",
+    "start",
+    "statement",
+    "status",
+    "status-change",
+    "system-name",
+    "system-name-short",
+    "text",
+    "title",
+    "usage",
+    "version",
+];
+
+/// Scalar properties whose kind depends on the assembly they belong to, as
+/// `(assembly, property, is a field)`
+const CONTEXT_FIELDS: &[(&str, &str, bool)] = &[
+    ("address", "state", true),
+    ("leveraged-authorization", "party-uuid", true),
+    ("party", "name", true),
+    ("port-range", "end", false),
+    ("port-range", "start", false),
+    ("within-date-range", "end", false),
+    ("within-date-range", "start", false),
+];
+
+/// Fields with flags, the property holding the value of the field
+const FIELD_VALUES: &[(&str, &str)] = &[
+    ("base64", "value"),
+    ("document-id", "identifier"),
+    ("external-id", "id"),
+    ("hash", "value"),
+    ("system-id", "id"),
+    ("telephone-number", "number"),
+    ("threat-id", "id"),
+];
+
+/// Element names of array members that are not the array name without its
+/// plural ending, as `(assembly or "*", array, member)`
+const MEMBER_NAMES: &[(&str, &str, &str)] = &[
+    ("import", "exclude-controls", "exclude-controls"),
+    ("import", "include-controls", "include-controls"),
+    ("*", "addresses", "address"),
+    ("*", "email-addresses", "email-address"),
+    ("*", "functions-performed", "function-performed"),
+    ("*", "hashes", "hash"),
+    ("*", "remediations", "response"),
+];
+
+/// Model order of the elements, the JSON properties being serialised in
+/// alphabetical order
+const ORDER: &[&str] = &[
+    "metadata",
+    "imports",
+    "import",
+    "import-profile",
+    "import-ssp",
+    "import-ap",
+    "import-component-definitions",
+    "system-id",
+    "system-ids",
+    "system-name",
+    "system-name-short",
+    "title",
+    "name",
+    "short-name",
+    "published",
+    "last-modified",
+    "version",
+    "oscal-version",
+    "external-ids",
+    "description",
+    "purpose",
+    "statement",
+    "text",
+    "start",
+    "end",
+    "categorizations",
+    "revisions",
+    "document-ids",
+    "address",
+    "addr-lines",
+    "city",
+    "state",
+    "postal-code",
+    "country",
+    "email-addresses",
+    "telephone-numbers",
+    "urls",
+    "params",
+    "props",
+    "links",
+    "local-definitions",
+    "party-uuid",
+    "party-uuids",
+    "date-authorized",
+    "security-sensitivity-level",
+    "system-information",
+    "information-types",
+    "confidentiality-impact",
+    "integrity-impact",
+    "availability-impact",
+    "base",
+    "selected",
+    "adjustment-justification",
+    "security-objective-confidentiality",
+    "security-objective-integrity",
+    "security-objective-availability",
+    "security-impact-level",
+    "status",
+    "authorization-boundary",
+    "network-architecture",
+    "data-flow",
+    "diagrams",
+    "caption",
+    "label",
+    "usage",
+    "constraints",
+    "tests",
+    "guidelines",
+    "values",
+    "select",
+    "include-all",
+    "include-controls",
+    "exclude-controls",
+    "with-ids",
+    "matching",
+    "include-subjects",
+    "exclude-subjects",
+    "methods",
+    "types",
+    "origins",
+    "origin",
+    "actors",
+    "threat-ids",
+    "characterizations",
+    "facets",
+    "mitigating-factors",
+    "deadline",
+    "remediations",
+    "required-assets",
+    "risk-log",
+    "entries",
+    "logged-by",
+    "status-change",
+    "related-responses",
+    "related-tasks",
+    "target",
+    "implementation-statement-uuid",
+    "roles",
+    "locations",
+    "parties",
+    "responsible-parties",
+    "actions",
+    "set-parameters",
+    "implementation-status",
+    "export",
+    "provided",
+    "responsibilities",
+    "inherited",
+    "satisfied",
+    "leveraged-authorizations",
+    "components",
+    "inventory-items",
+    "implemented-components",
+    "users",
+    "role-ids",
+    "authorized-privileges",
+    "functions-performed",
+    "objectives-and-methods",
+    "activities",
+    "terms-and-conditions",
+    "reviewed-controls",
+    "control-selections",
+    "control-objective-selections",
+    "include-objectives",
+    "exclude-objectives",
+    "assessment-subjects",
+    "assessment-assets",
+    "assessment-platforms",
+    "uses-components",
+    "timing",
+    "dependencies",
+    "tasks",
+    "associated-activities",
+    "subjects",
+    "identified-subject",
+    "relevant-evidence",
+    "collected",
+    "expires",
+    "steps",
+    "related-controls",
+    "responsible-roles",
+    "protocols",
+    "port-ranges",
+    "statements",
+    "by-components",
+    "incorporates-components",
+    "control-implementations",
+    "implemented-requirements",
+    "capabilities",
+    "attestations",
+    "assessment-log",
+    "results",
+    "observations",
+    "risks",
+    "findings",
+    "poam-items",
+    "related-findings",
+    "related-observations",
+    "related-risks",
+    "citation",
+    "rlinks",
+    "hashes",
+    "base64",
+    "prose",
+    "parts",
+    "controls",
+    "groups",
+    "alters",
+    "removes",
+    "adds",
+    "merge",
+    "combine",
+    "flat",
+    "as-is",
+    "custom",
+    "modify",
+    "system-characteristics",
+    "system-implementation",
+    "control-implementation",
+    "remarks",
+    "back-matter",
+    "resources",
+];
+
+/// Assemblies whose elements are not in the order of [`ORDER`]
+const ASSEMBLY_ORDERS: &[(&str, &[&str])] = &[
+    (
+        "associated-activity",
+        &["props", "links", "responsible-roles", "subjects", "remarks"],
+    ),
+    ("part", &["title", "props", "prose", "parts", "links"]),
+    (
+        "party",
+        &[
+            "name",
+            "short-name",
+            "external-ids",
+            "props",
+            "links",
+            "email-addresses",
+            "telephone-numbers",
+            "addresses",
+            "location-uuids",
+            "member-of-organizations",
+            "remarks",
+        ],
+    ),
+    (
+        "resource",
+        &[
+            "title",
+            "description",
+            "props",
+            "document-ids",
+            "citation",
+            "rlinks",
+            "base64",
+            "remarks",
+        ],
+    ),
+    (
+        "responsible-party",
+        &["party-uuids", "props", "links", "remarks"],
+    ),
+    (
+        "system-implementation",
+        &[
+            "props",
+            "links",
+            "leveraged-authorizations",
+            "users",
+            "components",
+            "inventory-items",
+            "remarks",
+        ],
+    ),
+];
+
+const MARKUP_LINES: &[&str] =
+    &["caption", "choice", "label", "purpose", "text", "title"];
+
+const MARKUP_MULTILINES: &[&str] =
+    &["description", "remarks", "statement", "usage"];
+
+/// Write a root model, or an [`AnyOscalDocument`](crate::document::AnyOscalDocument),
+/// as an indented OSCAL XML document
+pub fn to_string<T: Serialize>(model: &T) -> Result<String, XmlError> {
+    write(model, true)
+}
+
+/// Write a root model as an OSCAL XML document on a single line
+pub fn to_string_compact<T: Serialize>(model: &T) -> Result<String, XmlError> {
+    write(model, false)
+}
+
+fn write<T: Serialize>(model: &T, pretty: bool) -> Result<String, XmlError> {
+    let root = markup::as_markdown(|| serde_json::to_value(model))
+        .map_err(XmlError::Serialize)?;
+
+    let (name, model) = match root.as_object() {
+        Some(root) if root.len() == 1 => root.iter().next().unwrap(),
+        _ => return Err(XmlError::UnknownModel),
+    };
+    let model = match (ModelType::from_root_key(name), model) {
+        (Some(_), Value::Object(model)) => model,
+        _ => return Err(XmlError::UnknownModel),
+    };
+
+    let mut writer = Writer {
+        output: String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"),
+        pretty,
+    };
+    writer.assembly(name, model, 0, Some(NAMESPACE));
+
+    Ok(writer.output)
+}
+
+struct Writer {
+    output: String,
+    pretty: bool,
+}
+
+impl Writer {
+    fn indent(&mut self, depth: usize) {
+        if self.pretty && !self.output.is_empty() {
+            self.output.push('\n');
+            self.output.push_str(&"  ".repeat(depth));
+        }
+    }
+
+    fn assembly(
+        &mut self,
+        name: &str,
+        map: &Map<String, Value>,
+        depth: usize,
+        namespace: Option<&str>,
+    ) {
+        let value_key = FIELD_VALUES
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, key)| *key);
+
+        self.indent(depth);
+        self.output.push('<');
+        self.output.push_str(name);
+        if let Some(namespace) = namespace {
+            self.output.push_str(&format!(" xmlns=\"{namespace}\""));
+        }
+
+        let mut value = None;
+        let mut children = vec![];
+        for (key, child) in map {
+            if Some(key.as_str()) == value_key {
+                value = scalar(child);
+            } else if let (Some(flag), false) =
+                (scalar(child), is_field(name, key))
+            {
+                self.output
+                    .push_str(&format!(" {key}=\"{}\"", escape(&flag)));
+            } else if !child.is_null() {
+                children.push((key, child));
+            }
+        }
+
+        if let Some(value) = value {
+            self.output
+                .push_str(&format!(">{}</{name}>", escape(&value)));
+            return;
+        }
+        if children.is_empty() {
+            self.output.push_str("/>");
+            return;
+        }
+
+        children.sort_by_key(|(key, _)| position(name, key));
+        self.output.push('>');
+        for (key, child) in children {
+            self.child(name, key, child, depth + 1);
+        }
+        self.indent(depth);
+        self.output.push_str(&format!("</{name}>"));
+    }
+
+    fn child(&mut self, parent: &str, key: &str, value: &Value, depth: usize) {
+        match value {
+            Value::Array(members) => {
+                let name = member_name(parent, key);
+                for member in members {
+                    self.element(&name, member, depth);
+                }
+            }
+            // Prose has no element of its own, its blocks are part content
+            Value::String(prose) if key == "prose" => {
+                for block in
+                    Markup::from_markdown(prose.as_str()).to_xml_blocks()
+                {
+                    self.indent(depth);
+                    self.output.push_str(&block);
+                }
+            }
+            value => self.element(key, value, depth),
+        }
+    }
+
+    fn element(&mut self, name: &str, value: &Value, depth: usize) {
+        let text = match value {
+            Value::Object(map) => return self.assembly(name, map, depth, None),
+            Value::Null | Value::Array(_) => return,
+            value => scalar(value).unwrap_or_default(),
+        };
+
+        self.indent(depth);
+        if MARKUP_MULTILINES.contains(&name) {
+            self.output.push_str(&format!("<{name}>"));
+            for block in Markup::from_markdown(text).to_xml_blocks() {
+                self.indent(depth + 1);
+                self.output.push_str(&block);
+            }
+            self.indent(depth);
+            self.output.push_str(&format!("</{name}>"));
+        } else if MARKUP_LINES.contains(&name) {
+            self.output.push_str(&format!(
+                "<{name}>{}</{name}>",
+                Markup::from_markdown(text).to_xml_line()
+            ));
+        } else {
+            self.output
+                .push_str(&format!("<{name}>{}</{name}>", escape(&text)));
+        }
+    }
+}
+
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+fn is_field(assembly: &str, key: &str) -> bool {
+    CONTEXT_FIELDS
+        .iter()
+        .find(|(a, k, _)| *a == assembly && *k == key)
+        .map(|(_, _, is_field)| *is_field)
+        .unwrap_or_else(|| key == "prose" || FIELDS.contains(&key))
+}
+
+/// Position of an element in its assembly, unknown ones such as lenient
+/// extensions going last in their serialised order
+fn position(assembly: &str, key: &str) -> usize {
+    let order = ASSEMBLY_ORDERS
+        .iter()
+        .find(|(a, _)| *a == assembly)
+        .map(|(_, order)| *order)
+        .unwrap_or(ORDER);
+
+    order.iter().position(|k| *k == key).unwrap_or(order.len())
+}
+
+fn member_name(assembly: &str, array: &str) -> String {
+    let named = MEMBER_NAMES
+        .iter()
+        .find(|(a, k, _)| (*a == assembly || *a == "*") && *k == array);
+
+    match named {
+        Some((_, _, member)) => member.to_string(),
+        None => match array.strip_suffix("ies") {
+            Some(stem) => format!("{stem}y"),
+            None => array.strip_suffix('s').unwrap_or(array).to_owned(),
+        },
+    }
+}
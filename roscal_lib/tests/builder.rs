@@ -0,0 +1,66 @@
+// With typed-scalars the builders only take validated values, see scalar.rs
+#![cfg(not(feature = "typed-scalars"))]
+
+use roscal_lib::control::catalog::{
+    CatalogClassBuilder, DocumentMetadataBuilder, PartyBuilder, PartyType,
+};
+
+fn metadata() -> DocumentMetadataBuilder {
+    let mut builder = DocumentMetadataBuilder::default();
+    builder
+        .title("catalog")
+        .version("1")
+        .oscal_version("1.1.2")
+        .last_modified("2023-12-31T23:59:59Z");
+
+    builder
+}
+
+#[test]
+fn build_valid_model() {
+    let metadata = metadata().build().unwrap();
+
+    let catalog = CatalogClassBuilder::default()
+        .uuid("74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724")
+        .metadata(metadata)
+        .build();
+
+    assert!(catalog.is_ok());
+}
+
+#[test]
+fn reject_invalid_fields() {
+    let err = metadata().last_modified("31/12/2023").build().unwrap_err();
+    assert_eq!(
+        "invalid datetime pattern for field `last_modified`: `31/12/2023`",
+        err.to_string()
+    );
+
+    let err = CatalogClassBuilder::default()
+        .uuid("not-a-uuid")
+        .metadata(metadata().build().unwrap())
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        "invalid uuid pattern for field `uuid`: `not-a-uuid`",
+        err.to_string()
+    );
+}
+
+#[test]
+fn reject_invalid_optional_fields() {
+    let err = PartyBuilder::default()
+        .uuid("74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724")
+        .party_type(PartyType::Organization)
+        .member_of_organizations(vec![
+            "b1a5fd07-2f87-4b2c-9b5c-4ba6bd6b4ce4".to_owned(),
+            "org-1".to_owned(),
+        ])
+        .build()
+        .unwrap_err();
+
+    assert_eq!(
+        "invalid uuid pattern for field `member_of_organizations`: `org-1`",
+        err.to_string()
+    );
+}
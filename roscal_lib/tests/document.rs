@@ -0,0 +1,69 @@
+use std::fs::{read_dir, read_to_string, File};
+
+use roscal_lib::{
+    document::{AnyOscalDocument, DocumentError, ModelType},
+    parse::ParseOptions,
+};
+
+#[test]
+fn detect_model_types() {
+    let dirs = [
+        ("tests/ap", ModelType::AssessmentPlan),
+        ("tests/ar", ModelType::AssessmentResults),
+        ("tests/poam", ModelType::PlanOfActionAndMilestones),
+        ("tests/catalog", ModelType::Catalog),
+        ("tests/profile", ModelType::Profile),
+        ("tests/component-definition", ModelType::ComponentDefinition),
+        ("tests/ssp", ModelType::SystemSecurityPlan),
+    ];
+
+    for (dir, model_type) in dirs {
+        for format in ["json", "yaml"] {
+            for e in read_dir(format!("{dir}/{format}")).unwrap() {
+                let path = e.unwrap().path();
+                let input = read_to_string(&path).unwrap();
+
+                assert_eq!(model_type, ModelType::detect(&input).unwrap());
+
+                let document = AnyOscalDocument::from_reader_with(
+                    File::open(&path).unwrap(),
+                    &ParseOptions::default(),
+                )
+                .unwrap();
+                assert_eq!(model_type, document.model_type());
+            }
+        }
+    }
+}
+
+#[test]
+fn detect_xml_model_type() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<system-security-plan xmlns="http://csrc.nist.gov/ns/oscal/1.0" uuid="5e139edd-86aa-4b65-8431-1192bd276658">
+</system-security-plan>"#;
+
+    assert_eq!(
+        ModelType::SystemSecurityPlan,
+        ModelType::detect(xml).unwrap()
+    );
+    assert!(matches!(
+        AnyOscalDocument::from_str_with(xml, &ParseOptions::default()),
+        Err(DocumentError::UnsupportedXml(ModelType::SystemSecurityPlan))
+    ));
+}
+
+#[test]
+fn detect_unknown_model() {
+    assert!(matches!(
+        ModelType::detect("$schema: x\nunknown-model: {}\n"),
+        Err(DocumentError::UnknownModel)
+    ));
+    assert!(matches!(
+        ModelType::detect("<unknown-model/>"),
+        Err(DocumentError::UnknownModel)
+    ));
+    assert_eq!(
+        Some(ModelType::Profile),
+        ModelType::from_root_key(ModelType::Profile.root_key())
+    );
+}
@@ -0,0 +1,106 @@
+use roscal_lib::{
+    control::catalog::{Catalog, HashBuilder},
+    hash::HashAlgorithm,
+    parse::{ParseOptions, ParseWith},
+};
+
+fn catalog(algorithm: &str, value: &str) -> String {
+    format!(
+        r#"
+catalog:
+  uuid: 74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724
+  metadata:
+    title: Example catalog
+    last-modified: 2023-12-31T23:59:59Z
+    version: "1"
+    oscal-version: 1.1.2
+  back-matter:
+    resources:
+      - uuid: 2b4a1b3a-cbc1-4b5c-9dd2-1e8c2b7a0f31
+        rlinks:
+          - href: policy.txt
+            hashes:
+              - algorithm: {algorithm}
+                value: {value}
+"#
+    )
+}
+
+#[test]
+fn digests() {
+    let cases = [
+        (
+            HashAlgorithm::Sha224,
+            "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7",
+        ),
+        (
+            HashAlgorithm::Sha256,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        ),
+        (
+            HashAlgorithm::Sha3_256,
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+        ),
+    ];
+
+    for (algorithm, digest) in cases {
+        assert_eq!(digest, algorithm.digest(b"abc"));
+        assert!(algorithm.matches(b"abc", &digest.to_uppercase()));
+    }
+
+    for algorithm in HashAlgorithm::ALL {
+        assert_eq!(algorithm.hex_len(), algorithm.digest(b"").len());
+        assert_eq!(Some(algorithm), HashAlgorithm::from_name(algorithm.name()));
+    }
+}
+
+#[test]
+fn digest_length_follows_algorithm() {
+    let sha256 = HashAlgorithm::Sha256.digest(b"abc");
+    let sha512 = HashAlgorithm::Sha512.digest(b"abc");
+    let options = ParseOptions::default();
+
+    assert!(
+        Catalog::from_str_with(&catalog("SHA-256", &sha256), &options).is_ok()
+    );
+    assert!(
+        Catalog::from_str_with(&catalog("SHA-512", &sha512), &options).is_ok()
+    );
+    // Other algorithms are allowed, with a digest of any length
+    assert!(Catalog::from_str_with(
+        &catalog("MD5", "900150983cd24fb0d6963f7d28e17f72"),
+        &options
+    )
+    .is_ok());
+
+    let e = Catalog::from_str_with(&catalog("SHA-256", &sha512), &options)
+        .unwrap_err();
+    assert_eq!(
+        "catalog.back-matter.resources[0].rlinks[0].hashes[0]",
+        e.path()
+    );
+    assert_eq!(
+        "invalid SHA-256 digest: expected 64 hex characters, found 128",
+        e.message()
+    );
+
+    let e = Catalog::from_str_with(&catalog("SHA-256", "not-hex"), &options)
+        .unwrap_err();
+    assert_eq!("invalid hash pattern", e.message());
+}
+
+#[test]
+fn builder_checks_digest_length() {
+    let sha256 = HashAlgorithm::Sha256.digest(b"abc");
+
+    assert!(HashBuilder::default()
+        .algorithm("SHA3-256")
+        .value(sha256.clone())
+        .build()
+        .is_ok());
+    assert!(HashBuilder::default()
+        .algorithm("SHA-384")
+        .value(sha256)
+        .build()
+        .is_err());
+}
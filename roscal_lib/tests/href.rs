@@ -0,0 +1,139 @@
+use std::{fs::File, path::Path};
+
+use roscal_lib::{
+    document::AnyOscalDocument,
+    href::{
+        standard_resolver, BackMatterResolver, FileSystemResolver, HrefError,
+        HrefResolver, HrefSource, MirrorResolver, ResourceLinks,
+    },
+    parse::ParseOptions,
+};
+
+fn catalog_source() -> HrefSource {
+    HrefSource::new("tests/catalog/yaml/profile.yaml")
+}
+
+#[test]
+fn file_system() {
+    let source = catalog_source();
+
+    assert_eq!(
+        Path::new("tests/catalog/yaml/basic-catalog.yaml"),
+        FileSystemResolver
+            .resolve("./basic-catalog.yaml", &source)
+            .unwrap()
+    );
+    assert_eq!(
+        Path::new("tests/catalog/yaml/../json/basic-catalog.json"),
+        FileSystemResolver
+            .resolve("../json/basic-catalog.json", &source)
+            .unwrap()
+    );
+    assert_eq!(
+        Err(HrefError::NotFound(
+            "tests/catalog/yaml/missing.yaml".into()
+        )),
+        FileSystemResolver.resolve("missing.yaml", &source)
+    );
+
+    for href in ["#uuid", "https://example.com/catalog.json", "urn:x:y"] {
+        assert_eq!(
+            Err(HrefError::Unsupported(href.to_owned())),
+            FileSystemResolver.resolve(href, &source)
+        );
+    }
+}
+
+#[test]
+fn mirror() {
+    let mirror = MirrorResolver::new("tests");
+    let source = HrefSource::new("elsewhere/profile.json");
+
+    assert_eq!(
+        Path::new("tests/catalog/yaml/basic-catalog.yaml"),
+        mirror
+            .resolve("https://catalog/yaml/basic-catalog.yaml?v=2", &source)
+            .unwrap()
+    );
+    assert_eq!(
+        Err(HrefError::NotFound("tests/example.com/catalog.json".into())),
+        mirror.resolve("http://example.com/catalog.json", &source)
+    );
+    assert!(matches!(
+        mirror.resolve("https://example.com/../../Cargo.toml", &source),
+        Err(HrefError::Unsupported(_))
+    ));
+    assert!(matches!(
+        mirror.resolve("basic-catalog.yaml", &source),
+        Err(HrefError::Unsupported(_))
+    ));
+}
+
+#[test]
+fn back_matter() {
+    let mut source = catalog_source();
+    source.resources.push(ResourceLinks {
+        uuid: "74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724".to_owned(),
+        rlinks: vec![
+            ("./basic-catalog.xml".to_owned(), None),
+            ("https://example.com/basic-catalog.json".to_owned(), None),
+            ("./basic-catalog.yaml".to_owned(), None),
+        ],
+    });
+    source.resources.push(ResourceLinks {
+        uuid: "9f1c7e55-3a57-4d2c-8f0e-5b1d6c9a2e44".to_owned(),
+        rlinks: vec![("https://example.com/guidance.pdf".to_owned(), None)],
+    });
+
+    let resolver = BackMatterResolver::new(FileSystemResolver);
+    assert_eq!(
+        Path::new("tests/catalog/yaml/basic-catalog.yaml"),
+        resolver
+            .resolve("#74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724", &source)
+            .unwrap()
+    );
+    assert_eq!(
+        Err(HrefError::NoLocalLink(
+            "9f1c7e55-3a57-4d2c-8f0e-5b1d6c9a2e44".to_owned()
+        )),
+        resolver.resolve("#9f1c7e55-3a57-4d2c-8f0e-5b1d6c9a2e44", &source)
+    );
+    assert_eq!(
+        Err(HrefError::UnknownResource("unknown".to_owned())),
+        resolver.resolve("#unknown", &source)
+    );
+
+    // The mirror handles the remote rlinks
+    let resolver = standard_resolver(Some("tests/catalog".into()));
+    assert_eq!(
+        Err(HrefError::NotFound(
+            "tests/catalog/example.com/guidance.pdf".into()
+        )),
+        resolver.resolve("#9f1c7e55-3a57-4d2c-8f0e-5b1d6c9a2e44", &source)
+    );
+}
+
+#[test]
+fn source_from_document() {
+    let path =
+        "tests/profile/yaml/NIST_SP-800-53_rev5_HIGH-baseline_profile.yaml";
+    let document = AnyOscalDocument::from_reader_with(
+        File::open(path).unwrap(),
+        &ParseOptions::default(),
+    )
+    .unwrap();
+    let source = HrefSource::from_document(path, &document);
+
+    assert_eq!(1, source.resources.len());
+    assert_eq!(
+        "84cbf061-eb87-4ec1-8112-1f529232e907",
+        source.resources[0].uuid
+    );
+    assert_eq!(3, source.resources[0].rlinks.len());
+
+    // None of the catalogs is in the tree, the first missing one is reported
+    let e = standard_resolver(None)
+        .resolve("#84cbf061-eb87-4ec1-8112-1f529232e907", &source)
+        .unwrap_err();
+    assert!(matches!(e, HrefError::NotFound(_)), "{e}");
+}
@@ -0,0 +1,157 @@
+use std::fs::{read_dir, read_to_string};
+
+use roscal_lib::{
+    control::catalog::Catalog,
+    document::AnyOscalDocument,
+    parse::{ParseOptions, ParseWarning, ParseWith, UnknownFields},
+};
+
+const CATALOG: &str = r#"
+catalog:
+  uuid: 74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724
+  metadata:
+    title: Example catalog
+    last-modified: 2023-12-31T23:59:59Z
+    version: "1"
+    oscal-version: 1.1.2
+    x-vendor-id: 42
+  controls:
+    - id: ac-1
+      title: Policy and Procedures
+      x-vendor-notes:
+        owner: security
+        reviewed: true
+x-exported-by: vendor-tool
+"#;
+
+fn lenient() -> ParseOptions {
+    ParseOptions::default().with_unknown_fields(UnknownFields::Capture)
+}
+
+#[test]
+fn unknown_fields_denied_by_default() {
+    let e = Catalog::from_str_with(CATALOG, &ParseOptions::default())
+        .err()
+        .unwrap()
+        .to_string();
+    assert!(e.contains("unknown field `x-vendor-id`"), "{e}");
+
+    let res: Result<Catalog, serde_yaml::Error> = serde_yaml::from_str(CATALOG);
+    assert!(res.is_err());
+}
+
+#[test]
+fn unknown_fields_captured() {
+    let (catalog, warnings) =
+        Catalog::from_str_with_warnings(CATALOG, &lenient()).unwrap();
+
+    assert_eq!(
+        vec![
+            ParseWarning::UnknownField {
+                path: "catalog.metadata".to_owned(),
+                field: "x-vendor-id".to_owned(),
+            },
+            ParseWarning::UnknownField {
+                path: "catalog.controls[0]".to_owned(),
+                field: "x-vendor-notes".to_owned(),
+            },
+            ParseWarning::UnknownField {
+                path: String::new(),
+                field: "x-exported-by".to_owned(),
+            },
+        ],
+        warnings
+    );
+    assert_eq!(
+        "catalog.controls[0]: unknown field `x-vendor-notes` kept as an extension",
+        warnings[1].to_string()
+    );
+    assert_eq!(
+        "unknown field `x-exported-by` kept as an extension",
+        warnings[2].to_string()
+    );
+    assert_eq!("vendor-tool", catalog.extensions["x-exported-by"]);
+    assert_eq!(42, catalog.catalog.metadata.extensions["x-vendor-id"]);
+
+    let control = &catalog.catalog.controls.as_ref().unwrap()[0];
+    assert_eq!(
+        serde_json::json!({ "owner": "security", "reviewed": true }),
+        control.extensions["x-vendor-notes"]
+    );
+}
+
+#[test]
+fn unknown_fields_roundtrip() {
+    let (catalog, _) =
+        Catalog::from_str_with_warnings(CATALOG, &lenient()).unwrap();
+
+    let input: serde_json::Value = serde_yaml::from_str(CATALOG).unwrap();
+    let output = serde_json::to_value(&catalog).unwrap();
+    assert_eq!(input, output);
+
+    let json = serde_json::to_string(&catalog).unwrap();
+    let reparsed = Catalog::from_str_with(&json, &lenient()).unwrap();
+    assert_eq!(catalog, reparsed);
+}
+
+#[test]
+fn warnings_do_not_leak_between_parses() {
+    let (_, warnings) =
+        Catalog::from_str_with_warnings(CATALOG, &lenient()).unwrap();
+    assert_eq!(3, warnings.len());
+
+    let json = read_to_string("tests/catalog/json/basic-catalog.json").unwrap();
+    let (_, warnings) =
+        Catalog::from_str_with_warnings(&json, &lenient()).unwrap();
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn examples_have_no_extensions() {
+    for e in read_dir("tests/ssp/yaml").unwrap() {
+        let input = read_to_string(e.unwrap().path()).unwrap();
+
+        let document =
+            AnyOscalDocument::from_str_with(&input, &lenient()).unwrap();
+        let strict =
+            AnyOscalDocument::from_str_with(&input, &ParseOptions::default())
+                .unwrap();
+        assert_eq!(strict, document);
+    }
+}
+
+#[test]
+fn unknown_fields_located_in_nested_values() {
+    let input: serde_json::Value = serde_yaml::from_str(
+        r#"
+catalog:
+  uuid: 74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724
+  metadata:
+    title: Example catalog
+    last-modified: 2023-12-31T23:59:59Z
+    version: "1"
+    oscal-version: 1.1.2
+  controls:
+    - id: ac-1
+      title: Policy and Procedures
+    - id: ac-2
+      title: Account Management
+      parts:
+        - id: ac-2_smt
+          name: statement
+          x-vendor-notes: reviewed
+"#,
+    )
+    .unwrap();
+
+    let (_, warnings) =
+        Catalog::deserialize_with_warnings(&input, &lenient()).unwrap();
+
+    assert_eq!(
+        vec![ParseWarning::UnknownField {
+            path: "catalog.controls[1].parts[0]".to_owned(),
+            field: "x-vendor-notes".to_owned(),
+        }],
+        warnings
+    );
+}
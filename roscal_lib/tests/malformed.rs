@@ -1,7 +1,12 @@
-use std::{fs::File, io::BufReader};
+use std::{
+    fs::{read_to_string, File},
+    io::BufReader,
+};
 
 use roscal_lib::{
-    control::profile::Profile, implementation::ssp::SystemSecurityPlan,
+    control::profile::Profile,
+    implementation::ssp::SystemSecurityPlan,
+    parse::{ParseOptions, ParseWith},
 };
 
 #[test]
@@ -66,3 +71,49 @@ fn wrong_uuid() {
 
     assert_eq!("profile: invalid uuid pattern at line 2 column 3", e)
 }
+
+#[test]
+fn error_paths() {
+    let options = ParseOptions::default();
+
+    let file = File::open("tests/malformed/date.yaml").unwrap();
+    let e = SystemSecurityPlan::from_reader_with(file, &options)
+        .err()
+        .unwrap();
+    assert_eq!(
+        "system-security-plan.system-characteristics.date-authorized: invalid date pattern, found `2023-12-04T14:55:00.000000-04:00` at line 54 column 5",
+        e.to_string()
+    );
+
+    let file = File::open("tests/malformed/uri.yaml").unwrap();
+    let e = Profile::from_reader_with(file, &options).err().unwrap();
+    assert_eq!("profile.back-matter.resources[0].rlinks[0].href", e.path());
+    assert_eq!("invalid uri reference pattern", e.message());
+
+    let file = File::open("tests/malformed/uuid.yaml").unwrap();
+    let e = Profile::from_reader_with(file, &options).err().unwrap();
+    assert_eq!("profile.uuid", e.path());
+    assert_eq!(Some("wrong"), e.value());
+    assert_eq!((Some(2), Some(3)), (e.line(), e.column()));
+}
+
+#[test]
+fn error_paths_json() {
+    let json = read_to_string("tests/ssp/json/ssp-example.json")
+        .unwrap()
+        .replacen(
+            r#""component-uuid": "e00acdcf"#,
+            r#""component-uuid": "not-e00acdcf"#,
+            1,
+        );
+
+    let e = SystemSecurityPlan::from_str_with(&json, &ParseOptions::default())
+        .err()
+        .unwrap();
+    assert_eq!(
+        "system-security-plan.system-implementation.inventory-items[0].implemented-components[0].component-uuid",
+        e.path()
+    );
+    assert_eq!(Some("not-e00acdcf-911b-437d-a42f-b0b558cc4f03"), e.value());
+    assert!(e.line().is_some());
+}
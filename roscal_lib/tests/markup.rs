@@ -0,0 +1,149 @@
+use std::{fs::File, io::BufReader};
+
+use roscal_lib::{
+    control::catalog::Catalog,
+    markup::{Block, Inline, Markup, MarkupFormat},
+    parse::{MarkupMode, ParseOptions, ParseWith},
+};
+
+#[test]
+fn parse_markdown() {
+    let markup = Markup::from("## Scope\n\n- one\n- **two** [link](#a)");
+
+    assert_eq!(MarkupFormat::Markdown, markup.format());
+    assert_eq!(
+        vec![
+            Block::Heading {
+                level: 2,
+                content: vec![Inline::Text("Scope".to_owned())],
+            },
+            Block::List {
+                ordered: false,
+                items: vec![
+                    vec![Block::Paragraph(vec![Inline::Text(
+                        "one".to_owned()
+                    )])],
+                    vec![Block::Paragraph(vec![
+                        Inline::Strong(vec![Inline::Text("two".to_owned())]),
+                        Inline::Text(" ".to_owned()),
+                        Inline::Link {
+                            href: "#a".to_owned(),
+                            content: vec![Inline::Text("link".to_owned())],
+                        },
+                    ])],
+                ],
+            },
+        ],
+        markup.blocks()
+    );
+}
+
+#[test]
+fn parse_xml() {
+    let source = "<p>Protect <insert type=\"param\" id-ref=\"ac-1_prm_1\"/> \
+                  with <em>care</em> &amp; <code>tls</code>.</p>";
+    let markup = Markup::from_xml(source).unwrap();
+
+    assert_eq!(MarkupFormat::Xml, Markup::parse(source).format());
+    assert_eq!(
+        vec![Block::Paragraph(vec![
+            Inline::Text("Protect ".to_owned()),
+            Inline::Insert {
+                insert_type: "param".to_owned(),
+                id_ref: "ac-1_prm_1".to_owned(),
+            },
+            Inline::Text(" with ".to_owned()),
+            Inline::Emphasis(vec![Inline::Text("care".to_owned())]),
+            Inline::Text(" & ".to_owned()),
+            Inline::Code("tls".to_owned()),
+            Inline::Text(".".to_owned()),
+        ])],
+        markup.blocks()
+    );
+    assert_eq!(
+        "Protect {{ insert: param, ac-1_prm_1 }} with *care* & `tls`.",
+        markup.to_markdown()
+    );
+
+    assert!(Markup::from_xml("<p>unclosed").is_err());
+    assert_eq!(
+        MarkupFormat::Markdown,
+        Markup::parse("<p>unclosed").format()
+    );
+}
+
+#[test]
+fn render_markup() {
+    let markup = Markup::from(
+        "Use {{ insert: param, sc-8_prm_1 }}:\n\n1. a\n2. b\n\n| k | v |\n| --- | --- |\n| x | y |",
+    );
+
+    assert_eq!(
+        "<p>Use <insert type=\"param\" id-ref=\"sc-8_prm_1\"/>:</p>\n\
+         <ol><li>a</li><li>b</li></ol>\n\
+         <table><tr><th>k</th><th>v</th></tr><tr><td>x</td><td>y</td></tr></table>",
+        markup.to_html()
+    );
+    assert_eq!(
+        "Use {{ insert: param, sc-8_prm_1 }}:\n\na\nb\n\nk\tv\nx\ty",
+        markup.to_plain_text()
+    );
+    assert_eq!(
+        markup.blocks(),
+        Markup::from_blocks(&markup.blocks()).blocks()
+    );
+    assert_eq!(
+        markup.blocks(),
+        Markup::from_xml(markup.to_html()).unwrap().blocks()
+    );
+}
+
+#[test]
+fn document_markup_is_markdown() {
+    let markup: Markup =
+        serde_json::from_str("\"<em>Inline</em> HTML in Markdown\"").unwrap();
+
+    assert_eq!(MarkupFormat::Markdown, markup.format());
+    assert_eq!(MarkupFormat::Xml, Markup::parse(markup.as_str()).format());
+}
+
+#[test]
+fn lossless_roundtrip() {
+    let file = File::open("tests/ssp_gen/catalog.json").unwrap();
+    let original: serde_json::Value =
+        serde_json::from_reader(BufReader::new(file)).unwrap();
+
+    let catalog: Catalog = serde_json::from_value(original.clone()).unwrap();
+
+    assert_eq!(original, serde_json::to_value(&catalog).unwrap());
+}
+
+#[test]
+fn parse_options_per_call() {
+    let content =
+        std::fs::read_to_string("tests/ssp_gen/catalog.json").unwrap();
+    let html = ParseOptions::default().with_markup_mode(MarkupMode::Html);
+
+    let handles: Vec<_> = [html, ParseOptions::default()]
+        .into_iter()
+        .map(|options| {
+            let content = content.clone();
+            std::thread::spawn(move || {
+                Catalog::from_str_with(&content, &options).unwrap()
+            })
+        })
+        .collect();
+    let catalogs: Vec<Catalog> =
+        handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    let title = &catalogs[0].catalog.metadata.title;
+    assert_eq!(MarkupFormat::Xml, title.format());
+    assert!(title.starts_with("<p>"));
+
+    let title = &catalogs[1].catalog.metadata.title;
+    assert_eq!(MarkupFormat::Markdown, title.format());
+    assert!(!title.starts_with("<p>"));
+
+    let catalog: Catalog = serde_json::from_str(&content).unwrap();
+    assert_eq!(title, &catalog.catalog.metadata.title);
+}
@@ -0,0 +1,99 @@
+use std::{fs::File, io::BufReader};
+
+use roscal_lib::{
+    control::{catalog::Catalog, param::ParamResolver, profile::Profile},
+    implementation::ssp::SetParameterValue,
+};
+
+fn read_fixtures() -> (Catalog, Profile) {
+    let file = File::open("tests/ssp_gen/catalog.json").unwrap();
+    let catalog: Catalog =
+        serde_json::from_reader(BufReader::new(file)).unwrap();
+
+    let file = File::open("tests/param/profile.json").unwrap();
+    let profile: Profile =
+        serde_json::from_reader(BufReader::new(file)).unwrap();
+
+    (catalog, profile)
+}
+
+fn find_control<'a>(
+    catalog: &'a Catalog,
+    id: &str,
+) -> &'a roscal_lib::control::catalog::Control {
+    let mut controls: Vec<_> = catalog
+        .catalog
+        .groups
+        .iter()
+        .flatten()
+        .flat_map(|g| g.controls.iter().flatten())
+        .collect();
+
+    while let Some(control) = controls.pop() {
+        if control.id == id {
+            return control;
+        }
+        controls.extend(control.controls.iter().flatten());
+    }
+
+    panic!("control {id} not found")
+}
+
+#[test]
+fn param_catalog_fallback() {
+    let (catalog, _) = read_fixtures();
+    let resolver = ParamResolver::new(&catalog);
+
+    assert_eq!(
+        Some(
+            "[Selection (one or more): confidentiality; integrity]".to_owned()
+        ),
+        resolver.resolve("sc-8_prm_1")
+    );
+    assert_eq!(
+        Some(
+            "Implement cryptographic mechanisms to [Assignment: organization-defined safeguards] during transmission."
+                .to_owned()
+        ),
+        resolver.render_statement(find_control(&catalog, "sc-8.1"))
+    );
+    assert_eq!(None, resolver.resolve("unknown"));
+    assert_eq!(
+        "{{ insert: param, unknown }}",
+        resolver.render_prose("{{ insert: param, unknown }}")
+    );
+}
+
+#[test]
+fn param_profile_setting() {
+    let (catalog, profile) = read_fixtures();
+    let resolver = ParamResolver::new(&catalog).with_profile(&profile);
+
+    assert_eq!(
+        Some(
+            "Protect the confidentiality, integrity of transmitted information."
+                .to_owned()
+        ),
+        resolver.render_statement(find_control(&catalog, "sc-8"))
+    );
+    assert_eq!(
+        Some("[Assignment: safeguards defined by the system owner]".to_owned()),
+        resolver.resolve("sc-8.1_prm_1")
+    );
+}
+
+#[test]
+fn param_ssp_value_precedence() {
+    let (catalog, profile) = read_fixtures();
+    let set_parameters = vec![SetParameterValue {
+        param_id: "sc-8_prm_1".parse().unwrap(),
+        remarks: None,
+        values: vec!["integrity".to_owned()],
+        extensions: Default::default(),
+    }];
+    let resolver = ParamResolver::new(&catalog)
+        .with_profile(&profile)
+        .with_set_parameters(&set_parameters);
+
+    assert_eq!(Some("integrity".to_owned()), resolver.resolve("sc-8_prm_1"));
+}
@@ -0,0 +1,93 @@
+use std::{fs::File, io::BufReader};
+
+use roscal_lib::{control::catalog::Catalog, query::Query};
+use serde_json::{json, Value};
+
+fn catalog() -> Value {
+    let file = File::open("tests/catalog/json/basic-catalog.json").unwrap();
+    let catalog: Catalog =
+        serde_json::from_reader(BufReader::new(file)).unwrap();
+
+    serde_json::to_value(catalog).unwrap()
+}
+
+fn select(expr: &str) -> Vec<Value> {
+    let query: Query = expr.parse().unwrap();
+
+    query.select(&catalog()).into_iter().cloned().collect()
+}
+
+#[test]
+fn select_paths() {
+    assert_eq!(vec![json!("s1"), json!("s2")], select("catalog.groups.id"));
+    assert_eq!(vec![json!("s2")], select("$.catalog.groups[1].id"));
+    assert_eq!(select("catalog.groups.id"), select("catalog.groups[*].id"));
+    assert_eq!(
+        vec![
+            json!("s1.1.1"),
+            json!("s1.1.2"),
+            json!("s2.1.1"),
+            json!("s2.1.2")
+        ],
+        select("..controls.id")
+    );
+    assert_eq!(2, select("catalog.*[id]").len());
+    assert_eq!(1, select("$").len());
+}
+
+#[test]
+fn select_predicates() {
+    assert_eq!(
+        vec![json!("s1.1.2")],
+        select("..controls[props[name=label][value='1.1.2']].id")
+    );
+    assert_eq!(
+        vec![json!("s1.1.1"), json!("s2.1.1"), json!("s2.1.2")],
+        select("..controls[props.value != \"1.1.2\"].id")
+    );
+    assert_eq!(
+        vec![json!("s1.1.1")],
+        select("..controls[params[select.how-many=one-or-more]].id")
+    );
+    assert!(select("..controls[props[name=label][value=1.1]]").is_empty());
+}
+
+#[test]
+fn select_pointers() {
+    let catalog = catalog();
+    let query = Query::parse("..controls[id='s2.1.2'].props").unwrap();
+
+    assert_eq!(
+        vec!["/catalog/groups/1/groups/0/controls/1/props/0"],
+        query.pointers(&catalog)
+    );
+    assert_eq!(
+        query.select(&catalog),
+        vec![catalog.pointer(&query.pointers(&catalog)[0]).unwrap()]
+    );
+}
+
+#[test]
+fn select_model() {
+    let file = File::open("tests/catalog/json/basic-catalog.json").unwrap();
+    let catalog: Catalog =
+        serde_json::from_reader(BufReader::new(file)).unwrap();
+    let query = Query::parse("catalog.metadata.title").unwrap();
+
+    assert_eq!(
+        vec![json!(catalog.catalog.metadata.title.as_str())],
+        query.select_model(&catalog).unwrap()
+    );
+}
+
+#[test]
+fn reject_invalid_queries() {
+    for expr in ["catalog.", "catalog[", "..controls[id=x", "a[b='c]", "a b"] {
+        assert!(Query::parse(expr).is_err(), "{expr}");
+    }
+
+    assert_eq!(
+        "invalid query at position 8: expected a field name",
+        Query::parse("catalog.").unwrap_err().to_string()
+    );
+}
@@ -0,0 +1,88 @@
+use std::{fs::File, io::BufReader};
+
+use roscal_lib::{
+    assessment::poam::PlanOfActionAndMilestones, control::catalog::Catalog,
+    implementation::ssp::SystemSecurityPlan, render::Render,
+};
+
+#[test]
+fn render_catalog_substitutes_params() {
+    let file = File::open("tests/catalog/json/basic-catalog.json").unwrap();
+    let catalog: Catalog =
+        serde_json::from_reader(BufReader::new(file)).unwrap();
+
+    let res = catalog.to_markdown();
+
+    assert!(res.starts_with("# "));
+    assert!(res.contains(
+        "A value has been assigned to [Selection (one or more): initiating a device lock after [Assignment: a duration] of inactivity; requiring the user to initiate a device lock before leaving the system unattended]."
+    ));
+    assert!(!res.contains("{{ insert: param"));
+}
+
+#[test]
+fn render_catalog_statement_items() {
+    let file = File::open("tests/ssp_gen/catalog.json").unwrap();
+    let catalog: Catalog =
+        serde_json::from_reader(BufReader::new(file)).unwrap();
+
+    let res = catalog.to_markdown();
+
+    assert!(res.contains(
+        "Protect the [Selection (one or more): confidentiality; integrity] of transmitted information."
+    ));
+    assert!(res.contains(
+        "Implement cryptographic mechanisms to [Assignment: organization-defined safeguards] during transmission."
+    ));
+}
+
+#[test]
+fn render_ssp_html() {
+    let file = File::open("tests/ssp/json/ssp-example.json").unwrap();
+    let ssp: SystemSecurityPlan =
+        serde_json::from_reader(BufReader::new(file)).unwrap();
+
+    let res = ssp.to_html();
+
+    assert!(res.starts_with("<!DOCTYPE html>"));
+    assert!(res.contains(
+        "<title>Enterprise Logging and Auditing System Security Plan</title>"
+    ));
+    assert!(res.contains("<h2>Control Implementation</h2>"));
+}
+
+#[test]
+fn render_poam_markdown() {
+    let file =
+        File::open("tests/poam/json/ifa_plan-of-action-and-milestones.json")
+            .unwrap();
+    let poam: PlanOfActionAndMilestones =
+        serde_json::from_reader(BufReader::new(file)).unwrap();
+
+    let res = poam.to_markdown();
+
+    assert!(res.starts_with("# IFA GoodRead Plan of Action and Milestones"));
+    assert!(res.contains("## POA&M Items"));
+    assert!(res.contains("#### Remediation: IFA-GOODREAD-RISK1-RESPONSE"));
+}
+
+#[test]
+fn render_poam_html_escapes_markup() {
+    let file =
+        File::open("tests/poam/json/ifa_plan-of-action-and-milestones.json")
+            .unwrap();
+    let mut poam: serde_json::Value =
+        serde_json::from_reader(BufReader::new(file)).unwrap();
+    let item = &mut poam["plan-of-action-and-milestones"]["poam-items"][0];
+    item["description"] =
+        "<script>alert(1)</script>\n\nSee <img src=x onerror=alert(2)> here."
+            .into();
+    let poam: PlanOfActionAndMilestones = serde_json::from_value(poam).unwrap();
+
+    let res = poam.to_html();
+
+    assert!(!res.contains("<script>"));
+    assert!(!res.contains("<img"));
+    assert!(res.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+    assert!(res.contains("See &lt;img src=x onerror=alert(2)&gt; here."));
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "typed-scalars")]
+
+use std::{fs::File, io::BufReader};
+
+use chrono::{DateTime, NaiveDate};
+use roscal_lib::{
+    control::catalog::{Catalog, DocumentMetadataBuilder},
+    scalar::{DateTimeWithTz, OscalDate, OscalUuid, Token, UriReference},
+};
+
+#[test]
+fn validated_constructors() {
+    assert!(OscalUuid::new("74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724").is_ok());
+    assert!(OscalUuid::new("not-a-uuid").is_err());
+    assert!(DateTimeWithTz::new("2023-12-31T23:59:59Z").is_ok());
+    assert!(DateTimeWithTz::new("2023-12-31T23:59:59").is_err());
+    assert!(OscalDate::new("2023-12-31").is_ok());
+    assert!(OscalDate::new("2023-02-30").is_err());
+    assert!(Token::new("ac-1_smt.a").is_ok());
+    assert!(Token::new("1ac").is_err());
+    assert!(UriReference::new("#ref").is_ok());
+
+    assert_eq!(
+        "invalid token pattern: `1ac`",
+        "1ac".parse::<Token>().unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn conversions() {
+    let uuid = OscalUuid::new_v4();
+    assert_eq!(uuid.as_str(), uuid.to_uuid().to_string());
+    assert!(OscalUuid::try_from(uuid::Uuid::nil()).is_err());
+
+    let datetime =
+        DateTime::parse_from_rfc3339("2023-12-31T23:59:59+01:00").unwrap();
+    assert_eq!(datetime, DateTimeWithTz::from(datetime).to_datetime());
+
+    let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    assert_eq!(date, OscalDate::try_from(date).unwrap().to_naive_date());
+
+    let href = UriReference::new("catalog.json#ac-1").unwrap();
+    assert_eq!(
+        Some("ac-1"),
+        href.to_uri_reference().fragment().map(|f| f.as_str())
+    );
+}
+
+#[test]
+fn builders_take_typed_values() {
+    let metadata = DocumentMetadataBuilder::default()
+        .title("catalog")
+        .version("1")
+        .oscal_version("1.1.2")
+        .last_modified(DateTimeWithTz::new("2023-12-31T23:59:59Z").unwrap())
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        "2023-12-31T23:59:59Z",
+        serde_json::to_value(&metadata).unwrap()["last-modified"]
+    );
+}
+
+#[test]
+fn same_wire_format() {
+    let file = File::open("tests/ssp_gen/catalog.json").unwrap();
+    let original: serde_json::Value =
+        serde_json::from_reader(BufReader::new(file)).unwrap();
+
+    let catalog: Catalog = serde_json::from_value(original.clone()).unwrap();
+    assert_eq!(original, serde_json::to_value(&catalog).unwrap());
+
+    let mut invalid = original;
+    invalid["catalog"]["uuid"] = "not-a-uuid".into();
+    assert!(serde_json::from_value::<Catalog>(invalid).is_err());
+}
@@ -0,0 +1,134 @@
+use std::fs::{read_dir, read_to_string};
+
+use roscal_lib::{document::ModelType, schema};
+use serde_json::json;
+
+#[test]
+fn examples_conform_to_schemas() {
+    let dirs = [
+        "tests/ap",
+        "tests/ar",
+        "tests/poam",
+        "tests/catalog",
+        "tests/profile",
+        "tests/component-definition",
+        "tests/ssp",
+    ];
+
+    for dir in dirs {
+        for format in ["json", "yaml"] {
+            for e in read_dir(format!("{dir}/{format}")).unwrap() {
+                let path = e.unwrap().path();
+                let input = read_to_string(&path).unwrap();
+
+                let violations = schema::validate_str(&input).unwrap();
+                assert!(
+                    violations.is_empty(),
+                    "{}: {violations:?}",
+                    path.display()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn malformed_fields_break_schemas() {
+    let cases = [
+        (
+            "tests/malformed/date.yaml",
+            "/system-security-plan/system-characteristics/date-authorized",
+        ),
+        (
+            "tests/malformed/datetime.yaml",
+            "/profile/metadata/last-modified",
+        ),
+        (
+            "tests/malformed/email.yaml",
+            "/profile/metadata/parties/0/email-addresses/0",
+        ),
+        ("tests/malformed/uuid.yaml", "/profile/uuid"),
+    ];
+
+    for (file, pointer) in cases {
+        let input = read_to_string(file).unwrap();
+        let violations = schema::validate_str(&input).unwrap();
+
+        assert_eq!(1, violations.len(), "{file}: {violations:?}");
+        assert_eq!(pointer, violations[0].pointer);
+    }
+}
+
+#[test]
+fn missing_and_mistyped_properties() {
+    let catalog = json!({
+        "catalog": {
+            "metadata": {
+                "title": "Example catalog",
+                "last-modified": "2023-12-31T23:59:59Z",
+                "version": 1,
+                "oscal-version": "1.1.2"
+            },
+            "controls": []
+        }
+    });
+
+    let violations: Vec<String> =
+        schema::validate(ModelType::Catalog, &catalog)
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+    assert_eq!(
+        vec![
+            "/catalog: missing required property `uuid`",
+            "/catalog/metadata/version: expected string, found number",
+            "/catalog/controls: expected at least 1 item(s)",
+        ],
+        violations
+    );
+}
+
+#[test]
+fn wrong_root() {
+    let violations = schema::validate(ModelType::Profile, &json!([])).unwrap();
+
+    assert_eq!(1, violations.len());
+    assert_eq!(
+        "(root): expected object, found array",
+        violations[0].to_string()
+    );
+}
+
+#[test]
+fn combinator_reasons() {
+    let catalog = json!({
+        "catalog": {
+            "uuid": "74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724",
+            "metadata": {
+                "title": "Example catalog",
+                "last-modified": "2023-12-31T23:59:59Z",
+                "version": "1",
+                "oscal-version": "1.1.2",
+                "links": [{ "href": "#ref-1", "rel": "see also" }]
+            }
+        }
+    });
+
+    let violations = schema::validate(ModelType::Catalog, &catalog).unwrap();
+
+    assert_eq!(1, violations.len(), "{violations:?}");
+    assert_eq!("/catalog/metadata/links/0/rel", violations[0].pointer);
+    let message = &violations[0].message;
+    assert!(
+        message.starts_with(
+            "does not match any of the allowed schemas (1: \"see also\" does not match `"
+        ),
+        "{message}"
+    );
+    assert!(
+        message.ends_with("; 2: \"see also\" is not one of [\"reference\"])"),
+        "{message}"
+    );
+}
@@ -0,0 +1,99 @@
+use std::{fs::File, io::BufReader};
+
+use roscal_lib::{
+    control::catalog::Catalog,
+    implementation::{
+        component_definition::ComponentDefinition,
+        ssp_gen::gen_control_implementation,
+    },
+};
+
+fn read_fixtures() -> (Catalog, ComponentDefinition) {
+    let file = File::open("tests/ssp_gen/catalog.json").unwrap();
+    let catalog: Catalog =
+        serde_json::from_reader(BufReader::new(file)).unwrap();
+
+    let file = File::open(
+        "tests/component-definition/json/example-component-definition.json",
+    )
+    .unwrap();
+    let compdef: ComponentDefinition =
+        serde_json::from_reader(BufReader::new(file)).unwrap();
+
+    (catalog, compdef)
+}
+
+#[test]
+fn gen_requirement_per_selected_control() {
+    let (catalog, compdef) = read_fixtures();
+
+    let res = gen_control_implementation(&catalog, &[compdef], "generated")
+        .control_implementation;
+
+    let ids: Vec<&str> = res
+        .implemented_requirements
+        .iter()
+        .map(|r| r.control_id.as_str())
+        .collect();
+
+    assert_eq!(vec!["sa-4", "sa-4.9", "sc-8", "sc-8.1"], ids);
+    assert!(res.implemented_requirements[0].by_components.is_none());
+}
+
+#[test]
+fn gen_by_components_and_params() {
+    let (catalog, compdef) = read_fixtures();
+
+    let res = gen_control_implementation(&catalog, &[compdef], "generated")
+        .control_implementation;
+    let sc_8 = &res.implemented_requirements[2];
+    let by_components = sc_8.by_components.as_ref().unwrap();
+
+    assert_eq!(1, by_components.len());
+    assert_eq!(
+        "91f646c5-b1b6-4786-9ec3-2305a044e217",
+        by_components[0].component_uuid
+    );
+
+    let params = by_components[0].set_parameters.as_ref().unwrap();
+    assert_eq!("sc-8_prm_1", params[0].param_id);
+    assert_eq!(vec!["confidentiality".to_owned()], params[0].values);
+
+    let statements = sc_8.statements.as_ref().unwrap();
+    assert_eq!("sc-8_smt", statements[0].statement_id);
+    assert_eq!(
+        "91f646c5-b1b6-4786-9ec3-2305a044e217",
+        statements[0].by_components.as_ref().unwrap()[0].component_uuid
+    );
+
+    assert!(serde_json::to_string(&res).is_ok())
+}
+
+#[test]
+fn gen_components_for_every_reference() {
+    let (catalog, compdef) = read_fixtures();
+
+    let res = gen_control_implementation(&catalog, &[compdef], "generated");
+    let uuids: Vec<&str> =
+        res.components.iter().map(|c| c.uuid.as_str()).collect();
+    assert!(!uuids.is_empty());
+
+    for req in &res.control_implementation.implemented_requirements {
+        let by_components = req.by_components.iter().flatten().chain(
+            req.statements
+                .iter()
+                .flatten()
+                .flat_map(|s| s.by_components.iter().flatten()),
+        );
+        for by_component in by_components {
+            assert!(
+                uuids.contains(&by_component.component_uuid.as_str()),
+                "{} of {}",
+                by_component.component_uuid,
+                req.control_id
+            );
+        }
+    }
+
+    assert!(serde_json::to_string(&res.components).is_ok())
+}
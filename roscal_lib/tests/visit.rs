@@ -0,0 +1,68 @@
+use std::{fs::File, io::BufReader};
+
+use roscal_lib::control::catalog::{
+    visit::{self, Visit},
+    visit_mut::VisitMut,
+    Catalog, Control, Property,
+};
+
+fn catalog() -> Catalog {
+    let file = File::open("tests/catalog/json/basic-catalog.json").unwrap();
+
+    serde_json::from_reader(BufReader::new(file)).unwrap()
+}
+
+#[derive(Default)]
+struct Labels<'ast> {
+    controls: Vec<&'ast str>,
+    labels: Vec<&'ast str>,
+}
+
+impl<'ast> Visit<'ast> for Labels<'ast> {
+    fn visit_control(&mut self, node: &'ast Control) {
+        self.controls.push(&node.id);
+        visit::walk_control(self, node);
+    }
+
+    fn visit_property(&mut self, node: &'ast Property) {
+        if node.name == "label" {
+            self.labels.push(&node.value);
+        }
+    }
+}
+
+#[test]
+fn visit_catalog() {
+    let catalog = catalog();
+    let mut labels = Labels::default();
+
+    labels.visit_catalog(&catalog);
+
+    assert_eq!(
+        vec!["s1.1.1", "s1.1.2", "s2.1.1", "s2.1.2"],
+        labels.controls
+    );
+    assert_eq!(8, labels.labels.len());
+    assert!(labels.labels.contains(&"Other information"));
+}
+
+struct Relabel;
+
+impl VisitMut for Relabel {
+    fn visit_property_mut(&mut self, node: &mut Property) {
+        node.value = format!("§{}", node.value);
+    }
+}
+
+#[test]
+fn visit_mut_catalog() {
+    let mut catalog = catalog();
+
+    Relabel.visit_catalog_mut(&mut catalog);
+
+    let mut labels = Labels::default();
+    labels.visit_catalog(&catalog);
+
+    assert_eq!(8, labels.labels.len());
+    assert!(labels.labels.iter().all(|label| label.starts_with('§')));
+}
@@ -0,0 +1,199 @@
+use std::fs::{read_dir, File};
+
+use quick_xml::{events::Event, Reader};
+use roscal_lib::{
+    control::catalog::Catalog,
+    document::{AnyOscalDocument, DocumentError, ModelType},
+    parse::{MarkupMode, ParseOptions, ParseWith},
+    xml,
+};
+
+const CATALOG: &str = r##"
+catalog:
+  uuid: 74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724
+  metadata:
+    title: Example catalog
+    last-modified: 2023-12-31T23:59:59Z
+    version: "1"
+    oscal-version: 1.1.2
+    document-ids:
+      - scheme: http://www.doi.org/
+        identifier: 10.6028/NIST.SP.800-53r5
+  controls:
+    - id: ac-1
+      class: SP800-53
+      title: Policy and Procedures
+      links:
+        - href: "#ref-1"
+          rel: reference
+      params:
+        - id: ac-1_prm_1
+          label: personnel or roles
+      parts:
+        - id: ac-1_smt
+          name: statement
+          prose: "Disseminate to {{ insert: param, ac-1_prm_1 }}."
+          links:
+            - href: "#ref-2"
+          parts:
+            - id: ac-1_smt.a
+              name: item
+              prose: "Review <b>annually</b> & after incidents."
+  back-matter:
+    resources:
+      - uuid: 2b4a1b3a-cbc1-4b5c-9dd2-1e8c2b7a0f31
+        rlinks:
+          - href: policy.txt
+            hashes:
+              - algorithm: SHA-256
+                value: 0b3a93c37b341b2806c6278c18f3bc96fa64debef80629dcc74ff6b9388efdfa
+"##;
+
+fn catalog() -> AnyOscalDocument {
+    AnyOscalDocument::from_str_with(CATALOG, &ParseOptions::default()).unwrap()
+}
+
+/// Read the whole document, failing on mismatched or unclosed elements
+fn assert_well_formed(output: &str) {
+    let mut reader = Reader::from_str(output);
+    let mut depth = 0;
+
+    loop {
+        match reader.read_event().unwrap() {
+            Event::Start(_) => depth += 1,
+            Event::End(_) => depth -= 1,
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    assert_eq!(0, depth);
+}
+
+#[test]
+fn xml_every_model() {
+    let dirs = [
+        ("tests/ap", ModelType::AssessmentPlan),
+        ("tests/ar", ModelType::AssessmentResults),
+        ("tests/poam", ModelType::PlanOfActionAndMilestones),
+        ("tests/catalog", ModelType::Catalog),
+        ("tests/profile", ModelType::Profile),
+        ("tests/component-definition", ModelType::ComponentDefinition),
+        ("tests/ssp", ModelType::SystemSecurityPlan),
+    ];
+
+    for (dir, model_type) in dirs {
+        for format in ["json", "yaml"] {
+            for e in read_dir(format!("{dir}/{format}")).unwrap() {
+                let document = AnyOscalDocument::from_reader_with(
+                    File::open(e.unwrap().path()).unwrap(),
+                    &ParseOptions::default(),
+                )
+                .unwrap();
+                let output = xml::to_string(&document).unwrap();
+
+                assert_well_formed(&output);
+                assert_eq!(model_type, ModelType::detect(&output).unwrap());
+            }
+        }
+    }
+}
+
+#[test]
+fn xml_catalog_elements() {
+    let output = xml::to_string(&catalog()).unwrap();
+    assert_well_formed(&output);
+
+    assert!(output.starts_with(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<catalog xmlns="http://csrc.nist.gov/ns/oscal/1.0" uuid="74c8ba1e-5cd4-4ad1-bbfd-d888e2f6c724">
+  <metadata>
+    <title>Example catalog</title>
+    <last-modified>2023-12-31T23:59:59Z</last-modified>
+    <version>1</version>
+    <oscal-version>1.1.2</oscal-version>
+    <document-id scheme="http://www.doi.org/">10.6028/NIST.SP.800-53r5</document-id>
+  </metadata>
+"#
+    ));
+
+    // Elements follow the model order, prose is written as part content and
+    // raw HTML as text
+    assert!(output.contains(
+        r##"  <control class="SP800-53" id="ac-1">
+    <title>Policy and Procedures</title>
+    <param id="ac-1_prm_1">
+      <label>personnel or roles</label>
+    </param>
+    <link href="#ref-1" rel="reference"/>
+    <part id="ac-1_smt" name="statement">
+      <p>Disseminate to <insert type="param" id-ref="ac-1_prm_1"/>.</p>
+      <part id="ac-1_smt.a" name="item">
+        <p>Review &lt;b&gt;annually&lt;/b&gt; &amp; after incidents.</p>
+      </part>
+      <link href="#ref-2"/>
+    </part>
+  </control>
+"##
+    ));
+    assert!(output.ends_with(
+        r#"  <back-matter>
+    <resource uuid="2b4a1b3a-cbc1-4b5c-9dd2-1e8c2b7a0f31">
+      <rlink href="policy.txt">
+        <hash algorithm="SHA-256">0b3a93c37b341b2806c6278c18f3bc96fa64debef80629dcc74ff6b9388efdfa</hash>
+      </rlink>
+    </resource>
+  </back-matter>
+</catalog>"#
+    ));
+}
+
+#[test]
+fn xml_compact() {
+    let output = xml::to_string_compact(&catalog()).unwrap();
+
+    assert_well_formed(&output);
+    assert_eq!(1, output.lines().count());
+    assert!(output.contains("<title>Example catalog</title><last-modified>"));
+}
+
+#[test]
+fn xml_markup_modes() {
+    let html = ParseOptions::default().with_markup_mode(MarkupMode::Html);
+    let doc = AnyOscalDocument::from_str_with(CATALOG, &html).unwrap();
+
+    let output = xml::to_string(&doc).unwrap();
+
+    assert_well_formed(&output);
+    assert!(output.contains("<title>Example catalog</title>"));
+    assert!(output.contains(
+        "<p>Disseminate to <insert type=\"param\" id-ref=\"ac-1_prm_1\"/>.</p>"
+    ));
+}
+
+#[test]
+fn xml_is_export_only() {
+    let doc =
+        AnyOscalDocument::from_str_with(CATALOG, &ParseOptions::default())
+            .unwrap();
+    let output = xml::to_string(&doc).unwrap();
+
+    assert_eq!(ModelType::Catalog, ModelType::detect(&output).unwrap());
+    let err =
+        AnyOscalDocument::from_str_with(&output, &ParseOptions::default())
+            .unwrap_err();
+    assert!(matches!(
+        err,
+        DocumentError::UnsupportedXml(ModelType::Catalog)
+    ));
+    assert_eq!(
+        "XML catalog documents can't be read, the XML encoding is export only, read the JSON or YAML document it was written from instead",
+        err.to_string()
+    );
+
+    let err =
+        Catalog::from_str_with(&output, &ParseOptions::default()).unwrap_err();
+    assert_eq!(
+        "XML documents can't be read, the XML encoding is export only, read the JSON or YAML document it was written from instead",
+        err.to_string()
+    );
+}